@@ -0,0 +1,28 @@
+//
+// Copyright (c) 2022-2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::env;
+use std::path::Path;
+use wayland_scanner::Side;
+use wayland_scanner::generate_code;
+
+static PROTOCOLS: &[&str] = &[
+    "cursor-shape-v1",
+    "fractional-scale-v1",
+    "pointer-gestures-unstable-v1"
+];
+
+fn main()
+{
+    let out_dir_string = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(out_dir_string.as_str());
+    for protocol in PROTOCOLS {
+        let protocol_file_string = format!("protocols/{}.xml", protocol);
+        println!("cargo:rerun-if-changed={}", protocol_file_string);
+        generate_code(protocol_file_string.as_str(), out_dir.join(format!("{}_client_api.rs", protocol)), Side::Client);
+    }
+}
@@ -8,6 +8,7 @@
 use std::collections::VecDeque;
 use crate::client_context::*;
 use crate::queue_context::*;
+use crate::types::*;
 use crate::window_context::*;
 
 /// A structure of callback queue.
@@ -18,7 +19,7 @@ use crate::window_context::*;
 /// thread signal to a graphic thread. The callback queue empties after an event queue.
 pub struct CallbackQueue
 {
-    callbacks: VecDeque<Box<dyn FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext) -> Option<()> + Send + Sync + 'static>>,
+    callbacks: VecDeque<(Option<SeatId>, Box<dyn FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext) -> Option<()> + Send + Sync + 'static>)>,
 }
 
 impl CallbackQueue
@@ -30,15 +31,29 @@ impl CallbackQueue
     pub fn is_empty(&self) -> bool
     { self.callbacks.is_empty() }
     
-    /// Pushes a dynamic callback to the callback queue.
+    /// Pushes a dynamic callback without a seat identifier to the callback queue.
     pub fn push_dyn(&mut self, f: Box<dyn FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext) -> Option<()> + Send + Sync + 'static>)
-    { self.callbacks.push_back(f); }
+    { self.push_dyn_with_seat_id(None, f); }
     
-    /// Pushes a callback to the callback queue.
+    /// Pushes a callback without a seat identifier to the callback queue.
     pub fn push<F>(&mut self, f: F)
         where F: FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext) -> Option<()> + Send + Sync + 'static
     { self.push_dyn(Box::new(f)); }
+
+    /// Pushes a dynamic callback with the seat identifier to the callback queue.
+    ///
+    /// The seat identifier is restored as the seat identifier of the current event when the
+    /// callback is called.
+    pub fn push_dyn_with_seat_id(&mut self, seat_id: Option<SeatId>, f: Box<dyn FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext) -> Option<()> + Send + Sync + 'static>)
+    { self.callbacks.push_back((seat_id, f)); }
+
+    /// Pushes a callback with the seat identifier to the callback queue.
+    ///
+    /// See [`push_dyn_with_seat_id`](Self::push_dyn_with_seat_id).
+    pub fn push_with_seat_id<F>(&mut self, seat_id: Option<SeatId>, f: F)
+        where F: FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext) -> Option<()> + Send + Sync + 'static
+    { self.push_dyn_with_seat_id(seat_id, Box::new(f)); }
     
-    pub(crate) fn pop(&mut self) -> Option<Box<dyn FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext) -> Option<()> + Send + Sync + 'static>>
+    pub(crate) fn pop(&mut self) -> Option<(Option<SeatId>, Box<dyn FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext) -> Option<()> + Send + Sync + 'static>)>
     { self.callbacks.pop_front() }
 }
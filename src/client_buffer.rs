@@ -0,0 +1,337 @@
+//
+// Copyright (c) 2022-2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::cell::*;
+use std::cmp::max;
+#[cfg(target_os = "linux")]
+use std::ffi::CStr;
+use std::fs::*;
+#[cfg(target_os = "linux")]
+use std::os::unix::io::FromRawFd;
+use std::os::unix::io::AsRawFd;
+use std::rc::*;
+use cairo::Format;
+use cairo::ImageSurface;
+use memmap2::MmapOptions;
+use memmap2::MmapMut;
+#[cfg(target_os = "linux")]
+use nix::sys::memfd::MemFdCreateFlag;
+#[cfg(target_os = "linux")]
+use nix::sys::memfd::memfd_create;
+#[cfg(not(target_os = "linux"))]
+use tempfile;
+use wayland_client::protocol::wl_buffer;
+use wayland_client::protocol::wl_shm;
+use wayland_client::protocol::wl_shm_pool;
+use wayland_client::protocol::wl_surface;
+use wayland_client::Main;
+use crate::client_context::*;
+use crate::client_error::*;
+
+pub(crate) struct ClientBuffer
+{
+    buffer: Main<wl_buffer::WlBuffer>,
+    offset: usize,
+    width: i32,
+    height: i32,
+    stride: i32,
+    is_busy: Rc<Cell<bool>>,
+}
+
+impl ClientBuffer
+{
+    fn len(&self) -> usize
+    { (self.stride as usize) * (self.height as usize) }
+
+    fn is_busy(&self) -> bool
+    { self.is_busy.get() }
+}
+
+pub(crate) struct ClientBufferPool
+{
+    file: File,
+    mmap: MmapMut,
+    shm_pool: Main<wl_shm_pool::WlShmPool>,
+    size: usize,
+    buffers: Vec<ClientBuffer>,
+    old_buffers: Vec<ClientBuffer>,
+    front_index: Option<usize>,
+}
+
+/// Finds the offset of a free range with the length in a pool of the size.
+///
+/// The used ranges are pairs of an offset and a length. This function returns the offset of the
+/// first gap between the used ranges or after the used ranges that fits the length, otherwise
+/// `None`.
+fn find_free_offset(ranges: &[(usize, usize)], len: usize, size: usize) -> Option<usize>
+{
+    let mut ranges = ranges.to_vec();
+    ranges.sort();
+    let mut offset = 0;
+    for range in &ranges {
+        if range.0 >= offset + len {
+            return Some(offset);
+        }
+        offset = max(offset, range.0 + range.1);
+    }
+    if offset + len <= size {
+        Some(offset)
+    } else {
+        None
+    }
+}
+
+/// Allocates a range with the length in a pool of the size.
+///
+/// This function returns the offset of the allocated range and a new size of the pool. The pool
+/// grows to double size or to the size that fits the range after the used ranges if the pool
+/// doesn't have a free range.
+fn alloc_offset(ranges: &[(usize, usize)], len: usize, size: usize) -> (usize, usize)
+{
+    match find_free_offset(ranges, len, size) {
+        Some(offset) => (offset, size),
+        None => {
+            let end = ranges.iter().map(|r| r.0 + r.1).max().unwrap_or(0);
+            (end, max(end + len, size * 2))
+        },
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn create_shm_file(_client_context_fields: &ClientContextFields) -> Result<File, ClientError>
+{
+    let name = unsafe { CStr::from_bytes_with_nul_unchecked(b"lwltk\0") };
+    match memfd_create(name, MemFdCreateFlag::MFD_CLOEXEC) {
+        Ok(fd) => Ok(unsafe { File::from_raw_fd(fd) }),
+        Err(err) => Err(ClientError::Nix(err)),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn create_shm_file(client_context_fields: &ClientContextFields) -> Result<File, ClientError>
+{
+    let mut tempfile_builder = tempfile::Builder::new();
+    tempfile_builder.prefix("lwltk-");
+    match tempfile_builder.tempfile_in(client_context_fields.xdg_runtime_dir.as_str()) {
+        Ok(named_temp_file) => Ok(named_temp_file.into_file()),
+        Err(err) => Err(ClientError::Io(err)),
+    }
+}
+
+fn map_shm_file(file: &File, size: usize) -> Result<MmapMut, ClientError>
+{
+    match file.set_len(size as u64) {
+        Ok(()) => {
+            let mut mmap_opts = MmapOptions::new();
+            mmap_opts.len(size);
+            match unsafe { mmap_opts.map_mut(file) } {
+                Ok(mmap) => Ok(mmap),
+                Err(err) => Err(ClientError::Io(err)),
+            }
+        },
+        Err(err) => Err(ClientError::Io(err)),
+    }
+}
+
+impl ClientBufferPool
+{
+    pub(crate) fn new(client_context_fields: &ClientContextFields, size: usize) -> Result<Self, ClientError>
+    {
+        let size = max(size, 1);
+        let file = create_shm_file(client_context_fields)?;
+        let mmap = map_shm_file(&file, size)?;
+        let shm_pool = client_context_fields.shm.create_pool(file.as_raw_fd(), size as i32);
+        Ok(ClientBufferPool {
+                file,
+                mmap,
+                shm_pool,
+                size,
+                buffers: Vec::new(),
+                old_buffers: Vec::new(),
+                front_index: None,
+        })
+    }
+
+    fn destroy_free_old_buffers(&mut self)
+    {
+        self.old_buffers.retain(|b| {
+                if !b.is_busy() {
+                    b.buffer.destroy();
+                    false
+                } else {
+                    true
+                }
+        });
+    }
+
+    fn used_ranges(&self) -> Vec<(usize, usize)>
+    { self.buffers.iter().chain(self.old_buffers.iter()).map(|b| (b.offset, b.len())).collect() }
+
+    fn grow(&mut self, new_size: usize) -> Result<(), ClientError>
+    {
+        // Cairo surfaces never outlive drawing, so the memory can be remapped.
+        self.mmap = map_shm_file(&self.file, new_size)?;
+        self.shm_pool.resize(new_size as i32);
+        self.size = new_size;
+        Ok(())
+    }
+
+    /// Returns the index of a free buffer with the specified size.
+    ///
+    /// Buffers of other size are destroyed after they are released by the compositor. A new
+    /// buffer is allocated only if all buffers are busy.
+    pub(crate) fn next_buffer(&mut self, width: i32, height: i32) -> Result<usize, ClientError>
+    {
+        let mut i = 0;
+        while i < self.buffers.len() {
+            if self.buffers[i].width != width || self.buffers[i].height != height {
+                let buffer = self.buffers.remove(i);
+                self.old_buffers.push(buffer);
+                self.front_index = None;
+            } else {
+                i += 1;
+            }
+        }
+        self.destroy_free_old_buffers();
+        match self.buffers.iter().position(|b| !b.is_busy()) {
+            Some(idx) => Ok(idx),
+            None => {
+                let stride = match Format::ARgb32.stride_for_width(width as u32) {
+                    Ok(tmp_stride) => tmp_stride,
+                    Err(err) => return Err(ClientError::Cairo(err)),
+                };
+                let len = (stride as usize) * (height as usize);
+                // Busy old buffers are still used by the compositor, so their ranges aren't reused.
+                let (offset, new_size) = alloc_offset(self.used_ranges().as_slice(), len, self.size);
+                if new_size != self.size {
+                    self.grow(new_size)?;
+                }
+                let buffer = self.shm_pool.create_buffer(offset as i32, width, height, stride, wl_shm::Format::Argb8888);
+                let is_busy = Rc::new(Cell::new(false));
+                let is_busy2 = is_busy.clone();
+                buffer.quick_assign(move |_, event, _| {
+                        match event {
+                            wl_buffer::Event::Release => is_busy2.set(false),
+                            _ => (),
+                        }
+                });
+                self.buffers.push(ClientBuffer { buffer, offset, width, height, stride, is_busy, });
+                Ok(self.buffers.len() - 1)
+            },
+        }
+    }
+
+    pub(crate) fn create_cairo_surface(&mut self, idx: usize) -> Result<ImageSurface, ClientError>
+    {
+        match self.buffers.get(idx) {
+            Some(buffer) => {
+                let ptr = unsafe { self.mmap.as_mut_ptr().add(buffer.offset) };
+                match unsafe { ImageSurface::create_for_data_unsafe(ptr, Format::ARgb32, buffer.width, buffer.height, buffer.stride) } {
+                    Ok(cairo_surface) => Ok(cairo_surface),
+                    Err(err) => Err(ClientError::Cairo(err)),
+                }
+            },
+            None => Err(ClientError::NoBuffer),
+        }
+    }
+
+    /// Copies the content of the last attached buffer to the buffer.
+    ///
+    /// Returns `true` if the buffer has the content of the last attached buffer, otherwise
+    /// `false`.
+    pub(crate) fn copy_front_buffer(&mut self, idx: usize) -> bool
+    {
+        match self.front_index {
+            Some(front_idx) if front_idx == idx => true,
+            Some(front_idx) => {
+                match (self.buffers.get(front_idx), self.buffers.get(idx)) {
+                    (Some(front_buffer), Some(buffer)) => {
+                        let src = front_buffer.offset..(front_buffer.offset + front_buffer.len());
+                        let dst = buffer.offset;
+                        self.mmap.copy_within(src, dst);
+                        true
+                    },
+                    (_, _) => false,
+                }
+            },
+            None => false,
+        }
+    }
+
+    pub(crate) fn attach(&mut self, idx: usize, surface: &wl_surface::WlSurface) -> Result<(), ClientError>
+    {
+        match self.buffers.get(idx) {
+            Some(buffer) => {
+                surface.attach(Some(&buffer.buffer), 0, 0);
+                buffer.is_busy.set(true);
+                self.front_index = Some(idx);
+                Ok(())
+            },
+            None => Err(ClientError::NoBuffer),
+        }
+    }
+
+    pub(crate) fn destroy(&self)
+    {
+        for buffer in self.buffers.iter().chain(self.old_buffers.iter()) {
+            buffer.buffer.destroy();
+        }
+        self.shm_pool.destroy();
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_find_free_offset_finds_first_gap()
+    {
+        let ranges = vec![(40, 10), (0, 10), (15, 10)];
+        assert_eq!(Some(10), find_free_offset(ranges.as_slice(), 5, 100));
+        assert_eq!(Some(25), find_free_offset(ranges.as_slice(), 10, 100));
+    }
+
+    #[test]
+    fn test_find_free_offset_finds_tail_gap()
+    {
+        let ranges = vec![(0, 10), (10, 20)];
+        assert_eq!(Some(30), find_free_offset(ranges.as_slice(), 20, 50));
+        assert_eq!(Some(0), find_free_offset(&[], 50, 50));
+    }
+
+    #[test]
+    fn test_find_free_offset_does_not_find_gap()
+    {
+        let ranges = vec![(0, 10), (15, 20), (40, 10)];
+        assert_eq!(None, find_free_offset(ranges.as_slice(), 6, 50));
+        assert_eq!(None, find_free_offset(&[], 51, 50));
+    }
+
+    #[test]
+    fn test_alloc_offset_reuses_gap_without_growing_pool()
+    {
+        let ranges = vec![(0, 20), (30, 20)];
+        assert_eq!((20, 50), alloc_offset(ranges.as_slice(), 10, 50));
+    }
+
+    #[test]
+    fn test_alloc_offset_grows_pool_for_too_small_gap()
+    {
+        let ranges = vec![(0, 20), (30, 20)];
+        assert_eq!((50, 100), alloc_offset(ranges.as_slice(), 20, 50));
+    }
+
+    #[test]
+    fn test_alloc_offset_grows_pool_to_fit_range()
+    {
+        let ranges = vec![(0, 20), (20, 30)];
+        assert_eq!((50, 100), alloc_offset(ranges.as_slice(), 40, 50));
+        assert_eq!((50, 120), alloc_offset(ranges.as_slice(), 70, 50));
+    }
+}
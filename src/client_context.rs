@@ -6,6 +6,7 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 //
 use std::cell::*;
+use std::cmp::max;
 use std::cmp::min;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
@@ -14,6 +15,7 @@ use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::env;
 use std::io::ErrorKind;
+use std::mem::take;
 use std::rc::*;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -22,12 +24,18 @@ use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
 use std::time::Instant;
+use cairo::Operator;
 use nix::errno::Errno;
 use nix::poll::PollFd;
 use nix::poll::PollFlags;
 use nix::poll::poll;
 use wayland_client::protocol::wl_compositor;
+use wayland_client::protocol::wl_data_device;
+use wayland_client::protocol::wl_data_device_manager;
+use wayland_client::protocol::wl_data_offer;
+use wayland_client::protocol::wl_data_source;
 use wayland_client::protocol::wl_keyboard;
+use wayland_client::protocol::wl_output;
 use wayland_client::protocol::wl_pointer;
 use wayland_client::protocol::wl_seat;
 use wayland_client::protocol::wl_shell_surface;
@@ -40,12 +48,31 @@ use wayland_client::EventQueue as WaylandEventQueue;
 use wayland_client::Filter;
 use wayland_client::GlobalManager;
 use wayland_client::Main;
-use wayland_cursor::CursorTheme;
-use wayland_cursor::Cursor as WaylandCursor;
+use wayland_protocols::unstable::primary_selection::v1::client::zwp_primary_selection_device_manager_v1;
+use wayland_protocols::unstable::primary_selection::v1::client::zwp_primary_selection_device_v1;
+use wayland_protocols::unstable::primary_selection::v1::client::zwp_primary_selection_source_v1;
+use wayland_protocols::unstable::text_input::v3::client::zwp_text_input_manager_v3;
+use wayland_protocols::unstable::text_input::v3::client::zwp_text_input_v3;
+use wayland_protocols::unstable::xdg_decoration::v1::client::zxdg_decoration_manager_v1;
+use wayland_protocols::unstable::xdg_decoration::v1::client::zxdg_toplevel_decoration_v1;
+use wayland_protocols::viewporter::client::wp_viewporter;
+use wayland_protocols::wlr::unstable::layer_shell::v1::client::zwlr_layer_shell_v1;
+use wayland_protocols::wlr::unstable::layer_shell::v1::client::zwlr_layer_surface_v1;
+use wayland_protocols::xdg_shell::client::xdg_popup;
+use wayland_protocols::xdg_shell::client::xdg_surface;
+use wayland_protocols::xdg_shell::client::xdg_toplevel;
+use wayland_protocols::xdg_shell::client::xdg_wm_base;
 use xkbcommon::xkb;
+use crate::client_buffer::*;
+use crate::client_cursor::*;
+use crate::client_data_device::*;
 use crate::client_error::*;
 use crate::client_keyboard::*;
+use crate::client_output::*;
 use crate::client_pointer::*;
+use crate::client_seat::*;
+use crate::client_text_input::*;
+use crate::client_tooltip::*;
 use crate::client_touch::*;
 use crate::client_window::*;
 use crate::cursors::*;
@@ -54,9 +81,14 @@ use crate::event_queue::*;
 use crate::key_map_init::*;
 use crate::keys::*;
 use crate::mod_key_set_init::*;
+use crate::protocols::cursor_shape_v1::client::wp_cursor_shape_manager_v1;
+use crate::protocols::fractional_scale_v1::client::wp_fractional_scale_manager_v1;
+use crate::protocols::fractional_scale_v1::client::wp_fractional_scale_v1;
+use crate::protocols::pointer_gestures_unstable_v1::client::zwp_pointer_gestures_v1;
 use crate::queue_context::*;
 use crate::thread_signal::*;
 use crate::types::*;
+use crate::utils::*;
 use crate::window_context::*;
 
 const DEFAULT_SCALE: i32 = 1;
@@ -67,6 +99,7 @@ const DEFAULT_KEY_REPEAT_TIME: u64 = 30;
 const DEFAULT_TEXT_CURSOR_BLINK_TIME: u64 = 1200;
 const DEFAULT_DOUBLE_CLICK_DELAY: u64 = 400;
 const DEFAULT_LONG_CLICK_DELAY: u64 = 1000;
+const DEFAULT_TOOLTIP_DELAY: u64 = 500;
 
 struct DeepestFocusableWindowIndexPair
 {
@@ -119,54 +152,162 @@ pub(crate) struct EventPreparation
 pub(crate) struct ClientContextFields
 {
     pub(crate) compositor: Main<wl_compositor::WlCompositor>,
-    pub(crate) shell: Main<wl_shell::WlShell>,
-    pub(crate) seat: Main<wl_seat::WlSeat>,
+    pub(crate) xdg_wm_base: Option<Main<xdg_wm_base::XdgWmBase>>,
+    pub(crate) shell: Option<Main<wl_shell::WlShell>>,
+    pub(crate) xdg_decoration_manager: Option<Main<zxdg_decoration_manager_v1::ZxdgDecorationManagerV1>>,
+    pub(crate) layer_shell: Option<Main<zwlr_layer_shell_v1::ZwlrLayerShellV1>>,
+    pub(crate) viewporter: Option<Main<wp_viewporter::WpViewporter>>,
+    pub(crate) fractional_scale_manager: Option<Main<wp_fractional_scale_manager_v1::WpFractionalScaleManagerV1>>,
+    pub(crate) seat_globals: Rc<RefCell<ClientSeatGlobals>>,
+    pub(crate) seats: BTreeMap<SeatId, ClientSeat>,
+    pub(crate) current_seat_id: Option<SeatId>,
     pub(crate) shm: Main<wl_shm::WlShm>,
-    pub(crate) pointer: Option<Main<wl_pointer::WlPointer>>,
-    pub(crate) keyboard: Option<Main<wl_keyboard::WlKeyboard>>,
-    pub(crate) touch: Option<Main<wl_touch::WlTouch>>,
-    pub(crate) serial: Option<u32>,
-    #[allow(dead_code)]
-    pub(crate) cursor_theme: CursorTheme,
-    pub(crate) cursors: HashMap<Cursor, WaylandCursor>,
+    pub(crate) pointer_gestures: Option<Main<zwp_pointer_gestures_v1::ZwpPointerGesturesV1>>,
+    pub(crate) data_device_manager: Option<Main<wl_data_device_manager::WlDataDeviceManager>>,
+    pub(crate) selection_data_source: Option<Main<wl_data_source::WlDataSource>>,
+    pub(crate) drag_data_offer: Option<wl_data_offer::WlDataOffer>,
+    pub(crate) drag_serial: Option<u32>,
+    pub(crate) drop_data_offer: Option<wl_data_offer::WlDataOffer>,
+    pub(crate) drop_read_count: usize,
+    pub(crate) drag_data_source: Option<Main<wl_data_source::WlDataSource>>,
+    pub(crate) drag_icon: Rc<RefCell<Option<ClientDragIcon>>>,
+    pub(crate) primary_selection_device_manager: Option<Main<zwp_primary_selection_device_manager_v1::ZwpPrimarySelectionDeviceManagerV1>>,
+    pub(crate) primary_selection_source: Option<Main<zwp_primary_selection_source_v1::ZwpPrimarySelectionSourceV1>>,
+    pub(crate) text_input_manager: Option<Main<zwp_text_input_manager_v3::ZwpTextInputManagerV3>>,
+    pub(crate) queue_context: Option<Arc<Mutex<QueueContext>>>,
+    pub(crate) thread_signal_sender: Option<ThreadSignalSender>,
+    pub(crate) cursor_shape_manager: Option<Main<wp_cursor_shape_manager_v1::WpCursorShapeManagerV1>>,
+    pub(crate) cursors: ClientCursors,
+    pub(crate) cursor_buffer_pool: Option<ClientBufferPool>,
     pub(crate) cursor_surface: Main<wl_surface::WlSurface>,
     pub(crate) xkb_context: xkb::Context,
-    pub(crate) xkb_keymap: Option<xkb::Keymap>,
-    pub(crate) xkb_state: Option<xkb::State>,
-    pub(crate) xkb_shift_mask: xkb::ModMask,
-    pub(crate) xkb_caps_mask: xkb::ModMask,
-    pub(crate) xkb_ctrl_mask: xkb::ModMask,
-    pub(crate) xkb_alt_mask: xkb::ModMask,
-    pub(crate) xkb_num_mask: xkb::ModMask,
-    pub(crate) xkb_logo_mask: xkb::ModMask,
+    #[cfg_attr(target_os = "linux", allow(dead_code))]
     pub(crate) xdg_runtime_dir: String,
-    pub(crate) scale: i32,
+    pub(crate) outputs: Rc<RefCell<ClientOutputs>>,
+    pub(crate) forced_scale: Option<f64>,
+    pub(crate) has_frame_done: Rc<Cell<bool>>,
     pub(crate) click_repeat_delay: u64,
     pub(crate) click_repeat_time: u64,
     pub(crate) key_repeat_delay: u64,
     pub(crate) key_repeat_time: u64,
+    pub(crate) has_env_key_repeat_delay: bool,
+    pub(crate) has_env_key_repeat_time: bool,
+    pub(crate) has_key_repeat: bool,
     pub(crate) text_cursor_blink_time: u64,
     pub(crate) double_click_delay: u64,
     pub(crate) long_click_delay: u64,
-    pub(crate) start_time: Instant,
+    pub(crate) cursor_start_time: Instant,
     pub(crate) has_exit: bool,
-    pub(crate) event_preparations: BTreeMap<CallOnId, EventPreparation>,
-    pub(crate) has_pressed_button: bool,
-    pub(crate) keyboard_window_index: Option<WindowIndex>,
-    pub(crate) key_codes: BTreeSet<u32>,
-    pub(crate) key_modifiers: KeyModifiers,
     pub(crate) keys: HashMap<xkb::Keysym, VKey>,
     pub(crate) modifier_keys: HashSet<VKey>,
-    pub(crate) touch_ids: BTreeSet<i32>,
-    pub(crate) has_cursor: bool,
     pub(crate) cursor: Cursor,
-    pub(crate) has_old_cursor: bool,
     pub(crate) old_cursor: Cursor,
     pub(crate) post_button_release_call_on_path: Option<CallOnPath>,
     pub(crate) post_button_release_pos: Option<Pos<f64>>,
     pub(crate) has_sent_post_button_release_call_on_path: bool,
     pub(crate) has_button_timer_stop: bool,
     pub(crate) has_touch_timer_stop: bool,
+    pub(crate) tooltip_delay: u64,
+    pub(crate) tooltip_index: Option<WindowIndex>,
+    pub(crate) tooltip_text: Option<String>,
+    pub(crate) tooltip_path: Option<AbsWidgetPath>,
+    pub(crate) tooltip_anchor_rect: Rect<i32>,
+    pub(crate) tooltip_focused_path: Option<AbsWidgetPath>,
+    pub(crate) is_tooltip_pending: bool,
+    pub(crate) is_tooltip_shown: bool,
+    pub(crate) has_tooltip_timer_start: bool,
+    pub(crate) has_tooltip_timer_stop: bool,
+}
+
+impl ClientContextFields
+{
+    pub(crate) fn scale_for_output_names(&self, output_names: &BTreeSet<u32>) -> i32
+    {
+        let outputs_r = self.outputs.borrow();
+        let scale = output_names.iter().filter_map(|n| outputs_r.outputs.get(n).map(|o| o.scale)).max();
+        match scale {
+            Some(scale) => scale,
+            None => outputs_r.max_scale().unwrap_or(DEFAULT_SCALE),
+        }
+    }
+
+    pub(crate) fn output_for_name(&self, output_name: u32) -> Option<Main<wl_output::WlOutput>>
+    {
+        match self.outputs.borrow().outputs.get(&output_name) {
+            Some(output) => Some(output.output.clone()),
+            None => None,
+        }
+    }
+
+    pub(crate) fn current_seat(&self) -> Option<&ClientSeat>
+    {
+        match self.current_seat_id {
+            Some(seat_id) => self.seats.get(&seat_id),
+            None => None,
+        }
+    }
+
+    pub(crate) fn current_seat_mut(&mut self) -> Option<&mut ClientSeat>
+    {
+        match self.current_seat_id {
+            Some(seat_id) => self.seats.get_mut(&seat_id),
+            None => None,
+        }
+    }
+
+    /// Returns the seat of the current event and the last serial of this seat or `None`.
+    pub(crate) fn current_seat_and_serial(&self) -> Option<(&wl_seat::WlSeat, u32)>
+    {
+        match self.current_seat() {
+            Some(ClientSeat { seat, serial: Some(serial), .. }) => Some((&**seat, *serial)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set_serial(&mut self, serial: u32)
+    {
+        match self.current_seat_mut() {
+            Some(client_seat) => client_seat.serial = Some(serial),
+            None => (),
+        }
+    }
+
+    /// Sets the identifier and the serial of the last pointer button press or the last touch down
+    /// for the current seat.
+    pub(crate) fn set_press_call_on_id_and_serial(&mut self, call_on_id: CallOnId, serial: u32)
+    {
+        match self.current_seat_mut() {
+            Some(client_seat) => client_seat.press_call_on_id_and_serial = Some((call_on_id, serial)),
+            None => (),
+        }
+    }
+
+    /// Returns the last serial of the current seat or `None`.
+    pub(crate) fn current_serial(&self) -> Option<u32>
+    {
+        match self.current_seat() {
+            Some(client_seat) => client_seat.serial,
+            None => None,
+        }
+    }
+
+    /// Returns the data device of the current seat or `None`.
+    pub(crate) fn current_data_device(&self) -> Option<Main<wl_data_device::WlDataDevice>>
+    {
+        match self.current_seat() {
+            Some(client_seat) => client_seat.data_device.clone(),
+            None => None,
+        }
+    }
+
+    /// Returns the primary selection device of the current seat or `None`.
+    pub(crate) fn current_primary_selection_device(&self) -> Option<Main<zwp_primary_selection_device_v1::ZwpPrimarySelectionDeviceV1>>
+    {
+        match self.current_seat() {
+            Some(client_seat) => client_seat.primary_selection_device.clone(),
+            None => None,
+        }
+    }
 }
 
 /// A structure of client context.
@@ -190,76 +331,92 @@ impl ClientContext
         };
         let mut event_queue = display.create_event_queue();
         let attached_display = (*display).clone().attach(event_queue.token());
-        let global_manager = GlobalManager::new(&attached_display);
+        let outputs = Rc::new(RefCell::new(ClientOutputs::new()));
+        let outputs2 = outputs.clone();
+        let seat_globals = Rc::new(RefCell::new(ClientSeatGlobals::new()));
+        let seat_globals2 = seat_globals.clone();
+        let global_manager = GlobalManager::new_with_cb(&attached_display, move |event, registry, _| {
+                handle_global_event_for_client_outputs(outputs2.clone(), &event, &registry);
+                handle_global_event_for_client_seats(seat_globals2.clone(), &event, &registry);
+        });
+        match event_queue.sync_roundtrip(&mut (), |_, _, _| ()) {
+            Ok(_) => (),
+            Err(err) => return Err(ClientError::Io(err)),
+        }
+        // The second roundtrip receives the scales of the bound outputs and the capabilities of the
+        // bound seats.
         match event_queue.sync_roundtrip(&mut (), |_, _, _| ()) {
             Ok(_) => (),
             Err(err) => return Err(ClientError::Io(err)),
         }
-        let compositor = match global_manager.instantiate_exact::<wl_compositor::WlCompositor>(1) {
+        let compositor = match global_manager.instantiate_range::<wl_compositor::WlCompositor>(1, 4) {
             Ok(tmp_compositor) => tmp_compositor,
             Err(err) => return Err(ClientError::Global(err)),
         };
-        let shell = match global_manager.instantiate_exact::<wl_shell::WlShell>(1) {
-            Ok(tmp_shell) => tmp_shell,
-            Err(err) => return Err(ClientError::Global(err)),
+        let (xdg_wm_base, shell) = match global_manager.instantiate_range::<xdg_wm_base::XdgWmBase>(1, 3) {
+            Ok(tmp_xdg_wm_base) => {
+                tmp_xdg_wm_base.quick_assign(|xdg_wm_base, event, _| {
+                        match event {
+                            xdg_wm_base::Event::Ping { serial, } => xdg_wm_base.pong(serial),
+                            _ => (),
+                        }
+                });
+                (Some(tmp_xdg_wm_base), None)
+            },
+            Err(_) => {
+                match global_manager.instantiate_exact::<wl_shell::WlShell>(1) {
+                    Ok(tmp_shell) => (None, Some(tmp_shell)),
+                    Err(err) => return Err(ClientError::Global(err)),
+                }
+            },
         };
-        let seat = match global_manager.instantiate_exact::<wl_seat::WlSeat>(1) {
-            Ok(tmp_seat) => tmp_seat,
-            Err(err) => return Err(ClientError::Global(err)),
+        let xdg_decoration_manager = if xdg_wm_base.is_some() {
+            global_manager.instantiate_exact::<zxdg_decoration_manager_v1::ZxdgDecorationManagerV1>(1).ok()
+        } else {
+            None
+        };
+        let layer_shell = global_manager.instantiate_range::<zwlr_layer_shell_v1::ZwlrLayerShellV1>(1, 4).ok();
+        let viewporter = global_manager.instantiate_exact::<wp_viewporter::WpViewporter>(1).ok();
+        let pointer_gestures = global_manager.instantiate_range::<zwp_pointer_gestures_v1::ZwpPointerGesturesV1>(1, 3).ok();
+        let fractional_scale_manager = if viewporter.is_some() {
+            global_manager.instantiate_exact::<wp_fractional_scale_manager_v1::WpFractionalScaleManagerV1>(1).ok()
+        } else {
+            None
         };
+        let default_seat_id = match seat_globals.borrow().added_seats.first() {
+            Some((tmp_seat_id, _)) => *tmp_seat_id,
+            None => return Err(ClientError::NoSeat),
+        };
+        let data_device_manager = global_manager.instantiate_range::<wl_data_device_manager::WlDataDeviceManager>(1, 3).ok();
+        let primary_selection_device_manager = global_manager.instantiate_exact::<zwp_primary_selection_device_manager_v1::ZwpPrimarySelectionDeviceManagerV1>(1).ok();
+        let text_input_manager = global_manager.instantiate_exact::<zwp_text_input_manager_v3::ZwpTextInputManagerV3>(1).ok();
         let shm = match global_manager.instantiate_exact::<wl_shm::WlShm>(1) {
             Ok(tmp_shm) => tmp_shm,
             Err(err) => return Err(ClientError::Global(err)),
         };
-        let mut cursor_theme = CursorTheme::load(32, &shm);
-        let cursor_name_pairs = vec![
-            (Cursor::Default, "left_ptr"),
-            (Cursor::Text, "xterm"),
-            (Cursor::Hand, "hand1"),
-            (Cursor::Pencil, "pencil"),
-            (Cursor::Cross, "cross"),
-            (Cursor::Wait, "watch"),
-            (Cursor::TopLeftCorner, "top_left_corner"),
-            (Cursor::TopRightCorner, "top_right_corner"),
-            (Cursor::TopSide, "top_side"),
-            (Cursor::LeftSide, "left_side"),
-            (Cursor::BottomLeftCorner, "bottom_left_corner"),
-            (Cursor::BottomRightCorner, "bottom_right_corner"),
-            (Cursor::BottomSide, "bottom_side"),
-            (Cursor::RightSide, "right_side"),
-            (Cursor::HDoubleArrow, "sb_h_double_arrow"),
-            (Cursor::VDoubleArrow, "sb_v_double_arrow")
-        ];
-        let mut cursors: HashMap<Cursor, WaylandCursor> = HashMap::new();
-        for pair in &cursor_name_pairs {
-            match cursor_theme.get_cursor(pair.1) {
-                Some(cursor) => {
-                    cursors.insert(pair.0, cursor.clone());
-                },
-                None => return Err(ClientError::Cursor),
-            }
-        }
+        let cursor_shape_manager = global_manager.instantiate_exact::<wp_cursor_shape_manager_v1::WpCursorShapeManagerV1>(1).ok();
+        let cursors = ClientCursors::new(&shm, outputs.borrow().max_scale().unwrap_or(DEFAULT_SCALE));
         let cursor_surface = compositor.create_surface();
         let xkb_context = xkb::Context::new(0);
         let xdg_runtime_dir = match env::var("XDG_RUNTIME_DIR") {
             Ok(tmp_xdg_runtime_dir) => tmp_xdg_runtime_dir,
             Err(_) => return Err(ClientError::NoXdgRuntimeDir),
         };
-        let scale = match env::var("LWLTK_SCALE") {
+        let forced_scale = match env::var("LWLTK_SCALE") {
             Ok(s) => {
-                match s.parse::<i32>() {
-                    Ok(tmp_scale) if tmp_scale <= 0 => {
+                match s.parse::<f64>() {
+                    Ok(tmp_scale) if !tmp_scale.is_finite() || tmp_scale <= 0.0 => {
                         eprintln!("lwltk: warning: invalid value of scale");
-                        DEFAULT_SCALE
+                        None
                     },
-                    Ok(tmp_scale) => tmp_scale,
+                    Ok(tmp_scale) => Some(tmp_scale),
                     Err(_) => {
                         eprintln!("lwltk: warning: invalid value of scale");
-                        DEFAULT_SCALE
+                        None
                     },
                 }
             },
-            Err(_) => DEFAULT_SCALE,
+            Err(_) => None,
         };
         let click_repeat_delay = match env::var("LWLTK_CLICK_REPEAT_DELAY") {
             Ok(s) => {
@@ -285,29 +442,29 @@ impl ClientContext
             },
             Err(_) => DEFAULT_CLICK_REPEAT_TIME,
         };
-        let key_repeat_delay = match env::var("LWLTK_KEY_REPEAT_DELAY") {
+        let (key_repeat_delay, has_env_key_repeat_delay) = match env::var("LWLTK_KEY_REPEAT_DELAY") {
             Ok(s) => {
                 match s.parse::<u64>() {
-                    Ok(tmp_key_repeat_delay) => tmp_key_repeat_delay,
+                    Ok(tmp_key_repeat_delay) => (tmp_key_repeat_delay, true),
                     Err(_) => {
                         eprintln!("lwltk: warning: invalid value of key repeat delay");
-                        DEFAULT_KEY_REPEAT_DELAY
+                        (DEFAULT_KEY_REPEAT_DELAY, false)
                     },
                 }
             },
-            Err(_) => DEFAULT_KEY_REPEAT_DELAY,
+            Err(_) => (DEFAULT_KEY_REPEAT_DELAY, false),
         };
-        let key_repeat_time = match env::var("LWLTK_KEY_REPEAT_TIME") {
+        let (key_repeat_time, has_env_key_repeat_time) = match env::var("LWLTK_KEY_REPEAT_TIME") {
             Ok(s) => {
                 match s.parse::<u64>() {
-                    Ok(tmp_key_repeat_time) => tmp_key_repeat_time,
+                    Ok(tmp_key_repeat_time) => (tmp_key_repeat_time, true),
                     Err(_) => {
                         eprintln!("lwltk: warning: invalid value of key repeat time");
-                        DEFAULT_KEY_REPEAT_TIME
+                        (DEFAULT_KEY_REPEAT_TIME, false)
                     },
                 }
             },
-            Err(_) => DEFAULT_KEY_REPEAT_TIME,
+            Err(_) => (DEFAULT_KEY_REPEAT_TIME, false),
         };
         let text_cursor_blink_time = match env::var("LWLTK_TEXT_CURSOR_BLINK_TIME") {
             Ok(s) => {
@@ -345,56 +502,85 @@ impl ClientContext
             },
             Err(_) => DEFAULT_LONG_CLICK_DELAY,
         };
+        let tooltip_delay = match env::var("LWLTK_TOOLTIP_DELAY") {
+            Ok(s) => {
+                match s.parse::<u64>() {
+                    Ok(tmp_tooltip_delay) => tmp_tooltip_delay,
+                    Err(_) => {
+                        eprintln!("lwltk: warning: invalid value of tooltip delay");
+                        DEFAULT_TOOLTIP_DELAY
+                    },
+                }
+            },
+            Err(_) => DEFAULT_TOOLTIP_DELAY,
+        };
         let mut client_context = ClientContext {
             fields: ClientContextFields {
                 compositor,
+                xdg_wm_base,
                 shell,
-                seat,
+                xdg_decoration_manager,
+                layer_shell,
+                viewporter,
+                fractional_scale_manager,
+                seat_globals,
+                seats: BTreeMap::new(),
+                current_seat_id: Some(default_seat_id),
                 shm,
-                pointer: None,
-                keyboard: None,
-                touch: None,
-                cursor_theme,
+                pointer_gestures,
+                data_device_manager,
+                selection_data_source: None,
+                drag_data_offer: None,
+                drag_serial: None,
+                drop_data_offer: None,
+                drop_read_count: 0,
+                drag_data_source: None,
+                drag_icon: Rc::new(RefCell::new(None)),
+                primary_selection_device_manager,
+                primary_selection_source: None,
+                text_input_manager,
+                queue_context: None,
+                thread_signal_sender: None,
+                cursor_shape_manager,
                 cursors,
+                cursor_buffer_pool: None,
                 cursor_surface,
-                serial: None,
                 xkb_context,
-                xkb_keymap: None,
-                xkb_state: None,
-                xkb_shift_mask: 0 as xkb::ModMask,
-                xkb_caps_mask: 0 as xkb::ModMask,
-                xkb_ctrl_mask: 0 as xkb::ModMask,
-                xkb_alt_mask: 0 as xkb::ModMask,
-                xkb_num_mask: 0 as xkb::ModMask,
-                xkb_logo_mask: 0 as xkb::ModMask,
                 xdg_runtime_dir,
-                scale,
+                outputs,
+                forced_scale,
+                has_frame_done: Rc::new(Cell::new(false)),
                 click_repeat_delay,
                 click_repeat_time,
                 key_repeat_delay,
                 key_repeat_time,
+                has_env_key_repeat_delay,
+                has_env_key_repeat_time,
+                has_key_repeat: true,
                 text_cursor_blink_time,
                 double_click_delay,
                 long_click_delay,
-                start_time: Instant::now(),
+                cursor_start_time: Instant::now(),
                 has_exit: false,
-                event_preparations: BTreeMap::new(),
-                has_pressed_button: false,
-                keyboard_window_index: None,
-                key_codes: BTreeSet::new(),
-                key_modifiers: KeyModifiers::EMPTY,
                 keys: HashMap::new(),
                 modifier_keys: HashSet::new(),
-                touch_ids: BTreeSet::new(),
-                has_cursor: false,
                 cursor: Cursor::Default,
-                has_old_cursor: false,
                 old_cursor: Cursor::Default,
                 post_button_release_call_on_path: None,
                 post_button_release_pos: None,
                 has_sent_post_button_release_call_on_path: false,
                 has_button_timer_stop: false,
                 has_touch_timer_stop: false,
+                tooltip_delay,
+                tooltip_index: None,
+                tooltip_text: None,
+                tooltip_path: None,
+                tooltip_anchor_rect: Rect::new(0, 0, 0, 0),
+                tooltip_focused_path: None,
+                is_tooltip_pending: false,
+                is_tooltip_shown: false,
+                has_tooltip_timer_start: false,
+                has_tooltip_timer_stop: false,
             },
             client_windows: BTreeMap::new(),
             client_windows_to_destroy: VecDeque::new(),
@@ -429,16 +615,16 @@ impl ClientContext
     pub(crate) fn remove_client_window(&mut self, idx: WindowIndex) -> Option<Box<ClientWindow>>
     { self.client_windows.remove(&idx) }
 
-    fn create_client_windows_from(&mut self, window_context: &mut WindowContext, idx: WindowIndex, visiteds: &mut BTreeSet<WindowIndex>, parent_surface: Option<&wl_surface::WlSurface>, client_context2: Rc<RefCell<ClientContext>>, window_context2: Arc<RwLock<WindowContext>>, queue_context2: Arc<Mutex<QueueContext>>, timer_tx: &mpsc::Sender<ThreadTimerCommand>) -> Result<(), ClientError>
+    fn create_client_windows_from(&mut self, window_context: &mut WindowContext, idx: WindowIndex, visiteds: &mut BTreeSet<WindowIndex>, parent: Option<&ClientWindowParent>, client_context2: Rc<RefCell<ClientContext>>, window_context2: Arc<RwLock<WindowContext>>, queue_context2: Arc<Mutex<QueueContext>>, timer_tx: &mpsc::Sender<ThreadTimerCommand>) -> Result<(), ClientError>
     {
         if visiteds.contains(&idx) {
             return Err(ClientError::WindowCycle);
         }
         let child_idxs = match window_context.window_container.dyn_window_mut(idx) {
             Some(window) => {
-                let mut client_window = ClientWindow::new(&self.fields, window, &*window_context.theme)?;
+                let mut client_window = ClientWindow::new(&self.fields, window, &*window_context.theme, parent)?;
                 client_window.assign(client_context2.clone(), window_context2.clone(), queue_context2.clone(), timer_tx);
-                match client_window.set(&mut self.fields, window, &*window_context.theme, parent_surface) {
+                match client_window.set(&mut self.fields, window, &*window_context.theme, parent) {
                     Ok(()) => (),
                     Err(err) => {
                         client_window.destroy();
@@ -451,12 +637,12 @@ impl ClientContext
             None => return Err(ClientError::NoWindow),
         };
         visiteds.insert(idx);
-        let surface = match self.client_window(idx) {
-            Some(client_window) => client_window.surface.clone(),
+        let parent = match self.client_window(idx) {
+            Some(client_window) => client_window.parent(),
             None => return Err(ClientError::NoWindow),
         };
         for child_idx in &child_idxs {
-            self.create_client_windows_from(window_context, *child_idx, visiteds, Some(&surface), client_context2.clone(), window_context2.clone(), queue_context2.clone(), timer_tx)?;
+            self.create_client_windows_from(window_context, *child_idx, visiteds, Some(&parent), client_context2.clone(), window_context2.clone(), queue_context2.clone(), timer_tx)?;
             match self.client_window_mut(idx) {
                 Some(client_window) => client_window.add_child(*child_idx),
                 None => return Err(ClientError::NoWindow),
@@ -656,7 +842,7 @@ impl ClientContext
         Ok(())
     }
     
-    fn create_or_update_client_windows_from(&mut self, window_context: &mut WindowContext, idx: WindowIndex, visiteds: &mut BTreeSet<WindowIndex>, parent_surface: Option<&wl_surface::WlSurface>, client_context2: Rc<RefCell<ClientContext>>, window_context2: Arc<RwLock<WindowContext>>, queue_context2: Arc<Mutex<QueueContext>>, timer_tx: &mpsc::Sender<ThreadTimerCommand>) -> Result<(), ClientError>
+    fn create_or_update_client_windows_from(&mut self, window_context: &mut WindowContext, idx: WindowIndex, visiteds: &mut BTreeSet<WindowIndex>, parent: Option<&ClientWindowParent>, client_context2: Rc<RefCell<ClientContext>>, window_context2: Arc<RwLock<WindowContext>>, queue_context2: Arc<Mutex<QueueContext>>, timer_tx: &mpsc::Sender<ThreadTimerCommand>) -> Result<(), ClientError>
     {
         if visiteds.contains(&idx) {
             return Err(ClientError::WindowCycle);
@@ -674,9 +860,9 @@ impl ClientContext
             None => {
                 match window_context.window_container.dyn_window_mut(idx) {
                     Some(window) => {
-                        let mut client_window = ClientWindow::new(&self.fields, window, &*window_context.theme)?;
+                        let mut client_window = ClientWindow::new(&self.fields, window, &*window_context.theme, parent)?;
                         client_window.assign(client_context2.clone(), window_context2.clone(), queue_context2.clone(), timer_tx);
-                        match client_window.set(&mut self.fields, window, &*window_context.theme, parent_surface) {
+                        match client_window.set(&mut self.fields, window, &*window_context.theme, parent) {
                             Ok(()) => (),
                             Err(err) => {
                                 client_window.destroy();
@@ -691,12 +877,12 @@ impl ClientContext
             },
         };
         visiteds.insert(idx);
-        let surface = match self.client_window(idx) {
-            Some(client_window) => client_window.surface.clone(),
+        let parent = match self.client_window(idx) {
+            Some(client_window) => client_window.parent(),
             None => return Err(ClientError::NoWindow),
         };
         for child_idx in &child_idxs {
-            self.create_or_update_client_windows_from(window_context, *child_idx, visiteds, Some(&surface), client_context2.clone(), window_context2.clone(), queue_context2.clone(), timer_tx)?;
+            self.create_or_update_client_windows_from(window_context, *child_idx, visiteds, Some(&parent), client_context2.clone(), window_context2.clone(), queue_context2.clone(), timer_tx)?;
             match self.client_window_mut(idx) {
                 Some(client_window) => client_window.add_child(*child_idx),
                 None => return Err(ClientError::NoWindow),
@@ -775,6 +961,7 @@ impl ClientContext
             Ok(()) => (),
             Err(err) => eprintln!("lwltk: {}", err),
         }
+        update_text_input(self, window_context);
     }
     
     pub(crate) fn destroy_client_windows_to_destroy(&mut self) -> Result<(), ClientError>
@@ -799,11 +986,90 @@ impl ClientContext
             Err(err) => eprintln!("lwltk: {}", err),
         }
         self.client_windows.clear();
+        match &self.fields.selection_data_source {
+            Some(data_source) => data_source.destroy(),
+            None => (),
+        }
+        self.fields.selection_data_source = None;
+        match &self.fields.drag_data_offer {
+            Some(data_offer) => data_offer.destroy(),
+            None => (),
+        }
+        self.fields.drag_data_offer = None;
+        match &self.fields.drop_data_offer {
+            Some(data_offer) => data_offer.destroy(),
+            None => (),
+        }
+        self.fields.drop_data_offer = None;
+        match &self.fields.drag_data_source {
+            Some(data_source) => data_source.destroy(),
+            None => (),
+        }
+        self.fields.drag_data_source = None;
+        match self.fields.drag_icon.borrow_mut().take() {
+            Some(drag_icon) => drag_icon.destroy(),
+            None => (),
+        }
+        match &self.fields.primary_selection_source {
+            Some(source) => source.destroy(),
+            None => (),
+        }
+        self.fields.primary_selection_source = None;
+        for client_seat in self.fields.seats.values_mut() {
+            client_seat.destroy();
+        }
+        self.fields.seats.clear();
+        match &self.fields.primary_selection_device_manager {
+            Some(primary_selection_device_manager) => primary_selection_device_manager.destroy(),
+            None => (),
+        }
+        match &self.fields.text_input_manager {
+            Some(text_input_manager) => text_input_manager.destroy(),
+            None => (),
+        }
+        match &self.fields.cursor_shape_manager {
+            Some(cursor_shape_manager) => cursor_shape_manager.destroy(),
+            None => (),
+        }
+        match &self.fields.layer_shell {
+            Some(layer_shell) if layer_shell.as_ref().version() >= 3 => layer_shell.destroy(),
+            _ => (),
+        }
+        match &self.fields.cursor_buffer_pool {
+            Some(cursor_buffer_pool) => cursor_buffer_pool.destroy(),
+            None => (),
+        }
+        match &self.fields.pointer_gestures {
+            Some(pointer_gestures) if pointer_gestures.as_ref().version() >= 2 => pointer_gestures.release(),
+            _ => (),
+        }
+        self.fields.outputs.borrow_mut().destroy();
     }
     
     /// Returns the delay of long click in milliseconds.
     pub fn long_click_delay(&self) -> u64
     { self.fields.long_click_delay }
+
+    /// Returns the delay of tooltip in milliseconds.
+    ///
+    /// A tooltip is shown after this delay when a pointer is over a widget with the tooltip or when
+    /// the widget is focused by a key. The default delay can be changed by the
+    /// `LWLTK_TOOLTIP_DELAY` environment variable.
+    pub fn tooltip_delay(&self) -> u64
+    { self.fields.tooltip_delay }
+
+    /// Sets the delay of tooltip in milliseconds.
+    ///
+    /// See [`tooltip_delay`](Self::tooltip_delay) for more informations.
+    pub fn set_tooltip_delay(&mut self, delay: u64)
+    { self.fields.tooltip_delay = delay; }
+
+    /// Returns the names of outputs.
+    ///
+    /// The output name is a name of the output global that can be passed to
+    /// [`Window::set_fullscreen`](crate::window::Window::set_fullscreen).
+    pub fn output_names(&self) -> Vec<u32>
+    { self.fields.outputs.borrow().outputs.keys().copied().collect() }
     
     /// Returns `true` if the exit flag is `true`, otherwise `false`
     pub fn has_exit(&self) -> bool
@@ -828,13 +1094,117 @@ impl ClientContext
         })
     }
 
+    pub(crate) fn add_output_for_surface(&mut self, surface: &wl_surface::WlSurface, output: &wl_output::WlOutput)
+    {
+        let output_name = self.fields.outputs.borrow().output_name(output);
+        match (self.window_index_for_surface(surface), output_name) {
+            (Some(idx), Some(output_name)) => {
+                match self.client_window_mut(idx) {
+                    Some(client_window) => {
+                        client_window.output_names.insert(output_name);
+                    },
+                    None => (),
+                }
+            },
+            (_, _) => (),
+        }
+    }
+
+    pub(crate) fn remove_output_for_surface(&mut self, surface: &wl_surface::WlSurface, output: &wl_output::WlOutput)
+    {
+        let output_name = self.fields.outputs.borrow().output_name(output);
+        match (self.window_index_for_surface(surface), output_name) {
+            (Some(idx), Some(output_name)) => {
+                match self.client_window_mut(idx) {
+                    Some(client_window) => {
+                        client_window.output_names.remove(&output_name);
+                    },
+                    None => (),
+                }
+            },
+            (_, _) => (),
+        }
+    }
+
+    pub(crate) fn set_preferred_scale_for_fractional_scale(&mut self, fractional_scale: &wp_fractional_scale_v1::WpFractionalScaleV1, scale: u32)
+    {
+        match self.window_index_for_fractional_scale(fractional_scale) {
+            Some(idx) => {
+                match self.client_window_mut(idx) {
+                    Some(client_window) => client_window.preferred_scale = Some((scale as f64) / 120.0),
+                    None => (),
+                }
+            },
+            None => (),
+        }
+    }
+
     pub(crate) fn window_index_for_shell_surface(&self, shell_surface: &wl_shell_surface::WlShellSurface) -> Option<WindowIndex>
     {
         self.client_windows.iter().find_map(|p| {
-                if &**p.1.shell_surface == shell_surface {
-                    Some(*p.0)
-                } else {
-                    None
+                match &p.1.shell_surface {
+                    Some(tmp_shell_surface) if &***tmp_shell_surface == shell_surface => Some(*p.0),
+                    _ => None,
+                }
+        })
+    }
+
+    pub(crate) fn window_index_for_xdg_surface(&self, xdg_surface: &xdg_surface::XdgSurface) -> Option<WindowIndex>
+    {
+        self.client_windows.iter().find_map(|p| {
+                match &p.1.xdg_surface {
+                    Some(tmp_xdg_surface) if &***tmp_xdg_surface == xdg_surface => Some(*p.0),
+                    _ => None,
+                }
+        })
+    }
+
+    pub(crate) fn window_index_for_xdg_toplevel(&self, xdg_toplevel: &xdg_toplevel::XdgToplevel) -> Option<WindowIndex>
+    {
+        self.client_windows.iter().find_map(|p| {
+                match &p.1.xdg_toplevel {
+                    Some(tmp_xdg_toplevel) if &***tmp_xdg_toplevel == xdg_toplevel => Some(*p.0),
+                    _ => None,
+                }
+        })
+    }
+
+    pub(crate) fn window_index_for_xdg_toplevel_decoration(&self, xdg_toplevel_decoration: &zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1) -> Option<WindowIndex>
+    {
+        self.client_windows.iter().find_map(|p| {
+                match &p.1.xdg_toplevel_decoration {
+                    Some(tmp_xdg_toplevel_decoration) if &***tmp_xdg_toplevel_decoration == xdg_toplevel_decoration => Some(*p.0),
+                    _ => None,
+                }
+        })
+    }
+
+    pub(crate) fn window_index_for_layer_surface(&self, layer_surface: &zwlr_layer_surface_v1::ZwlrLayerSurfaceV1) -> Option<WindowIndex>
+    {
+        self.client_windows.iter().find_map(|p| {
+                match &p.1.layer_surface {
+                    Some(tmp_layer_surface) if &***tmp_layer_surface == layer_surface => Some(*p.0),
+                    _ => None,
+                }
+        })
+    }
+
+    pub(crate) fn window_index_for_fractional_scale(&self, fractional_scale: &wp_fractional_scale_v1::WpFractionalScaleV1) -> Option<WindowIndex>
+    {
+        self.client_windows.iter().find_map(|p| {
+                match &p.1.fractional_scale {
+                    Some(tmp_fractional_scale) if &***tmp_fractional_scale == fractional_scale => Some(*p.0),
+                    _ => None,
+                }
+        })
+    }
+
+    pub(crate) fn window_index_for_xdg_popup(&self, xdg_popup: &xdg_popup::XdgPopup) -> Option<WindowIndex>
+    {
+        self.client_windows.iter().find_map(|p| {
+                match &p.1.xdg_popup {
+                    Some(tmp_xdg_popup) if &***tmp_xdg_popup == xdg_popup => Some(*p.0),
+                    _ => None,
                 }
         })
     }
@@ -853,8 +1223,13 @@ impl ClientContext
                     call_on_path: call_on_path.clone(),
                     first_pos,
                 };
-                self.fields.event_preparations.insert(call_on_id, event_preparation);
-                Some((call_on_path, pos))
+                match self.fields.current_seat_mut() {
+                    Some(client_seat) => {
+                        client_seat.event_preparations.insert(call_on_id, event_preparation);
+                        Some((call_on_path, pos))
+                    },
+                    None => None,
+                }
             },
             None => None,
         }
@@ -862,8 +1237,13 @@ impl ClientContext
     
     pub(crate) fn set_event_preparation(&mut self, window_context: &WindowContext, call_on_id: CallOnId, pos: Pos<f64>) -> Option<(CallOnPath, Pos<f64>)>
     {
-        let pair = match self.fields.event_preparations.remove(&call_on_id) {
-            Some(event_preparation) => Some((event_preparation.window_index, event_preparation.first_pos)),
+        let pair = match self.fields.current_seat_mut() {
+            Some(client_seat) => {
+                match client_seat.event_preparations.remove(&call_on_id) {
+                    Some(event_preparation) => Some((event_preparation.window_index, event_preparation.first_pos)),
+                    None => None,
+                }
+            },
             None => None,
         };
         match pair {
@@ -874,7 +1254,11 @@ impl ClientContext
 
     pub(crate) fn update_event_preparation(&mut self, window_context: &WindowContext, call_on_id: CallOnId) -> Option<(CallOnPath, Pos<f64>)>
     {
-        match self.fields.event_preparations.get_mut(&call_on_id) {
+        let event_preparation = match self.fields.current_seat_mut() {
+            Some(client_seat) => client_seat.event_preparations.get_mut(&call_on_id),
+            None => None,
+        };
+        match event_preparation {
             Some(event_preparation) => {
                 let is_widget = match &event_preparation.call_on_path {
                     CallOnPath::Window(_) => false,
@@ -902,7 +1286,11 @@ impl ClientContext
 
     pub(crate) fn remove_event_preparation(&mut self, window_context: &WindowContext, call_on_id: CallOnId) -> Option<(CallOnPath, Pos<f64>)>
     {
-        match self.fields.event_preparations.remove(&call_on_id) {
+        let event_preparation = match self.fields.current_seat_mut() {
+            Some(client_seat) => client_seat.event_preparations.remove(&call_on_id),
+            None => None,
+        };
+        match event_preparation {
             Some(event_preparation) => {
                 let is_widget = match &event_preparation.call_on_path {
                     CallOnPath::Window(_) => false,
@@ -931,10 +1319,15 @@ impl ClientContext
     ///
     /// The first position is used to point a window or a widget instead the current position. The
     /// first position defaultly is unset. A left button release unsets the first position for a
-    /// pointer. This method returns `true` if the first position is set, otherwise `false`.
+    /// pointer. The first position is set for the seat of the current event. This method returns
+    /// `true` if the first position is set, otherwise `false`.
     pub fn set_first_pos(&mut self, call_on_id: CallOnId) -> bool
     {
-        match self.fields.event_preparations.get_mut(&call_on_id) {
+        let event_preparation = match self.fields.current_seat_mut() {
+            Some(client_seat) => client_seat.event_preparations.get_mut(&call_on_id),
+            None => None,
+        };
+        match event_preparation {
             Some(event_preparation) => {
                 event_preparation.first_pos = Some(event_preparation.pos);
                 true
@@ -948,7 +1341,11 @@ impl ClientContext
     /// See [`set_first_pos`](Self::set_first_pos) for more informations.
     pub fn unset_first_pos(&mut self, call_on_id: CallOnId) -> bool
     {
-        match self.fields.event_preparations.get_mut(&call_on_id) {
+        let event_preparation = match self.fields.current_seat_mut() {
+            Some(client_seat) => client_seat.event_preparations.get_mut(&call_on_id),
+            None => None,
+        };
+        match event_preparation {
             Some(event_preparation) => {
                 event_preparation.first_pos = None;
                 true
@@ -960,110 +1357,584 @@ impl ClientContext
     pub(crate) fn clear_for_client_windows_to_destroy(&mut self, client_windows_to_destroy: &BTreeMap<WindowIndex, Box<ClientWindow>>)
     {
         if !client_windows_to_destroy.is_empty() {
-            let call_on_ids: Vec<CallOnId> = self.fields.event_preparations.iter().filter(|p| {
-                    client_windows_to_destroy.keys().any(|i| *i == p.1.call_on_path.window_index())
-            }).map(|p| *(p.0)).collect();
-            for call_on_id in &call_on_ids {
-                self.fields.event_preparations.remove(call_on_id);
+            for client_seat in self.fields.seats.values_mut() {
+                let call_on_ids: Vec<CallOnId> = client_seat.event_preparations.iter().filter(|p| {
+                        client_windows_to_destroy.keys().any(|i| *i == p.1.call_on_path.window_index())
+                }).map(|p| *(p.0)).collect();
+                for call_on_id in &call_on_ids {
+                    client_seat.event_preparations.remove(call_on_id);
+                }
+                match client_seat.keyboard_window_index {
+                    Some(keyboard_window_idx) if client_windows_to_destroy.contains_key(&keyboard_window_idx) => client_seat.keyboard_window_index = None,
+                    _ => (),
+                }
             }
         }
     }
     
     /// Returns the key modifiers which are pressed.
+    ///
+    /// The key modifiers are pressed on a keyboard of the seat of the current event.
     pub fn key_modifiers(&self) -> KeyModifiers
-    { self.fields.key_modifiers }
+    {
+        match self.fields.current_seat() {
+            Some(client_seat) => client_seat.key_modifiers,
+            None => KeyModifiers::EMPTY,
+        }
+    }
+
+    pub(crate) fn set_current_seat_id(&mut self, queue_context: &mut QueueContext, seat_id: Option<SeatId>)
+    {
+        self.fields.current_seat_id = seat_id;
+        queue_context.current_seat_id = seat_id;
+    }
     
     /// Returns the cursor.
-    pub fn cursor(&self) -> Cursor
-    { self.fields.cursor }
+    pub fn cursor(&self) -> &Cursor
+    { &self.fields.cursor }
 
     /// Sets the cursor.
     pub fn set_cursor(&mut self, cursor: Cursor)
     { self.fields.cursor = cursor; }
 
-    fn set_cursor_surface(&mut self, timer_tx: &mpsc::Sender<ThreadTimerCommand>)
+    /// Sets the clipboard data that are offered in the MIME types.
+    ///
+    /// The clipboard data are pairs of MIME type and data. The clipboard data are sent to other
+    /// clients without blocking a main loop.
+    pub fn set_clipboard_data(&mut self, data: Vec<(String, Vec<u8>)>) -> Result<(), ClientError>
     {
-        let cursor = self.fields.cursor;
-        match self.fields.cursors.get(&cursor) {
-            Some(wayland_cursor) => {
-                let millis = self.fields.start_time.elapsed().as_millis();
-                let frame_info = wayland_cursor.frame_and_duration(millis as u32);
-                let buffer = wayland_cursor[frame_info.frame_index].clone();
-                self.fields.cursor_surface.attach(Some(&buffer), 0, 0);
-                self.fields.cursor_surface.damage(0, 0, buffer.dimensions().0 as i32, buffer.dimensions().1 as i32);
-                self.fields.cursor_surface.commit();
-                match self.fields.serial {
-                    Some(serial) => {
-                        match &self.fields.pointer {
-                            Some(pointer) => pointer.set_cursor(serial, Some(&self.fields.cursor_surface), buffer.hotspot().0 as i32, buffer.hotspot().1 as i32),
-                            None => (),
-                        }
-                    },
+        match (&self.fields.data_device_manager, self.fields.current_data_device()) {
+            (Some(data_device_manager), Some(data_device)) => {
+                let serial = match self.fields.current_serial() {
+                    Some(tmp_serial) => tmp_serial,
+                    None => return Err(ClientError::NoSerial),
+                };
+                let data_source = create_data_source(data_device_manager, data, false, || ());
+                data_device.set_selection(Some(&data_source), serial);
+                match &self.fields.selection_data_source {
+                    Some(old_data_source) => old_data_source.destroy(),
                     None => (),
                 }
-                let duration = Duration::from_millis(frame_info.frame_duration as u64);
-                match timer_tx.send(ThreadTimerCommand::SetDelay(ThreadTimer::Cursor, duration)) {
-                    Ok(()) => (),
-                    Err(_) => eprintln!("lwltk: {}", ClientError::Send),
-                }
+                self.fields.selection_data_source = Some(data_source);
+                Ok(())
             },
-            None => eprintln!("lwltk: {}", ClientError::NoCursor),
+            (_, _) => Err(ClientError::NoDataDevice),
         }
     }
 
-    pub(crate) fn update_cursor_surface(&mut self, timer_tx: &mpsc::Sender<ThreadTimerCommand>)
+    /// Clears the clipboard data if the clipboard data are set by this client.
+    pub fn clear_clipboard_data(&mut self) -> Result<(), ClientError>
     {
-        if self.fields.has_cursor != self.fields.has_old_cursor || self.fields.cursor != self.fields.old_cursor {
-            if self.fields.has_cursor {
-                self.set_cursor_surface(timer_tx);
-            }
+        match self.fields.current_data_device() {
+            Some(data_device) => {
+                if self.fields.selection_data_source.is_some() {
+                    let serial = match self.fields.current_serial() {
+                        Some(tmp_serial) => tmp_serial,
+                        None => return Err(ClientError::NoSerial),
+                    };
+                    data_device.set_selection(None, serial);
+                    match &self.fields.selection_data_source {
+                        Some(data_source) => data_source.destroy(),
+                        None => (),
+                    }
+                    self.fields.selection_data_source = None;
+                }
+                Ok(())
+            },
+            None => Err(ClientError::NoDataDevice),
         }
-        self.fields.has_old_cursor = self.fields.has_cursor;
-        self.fields.old_cursor = self.fields.cursor;
     }
 
-    pub(crate) fn update_cursor_surface_for_timer(&mut self, timer_tx: &mpsc::Sender<ThreadTimerCommand>)
+    /// Returns the MIME types of the clipboard data.
+    pub fn clipboard_mime_types(&self) -> Vec<String>
     {
-        if self.fields.has_cursor {
-            self.set_cursor_surface(timer_tx);
+        match self.fields.current_seat().and_then(|s| s.selection_data_offer.as_ref()) {
+            Some(data_offer) => data_offer_mime_types(data_offer),
+            None => Vec::new(),
         }
     }
-    
-    /// Returns a reference to the call-on path of the post button release or `None`.
+
+    /// Reads the clipboard data in the MIME type.
     ///
-    /// The post button release is an event that is called double click delay after the button
-    /// release. The call-on path of the post button release refers to a widget or a window that is
-    /// released by the pointer. The position of the post button release is pointed by the pointer
-    /// when the widget or the window is released by the pointer.
-    pub fn post_button_release_call_on_path(&self) -> Option<&CallOnPath>
+    /// The clipboard data are read asynchronously without blocking a main loop. The closure is
+    /// pushed to the callback queue with the read clipboard data after the clipboard data are
+    /// read. The closure takes the following arguments:
+    /// - a reference to the client context
+    /// - a reference to the window context
+    /// - a reference to the queue context
+    /// - a slice of the clipboard data
+    pub fn read_clipboard_data<F>(&mut self, mime_type: &str, f: F) -> Result<(), ClientError>
+        where F: FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext, &[u8]) -> Option<()> + Send + Sync + 'static
     {
-        match &self.fields.post_button_release_call_on_path {
-            Some(call_on_path) => Some(call_on_path),
-            None => None,
+        match self.fields.current_seat().and_then(|s| s.selection_data_offer.as_ref()) {
+            Some(data_offer) => {
+                if !data_offer_mime_types(data_offer).iter().any(|mt| mt.as_str() == mime_type) {
+                    return Err(ClientError::NoMimeType);
+                }
+                match (&self.fields.queue_context, self.fields.thread_signal_sender) {
+                    (Some(queue_context), Some(thread_signal_sender)) => receive_data_offer(data_offer, mime_type, queue_context.clone(), thread_signal_sender, self.fields.current_seat_id, f),
+                    (_, _) => Err(ClientError::NoQueueContext),
+                }
+            },
+            None => Err(ClientError::NoSelection),
         }
     }
 
-    /// Sets the call-on path of the post button release.
+    /// Sets the primary selection data that are offered in the MIME types.
     ///
-    /// See [`post_button_release_call_on_path`](Self::post_button_release_call_on_path) for more
-    /// informations.
-    pub fn set_post_button_release_call_on_path(&mut self, call_on_path: Option<CallOnPath>)
+    /// The primary selection data are pairs of MIME type and data. A text widget should set the
+    /// selected text as the primary selection data. This method does nothing if the compositor
+    /// doesn't support primary selection.
+    pub fn set_primary_selection_data(&mut self, data: Vec<(String, Vec<u8>)>) -> Result<(), ClientError>
     {
-        self.fields.post_button_release_call_on_path = call_on_path;
-        self.fields.has_sent_post_button_release_call_on_path = false;
+        match (&self.fields.primary_selection_device_manager, self.fields.current_primary_selection_device()) {
+            (Some(primary_selection_device_manager), Some(primary_selection_device)) => {
+                let serial = match self.fields.current_serial() {
+                    Some(tmp_serial) => tmp_serial,
+                    None => return Err(ClientError::NoSerial),
+                };
+                let source = create_primary_selection_source(primary_selection_device_manager, data);
+                primary_selection_device.set_selection(Some(&source), serial);
+                match &self.fields.primary_selection_source {
+                    Some(old_source) => old_source.destroy(),
+                    None => (),
+                }
+                self.fields.primary_selection_source = Some(source);
+                Ok(())
+            },
+            (_, _) => Ok(()),
+        }
     }
 
-    /// Returns the position of the post button release or `None`.
+    /// Clears the primary selection data if the primary selection data are set by this client.
     ///
-    /// See [`post_button_release_call_on_path`](Self::post_button_release_call_on_path) for more
-    /// informations.
-    pub fn post_button_release_pos(&self) -> Option<Pos<f64>>
-    { self.fields.post_button_release_pos }
+    /// This method does nothing if the compositor doesn't support primary selection.
+    pub fn clear_primary_selection_data(&mut self) -> Result<(), ClientError>
+    {
+        match self.fields.current_primary_selection_device() {
+            Some(primary_selection_device) => {
+                if self.fields.primary_selection_source.is_some() {
+                    let serial = match self.fields.current_serial() {
+                        Some(tmp_serial) => tmp_serial,
+                        None => return Err(ClientError::NoSerial),
+                    };
+                    primary_selection_device.set_selection(None, serial);
+                    match &self.fields.primary_selection_source {
+                        Some(source) => source.destroy(),
+                        None => (),
+                    }
+                    self.fields.primary_selection_source = None;
+                }
+                Ok(())
+            },
+            None => Ok(()),
+        }
+    }
 
-    /// Sets the position of the post button release.
+    /// Returns the MIME types of the primary selection data.
     ///
-    /// See [`post_button_release_call_on_path`](Self::post_button_release_call_on_path) for more
-    /// informations.
+    /// This method returns an empty vector if the compositor doesn't support primary selection.
+    pub fn primary_selection_mime_types(&self) -> Vec<String>
+    {
+        match self.fields.current_seat().and_then(|s| s.primary_selection_offer.as_ref()) {
+            Some(offer) => primary_selection_offer_mime_types(offer),
+            None => Vec::new(),
+        }
+    }
+
+    /// Reads the primary selection data in the MIME type.
+    ///
+    /// The primary selection text is defaultly read and pasted after a press of the middle pointer
+    /// button on a widget that accepts the text from an input method (see
+    /// [`default_widget_on_for_client_pointer`](crate::utils::default_widget_on_for_client_pointer)).
+    /// The primary selection data are read asynchronously as the clipboard data. See [`read_clipboard_data`](Self::read_clipboard_data) for more informations.
+    pub fn read_primary_selection_data<F>(&mut self, mime_type: &str, f: F) -> Result<(), ClientError>
+        where F: FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext, &[u8]) -> Option<()> + Send + Sync + 'static
+    {
+        match self.fields.current_seat().and_then(|s| s.primary_selection_offer.as_ref()) {
+            Some(offer) => {
+                if !primary_selection_offer_mime_types(offer).iter().any(|mt| mt.as_str() == mime_type) {
+                    return Err(ClientError::NoMimeType);
+                }
+                match (&self.fields.queue_context, self.fields.thread_signal_sender) {
+                    (Some(queue_context), Some(thread_signal_sender)) => receive_primary_selection_offer(offer, mime_type, queue_context.clone(), thread_signal_sender, self.fields.current_seat_id, f),
+                    (_, _) => Err(ClientError::NoQueueContext),
+                }
+            },
+            None => Err(ClientError::NoSelection),
+        }
+    }
+
+    /// Returns the window index and the serial of the pointer button press or the touch down that
+    /// starts the drag.
+    fn drag_window_index_and_serial(&self) -> Result<(WindowIndex, u32), ClientError>
+    {
+        let client_seat = match self.fields.current_seat() {
+            Some(tmp_client_seat) => tmp_client_seat,
+            None => return Err(ClientError::NoSeat),
+        };
+        let (call_on_id, serial) = match client_seat.press_call_on_id_and_serial {
+            Some(pair) => pair,
+            None => return Err(ClientError::NoSerial),
+        };
+        match client_seat.event_preparations.get(&call_on_id) {
+            Some(event_preparation) => Ok((event_preparation.window_index, serial)),
+            None => Err(ClientError::NoClientWindow),
+        }
+    }
+
+    fn start_drag_with_client_drag_icon(&mut self, data: Vec<(String, Vec<u8>)>, drag_icon: Option<ClientDragIcon>) -> Result<(), ClientError>
+    {
+        let res = match (&self.fields.data_device_manager, self.fields.current_data_device()) {
+            (Some(data_device_manager), Some(data_device)) => {
+                match self.drag_window_index_and_serial() {
+                    Ok((window_idx, serial)) => {
+                        match self.client_windows.get(&window_idx) {
+                            Some(client_window) => Ok((data_device_manager.clone(), data_device, client_window.surface.clone(), serial)),
+                            None => Err(ClientError::NoClientWindow),
+                        }
+                    },
+                    Err(err) => Err(err),
+                }
+            },
+            (_, _) => Err(ClientError::NoDataDevice),
+        };
+        let (data_device_manager, data_device, surface, serial) = match res {
+            Ok(tuple) => tuple,
+            Err(err) => {
+                match drag_icon {
+                    Some(drag_icon) => drag_icon.destroy(),
+                    None => (),
+                }
+                return Err(err);
+            },
+        };
+        match self.fields.drag_icon.borrow_mut().take() {
+            Some(old_drag_icon) => old_drag_icon.destroy(),
+            None => (),
+        }
+        let drag_icon2 = self.fields.drag_icon.clone();
+        let data_source = create_data_source(&data_device_manager, data, true, move || {
+                match drag_icon2.borrow_mut().take() {
+                    Some(drag_icon) => drag_icon.destroy(),
+                    None => (),
+                }
+        });
+        data_device.start_drag(Some(&data_source), &surface, drag_icon.as_ref().map(|i| i.surface()), serial);
+        match drag_icon {
+            Some(mut drag_icon) => {
+                match drag_icon.commit() {
+                    Ok(()) => *self.fields.drag_icon.borrow_mut() = Some(drag_icon),
+                    Err(err) => {
+                        data_source.destroy();
+                        drag_icon.destroy();
+                        return Err(err);
+                    },
+                }
+            },
+            None => (),
+        }
+        match &self.fields.drag_data_source {
+            Some(old_data_source) => old_data_source.destroy(),
+            None => (),
+        }
+        self.fields.drag_data_source = Some(data_source);
+        Ok(())
+    }
+
+    /// Starts drag and drop of the data that are offered in the MIME types.
+    ///
+    /// The data are pairs of MIME type and data. This method should be called when a pointer
+    /// button is pressed or when a touch point is down. The drag is started for the window and
+    /// the serial of the last pointer button press or the last touch down.
+    pub fn start_drag(&mut self, data: Vec<(String, Vec<u8>)>) -> Result<(), ClientError>
+    { self.start_drag_with_client_drag_icon(data, None) }
+
+    /// Starts drag and drop of the data with a drag icon.
+    ///
+    /// The drag icon has the size and is drawn by the closure. See
+    /// [`start_drag`](Self::start_drag) for more informations.
+    pub fn start_drag_with_icon<F>(&mut self, data: Vec<(String, Vec<u8>)>, icon_size: Size<i32>, f: F) -> Result<(), ClientError>
+        where F: FnOnce(&CairoContext) -> Result<(), CairoError>
+    {
+        let scale = match self.drag_window_index_and_serial() {
+            Ok((window_idx, _)) => {
+                match self.client_windows.get(&window_idx) {
+                    Some(client_window) => client_window.scale.ceil() as i32,
+                    None => DEFAULT_SCALE,
+                }
+            },
+            Err(_) => DEFAULT_SCALE,
+        };
+        let drag_icon = ClientDragIcon::new(&self.fields, icon_size, scale, f)?;
+        match self.start_drag_with_client_drag_icon(data, Some(drag_icon)) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns the MIME types of the dragged data.
+    pub fn drag_mime_types(&self) -> Vec<String>
+    {
+        match self.fields.drag_data_offer.as_ref().or(self.fields.drop_data_offer.as_ref()) {
+            Some(data_offer) => data_offer_mime_types(data_offer),
+            None => Vec::new(),
+        }
+    }
+
+    /// Accepts the dragged data in the MIME type or rejects the dragged data if the MIME type is
+    /// `None`.
+    ///
+    /// This method should be called for an event of drag enter or an event of drag motion. The
+    /// dragged data can be dropped only if the dragged data are accepted.
+    pub fn accept_drag(&mut self, mime_type: Option<&str>) -> Result<(), ClientError>
+    {
+        match (&self.fields.drag_data_offer, self.fields.drag_serial) {
+            (Some(data_offer), Some(serial)) => {
+                data_offer.accept(serial, mime_type.map(|s| String::from(s)));
+                if data_offer.as_ref().version() >= 3 {
+                    match mime_type {
+                        Some(_) => data_offer.set_actions(wl_data_device_manager::DndAction::Copy | wl_data_device_manager::DndAction::Move, wl_data_device_manager::DndAction::Copy),
+                        None => data_offer.set_actions(wl_data_device_manager::DndAction::None, wl_data_device_manager::DndAction::None),
+                    }
+                }
+                Ok(())
+            },
+            (_, _) => Err(ClientError::NoDragDataOffer),
+        }
+    }
+
+    /// Reads the dragged data in the MIME type.
+    ///
+    /// The dragged data are read asynchronously as the clipboard data. If the dragged data are
+    /// dropped, drag and drop is finished after the dropped data are passed to the closure and
+    /// other reading requests for the dropped data aren't pending, so the dropped data can be read
+    /// in other MIME type by the closure. See [`read_clipboard_data`](Self::read_clipboard_data)
+    /// for more informations.
+    pub fn read_drag_data<F>(&mut self, mime_type: &str, mut f: F) -> Result<(), ClientError>
+        where F: FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext, &[u8]) -> Option<()> + Send + Sync + 'static
+    {
+        let (data_offer, is_drop) = match (&self.fields.drag_data_offer, &self.fields.drop_data_offer) {
+            (Some(data_offer), _) => (data_offer.clone(), false),
+            (None, Some(data_offer)) => (data_offer.clone(), true),
+            (None, None) => return Err(ClientError::NoDragDataOffer),
+        };
+        if !data_offer_mime_types(&data_offer).iter().any(|mt| mt.as_str() == mime_type) {
+            return Err(ClientError::NoMimeType);
+        }
+        match (&self.fields.queue_context, self.fields.thread_signal_sender) {
+            (Some(queue_context), Some(thread_signal_sender)) => {
+                if is_drop {
+                    let data_offer2 = data_offer.clone();
+                    receive_data_offer(&data_offer, mime_type, queue_context.clone(), thread_signal_sender, self.fields.current_seat_id, move |client_context, window_context, queue_context, data| {
+                            let res = f(client_context, window_context, queue_context, data);
+                            finish_drop_reading(client_context, &data_offer2);
+                            res
+                    })?;
+                    self.fields.drop_read_count += 1;
+                } else {
+                    receive_data_offer(&data_offer, mime_type, queue_context.clone(), thread_signal_sender, self.fields.current_seat_id, f)?;
+                }
+                Ok(())
+            },
+            (_, _) => Err(ClientError::NoQueueContext),
+        }
+    }
+
+    fn attach_theme_cursor(&mut self, cursor: &Cursor, scale: i32) -> Option<(Pos<i32>, Option<Duration>)>
+    {
+        self.fields.cursors.set_scale(&self.fields.shm, scale);
+        match self.fields.cursors.get(cursor) {
+            Some(wayland_cursor) => {
+                let millis = self.fields.cursor_start_time.elapsed().as_millis();
+                let frame_info = wayland_cursor.frame_and_duration(millis as u32);
+                let buffer = wayland_cursor[frame_info.frame_index].clone();
+                let (width, height) = buffer.dimensions();
+                let (hotspot_x, hotspot_y) = buffer.hotspot();
+                // A buffer size must be a multiple of a buffer scale.
+                let buffer_scale = if self.fields.cursor_surface.as_ref().version() >= 3 && width % (scale as u32) == 0 && height % (scale as u32) == 0 {
+                    scale
+                } else {
+                    1
+                };
+                if self.fields.cursor_surface.as_ref().version() >= 3 {
+                    self.fields.cursor_surface.set_buffer_scale(buffer_scale);
+                }
+                self.fields.cursor_surface.attach(Some(&buffer), 0, 0);
+                self.fields.cursor_surface.damage(0, 0, (width as i32) / buffer_scale, (height as i32) / buffer_scale);
+                self.fields.cursor_surface.commit();
+                // Only an animated cursor needs the cursor timer to advance frames.
+                let duration = if wayland_cursor.image_count() > 1 {
+                    Some(Duration::from_millis(max(frame_info.frame_duration, 1) as u64))
+                } else {
+                    None
+                };
+                Some((Pos::new((hotspot_x as i32) / buffer_scale, (hotspot_y as i32) / buffer_scale), duration))
+            },
+            None => {
+                eprintln!("lwltk: {}", ClientError::NoCursor);
+                None
+            },
+        }
+    }
+
+    fn attach_custom_cursor(&mut self, custom_cursor: &CustomCursor, scale: i32) -> Result<Pos<i32>, ClientError>
+    {
+        let buffer_scale = if self.fields.cursor_surface.as_ref().version() >= 3 {
+            scale
+        } else {
+            1
+        };
+        let size = custom_cursor.size();
+        let width = max(size.width, 1) * buffer_scale;
+        let height = max(size.height, 1) * buffer_scale;
+        if self.fields.cursor_buffer_pool.is_none() {
+            let buffer_pool = ClientBufferPool::new(&self.fields, (width as usize) * (height as usize) * 4 * 2)?;
+            self.fields.cursor_buffer_pool = Some(buffer_pool);
+        }
+        match &mut self.fields.cursor_buffer_pool {
+            Some(buffer_pool) => {
+                let buffer_idx = buffer_pool.next_buffer(width, height)?;
+                let cairo_surface = buffer_pool.create_cairo_surface(buffer_idx)?;
+                let res = with_cairo_context(&cairo_surface, |cairo_context| {
+                        cairo_context.save()?;
+                        cairo_context.set_operator(Operator::Clear);
+                        cairo_context.paint()?;
+                        cairo_context.restore()?;
+                        cairo_context.scale(buffer_scale as f64, buffer_scale as f64);
+                        custom_cursor.draw(cairo_context)
+                });
+                match res {
+                    Ok(()) => (),
+                    Err(err) => return Err(ClientError::Cairo(err)),
+                }
+                cairo_surface.flush();
+                if self.fields.cursor_surface.as_ref().version() >= 3 {
+                    self.fields.cursor_surface.set_buffer_scale(buffer_scale);
+                }
+                buffer_pool.attach(buffer_idx, &self.fields.cursor_surface)?;
+                self.fields.cursor_surface.damage(0, 0, width / buffer_scale, height / buffer_scale);
+                self.fields.cursor_surface.commit();
+                Ok(custom_cursor.hotspot())
+            },
+            None => Err(ClientError::NoBuffer),
+        }
+    }
+
+    fn set_cursor_surface(&mut self, timer_tx: &mpsc::Sender<ThreadTimerCommand>)
+    {
+        let cursor = self.fields.cursor.clone();
+        let shape = cursor_shape(&cursor);
+        match shape {
+            Some(shape) => {
+                for client_seat in self.fields.seats.values() {
+                    match client_seat {
+                        ClientSeat { cursor_shape_device: Some(cursor_shape_device), serial: Some(serial), has_cursor: true, .. } => cursor_shape_device.set_shape(*serial, shape),
+                        _ => (),
+                    }
+                }
+            },
+            None => (),
+        }
+        // The cursor surface is used by the pointers without cursor shape devices and for the
+        // cursors without cursor shapes.
+        if !self.fields.seats.values().any(|s| s.has_cursor && (s.cursor_shape_device.is_none() || shape.is_none())) {
+            return;
+        }
+        let scale = self.fields.outputs.borrow().max_scale().unwrap_or(DEFAULT_SCALE);
+        let pair = match &cursor {
+            Cursor::Custom(custom_cursor) => {
+                match self.attach_custom_cursor(custom_cursor, scale) {
+                    Ok(hotspot) => Some((hotspot, None)),
+                    Err(err) => {
+                        eprintln!("lwltk: {}", err);
+                        None
+                    },
+                }
+            },
+            _ => self.attach_theme_cursor(&cursor, scale),
+        };
+        match pair {
+            Some((hotspot, duration)) => {
+                for client_seat in self.fields.seats.values() {
+                    match client_seat {
+                        ClientSeat { pointer: Some(pointer), cursor_shape_device, serial: Some(serial), has_cursor: true, .. } if cursor_shape_device.is_none() || shape.is_none() => {
+                            pointer.set_cursor(*serial, Some(&self.fields.cursor_surface), hotspot.x, hotspot.y)
+                        },
+                        _ => (),
+                    }
+                }
+                let cmd = match duration {
+                    Some(duration) => ThreadTimerCommand::SetDelay(ThreadTimer::Cursor, duration),
+                    None => ThreadTimerCommand::Stop(ThreadTimer::Cursor),
+                };
+                match timer_tx.send(cmd) {
+                    Ok(()) => (),
+                    Err(_) => eprintln!("lwltk: {}", ClientError::Send),
+                }
+            },
+            None => (),
+        }
+    }
+
+    pub(crate) fn update_cursor_surface(&mut self, timer_tx: &mpsc::Sender<ThreadTimerCommand>)
+    {
+        let is_cursor_diff = self.fields.cursor != self.fields.old_cursor;
+        if is_cursor_diff {
+            // An animation of a new cursor starts from the first frame.
+            self.fields.cursor_start_time = Instant::now();
+        }
+        if self.fields.seats.values().any(|s| s.has_cursor && (!s.has_old_cursor || is_cursor_diff)) {
+            self.set_cursor_surface(timer_tx);
+        }
+        for client_seat in self.fields.seats.values_mut() {
+            client_seat.has_old_cursor = client_seat.has_cursor;
+        }
+        self.fields.old_cursor = self.fields.cursor.clone();
+    }
+
+    pub(crate) fn update_cursor_surface_for_timer(&mut self, timer_tx: &mpsc::Sender<ThreadTimerCommand>)
+    {
+        if self.fields.seats.values().any(|s| s.has_cursor) {
+            self.set_cursor_surface(timer_tx);
+        }
+    }
+    
+    /// Returns a reference to the call-on path of the post button release or `None`.
+    ///
+    /// The post button release is an event that is called double click delay after the button
+    /// release. The call-on path of the post button release refers to a widget or a window that is
+    /// released by the pointer. The position of the post button release is pointed by the pointer
+    /// when the widget or the window is released by the pointer.
+    pub fn post_button_release_call_on_path(&self) -> Option<&CallOnPath>
+    {
+        match &self.fields.post_button_release_call_on_path {
+            Some(call_on_path) => Some(call_on_path),
+            None => None,
+        }
+    }
+
+    /// Sets the call-on path of the post button release.
+    ///
+    /// See [`post_button_release_call_on_path`](Self::post_button_release_call_on_path) for more
+    /// informations.
+    pub fn set_post_button_release_call_on_path(&mut self, call_on_path: Option<CallOnPath>)
+    {
+        self.fields.post_button_release_call_on_path = call_on_path;
+        self.fields.has_sent_post_button_release_call_on_path = false;
+    }
+
+    /// Returns the position of the post button release or `None`.
+    ///
+    /// See [`post_button_release_call_on_path`](Self::post_button_release_call_on_path) for more
+    /// informations.
+    pub fn post_button_release_pos(&self) -> Option<Pos<f64>>
+    { self.fields.post_button_release_pos }
+
+    /// Sets the position of the post button release.
+    ///
+    /// See [`post_button_release_call_on_path`](Self::post_button_release_call_on_path) for more
+    /// informations.
     pub fn set_post_button_release_pos(&mut self, pos: Option<Pos<f64>>)
     { self.fields.post_button_release_pos = pos; }
     
@@ -1105,21 +1976,49 @@ impl ClientContext
         self.fields.has_sent_post_button_release_call_on_path = true;
     }
     
-    pub(crate) fn stop_button_timer_and_touch_timer(&mut self, timer_tx: &mpsc::Sender<ThreadTimerCommand>)
+    pub(crate) fn send_tooltip_timer(&mut self, timer_tx: &mpsc::Sender<ThreadTimerCommand>)
     {
-        if self.fields.has_button_timer_stop {
-            self.fields.has_pressed_button = false;
-            match timer_tx.send(ThreadTimerCommand::Stop(ThreadTimer::Button)) {
+        if self.fields.has_tooltip_timer_start {
+            let duration = Duration::from_millis(self.fields.tooltip_delay);
+            match timer_tx.send(ThreadTimerCommand::SetDelay(ThreadTimer::Tooltip, duration)) {
+                Ok(()) => (),
+                Err(_) => eprintln!("lwltk: {}", ClientError::Send),
+            }
+        } else if self.fields.has_tooltip_timer_stop {
+            match timer_tx.send(ThreadTimerCommand::Stop(ThreadTimer::Tooltip)) {
                 Ok(()) => (),
                 Err(_) => eprintln!("lwltk: {}", ClientError::Send),
             }
         }
+        self.fields.has_tooltip_timer_start = false;
+        self.fields.has_tooltip_timer_stop = false;
+    }
+
+    pub(crate) fn stop_button_timer_and_touch_timer(&mut self, timer_tx: &mpsc::Sender<ThreadTimerCommand>)
+    {
+        if self.fields.has_button_timer_stop {
+            match self.fields.current_seat_mut() {
+                Some(client_seat) => client_seat.has_pressed_button = false,
+                None => (),
+            }
+            if self.fields.seats.values().all(|s| !s.has_pressed_button) {
+                match timer_tx.send(ThreadTimerCommand::Stop(ThreadTimer::Button)) {
+                    Ok(()) => (),
+                    Err(_) => eprintln!("lwltk: {}", ClientError::Send),
+                }
+            }
+        }
         self.fields.has_touch_timer_stop = false;
         if self.fields.has_touch_timer_stop {
-            self.fields.touch_ids.clear();
-            match timer_tx.send(ThreadTimerCommand::Stop(ThreadTimer::Touch)) {
-                Ok(()) => (),
-                Err(_) => eprintln!("lwltk: {}", ClientError::Send),
+            match self.fields.current_seat_mut() {
+                Some(client_seat) => client_seat.touch_ids.clear(),
+                None => (),
+            }
+            if self.fields.seats.values().all(|s| s.touch_ids.is_empty()) {
+                match timer_tx.send(ThreadTimerCommand::Stop(ThreadTimer::Touch)) {
+                    Ok(()) => (),
+                    Err(_) => eprintln!("lwltk: {}", ClientError::Send),
+                }
             }
         }
         self.fields.has_touch_timer_stop = false;
@@ -1173,20 +2072,212 @@ fn destroy_map_client_windows(client_windows: &BTreeMap<WindowIndex, Box<ClientW
 
 mod priv_wayland
 {
+    use wayland_client::protocol::wl_data_device;
     use wayland_client::protocol::wl_keyboard;
     use wayland_client::protocol::wl_pointer;
     use wayland_client::protocol::wl_touch;
     use wayland_client::event_enum;
+    use wayland_protocols::unstable::primary_selection::v1::client::zwp_primary_selection_device_v1;
+    use wayland_protocols::unstable::text_input::v3::client::zwp_text_input_v3;
+    use crate::protocols::pointer_gestures_unstable_v1::client::zwp_pointer_gesture_hold_v1;
+    use crate::protocols::pointer_gestures_unstable_v1::client::zwp_pointer_gesture_pinch_v1;
+    use crate::protocols::pointer_gestures_unstable_v1::client::zwp_pointer_gesture_swipe_v1;
 
     event_enum!(
         WaylandEvent |
         Pointer => wl_pointer::WlPointer,
         Keyboard => wl_keyboard::WlKeyboard,
-        Touch => wl_touch::WlTouch
+        Touch => wl_touch::WlTouch,
+        PointerGestureSwipe => zwp_pointer_gesture_swipe_v1::ZwpPointerGestureSwipeV1,
+        PointerGesturePinch => zwp_pointer_gesture_pinch_v1::ZwpPointerGesturePinchV1,
+        PointerGestureHold => zwp_pointer_gesture_hold_v1::ZwpPointerGestureHoldV1,
+        DataDevice => wl_data_device::WlDataDevice,
+        PrimarySelectionDevice => zwp_primary_selection_device_v1::ZwpPrimarySelectionDeviceV1,
+        TextInput => zwp_text_input_v3::ZwpTextInputV3
     );
 }
 
-enum ThreadTimerRepeat
+fn seat_id_for_wayland_event(event: &priv_wayland::WaylandEvent) -> Option<SeatId>
+{
+    match event {
+        priv_wayland::WaylandEvent::Pointer { object, .. } => seat_id_for_proxy(object.as_ref()),
+        priv_wayland::WaylandEvent::Keyboard { object, .. } => seat_id_for_proxy(object.as_ref()),
+        priv_wayland::WaylandEvent::Touch { object, .. } => seat_id_for_proxy(object.as_ref()),
+        priv_wayland::WaylandEvent::PointerGestureSwipe { object, .. } => seat_id_for_proxy(object.as_ref()),
+        priv_wayland::WaylandEvent::PointerGesturePinch { object, .. } => seat_id_for_proxy(object.as_ref()),
+        priv_wayland::WaylandEvent::PointerGestureHold { object, .. } => seat_id_for_proxy(object.as_ref()),
+        priv_wayland::WaylandEvent::DataDevice { object, .. } => seat_id_for_proxy(object.as_ref()),
+        priv_wayland::WaylandEvent::PrimarySelectionDevice { object, .. } => seat_id_for_proxy(object.as_ref()),
+        priv_wayland::WaylandEvent::TextInput { object, .. } => seat_id_for_proxy(object.as_ref()),
+    }
+}
+
+fn create_client_seat_pointer(client_seat: &mut ClientSeat, seat_id: SeatId, pointer_gestures: &Option<Main<zwp_pointer_gestures_v1::ZwpPointerGesturesV1>>, cursor_shape_manager: &Option<Main<wp_cursor_shape_manager_v1::WpCursorShapeManagerV1>>, filter: &Filter<priv_wayland::WaylandEvent>)
+{
+    let pointer = client_seat.seat.get_pointer();
+    pointer.as_ref().user_data().set(move || seat_id);
+    pointer.assign(filter.clone());
+    match pointer_gestures {
+        Some(pointer_gestures) => {
+            let pointer_gesture_swipe = pointer_gestures.get_swipe_gesture(&pointer);
+            pointer_gesture_swipe.as_ref().user_data().set(move || seat_id);
+            pointer_gesture_swipe.assign(filter.clone());
+            let pointer_gesture_pinch = pointer_gestures.get_pinch_gesture(&pointer);
+            pointer_gesture_pinch.as_ref().user_data().set(move || seat_id);
+            pointer_gesture_pinch.assign(filter.clone());
+            let pointer_gesture_hold = if pointer_gestures.as_ref().version() >= 3 {
+                let tmp_pointer_gesture_hold = pointer_gestures.get_hold_gesture(&pointer);
+                tmp_pointer_gesture_hold.as_ref().user_data().set(move || seat_id);
+                tmp_pointer_gesture_hold.assign(filter.clone());
+                Some(tmp_pointer_gesture_hold)
+            } else {
+                None
+            };
+            client_seat.pointer_gesture_swipe = Some(pointer_gesture_swipe);
+            client_seat.pointer_gesture_pinch = Some(pointer_gesture_pinch);
+            client_seat.pointer_gesture_hold = pointer_gesture_hold;
+        },
+        None => (),
+    }
+    match cursor_shape_manager {
+        Some(cursor_shape_manager) => client_seat.cursor_shape_device = Some(cursor_shape_manager.get_pointer(&pointer)),
+        None => (),
+    }
+    client_seat.pointer = Some(pointer);
+}
+
+fn create_client_seat_selection_devices_and_text_input(client_seat: &mut ClientSeat, seat_id: SeatId, data_device_manager: &Option<Main<wl_data_device_manager::WlDataDeviceManager>>, primary_selection_device_manager: &Option<Main<zwp_primary_selection_device_manager_v1::ZwpPrimarySelectionDeviceManagerV1>>, text_input_manager: &Option<Main<zwp_text_input_manager_v3::ZwpTextInputManagerV3>>, filter: &Filter<priv_wayland::WaylandEvent>)
+{
+    match data_device_manager {
+        Some(data_device_manager) => {
+            let data_device = data_device_manager.get_data_device(&client_seat.seat);
+            data_device.as_ref().user_data().set(move || seat_id);
+            data_device.assign(filter.clone());
+            client_seat.data_device = Some(data_device);
+        },
+        None => (),
+    }
+    match primary_selection_device_manager {
+        Some(primary_selection_device_manager) => {
+            let primary_selection_device = primary_selection_device_manager.get_device(&client_seat.seat);
+            primary_selection_device.as_ref().user_data().set(move || seat_id);
+            primary_selection_device.assign(filter.clone());
+            client_seat.primary_selection_device = Some(primary_selection_device);
+        },
+        None => (),
+    }
+    match text_input_manager {
+        Some(text_input_manager) => {
+            let text_input = text_input_manager.get_text_input(&client_seat.seat);
+            text_input.as_ref().user_data().set(move || seat_id);
+            text_input.assign(filter.clone());
+            client_seat.text_input = Some(text_input);
+        },
+        None => (),
+    }
+}
+
+fn update_client_seat_devices(client_context_fields: &mut ClientContextFields, seat_id: SeatId, capabilities: wl_seat::Capability, filter: &Filter<priv_wayland::WaylandEvent>)
+{
+    let client_seat = match client_context_fields.seats.get_mut(&seat_id) {
+        Some(tmp_client_seat) => tmp_client_seat,
+        None => return,
+    };
+    let pointer_gestures = &client_context_fields.pointer_gestures;
+    let cursor_shape_manager = &client_context_fields.cursor_shape_manager;
+    // The data device, the primary selection device, and the text input don't depend on the
+    // capabilities, so they are created with the first received capabilities.
+    if client_seat.data_device.is_none() && client_seat.primary_selection_device.is_none() && client_seat.text_input.is_none() {
+        create_client_seat_selection_devices_and_text_input(client_seat, seat_id, &client_context_fields.data_device_manager, &client_context_fields.primary_selection_device_manager, &client_context_fields.text_input_manager, filter);
+    }
+    if client_seat.pointer.is_none() && capabilities.contains(wl_seat::Capability::Pointer) {
+        create_client_seat_pointer(client_seat, seat_id, pointer_gestures, cursor_shape_manager, filter);
+    } else if client_seat.pointer.is_some() && !capabilities.contains(wl_seat::Capability::Pointer) {
+        client_seat.destroy_pointer();
+    }
+    if client_seat.keyboard.is_none() && capabilities.contains(wl_seat::Capability::Keyboard) {
+        let keyboard = client_seat.seat.get_keyboard();
+        keyboard.as_ref().user_data().set(move || seat_id);
+        keyboard.assign(filter.clone());
+        client_seat.keyboard = Some(keyboard);
+    } else if client_seat.keyboard.is_some() && !capabilities.contains(wl_seat::Capability::Keyboard) {
+        client_seat.destroy_keyboard();
+    }
+    if client_seat.touch.is_none() && capabilities.contains(wl_seat::Capability::Touch) {
+        let touch = client_seat.seat.get_touch();
+        touch.as_ref().user_data().set(move || seat_id);
+        touch.assign(filter.clone());
+        client_seat.touch = Some(touch);
+    } else if client_seat.touch.is_some() && !capabilities.contains(wl_seat::Capability::Touch) {
+        client_seat.destroy_touch();
+    }
+}
+
+fn reset_widget_states_for_removed_seat(window_context: &mut WindowContext, queue_context: &mut QueueContext, seat_id: SeatId)
+{
+    for call_on_path in &queue_context.clear_for_seat(seat_id) {
+        match call_on_path {
+            CallOnPath::Widget(abs_widget_path) => {
+                // The widget keeps own state if other seat uses the widget.
+                if !queue_context.active_id_sets.contains_key(call_on_path) {
+                    let is_hover = queue_context.motion_call_on_paths.values().any(|p| p == call_on_path);
+                    match window_context.dyn_widget_mut(abs_widget_path) {
+                        Some(widget) if is_hover => {
+                            widget.set_state(WidgetState::Hover);
+                        },
+                        Some(widget) => {
+                            widget.set_state(WidgetState::None);
+                        },
+                        None => (),
+                    }
+                }
+            },
+            CallOnPath::Window(_) => (),
+        }
+    }
+}
+
+fn update_client_seats(client_context: &mut ClientContext, window_context: &RwLock<WindowContext>, queue_context: &Mutex<QueueContext>, filter: &Filter<priv_wayland::WaylandEvent>)
+{
+    let (added_seats, removed_seat_ids, capabilities) = {
+        let mut seat_globals_r = client_context.fields.seat_globals.borrow_mut();
+        (take(&mut seat_globals_r.added_seats), take(&mut seat_globals_r.removed_seat_ids), take(&mut seat_globals_r.capabilities))
+    };
+    for (seat_id, seat) in added_seats {
+        let xkb_compose_state = create_xkb_compose_state(&client_context.fields.xkb_context);
+        client_context.fields.seats.insert(seat_id, ClientSeat::new(seat, xkb_compose_state));
+    }
+    for (seat_id, seat_capabilities) in &capabilities {
+        update_client_seat_devices(&mut client_context.fields, *seat_id, *seat_capabilities, filter);
+    }
+    for seat_id in &removed_seat_ids {
+        match client_context.fields.seats.remove(seat_id) {
+            Some(mut client_seat) => client_seat.destroy(),
+            None => (),
+        }
+        if client_context.fields.current_seat_id == Some(*seat_id) {
+            client_context.fields.current_seat_id = None;
+        }
+    }
+    if !removed_seat_ids.is_empty() {
+        match window_context.write() {
+            Ok(mut window_context_g) => {
+                match queue_context.lock() {
+                    Ok(mut queue_context_g) => {
+                        for seat_id in &removed_seat_ids {
+                            reset_widget_states_for_removed_seat(&mut *window_context_g, &mut *queue_context_g, *seat_id);
+                        }
+                    },
+                    Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                }
+            },
+            Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum ThreadTimerRepeat
 {
     None,
     OneDelay(Duration),
@@ -1203,6 +2294,7 @@ struct ThreadTimerData
 pub(crate) enum ThreadTimerCommand
 {
     SetDelay(ThreadTimer, Duration),
+    SetRepeat(ThreadTimer, ThreadTimerRepeat),
     Start(ThreadTimer),
     Stop(ThreadTimer),
     Quit,
@@ -1213,15 +2305,21 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
     let client_context2 = client_context.clone();
     let window_context2 = window_context.clone();
     let queue_context2 = queue_context.clone();
-    let client_context3 = client_context.clone();
     let client_context4 = client_context.clone();
     let window_context4 = window_context.clone();
     let queue_context4 = queue_context.clone();
     let (timer_tx, timer_rx) = mpsc::channel::<ThreadTimerCommand>();
-    let (click_repeat_delay, click_repeat_time, key_repeat_delay, key_repeat_time, text_cursor_blink_time) = {
+    let (filter, click_repeat_delay, click_repeat_time, key_repeat_delay, key_repeat_time, text_cursor_blink_time) = {
         let timer_tx2 = timer_tx.clone();
         let mut client_context_r = client_context.borrow_mut();
         let filter = Filter::new(move |event, _, _| {
+                {
+                    let mut client_context_r = client_context2.borrow_mut();
+                    match queue_context2.lock() {
+                        Ok(mut queue_context_g) => client_context_r.set_current_seat_id(&mut *queue_context_g, seat_id_for_wayland_event(&event)),
+                        Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                    }
+                }
                 match event {
                     priv_wayland::WaylandEvent::Pointer { event, .. } => {
                         match event {
@@ -1230,7 +2328,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 let window_context3 = window_context2.clone();
                                 let queue_context3 = queue_context2.clone();
                                 let mut client_context_r = client_context2.borrow_mut();
-                                client_context_r.fields.serial = Some(serial);
+                                client_context_r.fields.set_serial(serial);
                                 match window_context2.write() {
                                     Ok(mut window_context_g) => {
                                         match queue_context2.lock() {
@@ -1248,6 +2346,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 }
                                 client_context_r.update_cursor_surface(&timer_tx2);
                                 client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
                                 client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
                             wl_pointer::Event::Leave { serial, surface, } => {
@@ -1255,7 +2354,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 let window_context3 = window_context2.clone();
                                 let queue_context3 = queue_context2.clone();
                                 let mut client_context_r = client_context2.borrow_mut();
-                                client_context_r.fields.serial = Some(serial);
+                                client_context_r.fields.set_serial(serial);
                                 match window_context2.write() {
                                     Ok(mut window_context_g) => {
                                         match queue_context2.lock() {
@@ -1273,6 +2372,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 }
                                 client_context_r.update_cursor_surface(&timer_tx2);
                                 client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
                                 client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
                             wl_pointer::Event::Motion { time, surface_x, surface_y, } => {
@@ -1297,6 +2397,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 }
                                 client_context_r.update_cursor_surface(&timer_tx2);
                                 client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
                                 client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
                             wl_pointer::Event::Button { serial, time, button, state, } => {
@@ -1304,7 +2405,10 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 let window_context3 = window_context2.clone();
                                 let queue_context3 = queue_context2.clone();
                                 let mut client_context_r = client_context2.borrow_mut();
-                                client_context_r.fields.serial = Some(serial);
+                                client_context_r.fields.set_serial(serial);
+                                if state == wl_pointer::ButtonState::Pressed {
+                                    client_context_r.fields.set_press_call_on_id_and_serial(CallOnId::Pointer, serial);
+                                }
                                 match window_context2.write() {
                                     Ok(mut window_context_g) => {
                                         match queue_context2.lock() {
@@ -1323,6 +2427,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 client_context_r.update_cursor_surface(&timer_tx2);
                                 client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                                 client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
                             },
                             wl_pointer::Event::Axis { time, axis, value, } => {
                                 let client_context3 = client_context2.clone();
@@ -1346,8 +2451,49 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 }
                                 client_context_r.update_cursor_surface(&timer_tx2);
                                 client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
+                                client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
+                            },
+                            wl_pointer::Event::Frame => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                let client_events = client_events_for_client_pointer_frame(&mut client_context_r, &timer_tx2);
+                                for client_event in client_events {
+                                    let client_context3 = client_context2.clone();
+                                    let window_context3 = window_context2.clone();
+                                    let queue_context3 = queue_context2.clone();
+                                    match window_context2.write() {
+                                        Ok(mut window_context_g) => {
+                                            match queue_context2.lock() {
+                                                Ok(mut queue_context_g) => {
+                                                    match prepare_event_for_client_pointer_update(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, client_event) {
+                                                        Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                        None => (),
+                                                    }
+                                                },
+                                                Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                            }
+                                            client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context3, window_context3, queue_context3, &timer_tx2);
+                                        },
+                                        Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                    }
+                                }
+                                client_context_r.update_cursor_surface(&timer_tx2);
+                                client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
                                 client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
+                            wl_pointer::Event::AxisSource { axis_source, } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                set_client_pointer_axis_source(&mut client_context_r, axis_source);
+                            },
+                            wl_pointer::Event::AxisStop { time, axis, } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                set_client_pointer_axis_stop(&mut client_context_r, time, axis);
+                            },
+                            wl_pointer::Event::AxisDiscrete { axis, discrete, } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                set_client_pointer_axis_discrete(&mut client_context_r, axis, discrete);
+                            },
                             _ => (),
                         }
                     },
@@ -1362,7 +2508,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 let window_context3 = window_context2.clone();
                                 let queue_context3 = queue_context2.clone();
                                 let mut client_context_r = client_context2.borrow_mut();
-                                client_context_r.fields.serial = Some(serial);
+                                client_context_r.fields.set_serial(serial);
                                 match window_context2.write() {
                                     Ok(mut window_context_g) => {
                                         match queue_context2.lock() {
@@ -1380,6 +2526,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 }
                                 client_context_r.update_cursor_surface(&timer_tx2);
                                 client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
                                 client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
                             wl_keyboard::Event::Leave { serial, surface, } => {
@@ -1387,7 +2534,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 let window_context3 = window_context2.clone();
                                 let queue_context3 = queue_context2.clone();
                                 let mut client_context_r = client_context2.borrow_mut();
-                                client_context_r.fields.serial = Some(serial);
+                                client_context_r.fields.set_serial(serial);
                                 match window_context2.write() {
                                     Ok(mut window_context_g) => {
                                         match queue_context2.lock() {
@@ -1405,6 +2552,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 }
                                 client_context_r.update_cursor_surface(&timer_tx2);
                                 client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
                                 client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
                             wl_keyboard::Event::Key { serial, time, key, state, } => {
@@ -1412,7 +2560,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 let window_context3 = window_context2.clone();
                                 let queue_context3 = queue_context2.clone();
                                 let mut client_context_r = client_context2.borrow_mut();
-                                client_context_r.fields.serial = Some(serial);
+                                client_context_r.fields.set_serial(serial);
                                 match window_context2.write() {
                                     Ok(mut window_context_g) => {
                                         match queue_context2.lock() {
@@ -1430,6 +2578,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 }
                                 client_context_r.update_cursor_surface(&timer_tx2);
                                 client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
                                 client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
                             wl_keyboard::Event::Modifiers { serial, mods_depressed, mods_latched, mods_locked, group, } => {
@@ -1437,7 +2586,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 let window_context3 = window_context2.clone();
                                 let queue_context3 = queue_context2.clone();
                                 let mut client_context_r = client_context2.borrow_mut();
-                                client_context_r.fields.serial = Some(serial);
+                                client_context_r.fields.set_serial(serial);
                                 match window_context2.write() {
                                     Ok(mut window_context_g) => {
                                         match queue_context2.lock() {
@@ -1455,8 +2604,13 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 }
                                 client_context_r.update_cursor_surface(&timer_tx2);
                                 client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
                                 client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
+                            wl_keyboard::Event::RepeatInfo { rate, delay, } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                set_key_repeat_info(&mut *client_context_r, rate, delay, &timer_tx2);
+                            },
                             _ => (),
                         }
                     },
@@ -1467,7 +2621,8 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 let window_context3 = window_context2.clone();
                                 let queue_context3 = queue_context2.clone();
                                 let mut client_context_r = client_context2.borrow_mut();
-                                client_context_r.fields.serial = Some(serial);
+                                client_context_r.fields.set_serial(serial);
+                                client_context_r.fields.set_press_call_on_id_and_serial(CallOnId::Touch(id), serial);
                                 match window_context2.write() {
                                     Ok(mut window_context_g) => {
                                         match queue_context2.lock() {
@@ -1485,6 +2640,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 }
                                 client_context_r.update_cursor_surface(&timer_tx2);
                                 client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
                                 client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
                             wl_touch::Event::Up { serial, time, id,  } => {
@@ -1492,7 +2648,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 let window_context3 = window_context2.clone();
                                 let queue_context3 = queue_context2.clone();
                                 let mut client_context_r = client_context2.borrow_mut();
-                                client_context_r.fields.serial = Some(serial);
+                                client_context_r.fields.set_serial(serial);
                                 match window_context2.write() {
                                     Ok(mut window_context_g) => {
                                         match queue_context2.lock() {
@@ -1510,6 +2666,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 }
                                 client_context_r.update_cursor_surface(&timer_tx2);
                                 client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
                                 client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
                             wl_touch::Event::Motion { time, id, x, y, } => {
@@ -1534,36 +2691,276 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 }
                                 client_context_r.update_cursor_surface(&timer_tx2);
                                 client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.send_tooltip_timer(&timer_tx2);
                                 client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
                             _ => (),
                         }
                     },
-                }
-        });
-        client_context_r.fields.seat.quick_assign(move |seat, event, _| {
-                match event {
-                    wl_seat::Event::Capabilities { capabilities } => {
-                        let mut client_context_r = client_context3.borrow_mut();
-                        if !client_context_r.fields.pointer.is_some() && capabilities.contains(wl_seat::Capability::Pointer) {
-                            let pointer = seat.get_pointer();
-                            pointer.assign(filter.clone());
-                            client_context_r.fields.pointer = Some(pointer);
+                    priv_wayland::WaylandEvent::PointerGestureSwipe { event, .. } => {
+                        let client_context3 = client_context2.clone();
+                        let window_context3 = window_context2.clone();
+                        let queue_context3 = queue_context2.clone();
+                        let mut client_context_r = client_context2.borrow_mut();
+                        match window_context2.write() {
+                            Ok(mut window_context_g) => {
+                                match queue_context2.lock() {
+                                    Ok(mut queue_context_g) => {
+                                        match prepare_event_for_client_pointer_swipe(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, event) {
+                                            Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                            None => (),
+                                        }
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                }
+                                client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context3, window_context3, queue_context3, &timer_tx2);
+                            },
+                            Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
                         }
-                        if !client_context_r.fields.keyboard.is_some() && capabilities.contains(wl_seat::Capability::Keyboard) {
-                            let keyboard = seat.get_keyboard();
-                            keyboard.assign(filter.clone());
-                            client_context_r.fields.keyboard = Some(keyboard);
+                        client_context_r.update_cursor_surface(&timer_tx2);
+                        client_context_r.send_post_button_release(&timer_tx2);
+                        client_context_r.send_tooltip_timer(&timer_tx2);
+                        client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
+                    },
+                    priv_wayland::WaylandEvent::PointerGesturePinch { event, .. } => {
+                        let client_context3 = client_context2.clone();
+                        let window_context3 = window_context2.clone();
+                        let queue_context3 = queue_context2.clone();
+                        let mut client_context_r = client_context2.borrow_mut();
+                        match window_context2.write() {
+                            Ok(mut window_context_g) => {
+                                match queue_context2.lock() {
+                                    Ok(mut queue_context_g) => {
+                                        match prepare_event_for_client_pointer_pinch(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, event) {
+                                            Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                            None => (),
+                                        }
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                }
+                                client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context3, window_context3, queue_context3, &timer_tx2);
+                            },
+                            Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
                         }
-                        if !client_context_r.fields.touch.is_some() && capabilities.contains(wl_seat::Capability::Touch) {
-                            let touch = seat.get_touch();
-                            touch.assign(filter.clone());
-                            client_context_r.fields.touch = Some(touch);
+                        client_context_r.update_cursor_surface(&timer_tx2);
+                        client_context_r.send_post_button_release(&timer_tx2);
+                        client_context_r.send_tooltip_timer(&timer_tx2);
+                        client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
+                    },
+                    priv_wayland::WaylandEvent::PointerGestureHold { event, .. } => {
+                        let client_context3 = client_context2.clone();
+                        let window_context3 = window_context2.clone();
+                        let queue_context3 = queue_context2.clone();
+                        let mut client_context_r = client_context2.borrow_mut();
+                        match window_context2.write() {
+                            Ok(mut window_context_g) => {
+                                match queue_context2.lock() {
+                                    Ok(mut queue_context_g) => {
+                                        match prepare_event_for_client_pointer_hold(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, event) {
+                                            Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                            None => (),
+                                        }
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                }
+                                client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context3, window_context3, queue_context3, &timer_tx2);
+                            },
+                            Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                        }
+                        client_context_r.update_cursor_surface(&timer_tx2);
+                        client_context_r.send_post_button_release(&timer_tx2);
+                        client_context_r.send_tooltip_timer(&timer_tx2);
+                        client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
+                    },
+                    priv_wayland::WaylandEvent::DataDevice { event, .. } => {
+                        match event {
+                            wl_data_device::Event::DataOffer { id, } => assign_data_offer(&id),
+                            wl_data_device::Event::Enter { serial, surface, x, y, id, } => {
+                                let client_context3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_drag_enter(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, serial, &surface, x, y, id) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                        }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context3, window_context3, queue_context3, &timer_tx2);
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                            },
+                            wl_data_device::Event::Leave => {
+                                let client_context3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_drag_leave(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                        }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context3, window_context3, queue_context3, &timer_tx2);
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                            },
+                            wl_data_device::Event::Motion { x, y, .. } => {
+                                let client_context3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_drag_motion(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, x, y) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                        }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context3, window_context3, queue_context3, &timer_tx2);
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                            },
+                            wl_data_device::Event::Drop => {
+                                let client_context3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_drop(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                        }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context3, window_context3, queue_context3, &timer_tx2);
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                            },
+                            wl_data_device::Event::Selection { id, } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match client_context_r.fields.current_seat_mut() {
+                                    Some(client_seat) => {
+                                        match &client_seat.selection_data_offer {
+                                            Some(data_offer) => data_offer.destroy(),
+                                            None => (),
+                                        }
+                                        client_seat.selection_data_offer = id;
+                                    },
+                                    None => {
+                                        match id {
+                                            Some(data_offer) => data_offer.destroy(),
+                                            None => (),
+                                        }
+                                    },
+                                }
+                            },
+                            _ => (),
+                        }
+                    },
+                    priv_wayland::WaylandEvent::PrimarySelectionDevice { event, .. } => {
+                        match event {
+                            zwp_primary_selection_device_v1::Event::DataOffer { offer, } => assign_primary_selection_offer(&offer),
+                            zwp_primary_selection_device_v1::Event::Selection { id, } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match client_context_r.fields.current_seat_mut() {
+                                    Some(client_seat) => {
+                                        match &client_seat.primary_selection_offer {
+                                            Some(offer) => offer.destroy(),
+                                            None => (),
+                                        }
+                                        client_seat.primary_selection_offer = id;
+                                    },
+                                    None => {
+                                        match id {
+                                            Some(offer) => offer.destroy(),
+                                            None => (),
+                                        }
+                                    },
+                                }
+                            },
+                            _ => (),
+                        }
+                    },
+                    priv_wayland::WaylandEvent::TextInput { event, .. } => {
+                        match event {
+                            zwp_text_input_v3::Event::Enter { surface, } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => enter_text_input(&mut client_context_r, &mut *window_context_g, &surface),
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                            },
+                            zwp_text_input_v3::Event::Leave { .. } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => leave_text_input(&mut client_context_r, &mut *window_context_g),
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                            },
+                            zwp_text_input_v3::Event::PreeditString { text, cursor_begin, cursor_end, } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                set_text_input_preedit(&mut client_context_r, text, cursor_begin, cursor_end);
+                            },
+                            zwp_text_input_v3::Event::CommitString { text, } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                set_text_input_committed_text(&mut client_context_r, text);
+                            },
+                            zwp_text_input_v3::Event::DeleteSurroundingText { before_length, after_length, } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                set_text_input_deleted_lens(&mut client_context_r, before_length, after_length);
+                            },
+                            zwp_text_input_v3::Event::Done { .. } => {
+                                let client_context3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_text_input_done(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                        }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context3, window_context3, queue_context3, &timer_tx2);
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                            },
+                            _ => (),
                         }
                     },
-                    _ => (),
                 }
         });
+        client_context_r.fields.queue_context = Some(queue_context.clone());
+        client_context_r.fields.thread_signal_sender = Some(thread_signal_sender);
+        update_client_seats(&mut client_context_r, &*window_context, &*queue_context, &filter);
         match window_context.write() {
             Ok(mut window_context_g) => {
                 window_context_g.window_container.clear_indices_to_destroy();
@@ -1571,7 +2968,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
             },
             Err(_) => return Err(ClientError::RwLock),
         }
-        (client_context_r.fields.click_repeat_delay, client_context_r.fields.click_repeat_time, client_context_r.fields.key_repeat_delay, client_context_r.fields.key_repeat_time, client_context_r.fields.text_cursor_blink_time)
+        (filter, client_context_r.fields.click_repeat_delay, client_context_r.fields.click_repeat_time, client_context_r.fields.key_repeat_delay, client_context_r.fields.key_repeat_time, client_context_r.fields.text_cursor_blink_time)
     };
     let timer_thread = thread::spawn(move || {
             let mut timer_data_vec = vec![
@@ -1604,6 +3001,16 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                     timer: ThreadTimer::PostButtonRelease,
                     delay: None,
                     repeat: ThreadTimerRepeat::None,
+                },
+                ThreadTimerData {
+                    timer: ThreadTimer::KineticScroll,
+                    delay: None,
+                    repeat: ThreadTimerRepeat::OneDelay(Duration::from_millis(KINETIC_SCROLL_INTERVAL as u64)),
+                },
+                ThreadTimerData {
+                    timer: ThreadTimer::Tooltip,
+                    delay: None,
+                    repeat: ThreadTimerRepeat::None,
                 }
             ];
             loop {
@@ -1672,6 +3079,9 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 ThreadTimerCommand::SetDelay(timer, tmp_delay) if timer == timer_data.timer => {
                                     timer_data.delay = Some(tmp_delay);
                                 },
+                                ThreadTimerCommand::SetRepeat(timer, repeat) if timer == timer_data.timer => {
+                                    timer_data.repeat = repeat;
+                                },
                                 ThreadTimerCommand::Start(timer) if timer == timer_data.timer => {
                                     match timer_data.repeat {
                                         ThreadTimerRepeat::None => (),
@@ -1740,6 +3150,27 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                             return Err(ClientError::Io(err));
                         },
                     }
+                    let mut client_context_r = client_context.borrow_mut();
+                    let has_seat_change = client_context_r.fields.seat_globals.borrow().has_change();
+                    if has_seat_change {
+                        update_client_seats(&mut client_context_r, &*window_context, &*queue_context, &filter);
+                    }
+                    let has_output_change = {
+                        let mut outputs_r = client_context_r.fields.outputs.borrow_mut();
+                        let tmp_has_output_change = outputs_r.has_change;
+                        outputs_r.has_change = false;
+                        tmp_has_output_change
+                    };
+                    let has_frame_done = client_context_r.fields.has_frame_done.replace(false);
+                    if has_output_change || has_frame_done {
+                        let client_context2 = client_context.clone();
+                        let window_context2 = window_context.clone();
+                        let queue_context2 = queue_context.clone();
+                        match window_context.write() {
+                            Ok(mut window_context_g) => client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context2, window_context2, queue_context2, &timer_tx),
+                            Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                        }
+                    }
                 }
             },
             None => (),
@@ -1753,6 +3184,8 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                     let mut is_touch_timer = false;
                     let mut is_text_cursor_timer = false;
                     let mut is_post_button_release_timer = false;
+                    let mut is_kinetic_scroll_timer = false;
+                    let mut is_tooltip_timer = false;
                     let mut is_other = false;
                     loop {
                         match thread_signal_receiver.recv() {
@@ -1762,6 +3195,8 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                             Ok(Some(ThreadSignal::Timer(ThreadTimer::Touch))) => is_touch_timer = true,
                             Ok(Some(ThreadSignal::Timer(ThreadTimer::TextCursor))) => is_text_cursor_timer = true,
                             Ok(Some(ThreadSignal::Timer(ThreadTimer::PostButtonRelease))) => is_post_button_release_timer = true,
+                            Ok(Some(ThreadSignal::Timer(ThreadTimer::KineticScroll))) => is_kinetic_scroll_timer = true,
+                            Ok(Some(ThreadSignal::Timer(ThreadTimer::Tooltip))) => is_tooltip_timer = true,
                             Ok(Some(ThreadSignal::Other)) => is_other = true,
                             Ok(None) => (),
                             Err(err) => {
@@ -1797,7 +3232,8 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                     }
                     if is_button_timer {
                         let mut client_context_r = client_context.borrow_mut();
-                        if client_context_r.fields.has_pressed_button {
+                        let seat_ids: Vec<SeatId> = client_context_r.fields.seats.iter().filter(|p| p.1.has_pressed_button).map(|p| *(p.0)).collect();
+                        for seat_id in &seat_ids {
                             let client_context2 = client_context.clone();
                             let window_context2 = window_context.clone();
                             let queue_context2 = queue_context.clone();
@@ -1805,6 +3241,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 Ok(mut window_context_g) => {
                                     match queue_context.lock() {
                                         Ok(mut queue_context_g) => {
+                                            client_context_r.set_current_seat_id(&mut *queue_context_g, Some(*seat_id));
                                             match prepare_event_for_client_repeated_button(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g) {
                                                 Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
                                                 None => (),
@@ -1819,12 +3256,13 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                         }
                         client_context_r.update_cursor_surface(&timer_tx);
                         client_context_r.send_post_button_release(&timer_tx);
+                        client_context_r.send_tooltip_timer(&timer_tx);
                         client_context_r.stop_button_timer_and_touch_timer(&timer_tx);
                     }
                     if is_key_timer {
                         let mut client_context_r = client_context.borrow_mut();
-                        let key_codes: Vec<u32> = client_context_r.fields.key_codes.iter().map(|kc| *kc).collect();
-                        for key_code in &key_codes {
+                        let key_codes: Vec<(SeatId, u32)> = client_context_r.fields.seats.iter().flat_map(|p| p.1.key_codes.keys().map(move |kc| (*(p.0), *kc))).collect();
+                        for (seat_id, key_code) in &key_codes {
                             let client_context2 = client_context.clone();
                             let window_context2 = window_context.clone();
                             let queue_context2 = queue_context.clone();
@@ -1832,6 +3270,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 Ok(mut window_context_g) => {
                                     match queue_context.lock() {
                                         Ok(mut queue_context_g) => {
+                                            client_context_r.set_current_seat_id(&mut *queue_context_g, Some(*seat_id));
                                             match prepare_event_for_client_repeated_key(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, *key_code) {
                                                 Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
                                                 None => (),
@@ -1846,12 +3285,13 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                         }
                         client_context_r.update_cursor_surface(&timer_tx);
                         client_context_r.send_post_button_release(&timer_tx);
+                        client_context_r.send_tooltip_timer(&timer_tx);
                         client_context_r.stop_button_timer_and_touch_timer(&timer_tx);
                     }
                     if is_touch_timer {
                         let mut client_context_r = client_context.borrow_mut();
-                        let ids: Vec<i32> = client_context_r.fields.touch_ids.iter().map(|id| *id).collect();
-                        for id in &ids {
+                        let ids: Vec<(SeatId, i32)> = client_context_r.fields.seats.iter().flat_map(|p| p.1.touch_ids.iter().map(move |id| (*(p.0), *id))).collect();
+                        for (seat_id, id) in &ids {
                             let client_context2 = client_context.clone();
                             let window_context2 = window_context.clone();
                             let queue_context2 = queue_context.clone();
@@ -1859,6 +3299,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                                 Ok(mut window_context_g) => {
                                     match queue_context.lock() {
                                         Ok(mut queue_context_g) => {
+                                            client_context_r.set_current_seat_id(&mut *queue_context_g, Some(*seat_id));
                                             match prepare_event_for_client_repeated_touch(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, *id) {
                                                 Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
                                                 None => (),
@@ -1873,6 +3314,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                         }
                         client_context_r.update_cursor_surface(&timer_tx);
                         client_context_r.send_post_button_release(&timer_tx);
+                        client_context_r.send_tooltip_timer(&timer_tx);
                         client_context_r.stop_button_timer_and_touch_timer(&timer_tx);
                     }
                     if is_text_cursor_timer {
@@ -1900,8 +3342,51 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                         }
                         client_context_r.update_cursor_surface(&timer_tx);
                         client_context_r.send_post_button_release(&timer_tx);
+                        client_context_r.send_tooltip_timer(&timer_tx);
+                        client_context_r.stop_button_timer_and_touch_timer(&timer_tx);
+                    }
+                    if is_kinetic_scroll_timer {
+                        let mut client_context_r = client_context.borrow_mut();
+                        let client_events = client_events_for_client_kinetic_scroll(&mut client_context_r, &timer_tx);
+                        for (seat_id, client_event) in client_events {
+                            let client_context2 = client_context.clone();
+                            let window_context2 = window_context.clone();
+                            let queue_context2 = queue_context.clone();
+                            match window_context.write() {
+                                Ok(mut window_context_g) => {
+                                    match queue_context.lock() {
+                                        Ok(mut queue_context_g) => {
+                                            client_context_r.set_current_seat_id(&mut *queue_context_g, Some(seat_id));
+                                            match prepare_event_for_client_pointer_update(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, client_event) {
+                                                Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                None => (),
+                                            }
+                                        },
+                                        Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                    }
+                                    client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context2, window_context2, queue_context2, &timer_tx);
+                                },
+                                Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                            }
+                        }
+                        client_context_r.update_cursor_surface(&timer_tx);
+                        client_context_r.send_post_button_release(&timer_tx);
+                        client_context_r.send_tooltip_timer(&timer_tx);
                         client_context_r.stop_button_timer_and_touch_timer(&timer_tx);
                     }
+                    if is_tooltip_timer {
+                        let client_context2 = client_context.clone();
+                        let window_context2 = window_context.clone();
+                        let queue_context2 = queue_context.clone();
+                        let mut client_context_r = client_context.borrow_mut();
+                        match window_context.write() {
+                            Ok(mut window_context_g) => {
+                                show_client_tooltip_for_timer(&mut client_context_r, &mut *window_context_g);
+                                client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context2, window_context2, queue_context2, &timer_tx);
+                            },
+                            Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                        }
+                    }
                     if is_other {
                         let client_context2 = client_context.clone();
                         let window_context2 = window_context.clone();
@@ -1919,6 +3404,7 @@ pub(crate) fn run_main_loop(client_display: &mut ClientDisplay, client_context:
                         }
                         client_context_r.update_cursor_surface(&timer_tx);
                         client_context_r.send_post_button_release(&timer_tx);
+                        client_context_r.send_tooltip_timer(&timer_tx);
                         client_context_r.stop_button_timer_and_touch_timer(&timer_tx);
                     }
                 }
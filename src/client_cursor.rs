@@ -0,0 +1,191 @@
+//
+// Copyright (c) 2022-2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::collections::HashMap;
+use std::env;
+use wayland_client::protocol::wl_shm;
+use wayland_client::Attached;
+use wayland_cursor::Cursor as WaylandCursor;
+use wayland_cursor::CursorTheme;
+use crate::cursors::*;
+use crate::protocols::cursor_shape_v1::client::wp_cursor_shape_device_v1;
+
+const DEFAULT_CURSOR_THEME_NAME: &str = "default";
+
+const DEFAULT_CURSOR_SIZE: u32 = 32;
+
+static CURSORS: &[Cursor] = &[
+    Cursor::Default,
+    Cursor::Text,
+    Cursor::Hand,
+    Cursor::Pencil,
+    Cursor::Cross,
+    Cursor::Wait,
+    Cursor::TopLeftCorner,
+    Cursor::TopRightCorner,
+    Cursor::TopSide,
+    Cursor::LeftSide,
+    Cursor::BottomLeftCorner,
+    Cursor::BottomRightCorner,
+    Cursor::BottomSide,
+    Cursor::RightSide,
+    Cursor::HDoubleArrow,
+    Cursor::VDoubleArrow,
+    Cursor::Grab,
+    Cursor::Grabbing,
+    Cursor::NotAllowed,
+    Cursor::Move,
+    Cursor::ZoomIn,
+    Cursor::ZoomOut,
+    Cursor::Help,
+    Cursor::Progress
+];
+
+/// Returns the names of the cursor in a cursor theme.
+///
+/// The first name is a name from the X11 cursor font. Next names are fallback names which are used
+/// by cursor themes that follow the CSS cursor names or the names of other toolkits.
+fn cursor_names(cursor: &Cursor) -> &'static [&'static str]
+{
+    match cursor {
+        Cursor::Default => &["left_ptr", "default", "top_left_arrow", "left_arrow"],
+        Cursor::Text => &["xterm", "text", "ibeam"],
+        Cursor::Hand => &["hand1", "pointer", "hand2", "pointing_hand"],
+        Cursor::Pencil => &["pencil", "draft"],
+        Cursor::Cross => &["cross", "crosshair", "tcross"],
+        Cursor::Wait => &["watch", "wait"],
+        Cursor::TopLeftCorner => &["top_left_corner", "nw-resize", "size_fdiag"],
+        Cursor::TopRightCorner => &["top_right_corner", "ne-resize", "size_bdiag"],
+        Cursor::TopSide => &["top_side", "n-resize", "size_ver"],
+        Cursor::LeftSide => &["left_side", "w-resize", "size_hor"],
+        Cursor::BottomLeftCorner => &["bottom_left_corner", "sw-resize", "size_bdiag"],
+        Cursor::BottomRightCorner => &["bottom_right_corner", "se-resize", "size_fdiag"],
+        Cursor::BottomSide => &["bottom_side", "s-resize", "size_ver"],
+        Cursor::RightSide => &["right_side", "e-resize", "size_hor"],
+        Cursor::HDoubleArrow => &["sb_h_double_arrow", "ew-resize", "col-resize", "size_hor"],
+        Cursor::VDoubleArrow => &["sb_v_double_arrow", "ns-resize", "row-resize", "size_ver"],
+        Cursor::Grab => &["grab", "openhand", "hand1"],
+        Cursor::Grabbing => &["grabbing", "closedhand", "fleur"],
+        Cursor::NotAllowed => &["not-allowed", "crossed_circle", "forbidden"],
+        Cursor::Move => &["move", "fleur", "all-scroll"],
+        Cursor::ZoomIn => &["zoom-in", "zoom_in"],
+        Cursor::ZoomOut => &["zoom-out", "zoom_out"],
+        Cursor::Help => &["help", "question_arrow", "left_ptr_help"],
+        Cursor::Progress => &["progress", "left_ptr_watch", "half-busy"],
+        Cursor::Custom(_) => &[],
+    }
+}
+
+/// Returns the cursor shape for the cursor-shape protocol or `None`.
+///
+/// The cursor that hasn't a cursor shape is loaded from a cursor theme or is drawn if the cursor is
+/// a custom cursor.
+pub(crate) fn cursor_shape(cursor: &Cursor) -> Option<wp_cursor_shape_device_v1::Shape>
+{
+    match cursor {
+        Cursor::Default => Some(wp_cursor_shape_device_v1::Shape::Default),
+        Cursor::Text => Some(wp_cursor_shape_device_v1::Shape::Text),
+        Cursor::Hand => Some(wp_cursor_shape_device_v1::Shape::Pointer),
+        Cursor::Pencil => None,
+        Cursor::Cross => Some(wp_cursor_shape_device_v1::Shape::Crosshair),
+        Cursor::Wait => Some(wp_cursor_shape_device_v1::Shape::Wait),
+        Cursor::TopLeftCorner => Some(wp_cursor_shape_device_v1::Shape::NwResize),
+        Cursor::TopRightCorner => Some(wp_cursor_shape_device_v1::Shape::NeResize),
+        Cursor::TopSide => Some(wp_cursor_shape_device_v1::Shape::NResize),
+        Cursor::LeftSide => Some(wp_cursor_shape_device_v1::Shape::WResize),
+        Cursor::BottomLeftCorner => Some(wp_cursor_shape_device_v1::Shape::SwResize),
+        Cursor::BottomRightCorner => Some(wp_cursor_shape_device_v1::Shape::SeResize),
+        Cursor::BottomSide => Some(wp_cursor_shape_device_v1::Shape::SResize),
+        Cursor::RightSide => Some(wp_cursor_shape_device_v1::Shape::EResize),
+        Cursor::HDoubleArrow => Some(wp_cursor_shape_device_v1::Shape::EwResize),
+        Cursor::VDoubleArrow => Some(wp_cursor_shape_device_v1::Shape::NsResize),
+        Cursor::Grab => Some(wp_cursor_shape_device_v1::Shape::Grab),
+        Cursor::Grabbing => Some(wp_cursor_shape_device_v1::Shape::Grabbing),
+        Cursor::NotAllowed => Some(wp_cursor_shape_device_v1::Shape::NotAllowed),
+        Cursor::Move => Some(wp_cursor_shape_device_v1::Shape::Move),
+        Cursor::ZoomIn => Some(wp_cursor_shape_device_v1::Shape::ZoomIn),
+        Cursor::ZoomOut => Some(wp_cursor_shape_device_v1::Shape::ZoomOut),
+        Cursor::Help => Some(wp_cursor_shape_device_v1::Shape::Help),
+        Cursor::Progress => Some(wp_cursor_shape_device_v1::Shape::Progress),
+        Cursor::Custom(_) => None,
+    }
+}
+
+/// A structure of client cursors.
+///
+/// The client cursors are loaded from a cursor theme that is specified by the `XCURSOR_THEME`
+/// environment variable. A cursor size is specified by the `XCURSOR_SIZE` environment variable
+/// and is multiplied by a scale of outputs.
+pub(crate) struct ClientCursors
+{
+    theme_name: String,
+    size: u32,
+    pub(crate) scale: i32,
+    theme: CursorTheme,
+    cursors: HashMap<Cursor, WaylandCursor>,
+}
+
+impl ClientCursors
+{
+    pub(crate) fn new(shm: &Attached<wl_shm::WlShm>, scale: i32) -> Self
+    {
+        let theme_name = match env::var("XCURSOR_THEME") {
+            Ok(tmp_theme_name) if !tmp_theme_name.is_empty() => tmp_theme_name,
+            _ => String::from(DEFAULT_CURSOR_THEME_NAME),
+        };
+        let size = match env::var("XCURSOR_SIZE") {
+            Ok(s) => {
+                match s.parse::<u32>() {
+                    Ok(tmp_size) if tmp_size > 0 => tmp_size,
+                    _ => DEFAULT_CURSOR_SIZE,
+                }
+            },
+            Err(_) => DEFAULT_CURSOR_SIZE,
+        };
+        let mut theme = CursorTheme::load_from_name(theme_name.as_str(), size * (scale as u32), shm);
+        let cursors = load_cursors(&mut theme);
+        ClientCursors { theme_name, size, scale, theme, cursors, }
+    }
+
+    /// Reloads the cursors if the scale is changed.
+    pub(crate) fn set_scale(&mut self, shm: &Attached<wl_shm::WlShm>, scale: i32)
+    {
+        if self.scale != scale {
+            self.theme = CursorTheme::load_from_name(self.theme_name.as_str(), self.size * (scale as u32), shm);
+            self.cursors = load_cursors(&mut self.theme);
+            self.scale = scale;
+        }
+    }
+
+    /// Returns a reference to the cursor or `None`.
+    ///
+    /// This method returns the default cursor if the cursor isn't in the cursor theme.
+    pub(crate) fn get(&self, cursor: &Cursor) -> Option<&WaylandCursor>
+    {
+        match self.cursors.get(cursor) {
+            Some(wayland_cursor) => Some(wayland_cursor),
+            None => self.cursors.get(&Cursor::Default),
+        }
+    }
+}
+
+fn load_cursors(theme: &mut CursorTheme) -> HashMap<Cursor, WaylandCursor>
+{
+    let mut cursors: HashMap<Cursor, WaylandCursor> = HashMap::new();
+    for cursor in CURSORS {
+        for name in cursor_names(cursor) {
+            match theme.get_cursor(name) {
+                Some(wayland_cursor) => {
+                    cursors.insert(cursor.clone(), wayland_cursor.clone());
+                    break;
+                },
+                None => (),
+            }
+        }
+    }
+    cursors
+}
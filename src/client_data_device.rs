@@ -0,0 +1,434 @@
+//
+// Copyright (c) 2022-2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::cell::*;
+use std::cmp::max;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::os::unix::io::FromRawFd;
+use std::os::unix::io::RawFd;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread;
+use nix::fcntl::OFlag;
+use nix::unistd::close;
+use nix::unistd::pipe2;
+use wayland_client::protocol::wl_data_device_manager;
+use wayland_client::protocol::wl_data_offer;
+use wayland_client::protocol::wl_data_source;
+use wayland_client::protocol::wl_surface;
+use wayland_client::Main;
+use wayland_protocols::unstable::primary_selection::v1::client::zwp_primary_selection_device_manager_v1;
+use wayland_protocols::unstable::primary_selection::v1::client::zwp_primary_selection_offer_v1;
+use wayland_protocols::unstable::primary_selection::v1::client::zwp_primary_selection_source_v1;
+use crate::client_buffer::*;
+use crate::client_context::*;
+use crate::client_error::*;
+use crate::events::*;
+use crate::queue_context::*;
+use crate::thread_signal::*;
+use crate::types::*;
+use crate::utils::*;
+use crate::window_context::*;
+
+pub(crate) struct ClientDragIcon
+{
+    surface: Main<wl_surface::WlSurface>,
+    buffer_pool: ClientBufferPool,
+    buffer_index: usize,
+    scale: i32,
+}
+
+impl ClientDragIcon
+{
+    /// Creates a drag icon that is drawn by the closure.
+    pub(crate) fn new<F>(client_context_fields: &ClientContextFields, size: Size<i32>, scale: i32, f: F) -> Result<Self, ClientError>
+        where F: FnOnce(&CairoContext) -> Result<(), CairoError>
+    {
+        let width = max(size.width, 1) * scale;
+        let height = max(size.height, 1) * scale;
+        let mut buffer_pool = ClientBufferPool::new(client_context_fields, (width as usize) * (height as usize) * 4)?;
+        let res = match buffer_pool.next_buffer(width, height) {
+            Ok(buffer_idx) => {
+                match buffer_pool.create_cairo_surface(buffer_idx) {
+                    Ok(cairo_surface) => {
+                        let res2 = with_cairo_context(&cairo_surface, |cairo_context| {
+                                cairo_context.scale(scale as f64, scale as f64);
+                                f(cairo_context)
+                        });
+                        cairo_surface.flush();
+                        match res2 {
+                            Ok(()) => Ok(buffer_idx),
+                            Err(err) => Err(ClientError::Cairo(err)),
+                        }
+                    },
+                    Err(err) => Err(err),
+                }
+            },
+            Err(err) => Err(err),
+        };
+        match res {
+            Ok(buffer_idx) => {
+                let surface = client_context_fields.compositor.create_surface();
+                Ok(ClientDragIcon {
+                        surface,
+                        buffer_pool,
+                        buffer_index: buffer_idx,
+                        scale,
+                })
+            },
+            Err(err) => {
+                buffer_pool.destroy();
+                Err(err)
+            },
+        }
+    }
+
+    pub(crate) fn surface(&self) -> &wl_surface::WlSurface
+    { &*self.surface }
+
+    pub(crate) fn commit(&mut self) -> Result<(), ClientError>
+    {
+        self.buffer_pool.attach(self.buffer_index, &self.surface)?;
+        if self.surface.as_ref().version() >= 3 {
+            self.surface.set_buffer_scale(self.scale);
+        }
+        self.surface.damage(0, 0, i32::MAX, i32::MAX);
+        self.surface.commit();
+        Ok(())
+    }
+
+    pub(crate) fn destroy(&self)
+    {
+        self.surface.destroy();
+        self.buffer_pool.destroy();
+    }
+}
+
+pub(crate) fn assign_data_offer(data_offer: &Main<wl_data_offer::WlDataOffer>)
+{
+    data_offer.as_ref().user_data().set(|| RefCell::new(Vec::<String>::new()));
+    data_offer.quick_assign(|data_offer, event, _| {
+            match event {
+                wl_data_offer::Event::Offer { mime_type, } => {
+                    match data_offer.as_ref().user_data().get::<RefCell<Vec<String>>>() {
+                        Some(mime_types) => mime_types.borrow_mut().push(mime_type),
+                        None => (),
+                    }
+                },
+                _ => (),
+            }
+    });
+}
+
+pub(crate) fn data_offer_mime_types(data_offer: &wl_data_offer::WlDataOffer) -> Vec<String>
+{
+    match data_offer.as_ref().user_data().get::<RefCell<Vec<String>>>() {
+        Some(mime_types) => mime_types.borrow().clone(),
+        None => Vec::new(),
+    }
+}
+
+fn push_data_callback<F>(queue_context: &Mutex<QueueContext>, thread_signal_sender: ThreadSignalSender, seat_id: Option<SeatId>, mut f: F, data: Vec<u8>)
+    where F: FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext, &[u8]) -> Option<()> + Send + Sync + 'static
+{
+    match queue_context.lock() {
+        Ok(mut queue_context_g) => {
+            queue_context_g.callback_queue_mut().push_with_seat_id(seat_id, move |client_context, window_context, queue_context| {
+                    f(client_context, window_context, queue_context, data.as_slice())
+            });
+        },
+        Err(_) => {
+            eprintln!("lwltk: {}", ClientError::Mutex);
+            return;
+        },
+    }
+    match thread_signal_sender.commit() {
+        Ok(()) => (),
+        Err(err) => eprintln!("lwltk: {}", err),
+    }
+}
+
+fn receive_data<F, G>(receiving_f: G, queue_context: Arc<Mutex<QueueContext>>, thread_signal_sender: ThreadSignalSender, seat_id: Option<SeatId>, f: F) -> Result<(), ClientError>
+    where F: FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext, &[u8]) -> Option<()> + Send + Sync + 'static,
+          G: FnOnce(RawFd)
+{
+    let (reading_fd, writing_fd) = match pipe2(OFlag::O_CLOEXEC) {
+        Ok(tmp_fds) => tmp_fds,
+        Err(err) => return Err(ClientError::Nix(err)),
+    };
+    receiving_f(writing_fd);
+    match close(writing_fd) {
+        Ok(()) => (),
+        Err(err) => {
+            let _res = close(reading_fd);
+            return Err(ClientError::Nix(err));
+        },
+    }
+    thread::spawn(move || {
+            let mut file = unsafe { File::from_raw_fd(reading_fd) };
+            let mut data: Vec<u8> = Vec::new();
+            match file.read_to_end(&mut data) {
+                Ok(_) => push_data_callback(&*queue_context, thread_signal_sender, seat_id, f, data),
+                Err(err) => eprintln!("lwltk: {}", ClientError::Io(err)),
+            }
+    });
+    Ok(())
+}
+
+/// Receives data of the data offer in the MIME type.
+///
+/// The data are read from a pipe by other thread, so a main loop isn't blocked. The closure is
+/// pushed to the callback queue with the seat identifier of the reading request after the data are
+/// read.
+pub(crate) fn receive_data_offer<F>(data_offer: &wl_data_offer::WlDataOffer, mime_type: &str, queue_context: Arc<Mutex<QueueContext>>, thread_signal_sender: ThreadSignalSender, seat_id: Option<SeatId>, f: F) -> Result<(), ClientError>
+    where F: FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext, &[u8]) -> Option<()> + Send + Sync + 'static
+{ receive_data(|fd| data_offer.receive(String::from(mime_type), fd), queue_context, thread_signal_sender, seat_id, f) }
+
+fn finish_drop_data_offer(client_context: &mut ClientContext)
+{
+    match client_context.fields.drop_data_offer.take() {
+        Some(data_offer) => {
+            if data_offer.as_ref().version() >= 3 {
+                data_offer.finish();
+            }
+            data_offer.destroy();
+        },
+        None => (),
+    }
+    client_context.fields.drop_read_count = 0;
+}
+
+/// Finishes drag and drop after reading of the dropped data if other reading requests for the
+/// dropped data aren't pending.
+///
+/// This function is called after the dropped data are passed to a closure, so the closure can
+/// request reading of the dropped data in other MIME type.
+pub(crate) fn finish_drop_reading(client_context: &mut ClientContext, data_offer: &wl_data_offer::WlDataOffer)
+{
+    if client_context.fields.drop_data_offer.as_ref() != Some(data_offer) {
+        return;
+    }
+    client_context.fields.drop_read_count = client_context.fields.drop_read_count.saturating_sub(1);
+    if client_context.fields.drop_read_count == 0 {
+        finish_drop_data_offer(client_context);
+    }
+}
+
+fn send_data(fd: RawFd, data: Arc<Vec<u8>>)
+{
+    thread::spawn(move || {
+            let mut file = unsafe { File::from_raw_fd(fd) };
+            match file.write_all(data.as_slice()) {
+                Ok(()) => (),
+                Err(err) => eprintln!("lwltk: {}", ClientError::Io(err)),
+            }
+    });
+}
+
+/// Creates a data source that offers the data in the MIME types.
+///
+/// The data are written to a pipe by other thread, so a main loop isn't blocked. The data source
+/// for drag and drop offers the copy action and the move action. The closure is called when the
+/// data source is cancelled or drag and drop is finished.
+pub(crate) fn create_data_source<F>(data_device_manager: &wl_data_device_manager::WlDataDeviceManager, data: Vec<(String, Vec<u8>)>, is_drag: bool, mut ending_f: F) -> Main<wl_data_source::WlDataSource>
+    where F: FnMut() + 'static
+{
+    let data_source = data_device_manager.create_data_source();
+    let mut data_map: BTreeMap<String, Arc<Vec<u8>>> = BTreeMap::new();
+    for (mime_type, mime_type_data) in data {
+        data_source.offer(mime_type.clone());
+        data_map.insert(mime_type, Arc::new(mime_type_data));
+    }
+    if is_drag && data_source.as_ref().version() >= 3 {
+        data_source.set_actions(wl_data_device_manager::DndAction::Copy | wl_data_device_manager::DndAction::Move);
+    }
+    data_source.quick_assign(move |data_source, event, _| {
+            match event {
+                wl_data_source::Event::Send { mime_type, fd, } => {
+                    match data_map.get(&mime_type) {
+                        Some(mime_type_data) => send_data(fd, mime_type_data.clone()),
+                        None => {
+                            let _res = close(fd);
+                        },
+                    }
+                },
+                wl_data_source::Event::Cancelled => {
+                    data_source.destroy();
+                    ending_f();
+                },
+                wl_data_source::Event::DndFinished => {
+                    data_source.destroy();
+                    ending_f();
+                },
+                _ => (),
+            }
+    });
+    data_source
+}
+
+pub(crate) fn assign_primary_selection_offer(offer: &Main<zwp_primary_selection_offer_v1::ZwpPrimarySelectionOfferV1>)
+{
+    offer.as_ref().user_data().set(|| RefCell::new(Vec::<String>::new()));
+    offer.quick_assign(|offer, event, _| {
+            match event {
+                zwp_primary_selection_offer_v1::Event::Offer { mime_type, } => {
+                    match offer.as_ref().user_data().get::<RefCell<Vec<String>>>() {
+                        Some(mime_types) => mime_types.borrow_mut().push(mime_type),
+                        None => (),
+                    }
+                },
+                _ => (),
+            }
+    });
+}
+
+pub(crate) fn primary_selection_offer_mime_types(offer: &zwp_primary_selection_offer_v1::ZwpPrimarySelectionOfferV1) -> Vec<String>
+{
+    match offer.as_ref().user_data().get::<RefCell<Vec<String>>>() {
+        Some(mime_types) => mime_types.borrow().clone(),
+        None => Vec::new(),
+    }
+}
+
+/// Receives data of the primary selection offer in the MIME type.
+///
+/// See [`receive_data_offer`] for more informations.
+pub(crate) fn receive_primary_selection_offer<F>(offer: &zwp_primary_selection_offer_v1::ZwpPrimarySelectionOfferV1, mime_type: &str, queue_context: Arc<Mutex<QueueContext>>, thread_signal_sender: ThreadSignalSender, seat_id: Option<SeatId>, f: F) -> Result<(), ClientError>
+    where F: FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext, &[u8]) -> Option<()> + Send + Sync + 'static
+{ receive_data(|fd| offer.receive(String::from(mime_type), fd), queue_context, thread_signal_sender, seat_id, f) }
+
+/// Creates a primary selection source that offers the data in the MIME types.
+pub(crate) fn create_primary_selection_source(primary_selection_device_manager: &zwp_primary_selection_device_manager_v1::ZwpPrimarySelectionDeviceManagerV1, data: Vec<(String, Vec<u8>)>) -> Main<zwp_primary_selection_source_v1::ZwpPrimarySelectionSourceV1>
+{
+    let source = primary_selection_device_manager.create_source();
+    let mut data_map: BTreeMap<String, Arc<Vec<u8>>> = BTreeMap::new();
+    for (mime_type, mime_type_data) in data {
+        source.offer(mime_type.clone());
+        data_map.insert(mime_type, Arc::new(mime_type_data));
+    }
+    source.quick_assign(move |source, event, _| {
+            match event {
+                zwp_primary_selection_source_v1::Event::Send { mime_type, fd, } => {
+                    match data_map.get(&mime_type) {
+                        Some(mime_type_data) => send_data(fd, mime_type_data.clone()),
+                        None => {
+                            let _res = close(fd);
+                        },
+                    }
+                },
+                zwp_primary_selection_source_v1::Event::Cancelled => source.destroy(),
+                _ => (),
+            }
+    });
+    source
+}
+
+pub(crate) fn prepare_event_for_client_drag_enter(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, serial: u32, surface: &wl_surface::WlSurface, x: f64, y: f64, data_offer: Option<wl_data_offer::WlDataOffer>) -> Option<Event>
+{
+    match &client_context.fields.drag_data_offer {
+        Some(old_data_offer) => old_data_offer.destroy(),
+        None => (),
+    }
+    let mime_types = match &data_offer {
+        Some(data_offer) => data_offer_mime_types(data_offer),
+        None => Vec::new(),
+    };
+    client_context.fields.drag_data_offer = data_offer;
+    client_context.fields.drag_serial = Some(serial);
+    // A data offer of the previous drop is destroyed if its dropped data aren't read.
+    if client_context.fields.drop_read_count == 0 {
+        match client_context.fields.drop_data_offer.take() {
+            Some(data_offer) => data_offer.destroy(),
+            None => (),
+        }
+    }
+    match client_context.window_index_for_surface(surface) {
+        Some(window_idx) => {
+            let pos = Pos::new(x, y);
+            match client_context.add_event_preparation(window_context, CallOnId::Drag, window_idx, pos, None) {
+                Some((call_on_path, pos)) => {
+                    window_context.current_window_index = Some(call_on_path.window_index());
+                    window_context.current_pos = Some(pos);
+                    queue_context.current_call_on_path = Some(call_on_path);
+                    Some(Event::DragEnter(mime_types, pos))
+                },
+                None => {
+                    eprintln!("lwltk: {}", ClientError::EventPreparation);
+                    None
+                },
+            }
+        },
+        None => {
+            eprintln!("lwltk: {}", ClientError::NoClientWindow);
+            None
+        },
+    }
+}
+
+pub(crate) fn prepare_event_for_client_drag_leave(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext) -> Option<Event>
+{
+    // A data offer of drop is moved by the drop event and is destroyed after reading of dropped
+    // data.
+    match client_context.fields.drag_data_offer.take() {
+        Some(data_offer) => data_offer.destroy(),
+        None => (),
+    }
+    client_context.fields.drag_serial = None;
+    match client_context.remove_event_preparation(window_context, CallOnId::Drag) {
+        Some((call_on_path, pos)) => {
+            window_context.current_window_index = Some(call_on_path.window_index());
+            window_context.current_pos = Some(pos);
+            queue_context.current_call_on_path = Some(call_on_path);
+            Some(Event::DragLeave)
+        },
+        None => None,
+    }
+}
+
+pub(crate) fn prepare_event_for_client_drag_motion(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, x: f64, y: f64) -> Option<Event>
+{
+    let pos = Pos::new(x, y);
+    match client_context.set_event_preparation(window_context, CallOnId::Drag, pos) {
+        Some((call_on_path, pos)) => {
+            window_context.current_window_index = Some(call_on_path.window_index());
+            window_context.current_pos = Some(pos);
+            queue_context.current_call_on_path = Some(call_on_path);
+            Some(Event::DragMotion(pos))
+        },
+        None => {
+            eprintln!("lwltk: {}", ClientError::EventPreparation);
+            None
+        },
+    }
+}
+
+pub(crate) fn prepare_event_for_client_drop(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext) -> Option<Event>
+{
+    // The previous drop is finished because its data offer is replaced.
+    finish_drop_data_offer(client_context);
+    let data_offer = client_context.fields.drag_data_offer.take();
+    let mime_types = match &data_offer {
+        Some(data_offer) => data_offer_mime_types(data_offer),
+        None => Vec::new(),
+    };
+    client_context.fields.drop_data_offer = data_offer;
+    match client_context.remove_event_preparation(window_context, CallOnId::Drag) {
+        Some((call_on_path, pos)) => {
+            window_context.current_window_index = Some(call_on_path.window_index());
+            window_context.current_pos = Some(pos);
+            queue_context.current_call_on_path = Some(call_on_path);
+            Some(Event::Drop(mime_types, pos))
+        },
+        None => {
+            eprintln!("lwltk: {}", ClientError::EventPreparation);
+            None
+        },
+    }
+}
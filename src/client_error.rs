@@ -88,6 +88,24 @@ pub enum ClientError
     NoCursor,
     /// An error of no call-on path of post button release.
     NoPostButtonReleaseCallOnPath,
+    /// An error of no shell.
+    NoShell,
+    /// An error of no layer shell.
+    NoLayerShell,
+    /// An error of no buffer.
+    NoBuffer,
+    /// An error of no data device.
+    NoDataDevice,
+    /// An error of no selection.
+    NoSelection,
+    /// An error of no MIME type.
+    NoMimeType,
+    /// An error of no queue context.
+    NoQueueContext,
+    /// An error of no data offer of drag and drop.
+    NoDragDataOffer,
+    /// An error of no seat.
+    NoSeat,
 }
 
 impl error::Error for ClientError
@@ -133,6 +151,15 @@ impl fmt::Display for ClientError
             ClientError::NoWidgetIndexPair => write!(f, "no widget index pair"),
             ClientError::NoCursor => write!(f, "no cursor"),
             ClientError::NoPostButtonReleaseCallOnPath => write!(f, "no post-button release call on path"),
+            ClientError::NoShell => write!(f, "no shell"),
+            ClientError::NoLayerShell => write!(f, "no layer shell"),
+            ClientError::NoBuffer => write!(f, "no buffer"),
+            ClientError::NoDataDevice => write!(f, "no data device"),
+            ClientError::NoSelection => write!(f, "no selection"),
+            ClientError::NoMimeType => write!(f, "no MIME type"),
+            ClientError::NoQueueContext => write!(f, "no queue context"),
+            ClientError::NoDragDataOffer => write!(f, "no drag data offer"),
+            ClientError::NoSeat => write!(f, "no seat"),
         }
     }
 }
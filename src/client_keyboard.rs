@@ -5,13 +5,18 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 //
+use std::cmp::max;
+use std::env;
+use std::ffi::OsStr;
 use std::os::unix::io::RawFd;
 use std::sync::mpsc;
+use std::time::Duration;
 use wayland_client::protocol::wl_keyboard;
 use wayland_client::protocol::wl_surface;
 use xkbcommon::xkb;
 use crate::client_context::*;
 use crate::client_error::*;
+use crate::client_seat::*;
 use crate::event_queue::*;
 use crate::events::*;
 use crate::keys::*;
@@ -20,7 +25,7 @@ use crate::thread_signal::*;
 use crate::types::*;
 use crate::window_context::*;
 
-fn update_focused_rel_widget_path(window_context: &mut WindowContext, window_idx: WindowIndex) -> Option<CallOnPath>
+pub(crate) fn update_focused_rel_widget_path(window_context: &mut WindowContext, window_idx: WindowIndex) -> Option<CallOnPath>
 {
     match window_context.window_container.dyn_window_mut(window_idx) {
         Some(window) => {
@@ -34,13 +39,70 @@ fn update_focused_rel_widget_path(window_context: &mut WindowContext, window_idx
     }
 }
 
-fn decode_key_code(client_context: &ClientContext, key_code: u32) -> Option<Option<(Vec<VKey>, String)>>
+fn locale_from_env() -> String
 {
-    match &client_context.fields.xkb_state {
-        Some(xkb_state) => {
-            let keys: Vec<VKey> = xkb_state.key_get_syms(key_code).iter().map(|ks| client_context.fields.keys.get(ks).map(|k| *k)).flatten().collect();
-            let s = if (client_context.fields.key_modifiers & (KeyModifiers::CTRL | KeyModifiers::ALT | KeyModifiers::LOGO)) == KeyModifiers::EMPTY {
-                xkb_state.key_get_utf8(key_code)
+    for name in ["LC_ALL", "LC_CTYPE", "LANG"] {
+        match env::var(name) {
+            Ok(locale) if !locale.is_empty() => return locale,
+            _ => (),
+        }
+    }
+    String::from("C")
+}
+
+/// Creates a compose state from a compose table for a locale.
+///
+/// The locale is taken from the `LC_ALL`, `LC_CTYPE`, or `LANG` environment variable. This
+/// function returns `None` if the compose table can't be loaded.
+pub(crate) fn create_xkb_compose_state(xkb_context: &xkb::Context) -> Option<xkb::compose::State>
+{
+    let locale = locale_from_env();
+    match xkb::compose::Table::new_from_locale(xkb_context, OsStr::new(locale.as_str()), xkb::compose::COMPILE_NO_FLAGS) {
+        Ok(table) => Some(xkb::compose::State::new(&table, xkb::compose::STATE_NO_FLAGS)),
+        Err(()) => None,
+    }
+}
+
+fn compose_key_code(xkb_state: &xkb::State, xkb_compose_state: &mut Option<xkb::compose::State>, key_code: u32) -> String
+{
+    match xkb_compose_state {
+        Some(xkb_compose_state) => {
+            let keysym = xkb_state.key_get_one_sym(key_code);
+            match xkb_compose_state.feed(keysym) {
+                xkb::compose::FeedResult::Accepted => {
+                    match xkb_compose_state.status() {
+                        xkb::compose::Status::Composing | xkb::compose::Status::Cancelled => String::new(),
+                        xkb::compose::Status::Composed => {
+                            let s = xkb_compose_state.utf8().unwrap_or_default();
+                            xkb_compose_state.reset();
+                            s
+                        },
+                        xkb::compose::Status::Nothing => xkb_state.key_get_utf8(key_code),
+                    }
+                },
+                xkb::compose::FeedResult::Ignored => xkb_state.key_get_utf8(key_code),
+            }
+        },
+        None => xkb_state.key_get_utf8(key_code),
+    }
+}
+
+fn decode_key_code(client_context: &mut ClientContext, key_code: u32, is_pressed: bool) -> Option<Option<(Vec<VKey>, String)>>
+{
+    let key_map = &client_context.fields.keys;
+    let client_seat = match client_context.fields.current_seat_id {
+        Some(seat_id) => client_context.fields.seats.get_mut(&seat_id),
+        None => None,
+    };
+    match client_seat {
+        Some(ClientSeat { xkb_state: Some(xkb_state), xkb_compose_state, key_modifiers, .. }) => {
+            let keys: Vec<VKey> = xkb_state.key_get_syms(key_code).iter().map(|ks| key_map.get(ks).map(|k| *k)).flatten().collect();
+            let s = if (*key_modifiers & (KeyModifiers::CTRL | KeyModifiers::ALT | KeyModifiers::LOGO)) == KeyModifiers::EMPTY {
+                if is_pressed {
+                    compose_key_code(xkb_state, xkb_compose_state, key_code)
+                } else {
+                    xkb_state.key_get_utf8(key_code)
+                }
             } else {
                 String::new()
             };
@@ -50,30 +112,41 @@ fn decode_key_code(client_context: &ClientContext, key_code: u32) -> Option<Opti
                 Some(None)
             }
         },
-        None => None,
+        _ => None,
     }
 }
 
 pub(crate) fn initialize_keyboard(client_context: &mut ClientContext, format: wl_keyboard::KeymapFormat, fd: RawFd, size: u32)
 {
+    let client_seat = match client_context.fields.current_seat_id {
+        Some(seat_id) => client_context.fields.seats.get_mut(&seat_id),
+        None => None,
+    };
+    let client_seat = match client_seat {
+        Some(tmp_client_seat) => tmp_client_seat,
+        None => {
+            eprintln!("lwltk: {}", ClientError::NoSeat);
+            return;
+        },
+    };
     match format {
         wl_keyboard::KeymapFormat::XkbV1 => {
             match unsafe { xkb::Keymap::new_from_fd(&client_context.fields.xkb_context, fd, size as usize, xkb::compose::FORMAT_TEXT_V1, 0) } {
-                Ok(keymap) => client_context.fields.xkb_keymap = keymap,
+                Ok(keymap) => client_seat.xkb_keymap = keymap,
                 Err(err) => {
                     eprintln!("lwltk: {}", ClientError::Io(err));
-                    client_context.fields.xkb_keymap = None;
+                    client_seat.xkb_keymap = None;
                 },
             }
-            match &client_context.fields.xkb_keymap {
+            match &client_seat.xkb_keymap {
                 Some(keymap) => {
-                    client_context.fields.xkb_state = Some(xkb::State::new(keymap));
-                    client_context.fields.xkb_shift_mask = (1 as xkb::ModMask) << keymap.mod_get_index("Shift");
-                    client_context.fields.xkb_caps_mask = (1 as xkb::ModMask) << keymap.mod_get_index("Lock");
-                    client_context.fields.xkb_ctrl_mask = (1 as xkb::ModMask) << keymap.mod_get_index("Control");
-                    client_context.fields.xkb_alt_mask = (1 as xkb::ModMask) << keymap.mod_get_index("Mod1");
-                    client_context.fields.xkb_num_mask = (1 as xkb::ModMask) << keymap.mod_get_index("Mod2");
-                    client_context.fields.xkb_logo_mask = (1 as xkb::ModMask) << keymap.mod_get_index("Mod4");
+                    client_seat.xkb_state = Some(xkb::State::new(keymap));
+                    client_seat.xkb_shift_mask = (1 as xkb::ModMask) << keymap.mod_get_index("Shift");
+                    client_seat.xkb_caps_mask = (1 as xkb::ModMask) << keymap.mod_get_index("Lock");
+                    client_seat.xkb_ctrl_mask = (1 as xkb::ModMask) << keymap.mod_get_index("Control");
+                    client_seat.xkb_alt_mask = (1 as xkb::ModMask) << keymap.mod_get_index("Mod1");
+                    client_seat.xkb_num_mask = (1 as xkb::ModMask) << keymap.mod_get_index("Mod2");
+                    client_seat.xkb_logo_mask = (1 as xkb::ModMask) << keymap.mod_get_index("Mod4");
                 },
                 None => eprintln!("lwltk: {}", ClientError::NoXkbKeymap),
             }
@@ -82,13 +155,44 @@ pub(crate) fn initialize_keyboard(client_context: &mut ClientContext, format: wl
     }
 }
 
+/// Sets the key repeat delay and the key repeat rate from a compositor.
+///
+/// The key repeat is disabled if the key repeat rate is zero. The key repeat delay and the key
+/// repeat time from the environment variables override the values from the compositor.
+pub(crate) fn set_key_repeat_info(client_context: &mut ClientContext, rate: i32, delay: i32, timer_tx: &mpsc::Sender<ThreadTimerCommand>)
+{
+    if rate > 0 {
+        if !client_context.fields.has_env_key_repeat_delay {
+            client_context.fields.key_repeat_delay = max(delay, 0) as u64;
+        }
+        if !client_context.fields.has_env_key_repeat_time {
+            client_context.fields.key_repeat_time = max(1000 / (rate as u64), 1);
+        }
+        client_context.fields.has_key_repeat = true;
+        let repeat = ThreadTimerRepeat::TwoDelays(Duration::from_millis(client_context.fields.key_repeat_delay), Duration::from_millis(client_context.fields.key_repeat_time));
+        match timer_tx.send(ThreadTimerCommand::SetRepeat(ThreadTimer::Key, repeat)) {
+            Ok(()) => (),
+            Err(_) => eprintln!("lwltk: {}", ClientError::Send),
+        }
+    } else {
+        client_context.fields.has_key_repeat = false;
+        match timer_tx.send(ThreadTimerCommand::Stop(ThreadTimer::Key)) {
+            Ok(()) => (),
+            Err(_) => eprintln!("lwltk: {}", ClientError::Send),
+        }
+    }
+}
+
 pub(crate) fn prepare_event_for_client_keyboard_enter(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, surface: &wl_surface::WlSurface) -> Option<Event>
 {
     match client_context.window_index_for_surface(surface) {
         Some(window_idx) => {
             match update_focused_rel_widget_path(window_context, window_idx) {
                 Some(call_on_path) => {
-                    client_context.fields.keyboard_window_index = Some(call_on_path.window_index());
+                    match client_context.fields.current_seat_mut() {
+                        Some(client_seat) => client_seat.keyboard_window_index = Some(call_on_path.window_index()),
+                        None => (),
+                    }
                     window_context.current_window_index = Some(call_on_path.window_index());
                     window_context.current_pos = None;
                     queue_context.current_call_on_path = Some(call_on_path);
@@ -113,18 +217,22 @@ pub(crate) fn prepare_event_for_client_keyboard_leave(client_context: &mut Clien
         Some(window_idx) => {
             match update_focused_rel_widget_path(window_context, window_idx) {
                 Some(call_on_path) => {
-                    match client_context.fields.keyboard_window_index {
-                        Some(keyboard_window_idx) => {
-                            if call_on_path.window_index() != keyboard_window_idx {
+                    match client_context.fields.current_seat_mut() {
+                        Some(ClientSeat { keyboard_window_index: keyboard_window_index @ Some(_), xkb_compose_state, .. }) => {
+                            if Some(call_on_path.window_index()) != *keyboard_window_index {
                                 eprintln!("lwltk: {}", ClientError::DifferentWindows);
                             }
-                            client_context.fields.keyboard_window_index = None;
+                            *keyboard_window_index = None;
+                            match xkb_compose_state {
+                                Some(xkb_compose_state) => xkb_compose_state.reset(),
+                                None => (),
+                            }
                             window_context.current_window_index = Some(call_on_path.window_index());
                             window_context.current_pos = None;
                             queue_context.current_call_on_path = Some(call_on_path);
                             Some(Event::Client(ClientEvent::KeyboardEnter))
                         },
-                        None => {
+                        _ => {
                             eprintln!("lwltk: {}", ClientError::NoKeyboardWindowIndex);
                             None
                         },
@@ -153,9 +261,9 @@ pub(crate) fn prepare_event_for_client_keyboard_key(client_context: &mut ClientC
     match client_state {
         Some(client_state) => {
             let key_code = key + 8;
-            match decode_key_code(client_context, key_code) {
+            match decode_key_code(client_context, key_code, client_state == ClientState::Pressed) {
                 Some(Some((keys, s))) => {
-                    match client_context.fields.keyboard_window_index {
+                    match client_context.fields.current_seat().and_then(|s| s.keyboard_window_index) {
                         Some(keyboard_window_index) => {
                             match update_focused_rel_widget_path(window_context, keyboard_window_index) {
                                 Some(call_on_path) => {
@@ -163,17 +271,29 @@ pub(crate) fn prepare_event_for_client_keyboard_key(client_context: &mut ClientC
                                     if !are_only_modifiers {
                                         match client_state {
                                             ClientState::Pressed => {
-                                                if client_context.fields.key_codes.is_empty() {
+                                                if client_context.fields.seats.values().all(|s| s.key_codes.is_empty()) && client_context.fields.has_key_repeat {
                                                     match timer_tx.send(ThreadTimerCommand::Start(ThreadTimer::Key)) {
                                                         Ok(()) => (),
                                                         Err(_) => eprintln!("lwltk: {}", ClientError::Send),
                                                     }
                                                 }
-                                                client_context.fields.key_codes.insert(key_code);
+                                                // The string of the pressed key is repeated, so a composed
+                                                // character is repeated instead of the raw character.
+                                                match client_context.fields.current_seat_mut() {
+                                                    Some(client_seat) => {
+                                                        client_seat.key_codes.insert(key_code, s.clone());
+                                                    },
+                                                    None => (),
+                                                }
                                             },
                                             ClientState::Released => {
-                                                client_context.fields.key_codes.remove(&key_code);
-                                                if client_context.fields.key_codes.is_empty() {
+                                                match client_context.fields.current_seat_mut() {
+                                                    Some(client_seat) => {
+                                                        client_seat.key_codes.remove(&key_code);
+                                                    },
+                                                    None => (),
+                                                }
+                                                if client_context.fields.seats.values().all(|s| s.key_codes.is_empty()) {
                                                     match timer_tx.send(ThreadTimerCommand::Stop(ThreadTimer::Key)) {
                                                         Ok(()) => (),
                                                         Err(_) => eprintln!("lwltk: {}", ClientError::Send),
@@ -215,31 +335,31 @@ pub(crate) fn prepare_event_for_client_keyboard_key(client_context: &mut ClientC
 
 pub(crate) fn prepare_event_for_client_keyboard_modifiers(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, mods_depressed: u32, mods_latched: u32, mods_locked: u32, group: u32) -> Option<Event>
 {
-    match &mut client_context.fields.xkb_state {
-        Some(xkb_state) => {
+    match client_context.fields.current_seat_mut() {
+        Some(ClientSeat { xkb_state: Some(xkb_state), xkb_shift_mask, xkb_caps_mask, xkb_ctrl_mask, xkb_alt_mask, xkb_num_mask, xkb_logo_mask, key_modifiers: seat_key_modifiers, keyboard_window_index, .. }) => {
             xkb_state.update_mask(mods_depressed, mods_latched, mods_locked, 0, 0, group);
             let mask = xkb_state.serialize_mods(xkb::STATE_MODS_DEPRESSED | xkb::STATE_MODS_LATCHED | xkb::STATE_MODS_LOCKED);
             let mut key_modifiers = KeyModifiers::EMPTY;
-            if (mask & client_context.fields.xkb_shift_mask) != 0 {
+            if (mask & *xkb_shift_mask) != 0 {
                 key_modifiers |= KeyModifiers::SHIFT;
             }
-            if (mask & client_context.fields.xkb_caps_mask) != 0 {
+            if (mask & *xkb_caps_mask) != 0 {
                 key_modifiers |= KeyModifiers::CAPS;
             }
-            if (mask & client_context.fields.xkb_ctrl_mask) != 0 {
+            if (mask & *xkb_ctrl_mask) != 0 {
                 key_modifiers |= KeyModifiers::CTRL;
             }
-            if (mask & client_context.fields.xkb_alt_mask) != 0 {
+            if (mask & *xkb_alt_mask) != 0 {
                 key_modifiers |= KeyModifiers::ALT;
             }
-            if (mask & client_context.fields.xkb_num_mask) != 0 {
+            if (mask & *xkb_num_mask) != 0 {
                 key_modifiers |= KeyModifiers::NUM;
             }
-            if (mask & client_context.fields.xkb_logo_mask) != 0 {
+            if (mask & *xkb_logo_mask) != 0 {
                 key_modifiers |= KeyModifiers::LOGO;
             }
-            client_context.fields.key_modifiers = key_modifiers;
-            match client_context.fields.keyboard_window_index {
+            *seat_key_modifiers = key_modifiers;
+            match *keyboard_window_index {
                 Some(keyboard_window_index) => {
                     match update_focused_rel_widget_path(window_context, keyboard_window_index) {
                         Some(call_on_path) => {
@@ -257,7 +377,7 @@ pub(crate) fn prepare_event_for_client_keyboard_modifiers(client_context: &mut C
                 None => None,
             }
         },
-        None => {
+        _ => {
             eprintln!("lwltk: {}", ClientError::NoXkbState);
             None
         },
@@ -266,9 +386,15 @@ pub(crate) fn prepare_event_for_client_keyboard_modifiers(client_context: &mut C
 
 pub(crate) fn prepare_event_for_client_repeated_key(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, key_code: u32) -> Option<Event>
 {
-    match decode_key_code(client_context, key_code) {
+    let pressed_s = client_context.fields.current_seat().and_then(|s| s.key_codes.get(&key_code).cloned());
+    let key_pair = match (decode_key_code(client_context, key_code, false), pressed_s) {
+        (Some(Some((keys, _))), Some(pressed_s)) => Some(Some((keys, pressed_s))),
+        (Some(None), Some(pressed_s)) if !pressed_s.is_empty() => Some(Some((Vec::new(), pressed_s))),
+        (key_pair, _) => key_pair,
+    };
+    match key_pair {
         Some(Some((keys, s))) => {
-            match client_context.fields.keyboard_window_index {
+            match client_context.fields.current_seat().and_then(|s| s.keyboard_window_index) {
                 Some(keyboard_window_index) => {
                     match update_focused_rel_widget_path(window_context, keyboard_window_index) {
                         Some(call_on_path) => {
@@ -0,0 +1,115 @@
+//
+// Copyright (c) 2022-2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::cell::*;
+use std::cmp::min;
+use std::collections::BTreeMap;
+use std::rc::*;
+use wayland_client::protocol::wl_output;
+use wayland_client::protocol::wl_registry;
+use wayland_client::Attached;
+use wayland_client::GlobalEvent;
+use wayland_client::Interface;
+use wayland_client::Main;
+
+const MAX_OUTPUT_VERSION: u32 = 3;
+
+pub(crate) struct ClientOutput
+{
+    pub(crate) output: Main<wl_output::WlOutput>,
+    pub(crate) scale: i32,
+    pending_scale: i32,
+}
+
+pub(crate) struct ClientOutputs
+{
+    pub(crate) outputs: BTreeMap<u32, ClientOutput>,
+    pub(crate) has_change: bool,
+}
+
+impl ClientOutputs
+{
+    pub(crate) fn new() -> Self
+    { ClientOutputs { outputs: BTreeMap::new(), has_change: false, } }
+
+    pub(crate) fn output_name(&self, output: &wl_output::WlOutput) -> Option<u32>
+    {
+        self.outputs.iter().find_map(|p| {
+                if &**p.1.output == output {
+                    Some(*p.0)
+                } else {
+                    None
+                }
+        })
+    }
+
+    pub(crate) fn max_scale(&self) -> Option<i32>
+    { self.outputs.values().map(|o| o.scale).max() }
+
+    pub(crate) fn destroy(&mut self)
+    {
+        for client_output in self.outputs.values() {
+            if client_output.output.as_ref().version() >= 3 {
+                client_output.output.release();
+            }
+        }
+        self.outputs.clear();
+    }
+}
+
+fn add_client_output(client_outputs: Rc<RefCell<ClientOutputs>>, registry: &Attached<wl_registry::WlRegistry>, name: u32, version: u32)
+{
+    let output = registry.bind::<wl_output::WlOutput>(min(version, MAX_OUTPUT_VERSION), name);
+    let client_outputs2 = client_outputs.clone();
+    output.quick_assign(move |_, event, _| {
+            let mut client_outputs_r = client_outputs2.borrow_mut();
+            match client_outputs_r.outputs.get_mut(&name) {
+                Some(client_output) => {
+                    match event {
+                        wl_output::Event::Scale { factor, } => client_output.pending_scale = factor,
+                        wl_output::Event::Done => {
+                            if client_output.scale != client_output.pending_scale {
+                                client_output.scale = client_output.pending_scale;
+                                client_outputs_r.has_change = true;
+                            }
+                        },
+                        _ => (),
+                    }
+                },
+                None => (),
+            }
+    });
+    let mut client_outputs_r = client_outputs.borrow_mut();
+    client_outputs_r.outputs.insert(name, ClientOutput { output, scale: 1, pending_scale: 1, });
+}
+
+fn remove_client_output(client_outputs: Rc<RefCell<ClientOutputs>>, name: u32)
+{
+    let mut client_outputs_r = client_outputs.borrow_mut();
+    match client_outputs_r.outputs.remove(&name) {
+        Some(client_output) => {
+            if client_output.output.as_ref().version() >= 3 {
+                client_output.output.release();
+            }
+            client_outputs_r.has_change = true;
+        },
+        None => (),
+    }
+}
+
+pub(crate) fn handle_global_event_for_client_outputs(client_outputs: Rc<RefCell<ClientOutputs>>, event: &GlobalEvent, registry: &Attached<wl_registry::WlRegistry>)
+{
+    match event {
+        GlobalEvent::New { id, interface, version, } if interface == wl_output::WlOutput::NAME => {
+            if *version >= 2 {
+                add_client_output(client_outputs, registry, *id, *version);
+            }
+        },
+        GlobalEvent::Removed { id, interface, } if interface == wl_output::WlOutput::NAME => remove_client_output(client_outputs, *id),
+        _ => (),
+    }
+}
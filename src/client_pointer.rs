@@ -5,12 +5,17 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 //
+use std::mem::take;
 use std::sync::mpsc;
 use wayland_client::protocol::wl_pointer;
 use wayland_client::protocol::wl_surface;
 use crate::client_context::*;
 use crate::client_error::*;
+use crate::client_seat::*;
 use crate::events::*;
+use crate::protocols::pointer_gestures_unstable_v1::client::zwp_pointer_gesture_hold_v1;
+use crate::protocols::pointer_gestures_unstable_v1::client::zwp_pointer_gesture_pinch_v1;
+use crate::protocols::pointer_gestures_unstable_v1::client::zwp_pointer_gesture_swipe_v1;
 use crate::queue_context::*;
 use crate::thread_signal::*;
 use crate::types::*;
@@ -20,14 +25,31 @@ const BTN_LEFT: u32 = 0x110;
 const BTN_RIGHT: u32 = 0x111;
 const BTN_MIDDLE: u32 = 0x112;
 
+const MAX_AXIS_TIME_DIFF: u32 = 100;
+pub(crate) const KINETIC_SCROLL_INTERVAL: u32 = 16;
+const KINETIC_SCROLL_FRICTION: f64 = 0.95;
+const MIN_KINETIC_SCROLL_VELOCITY: f64 = 0.05;
+
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct ClientPointerAxis
+{
+    time: u32,
+    value: Option<f64>,
+    discrete: Option<i32>,
+    is_stopped: bool,
+}
+
 pub(crate) fn prepare_event_for_client_pointer_enter(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, surface: &wl_surface::WlSurface, surface_x: f64, surface_y: f64) -> Option<Event>
 {
     match client_context.window_index_for_surface(surface) {
         Some(window_idx) => {
-            let pos = Pos::new(surface_x / (client_context.fields.scale as f64), surface_y / (client_context.fields.scale as f64));
+            let pos = Pos::new(surface_x, surface_y);
             match client_context.add_event_preparation(window_context, CallOnId::Pointer, window_idx, pos, None) {
                 Some((call_on_path, pos)) => {
-                    client_context.fields.has_cursor = true;
+                    match client_context.fields.current_seat_mut() {
+                        Some(client_seat) => client_seat.has_cursor = true,
+                        None => (),
+                    }
                     window_context.current_window_index = Some(call_on_path.window_index());
                     window_context.current_pos = Some(pos);
                     queue_context.current_call_on_path = Some(call_on_path);
@@ -55,7 +77,16 @@ pub(crate) fn prepare_event_for_client_pointer_leave(client_context: &mut Client
                     if call_on_path.window_index() != window_idx {
                         eprintln!("lwltk: {}", ClientError::DifferentWindows);
                     }
-                    client_context.fields.has_cursor = false;
+                    match client_context.fields.current_seat_mut() {
+                        Some(client_seat) => {
+                            client_seat.has_cursor = false;
+                            client_seat.pointer_axis_source = None;
+                            client_seat.pointer_axes.clear();
+                            client_seat.pointer_axis_velocities.clear();
+                            client_seat.kinetic_scroll_velocities.clear();
+                        },
+                        None => (),
+                    }
                     window_context.current_window_index = Some(call_on_path.window_index());
                     window_context.current_pos = Some(pos);
                     queue_context.current_call_on_path = Some(call_on_path);
@@ -76,7 +107,7 @@ pub(crate) fn prepare_event_for_client_pointer_leave(client_context: &mut Client
 
 pub(crate) fn prepare_event_for_client_pointer_motion(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, time: u32, surface_x: f64, surface_y: f64) -> Option<Event>
 {
-    let pos = Pos::new(surface_x / (client_context.fields.scale as f64), surface_y / (client_context.fields.scale as f64));
+    let pos = Pos::new(surface_x, surface_y);
     match client_context.set_event_preparation(window_context, CallOnId::Pointer, pos) {
         Some((call_on_path, pos)) => {
             window_context.current_window_index = Some(call_on_path.window_index());
@@ -111,6 +142,12 @@ pub(crate) fn prepare_event_for_client_pointer_button(client_context: &mut Clien
         Some(client_button) => {
             match client_state {
                 Some(client_state) => {
+                    if client_state == ClientState::Pressed {
+                        match client_context.fields.current_seat_mut() {
+                            Some(client_seat) => client_seat.kinetic_scroll_velocities.clear(),
+                            None => (),
+                        }
+                    }
                     match client_context.update_event_preparation(window_context, CallOnId::Pointer) {
                         Some((call_on_path, pos)) => {
                             match (client_button, client_state) {
@@ -119,18 +156,29 @@ pub(crate) fn prepare_event_for_client_pointer_button(client_context: &mut Clien
                                         Ok(()) => (),
                                         Err(_) => eprintln!("lwltk: {}", ClientError::Send),
                                     }
-                                    client_context.fields.has_pressed_button = true;
+                                    match client_context.fields.current_seat_mut() {
+                                        Some(client_seat) => client_seat.has_pressed_button = true,
+                                        None => (),
+                                    }
                                 },
                                 (ClientButton::Left, ClientState::Released) => {
-                                    client_context.fields.has_pressed_button = false;
-                                    match timer_tx.send(ThreadTimerCommand::Stop(ThreadTimer::Button)) {
-                                        Ok(()) => (),
-                                        Err(_) => eprintln!("lwltk: {}", ClientError::Send),
+                                    match client_context.fields.current_seat_mut() {
+                                        Some(client_seat) => client_seat.has_pressed_button = false,
+                                        None => (),
+                                    }
+                                    if client_context.fields.seats.values().all(|s| !s.has_pressed_button) {
+                                        match timer_tx.send(ThreadTimerCommand::Stop(ThreadTimer::Button)) {
+                                            Ok(()) => (),
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Send),
+                                        }
                                     }
                                     client_context.unset_first_pos(CallOnId::Pointer);
                                 },
                                 _ => (),
                             }
+                            if client_state == ClientState::Pressed {
+                                window_context.window_container.unset_parents_for_outside_popups(call_on_path.window_index());
+                            }
                             window_context.current_window_index = Some(call_on_path.window_index());
                             window_context.current_pos = Some(pos);
                             queue_context.current_call_on_path = Some(call_on_path);
@@ -155,26 +203,44 @@ pub(crate) fn prepare_event_for_client_pointer_button(client_context: &mut Clien
     }
 }
 
-pub(crate) fn prepare_event_for_client_pointer_axis(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, time: u32, axis: wl_pointer::Axis, value: f64) -> Option<Event>
+fn client_axis_for_axis(axis: wl_pointer::Axis) -> Option<ClientAxis>
 {
-    let client_axis = match axis {
+    match axis {
         wl_pointer::Axis::VerticalScroll => Some(ClientAxis::VScroll),
         wl_pointer::Axis::HorizontalScroll => Some(ClientAxis::HScroll),
         _ => None,
-    };
-    match client_axis {
+    }
+}
+
+fn has_pointer_frames(client_context: &ClientContext) -> bool
+{
+    match client_context.fields.current_seat() {
+        Some(ClientSeat { pointer: Some(pointer), .. }) => pointer.as_ref().version() >= 5,
+        _ => false,
+    }
+}
+
+/// Prepares an event of pointer axis.
+///
+/// Axis values are grouped by pointer frames if the pointer sends pointer frames, so the event
+/// isn't returned in this case. The event is returned by [`client_events_for_client_pointer_frame`]
+/// after the pointer frame.
+pub(crate) fn prepare_event_for_client_pointer_axis(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, time: u32, axis: wl_pointer::Axis, value: f64) -> Option<Event>
+{
+    match client_axis_for_axis(axis) {
         Some(client_axis) => {
-            match client_context.update_event_preparation(window_context, CallOnId::Pointer) {
-                Some((call_on_path, pos)) => {
-                    window_context.current_window_index = Some(call_on_path.window_index());
-                    window_context.current_pos = Some(pos);
-                    queue_context.current_call_on_path = Some(call_on_path);
-                    Some(Event::Client(ClientEvent::PointerAxis(time, client_axis, value)))
-                },
-                None => {
-                    eprintln!("lwltk: {}", ClientError::EventPreparation);
-                    None
-                },
+            if has_pointer_frames(client_context) {
+                match client_context.fields.current_seat_mut() {
+                    Some(client_seat) => {
+                        let pointer_axis = client_seat.pointer_axes.entry(client_axis).or_default();
+                        pointer_axis.time = time;
+                        pointer_axis.value = Some(value);
+                    },
+                    None => eprintln!("lwltk: {}", ClientError::NoSeat),
+                }
+                None
+            } else {
+                prepare_event_for_client_pointer_update(client_context, window_context, queue_context, ClientEvent::PointerAxis(time, client_axis, value, None, None))
             }
         },
         None => {
@@ -184,6 +250,179 @@ pub(crate) fn prepare_event_for_client_pointer_axis(client_context: &mut ClientC
     }
 }
 
+pub(crate) fn set_client_pointer_axis_source(client_context: &mut ClientContext, axis_source: wl_pointer::AxisSource)
+{
+    let client_axis_source = match axis_source {
+        wl_pointer::AxisSource::Wheel => Some(ClientAxisSource::Wheel),
+        wl_pointer::AxisSource::Finger => Some(ClientAxisSource::Finger),
+        wl_pointer::AxisSource::Continuous => Some(ClientAxisSource::Continuous),
+        wl_pointer::AxisSource::WheelTilt => Some(ClientAxisSource::WheelTilt),
+        _ => None,
+    };
+    match client_context.fields.current_seat_mut() {
+        Some(client_seat) => client_seat.pointer_axis_source = client_axis_source,
+        None => eprintln!("lwltk: {}", ClientError::NoSeat),
+    }
+}
+
+pub(crate) fn set_client_pointer_axis_discrete(client_context: &mut ClientContext, axis: wl_pointer::Axis, discrete: i32)
+{
+    match (client_axis_for_axis(axis), client_context.fields.current_seat_mut()) {
+        (Some(client_axis), Some(client_seat)) => client_seat.pointer_axes.entry(client_axis).or_default().discrete = Some(discrete),
+        (None, _) => eprintln!("lwltk: {}", ClientError::InvalidAxis),
+        (_, None) => eprintln!("lwltk: {}", ClientError::NoSeat),
+    }
+}
+
+pub(crate) fn set_client_pointer_axis_stop(client_context: &mut ClientContext, time: u32, axis: wl_pointer::Axis)
+{
+    match (client_axis_for_axis(axis), client_context.fields.current_seat_mut()) {
+        (Some(client_axis), Some(client_seat)) => {
+            let pointer_axis = client_seat.pointer_axes.entry(client_axis).or_default();
+            pointer_axis.time = time;
+            pointer_axis.is_stopped = true;
+        },
+        (None, _) => eprintln!("lwltk: {}", ClientError::InvalidAxis),
+        (_, None) => eprintln!("lwltk: {}", ClientError::NoSeat),
+    }
+}
+
+/// Returns client events of the axis values that are grouped by the pointer frame.
+///
+/// Velocities of a finger scrolling are tracked for a kinetic scrolling. The kinetic scrolling is
+/// started when the fingers are lifted from a touchpad and it is stopped by a next axis value.
+pub(crate) fn client_events_for_client_pointer_frame(client_context: &mut ClientContext, timer_tx: &mpsc::Sender<ThreadTimerCommand>) -> Vec<ClientEvent>
+{
+    let client_seat = match client_context.fields.current_seat_mut() {
+        Some(tmp_client_seat) => tmp_client_seat,
+        None => {
+            eprintln!("lwltk: {}", ClientError::NoSeat);
+            return Vec::new();
+        },
+    };
+    let client_axis_source = client_seat.pointer_axis_source.take();
+    let pointer_axes = take(&mut client_seat.pointer_axes);
+    let mut client_events: Vec<ClientEvent> = Vec::new();
+    let mut is_kinetic_scroll = false;
+    for (client_axis, pointer_axis) in &pointer_axes {
+        match pointer_axis.value {
+            Some(value) => {
+                client_seat.kinetic_scroll_velocities.clear();
+                if client_axis_source == Some(ClientAxisSource::Finger) && !pointer_axis.is_stopped {
+                    let velocity = match client_seat.pointer_axis_velocities.get(client_axis) {
+                        Some((old_time, old_velocity)) if pointer_axis.time > *old_time && pointer_axis.time - *old_time <= MAX_AXIS_TIME_DIFF => {
+                            let new_velocity = value / ((pointer_axis.time - *old_time) as f64);
+                            match old_velocity {
+                                Some(old_velocity) => Some((old_velocity + new_velocity) / 2.0),
+                                None => Some(new_velocity),
+                            }
+                        },
+                        _ => None,
+                    };
+                    client_seat.pointer_axis_velocities.insert(*client_axis, (pointer_axis.time, velocity));
+                } else if !pointer_axis.is_stopped {
+                    client_seat.pointer_axis_velocities.remove(client_axis);
+                }
+                client_events.push(ClientEvent::PointerAxis(pointer_axis.time, *client_axis, value, client_axis_source, pointer_axis.discrete));
+            },
+            None => (),
+        }
+        if pointer_axis.is_stopped {
+            match client_seat.pointer_axis_velocities.remove(client_axis) {
+                Some((old_time, Some(velocity))) if pointer_axis.time >= old_time && pointer_axis.time - old_time <= MAX_AXIS_TIME_DIFF => {
+                    if velocity.abs() >= MIN_KINETIC_SCROLL_VELOCITY {
+                        client_seat.kinetic_scroll_velocities.insert(*client_axis, velocity);
+                        client_seat.kinetic_scroll_time = pointer_axis.time;
+                        is_kinetic_scroll = true;
+                    }
+                },
+                _ => (),
+            }
+            client_events.push(ClientEvent::PointerAxisStop(pointer_axis.time, *client_axis));
+        }
+    }
+    if is_kinetic_scroll {
+        match timer_tx.send(ThreadTimerCommand::Start(ThreadTimer::KineticScroll)) {
+            Ok(()) => (),
+            Err(_) => eprintln!("lwltk: {}", ClientError::Send),
+        }
+    }
+    client_events
+}
+
+/// Returns client events of the kinetic scrolling for a tick of the timer.
+///
+/// The client events are paired with the seats of the kinetic scrolling. The velocities of the
+/// kinetic scrolling are decreased by a friction on each tick. The timer is stopped if all
+/// velocities of all seats are too small or the kinetic scrolling is stopped.
+pub(crate) fn client_events_for_client_kinetic_scroll(client_context: &mut ClientContext, timer_tx: &mpsc::Sender<ThreadTimerCommand>) -> Vec<(SeatId, ClientEvent)>
+{
+    let mut client_events: Vec<(SeatId, ClientEvent)> = Vec::new();
+    for (seat_id, client_seat) in client_context.fields.seats.iter_mut() {
+        if !client_seat.kinetic_scroll_velocities.is_empty() {
+            client_seat.kinetic_scroll_time = client_seat.kinetic_scroll_time.wrapping_add(KINETIC_SCROLL_INTERVAL);
+            let time = client_seat.kinetic_scroll_time;
+            for (client_axis, velocity) in client_seat.kinetic_scroll_velocities.iter_mut() {
+                client_events.push((*seat_id, ClientEvent::PointerAxis(time, *client_axis, *velocity * (KINETIC_SCROLL_INTERVAL as f64), Some(ClientAxisSource::Kinetic), None)));
+                *velocity *= KINETIC_SCROLL_FRICTION;
+            }
+            client_seat.kinetic_scroll_velocities.retain(|_, v| v.abs() >= MIN_KINETIC_SCROLL_VELOCITY);
+        }
+    }
+    if client_context.fields.seats.values().all(|s| s.kinetic_scroll_velocities.is_empty()) {
+        match timer_tx.send(ThreadTimerCommand::Stop(ThreadTimer::KineticScroll)) {
+            Ok(()) => (),
+            Err(_) => eprintln!("lwltk: {}", ClientError::Send),
+        }
+    }
+    client_events
+}
+
+pub(crate) fn prepare_event_for_client_pointer_update(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, client_event: ClientEvent) -> Option<Event>
+{
+    match client_context.update_event_preparation(window_context, CallOnId::Pointer) {
+        Some((call_on_path, pos)) => {
+            window_context.current_window_index = Some(call_on_path.window_index());
+            window_context.current_pos = Some(pos);
+            queue_context.current_call_on_path = Some(call_on_path);
+            Some(Event::Client(client_event))
+        },
+        None => {
+            eprintln!("lwltk: {}", ClientError::EventPreparation);
+            None
+        },
+    }
+}
+
+pub(crate) fn prepare_event_for_client_pointer_swipe(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, event: zwp_pointer_gesture_swipe_v1::Event) -> Option<Event>
+{
+    let client_event = match event {
+        zwp_pointer_gesture_swipe_v1::Event::Begin { time, fingers, .. } => ClientEvent::PointerSwipeBegin(time, fingers),
+        zwp_pointer_gesture_swipe_v1::Event::Update { time, dx, dy, } => ClientEvent::PointerSwipeUpdate(time, dx, dy),
+        zwp_pointer_gesture_swipe_v1::Event::End { time, cancelled, .. } => ClientEvent::PointerSwipeEnd(time, cancelled != 0),
+    };
+    prepare_event_for_client_pointer_update(client_context, window_context, queue_context, client_event)
+}
+
+pub(crate) fn prepare_event_for_client_pointer_pinch(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, event: zwp_pointer_gesture_pinch_v1::Event) -> Option<Event>
+{
+    let client_event = match event {
+        zwp_pointer_gesture_pinch_v1::Event::Begin { time, fingers, .. } => ClientEvent::PointerPinchBegin(time, fingers),
+        zwp_pointer_gesture_pinch_v1::Event::Update { time, dx, dy, scale, rotation, } => ClientEvent::PointerPinchUpdate(time, dx, dy, scale, rotation),
+        zwp_pointer_gesture_pinch_v1::Event::End { time, cancelled, .. } => ClientEvent::PointerPinchEnd(time, cancelled != 0),
+    };
+    prepare_event_for_client_pointer_update(client_context, window_context, queue_context, client_event)
+}
+
+pub(crate) fn prepare_event_for_client_pointer_hold(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, event: zwp_pointer_gesture_hold_v1::Event) -> Option<Event>
+{
+    let client_event = match event {
+        zwp_pointer_gesture_hold_v1::Event::Begin { time, fingers, .. } => ClientEvent::PointerHoldBegin(time, fingers),
+        zwp_pointer_gesture_hold_v1::Event::End { time, cancelled, .. } => ClientEvent::PointerHoldEnd(time, cancelled != 0),
+    };
+    prepare_event_for_client_pointer_update(client_context, window_context, queue_context, client_event)
+}
+
 pub(crate) fn prepare_event_for_client_repeated_button(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext) -> Option<Event>
 {
     match client_context.update_event_preparation(window_context, CallOnId::Pointer) {
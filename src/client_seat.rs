@@ -0,0 +1,300 @@
+//
+// Copyright (c) 2022-2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::cell::*;
+use std::cmp::min;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::rc::*;
+use wayland_client::protocol::wl_data_device;
+use wayland_client::protocol::wl_data_offer;
+use wayland_client::protocol::wl_keyboard;
+use wayland_client::protocol::wl_pointer;
+use wayland_client::protocol::wl_registry;
+use wayland_client::protocol::wl_seat;
+use wayland_client::protocol::wl_touch;
+use wayland_client::Attached;
+use wayland_client::GlobalEvent;
+use wayland_client::Interface;
+use wayland_client::Main;
+use wayland_client::Proxy;
+use wayland_protocols::unstable::primary_selection::v1::client::zwp_primary_selection_device_v1;
+use wayland_protocols::unstable::primary_selection::v1::client::zwp_primary_selection_offer_v1;
+use wayland_protocols::unstable::text_input::v3::client::zwp_text_input_v3;
+use xkbcommon::xkb;
+use crate::client_context::*;
+use crate::client_pointer::*;
+use crate::events::*;
+use crate::keys::*;
+use crate::protocols::cursor_shape_v1::client::wp_cursor_shape_device_v1;
+use crate::protocols::pointer_gestures_unstable_v1::client::zwp_pointer_gesture_hold_v1;
+use crate::protocols::pointer_gestures_unstable_v1::client::zwp_pointer_gesture_pinch_v1;
+use crate::protocols::pointer_gestures_unstable_v1::client::zwp_pointer_gesture_swipe_v1;
+use crate::queue_context::*;
+use crate::types::*;
+
+// The axis_value120 event of version 8 isn't supported by the protocol bindings.
+const MAX_SEAT_VERSION: u32 = 5;
+
+/// A structure of client seat.
+///
+/// The client seat contains input devices of one seat and an input state of these devices.
+pub(crate) struct ClientSeat
+{
+    pub(crate) seat: Main<wl_seat::WlSeat>,
+    pub(crate) pointer: Option<Main<wl_pointer::WlPointer>>,
+    pub(crate) keyboard: Option<Main<wl_keyboard::WlKeyboard>>,
+    pub(crate) touch: Option<Main<wl_touch::WlTouch>>,
+    pub(crate) cursor_shape_device: Option<Main<wp_cursor_shape_device_v1::WpCursorShapeDeviceV1>>,
+    pub(crate) pointer_gesture_swipe: Option<Main<zwp_pointer_gesture_swipe_v1::ZwpPointerGestureSwipeV1>>,
+    pub(crate) pointer_gesture_pinch: Option<Main<zwp_pointer_gesture_pinch_v1::ZwpPointerGesturePinchV1>>,
+    pub(crate) pointer_gesture_hold: Option<Main<zwp_pointer_gesture_hold_v1::ZwpPointerGestureHoldV1>>,
+    pub(crate) data_device: Option<Main<wl_data_device::WlDataDevice>>,
+    pub(crate) selection_data_offer: Option<wl_data_offer::WlDataOffer>,
+    pub(crate) primary_selection_device: Option<Main<zwp_primary_selection_device_v1::ZwpPrimarySelectionDeviceV1>>,
+    pub(crate) primary_selection_offer: Option<zwp_primary_selection_offer_v1::ZwpPrimarySelectionOfferV1>,
+    pub(crate) text_input: Option<Main<zwp_text_input_v3::ZwpTextInputV3>>,
+    pub(crate) text_input_window_index: Option<WindowIndex>,
+    pub(crate) text_input_state: Option<(Option<Rect<i32>>, Option<(String, usize, usize)>)>,
+    pub(crate) text_input_preedit: Option<(String, Option<(usize, usize)>)>,
+    pub(crate) text_input_committed_text: Option<String>,
+    pub(crate) text_input_deleted_lens: Option<(usize, usize)>,
+    pub(crate) serial: Option<u32>,
+    pub(crate) press_call_on_id_and_serial: Option<(CallOnId, u32)>,
+    pub(crate) xkb_keymap: Option<xkb::Keymap>,
+    pub(crate) xkb_state: Option<xkb::State>,
+    pub(crate) xkb_compose_state: Option<xkb::compose::State>,
+    pub(crate) xkb_shift_mask: xkb::ModMask,
+    pub(crate) xkb_caps_mask: xkb::ModMask,
+    pub(crate) xkb_ctrl_mask: xkb::ModMask,
+    pub(crate) xkb_alt_mask: xkb::ModMask,
+    pub(crate) xkb_num_mask: xkb::ModMask,
+    pub(crate) xkb_logo_mask: xkb::ModMask,
+    pub(crate) event_preparations: BTreeMap<CallOnId, EventPreparation>,
+    pub(crate) has_pressed_button: bool,
+    pub(crate) pointer_axis_source: Option<ClientAxisSource>,
+    pub(crate) pointer_axes: BTreeMap<ClientAxis, ClientPointerAxis>,
+    pub(crate) pointer_axis_velocities: BTreeMap<ClientAxis, (u32, Option<f64>)>,
+    pub(crate) kinetic_scroll_velocities: BTreeMap<ClientAxis, f64>,
+    pub(crate) kinetic_scroll_time: u32,
+    pub(crate) keyboard_window_index: Option<WindowIndex>,
+    pub(crate) key_codes: BTreeMap<u32, String>,
+    pub(crate) key_modifiers: KeyModifiers,
+    pub(crate) touch_ids: BTreeSet<i32>,
+    pub(crate) has_cursor: bool,
+    pub(crate) has_old_cursor: bool,
+}
+
+impl ClientSeat
+{
+    pub(crate) fn new(seat: Main<wl_seat::WlSeat>, xkb_compose_state: Option<xkb::compose::State>) -> Self
+    {
+        ClientSeat {
+            seat,
+            pointer: None,
+            keyboard: None,
+            touch: None,
+            cursor_shape_device: None,
+            pointer_gesture_swipe: None,
+            pointer_gesture_pinch: None,
+            pointer_gesture_hold: None,
+            data_device: None,
+            selection_data_offer: None,
+            primary_selection_device: None,
+            primary_selection_offer: None,
+            text_input: None,
+            text_input_window_index: None,
+            text_input_state: None,
+            text_input_preedit: None,
+            text_input_committed_text: None,
+            text_input_deleted_lens: None,
+            serial: None,
+            press_call_on_id_and_serial: None,
+            xkb_keymap: None,
+            xkb_state: None,
+            xkb_compose_state,
+            xkb_shift_mask: 0 as xkb::ModMask,
+            xkb_caps_mask: 0 as xkb::ModMask,
+            xkb_ctrl_mask: 0 as xkb::ModMask,
+            xkb_alt_mask: 0 as xkb::ModMask,
+            xkb_num_mask: 0 as xkb::ModMask,
+            xkb_logo_mask: 0 as xkb::ModMask,
+            event_preparations: BTreeMap::new(),
+            has_pressed_button: false,
+            pointer_axis_source: None,
+            pointer_axes: BTreeMap::new(),
+            pointer_axis_velocities: BTreeMap::new(),
+            kinetic_scroll_velocities: BTreeMap::new(),
+            kinetic_scroll_time: 0,
+            keyboard_window_index: None,
+            key_codes: BTreeMap::new(),
+            key_modifiers: KeyModifiers::EMPTY,
+            touch_ids: BTreeSet::new(),
+            has_cursor: false,
+            has_old_cursor: false,
+        }
+    }
+
+    pub(crate) fn destroy_pointer(&mut self)
+    {
+        match self.cursor_shape_device.take() {
+            Some(cursor_shape_device) => cursor_shape_device.destroy(),
+            None => (),
+        }
+        match self.pointer_gesture_swipe.take() {
+            Some(pointer_gesture_swipe) => pointer_gesture_swipe.destroy(),
+            None => (),
+        }
+        match self.pointer_gesture_pinch.take() {
+            Some(pointer_gesture_pinch) => pointer_gesture_pinch.destroy(),
+            None => (),
+        }
+        match self.pointer_gesture_hold.take() {
+            Some(pointer_gesture_hold) => pointer_gesture_hold.destroy(),
+            None => (),
+        }
+        match self.pointer.take() {
+            Some(pointer) if pointer.as_ref().version() >= 3 => pointer.release(),
+            _ => (),
+        }
+        self.event_preparations.remove(&CallOnId::Pointer);
+        self.has_pressed_button = false;
+        self.pointer_axis_source = None;
+        self.pointer_axes.clear();
+        self.pointer_axis_velocities.clear();
+        self.kinetic_scroll_velocities.clear();
+        self.has_cursor = false;
+    }
+
+    pub(crate) fn destroy_keyboard(&mut self)
+    {
+        match self.keyboard.take() {
+            Some(keyboard) if keyboard.as_ref().version() >= 3 => keyboard.release(),
+            _ => (),
+        }
+        self.keyboard_window_index = None;
+        self.key_codes.clear();
+        self.key_modifiers = KeyModifiers::EMPTY;
+    }
+
+    pub(crate) fn destroy_touch(&mut self)
+    {
+        match self.touch.take() {
+            Some(touch) if touch.as_ref().version() >= 3 => touch.release(),
+            _ => (),
+        }
+        for id in &self.touch_ids {
+            self.event_preparations.remove(&CallOnId::Touch(*id));
+        }
+        self.touch_ids.clear();
+    }
+
+    pub(crate) fn destroy_selection_devices_and_text_input(&mut self)
+    {
+        match self.selection_data_offer.take() {
+            Some(data_offer) => data_offer.destroy(),
+            None => (),
+        }
+        match self.data_device.take() {
+            Some(data_device) if data_device.as_ref().version() >= 2 => data_device.release(),
+            _ => (),
+        }
+        match self.primary_selection_offer.take() {
+            Some(offer) => offer.destroy(),
+            None => (),
+        }
+        match self.primary_selection_device.take() {
+            Some(primary_selection_device) => primary_selection_device.destroy(),
+            None => (),
+        }
+        match self.text_input.take() {
+            Some(text_input) => text_input.destroy(),
+            None => (),
+        }
+        self.text_input_window_index = None;
+        self.text_input_state = None;
+        self.text_input_preedit = None;
+        self.text_input_committed_text = None;
+        self.text_input_deleted_lens = None;
+    }
+
+    pub(crate) fn destroy(&mut self)
+    {
+        self.destroy_pointer();
+        self.destroy_keyboard();
+        self.destroy_touch();
+        self.destroy_selection_devices_and_text_input();
+        if self.seat.as_ref().version() >= 5 {
+            self.seat.release();
+        }
+    }
+}
+
+/// A structure of seat globals.
+///
+/// The seat globals contain seats that are added or removed by the global manager and received
+/// capabilities of the seats. These changes are applied to the client seats by a main loop.
+pub(crate) struct ClientSeatGlobals
+{
+    pub(crate) added_seats: Vec<(SeatId, Main<wl_seat::WlSeat>)>,
+    pub(crate) removed_seat_ids: Vec<SeatId>,
+    pub(crate) capabilities: BTreeMap<SeatId, wl_seat::Capability>,
+}
+
+impl ClientSeatGlobals
+{
+    pub(crate) fn new() -> Self
+    { ClientSeatGlobals { added_seats: Vec::new(), removed_seat_ids: Vec::new(), capabilities: BTreeMap::new(), } }
+
+    pub(crate) fn has_change(&self) -> bool
+    { !self.added_seats.is_empty() || !self.removed_seat_ids.is_empty() || !self.capabilities.is_empty() }
+}
+
+fn add_client_seat(client_seat_globals: Rc<RefCell<ClientSeatGlobals>>, registry: &Attached<wl_registry::WlRegistry>, name: u32, version: u32)
+{
+    let seat = registry.bind::<wl_seat::WlSeat>(min(version, MAX_SEAT_VERSION), name);
+    let client_seat_globals2 = client_seat_globals.clone();
+    seat.quick_assign(move |_, event, _| {
+            match event {
+                wl_seat::Event::Capabilities { capabilities, } => {
+                    let mut client_seat_globals_r = client_seat_globals2.borrow_mut();
+                    client_seat_globals_r.capabilities.insert(SeatId(name), capabilities);
+                },
+                _ => (),
+            }
+    });
+    let mut client_seat_globals_r = client_seat_globals.borrow_mut();
+    client_seat_globals_r.added_seats.push((SeatId(name), seat));
+}
+
+fn remove_client_seat(client_seat_globals: Rc<RefCell<ClientSeatGlobals>>, name: u32)
+{
+    let mut client_seat_globals_r = client_seat_globals.borrow_mut();
+    client_seat_globals_r.removed_seat_ids.push(SeatId(name));
+}
+
+pub(crate) fn handle_global_event_for_client_seats(client_seat_globals: Rc<RefCell<ClientSeatGlobals>>, event: &GlobalEvent, registry: &Attached<wl_registry::WlRegistry>)
+{
+    match event {
+        GlobalEvent::New { id, interface, version, } if interface == wl_seat::WlSeat::NAME => add_client_seat(client_seat_globals, registry, *id, *version),
+        GlobalEvent::Removed { id, interface, } if interface == wl_seat::WlSeat::NAME => remove_client_seat(client_seat_globals, *id),
+        _ => (),
+    }
+}
+
+/// Returns the seat identifier of an input device.
+///
+/// The seat identifier is stored in user data of the input device when the input device is
+/// created.
+pub(crate) fn seat_id_for_proxy<I>(proxy: &Proxy<I>) -> Option<SeatId>
+    where I: Interface + AsRef<Proxy<I>> + From<Proxy<I>>
+{
+    match proxy.user_data().get::<SeatId>() {
+        Some(seat_id) => Some(*seat_id),
+        None => None,
+    }
+}
@@ -6,6 +6,11 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 //
 use wayland_client::protocol::wl_shell_surface;
+use wayland_protocols::unstable::xdg_decoration::v1::client::zxdg_toplevel_decoration_v1;
+use wayland_protocols::wlr::unstable::layer_shell::v1::client::zwlr_layer_surface_v1;
+use wayland_protocols::xdg_shell::client::xdg_popup;
+use wayland_protocols::xdg_shell::client::xdg_surface;
+use wayland_protocols::xdg_shell::client::xdg_toplevel;
 use crate::client_context::*;
 use crate::client_error::*;
 use crate::event_queue::*;
@@ -29,10 +34,18 @@ pub(crate) fn prepare_event_for_client_shell_surface_configure(client_context: &
     };
     match client_context.window_index_for_shell_surface(shell_surface) {
         Some(window_idx) => {
-            let size = Size::new((width + client_context.fields.scale - 1) / client_context.fields.scale, (height + client_context.fields.scale - 1) / client_context.fields.scale);
+            let size = Size::new(width, height);
+            let mut states = ClientToplevelStates::EMPTY;
+            match client_context.client_window(window_idx) {
+                Some(client_window) => {
+                    states.set(ClientToplevelStates::MAXIMIZED, client_window.is_maximized);
+                    states.set(ClientToplevelStates::FULLSCREEN, client_window.is_fullscreen);
+                },
+                None => (),
+            }
             window_context.current_window_index = Some(window_idx);
             queue_context.current_call_on_path = Some(CallOnPath::Window(window_idx));
-            Some(Event::Client(ClientEvent::ShellSurfaceConfigure(client_resize, size)))
+            Some(Event::Client(ClientEvent::ShellSurfaceConfigure(client_resize, size, states)))
         },
         None => {
             eprintln!("lwltk: {}", ClientError::NoClientWindow);
@@ -55,3 +68,169 @@ pub(crate) fn prepare_event_for_client_shell_surface_popup_done(client_context:
         },
     }
 }
+
+pub(crate) fn set_configure_for_client_xdg_toplevel(client_context: &mut ClientContext, xdg_toplevel: &xdg_toplevel::XdgToplevel, width: i32, height: i32, states: &[u8])
+{
+    let mut client_states = ClientToplevelStates::EMPTY;
+    for bytes in states.chunks_exact(4) {
+        let state = u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        match xdg_toplevel::State::from_raw(state) {
+            Some(xdg_toplevel::State::Maximized) => client_states.insert(ClientToplevelStates::MAXIMIZED),
+            Some(xdg_toplevel::State::Fullscreen) => client_states.insert(ClientToplevelStates::FULLSCREEN),
+            Some(xdg_toplevel::State::Resizing) => client_states.insert(ClientToplevelStates::RESIZING),
+            Some(xdg_toplevel::State::Activated) => client_states.insert(ClientToplevelStates::ACTIVATED),
+            Some(xdg_toplevel::State::TiledLeft) => client_states.insert(ClientToplevelStates::TILED_LEFT),
+            Some(xdg_toplevel::State::TiledRight) => client_states.insert(ClientToplevelStates::TILED_RIGHT),
+            Some(xdg_toplevel::State::TiledTop) => client_states.insert(ClientToplevelStates::TILED_TOP),
+            Some(xdg_toplevel::State::TiledBottom) => client_states.insert(ClientToplevelStates::TILED_BOTTOM),
+            _ => (),
+        }
+    }
+    match client_context.window_index_for_xdg_toplevel(xdg_toplevel) {
+        Some(window_idx) => {
+            match client_context.client_window_mut(window_idx) {
+                Some(client_window) => {
+                    client_window.configure_size = Size::new(width, height);
+                    client_window.toplevel_states = client_states;
+                },
+                None => eprintln!("lwltk: {}", ClientError::NoClientWindow),
+            }
+        },
+        None => eprintln!("lwltk: {}", ClientError::NoClientWindow),
+    }
+}
+
+pub(crate) fn prepare_event_for_client_xdg_surface_configure(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, xdg_surface: &xdg_surface::XdgSurface, serial: u32) -> Option<Event>
+{
+    match client_context.window_index_for_xdg_surface(xdg_surface) {
+        Some(window_idx) => {
+            match client_context.client_window_mut(window_idx) {
+                Some(client_window) => {
+                    client_window.is_configured = true;
+                    client_window.configure_serial = Some(serial);
+                    if client_window.xdg_toplevel.is_some() {
+                        let is_maximized = client_window.toplevel_states.contains(ClientToplevelStates::MAXIMIZED);
+                        let is_fullscreen = client_window.toplevel_states.contains(ClientToplevelStates::FULLSCREEN);
+                        let mut size = client_window.configure_size;
+                        if !client_window.is_maximized && is_maximized {
+                            client_window.unmaximized_size = client_window.size;
+                        } else if client_window.is_maximized && !is_maximized && (size.width == 0 || size.height == 0) {
+                            size = client_window.unmaximized_size;
+                        }
+                        if !client_window.is_fullscreen && is_fullscreen {
+                            client_window.unfullscreen_size = client_window.size;
+                        } else if client_window.is_fullscreen && !is_fullscreen && (size.width == 0 || size.height == 0) {
+                            size = client_window.unfullscreen_size;
+                        }
+                        client_window.is_maximized = is_maximized;
+                        client_window.is_fullscreen = is_fullscreen;
+                        let states = client_window.toplevel_states;
+                        window_context.current_window_index = Some(window_idx);
+                        queue_context.current_call_on_path = Some(CallOnPath::Window(window_idx));
+                        Some(Event::Client(ClientEvent::ShellSurfaceConfigure(ClientResize::None, size, states)))
+                    } else {
+                        None
+                    }
+                },
+                None => {
+                    eprintln!("lwltk: {}", ClientError::NoClientWindow);
+                    None
+                },
+            }
+        },
+        None => {
+            eprintln!("lwltk: {}", ClientError::NoClientWindow);
+            None
+        },
+    }
+}
+
+pub(crate) fn prepare_event_for_client_xdg_toplevel_close(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, xdg_toplevel: &xdg_toplevel::XdgToplevel) -> Option<Event>
+{
+    match client_context.window_index_for_xdg_toplevel(xdg_toplevel) {
+        Some(window_idx) => {
+            window_context.current_window_index = Some(window_idx);
+            queue_context.current_call_on_path = Some(CallOnPath::Window(window_idx));
+            Some(Event::Client(ClientEvent::ShellSurfaceClose))
+        },
+        None => {
+            eprintln!("lwltk: {}", ClientError::NoClientWindow);
+            None
+        },
+    }
+}
+
+pub(crate) fn prepare_event_for_client_xdg_popup_done(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, xdg_popup: &xdg_popup::XdgPopup) -> Option<Event>
+{
+    match client_context.window_index_for_xdg_popup(xdg_popup) {
+        Some(window_idx) => {
+            window_context.current_window_index = Some(window_idx);
+            queue_context.current_call_on_path = Some(CallOnPath::Window(window_idx));
+            Some(Event::Client(ClientEvent::ShellSurfacePopupDone))
+        },
+        None => {
+            eprintln!("lwltk: {}", ClientError::NoClientWindow);
+            None
+        },
+    }
+}
+
+pub(crate) fn prepare_event_for_client_xdg_toplevel_decoration_configure(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, xdg_toplevel_decoration: &zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1, mode: zxdg_toplevel_decoration_v1::Mode) -> Option<Event>
+{
+    let client_mode = match mode {
+        zxdg_toplevel_decoration_v1::Mode::ServerSide => ClientDecorationMode::Server,
+        _ => ClientDecorationMode::Client,
+    };
+    match client_context.window_index_for_xdg_toplevel_decoration(xdg_toplevel_decoration) {
+        Some(window_idx) => {
+            window_context.current_window_index = Some(window_idx);
+            queue_context.current_call_on_path = Some(CallOnPath::Window(window_idx));
+            Some(Event::Client(ClientEvent::ToplevelDecorationConfigure(client_mode)))
+        },
+        None => {
+            eprintln!("lwltk: {}", ClientError::NoClientWindow);
+            None
+        },
+    }
+}
+
+pub(crate) fn prepare_event_for_client_layer_surface_configure(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, layer_surface: &zwlr_layer_surface_v1::ZwlrLayerSurfaceV1, serial: u32, width: u32, height: u32) -> Option<Event>
+{
+    match client_context.window_index_for_layer_surface(layer_surface) {
+        Some(window_idx) => {
+            match client_context.client_window_mut(window_idx) {
+                Some(client_window) => {
+                    client_window.is_configured = true;
+                    client_window.configure_serial = Some(serial);
+                    let size = Size::new(width as i32, height as i32);
+                    window_context.current_window_index = Some(window_idx);
+                    queue_context.current_call_on_path = Some(CallOnPath::Window(window_idx));
+                    Some(Event::Client(ClientEvent::ShellSurfaceConfigure(ClientResize::None, size, ClientToplevelStates::EMPTY)))
+                },
+                None => {
+                    eprintln!("lwltk: {}", ClientError::NoClientWindow);
+                    None
+                },
+            }
+        },
+        None => {
+            eprintln!("lwltk: {}", ClientError::NoClientWindow);
+            None
+        },
+    }
+}
+
+pub(crate) fn prepare_event_for_client_layer_surface_closed(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, layer_surface: &zwlr_layer_surface_v1::ZwlrLayerSurfaceV1) -> Option<Event>
+{
+    match client_context.window_index_for_layer_surface(layer_surface) {
+        Some(window_idx) => {
+            window_context.current_window_index = Some(window_idx);
+            queue_context.current_call_on_path = Some(CallOnPath::Window(window_idx));
+            Some(Event::Client(ClientEvent::ShellSurfaceClose))
+        },
+        None => {
+            eprintln!("lwltk: {}", ClientError::NoClientWindow);
+            None
+        },
+    }
+}
@@ -0,0 +1,228 @@
+//
+// Copyright (c) 2022-2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use wayland_client::protocol::wl_surface;
+use crate::client_context::*;
+use crate::client_error::*;
+use crate::client_keyboard::*;
+use crate::event_queue::*;
+use crate::events::*;
+use crate::queue_context::*;
+use crate::types::*;
+use crate::window_context::*;
+
+fn text_input_state(window_context: &mut WindowContext, window_idx: WindowIndex) -> Option<(Option<Rect<i32>>, Option<(String, usize, usize)>)>
+{
+    match update_focused_rel_widget_path(window_context, window_idx) {
+        Some(CallOnPath::Widget(abs_widget_path)) => {
+            match window_context.window_container.dyn_widget(&abs_widget_path) {
+                Some(widget) if widget.is_text_input() && widget.is_enabled() => Some((widget.text_cursor_rect(), widget.surrounding_text())),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum TextInputRequest
+{
+    Enable,
+    SetSurroundingText(String, usize, usize),
+    SetCursorRectangle(Rect<i32>),
+    Disable,
+    Commit,
+}
+
+/// Returns the requests of text input for the change of the text input state.
+///
+/// The requests are empty if the text input state isn't changed. The text input is enabled only if
+/// the old state is `None`, and the requests are ended by the commit request.
+fn text_input_requests(old_state: &Option<(Option<Rect<i32>>, Option<(String, usize, usize)>)>, new_state: &Option<(Option<Rect<i32>>, Option<(String, usize, usize)>)>) -> Vec<TextInputRequest>
+{
+    let mut requests: Vec<TextInputRequest> = Vec::new();
+    if new_state != old_state {
+        match new_state {
+            Some((cursor_rect, surrounding_text)) => {
+                if old_state.is_none() {
+                    requests.push(TextInputRequest::Enable);
+                }
+                match surrounding_text {
+                    Some((text, cursor, anchor)) => requests.push(TextInputRequest::SetSurroundingText(text.clone(), *cursor, *anchor)),
+                    None => (),
+                }
+                match cursor_rect {
+                    Some(cursor_rect) => requests.push(TextInputRequest::SetCursorRectangle(*cursor_rect)),
+                    None => (),
+                }
+            },
+            None => requests.push(TextInputRequest::Disable),
+        }
+        requests.push(TextInputRequest::Commit);
+    }
+    requests
+}
+
+/// Enables or disables an input method for the focused widget.
+///
+/// The input method is enabled if the focused widget accepts the text from the input method. The
+/// requests of text input are sent only if a state of the text input is changed. Each seat has
+/// own text input, so the states of text inputs are updated for all seats.
+pub(crate) fn update_text_input(client_context: &mut ClientContext, window_context: &mut WindowContext)
+{
+    for client_seat in client_context.fields.seats.values_mut() {
+        let new_state = match client_seat.text_input_window_index {
+            Some(window_idx) => text_input_state(window_context, window_idx),
+            None => None,
+        };
+        let requests = text_input_requests(&client_seat.text_input_state, &new_state);
+        if !requests.is_empty() {
+            match &client_seat.text_input {
+                Some(text_input) => {
+                    for request in requests {
+                        match request {
+                            TextInputRequest::Enable => text_input.enable(),
+                            TextInputRequest::SetSurroundingText(text, cursor, anchor) => text_input.set_surrounding_text(text, cursor as i32, anchor as i32),
+                            TextInputRequest::SetCursorRectangle(cursor_rect) => text_input.set_cursor_rectangle(cursor_rect.x, cursor_rect.y, cursor_rect.width, cursor_rect.height),
+                            TextInputRequest::Disable => text_input.disable(),
+                            TextInputRequest::Commit => text_input.commit(),
+                        }
+                    }
+                },
+                None => continue,
+            }
+            client_seat.text_input_state = new_state;
+        }
+    }
+}
+
+pub(crate) fn enter_text_input(client_context: &mut ClientContext, window_context: &mut WindowContext, surface: &wl_surface::WlSurface)
+{
+    match client_context.window_index_for_surface(surface) {
+        Some(window_idx) => {
+            match client_context.fields.current_seat_mut() {
+                Some(client_seat) => {
+                    client_seat.text_input_window_index = Some(window_idx);
+                    client_seat.text_input_state = None;
+                },
+                None => (),
+            }
+            update_text_input(client_context, window_context);
+        },
+        None => eprintln!("lwltk: {}", ClientError::NoClientWindow),
+    }
+}
+
+pub(crate) fn leave_text_input(client_context: &mut ClientContext, window_context: &mut WindowContext)
+{
+    match client_context.fields.current_seat_mut() {
+        Some(client_seat) => client_seat.text_input_window_index = None,
+        None => (),
+    }
+    update_text_input(client_context, window_context);
+    match client_context.fields.current_seat_mut() {
+        Some(client_seat) => {
+            client_seat.text_input_preedit = None;
+            client_seat.text_input_committed_text = None;
+            client_seat.text_input_deleted_lens = None;
+        },
+        None => (),
+    }
+}
+
+pub(crate) fn set_text_input_preedit(client_context: &mut ClientContext, text: Option<String>, cursor_begin: i32, cursor_end: i32)
+{
+    let cursor = if cursor_begin >= 0 && cursor_end >= 0 {
+        Some((cursor_begin as usize, cursor_end as usize))
+    } else {
+        None
+    };
+    match client_context.fields.current_seat_mut() {
+        Some(client_seat) => client_seat.text_input_preedit = Some((text.unwrap_or_default(), cursor)),
+        None => (),
+    }
+}
+
+pub(crate) fn set_text_input_committed_text(client_context: &mut ClientContext, text: Option<String>)
+{
+    match client_context.fields.current_seat_mut() {
+        Some(client_seat) => client_seat.text_input_committed_text = text,
+        None => (),
+    }
+}
+
+pub(crate) fn set_text_input_deleted_lens(client_context: &mut ClientContext, before_len: u32, after_len: u32)
+{
+    match client_context.fields.current_seat_mut() {
+        Some(client_seat) => client_seat.text_input_deleted_lens = Some((before_len as usize, after_len as usize)),
+        None => (),
+    }
+}
+
+pub(crate) fn prepare_event_for_client_text_input_done(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext) -> Option<Event>
+{
+    let client_seat = client_context.fields.current_seat_mut()?;
+    let deleted_lens = client_seat.text_input_deleted_lens.take();
+    let committed_text = client_seat.text_input_committed_text.take();
+    let (preedit_text, preedit_cursor) = client_seat.text_input_preedit.take().unwrap_or_default();
+    match client_seat.text_input_window_index {
+        Some(text_input_window_idx) => {
+            match update_focused_rel_widget_path(window_context, text_input_window_idx) {
+                Some(call_on_path) => {
+                    window_context.current_window_index = Some(call_on_path.window_index());
+                    window_context.current_pos = None;
+                    queue_context.current_call_on_path = Some(call_on_path);
+                    Some(Event::Client(ClientEvent::TextInputDone(deleted_lens, committed_text, preedit_text, preedit_cursor)))
+                },
+                None => {
+                    eprintln!("lwltk: {}", ClientError::NoWindow);
+                    None
+                },
+            }
+        },
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_text_input_requests_enable_text_input_and_set_state()
+    {
+        let new_state = Some((Some(Rect::new(1, 2, 3, 4)), Some((String::from("abc"), 1, 2))));
+        let expected_requests = vec![TextInputRequest::Enable, TextInputRequest::SetSurroundingText(String::from("abc"), 1, 2), TextInputRequest::SetCursorRectangle(Rect::new(1, 2, 3, 4)), TextInputRequest::Commit];
+        assert_eq!(expected_requests, text_input_requests(&None, &new_state));
+    }
+
+    #[test]
+    fn test_text_input_requests_update_state_without_enabling_for_changed_state()
+    {
+        let old_state = Some((Some(Rect::new(1, 2, 3, 4)), Some((String::from("abc"), 1, 2))));
+        let new_state = Some((Some(Rect::new(5, 2, 3, 4)), None));
+        let expected_requests = vec![TextInputRequest::SetCursorRectangle(Rect::new(5, 2, 3, 4)), TextInputRequest::Commit];
+        assert_eq!(expected_requests, text_input_requests(&old_state, &new_state));
+    }
+
+    #[test]
+    fn test_text_input_requests_disable_text_input()
+    {
+        let old_state = Some((Some(Rect::new(1, 2, 3, 4)), None));
+        let expected_requests = vec![TextInputRequest::Disable, TextInputRequest::Commit];
+        assert_eq!(expected_requests, text_input_requests(&old_state, &None));
+    }
+
+    #[test]
+    fn test_text_input_requests_are_empty_for_unchanged_state()
+    {
+        let state = Some((Some(Rect::new(1, 2, 3, 4)), Some((String::from("abc"), 1, 2))));
+        assert_eq!(true, text_input_requests(&state, &state.clone()).is_empty());
+        assert_eq!(true, text_input_requests(&None, &None).is_empty());
+    }
+}
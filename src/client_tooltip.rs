@@ -0,0 +1,174 @@
+//
+// Copyright (c) 2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use crate::client_context::*;
+use crate::event_queue::*;
+use crate::events::*;
+use crate::queue_context::*;
+use crate::types::*;
+use crate::window_context::*;
+use crate::windows::*;
+
+const TOOLTIP_POINTER_SIZE: i32 = 16;
+
+fn hide_client_tooltip(client_context: &mut ClientContext, window_context: &mut WindowContext)
+{
+    if client_context.fields.is_tooltip_pending {
+        client_context.fields.is_tooltip_pending = false;
+        client_context.fields.has_tooltip_timer_start = false;
+        client_context.fields.has_tooltip_timer_stop = true;
+    }
+    if client_context.fields.is_tooltip_shown {
+        match client_context.fields.tooltip_index {
+            Some(tooltip_idx) => {
+                hide_tooltip(window_context, tooltip_idx);
+            },
+            None => (),
+        }
+        client_context.fields.is_tooltip_shown = false;
+    }
+}
+
+fn unset_client_tooltip(client_context: &mut ClientContext, window_context: &mut WindowContext)
+{
+    hide_client_tooltip(client_context, window_context);
+    client_context.fields.tooltip_text = None;
+    client_context.fields.tooltip_path = None;
+}
+
+fn set_client_tooltip(client_context: &mut ClientContext, window_context: &mut WindowContext, tooltip_text: String, path: AbsWidgetPath, anchor_rect: Rect<i32>)
+{
+    hide_client_tooltip(client_context, window_context);
+    client_context.fields.tooltip_text = Some(tooltip_text);
+    client_context.fields.tooltip_path = Some(path);
+    client_context.fields.tooltip_anchor_rect = anchor_rect;
+    client_context.fields.is_tooltip_pending = true;
+    client_context.fields.has_tooltip_timer_start = true;
+    client_context.fields.has_tooltip_timer_stop = false;
+}
+
+fn client_tooltip_index(client_context: &mut ClientContext, window_context: &mut WindowContext, tooltip_text: &str) -> Option<WindowIndex>
+{
+    match client_context.fields.tooltip_index {
+        Some(tooltip_idx) => {
+            match window_context.window_mut::<Tooltip>(tooltip_idx) {
+                Some(tooltip) => {
+                    tooltip.set_text(tooltip_text);
+                    return Some(tooltip_idx);
+                },
+                None => (),
+            }
+        },
+        None => (),
+    }
+    let tooltip_idx = window_context.add_window(Tooltip::new(tooltip_text))?;
+    client_context.fields.tooltip_index = Some(tooltip_idx);
+    Some(tooltip_idx)
+}
+
+fn focused_abs_widget_path(window_context: &WindowContext) -> Option<AbsWidgetPath>
+{
+    let window_idx = window_context.focused_window_index()?;
+    let window = window_context.dyn_window(window_idx)?;
+    match window.focused_rel_widget_path() {
+        Some(rel_widget_path) => Some(rel_widget_path.to_abs_widget_path(window_idx)),
+        None => None,
+    }
+}
+
+/// Updates the tooltip before an event handling.
+///
+/// A tooltip of a widget under a pointer waits for a timer. A tooltip is hidden when the pointer
+/// leaves the widget, or when an user clicks or presses a key. The tooltip isn't shown again for
+/// the same widget until the pointer leaves the widget.
+pub(crate) fn update_client_tooltip_before_event(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &QueueContext, event: &Event)
+{
+    match event {
+        Event::Client(ClientEvent::PointerEnter(pos)) | Event::Client(ClientEvent::PointerMotion(_, pos)) => {
+            let pair = match &queue_context.current_call_on_path {
+                Some(call_on_path) if !window_context.window_container.is_blocked(call_on_path.window_index()) => tooltip_text_and_path(window_context, call_on_path),
+                _ => None,
+            };
+            match pair {
+                Some((tooltip_text, path)) => {
+                    let anchor_rect = Rect::new(pos.x as i32, pos.y as i32, TOOLTIP_POINTER_SIZE, TOOLTIP_POINTER_SIZE);
+                    if client_context.fields.tooltip_text.as_ref() != Some(&tooltip_text) || client_context.fields.tooltip_path.as_ref() != Some(&path) {
+                        set_client_tooltip(client_context, window_context, tooltip_text, path, anchor_rect);
+                    } else if client_context.fields.is_tooltip_pending {
+                        client_context.fields.tooltip_anchor_rect = anchor_rect;
+                    }
+                },
+                None => unset_client_tooltip(client_context, window_context),
+            }
+        },
+        Event::Client(ClientEvent::PointerLeave) => unset_client_tooltip(client_context, window_context),
+        Event::Client(ClientEvent::PointerButton(_, _, ClientState::Pressed)) => hide_client_tooltip(client_context, window_context),
+        Event::Client(ClientEvent::PointerAxis(_, _, _, _, _)) => hide_client_tooltip(client_context, window_context),
+        Event::Client(ClientEvent::KeyboardKey(_, _, _, ClientState::Pressed)) => hide_client_tooltip(client_context, window_context),
+        Event::Client(ClientEvent::TouchDown(_, _, _)) => hide_client_tooltip(client_context, window_context),
+        _ => (),
+    }
+}
+
+/// Updates the tooltip after an event handling.
+///
+/// A tooltip of a widget that is focused by a key waits for a timer.
+pub(crate) fn update_client_tooltip_after_event(client_context: &mut ClientContext, window_context: &mut WindowContext, event: &Event)
+{
+    let focused_path = focused_abs_widget_path(window_context);
+    let is_key = match event {
+        Event::Client(ClientEvent::KeyboardKey(_, _, _, ClientState::Pressed)) => true,
+        Event::Client(ClientEvent::RepeatedKey(_, _)) => true,
+        _ => false,
+    };
+    if is_key && focused_path != client_context.fields.tooltip_focused_path {
+        match &focused_path {
+            Some(path) => {
+                match tooltip_text_and_path(window_context, &CallOnPath::Widget(path.clone())) {
+                    Some((tooltip_text, tooltip_path)) => {
+                        match window_context.dyn_widget(path) {
+                            Some(widget) => {
+                                let bounds = widget.bounds();
+                                set_client_tooltip(client_context, window_context, tooltip_text, tooltip_path, bounds);
+                            },
+                            None => (),
+                        }
+                    },
+                    None => (),
+                }
+            },
+            None => (),
+        }
+    }
+    client_context.fields.tooltip_focused_path = focused_path;
+}
+
+/// Shows the tooltip that waits for the timer.
+///
+/// The tooltip window is created by the toolkit for the first shown tooltip and it is reused for
+/// the next tooltips.
+pub(crate) fn show_client_tooltip_for_timer(client_context: &mut ClientContext, window_context: &mut WindowContext)
+{
+    if client_context.fields.is_tooltip_pending {
+        client_context.fields.is_tooltip_pending = false;
+        match (client_context.fields.tooltip_text.clone(), &client_context.fields.tooltip_path) {
+            (Some(tooltip_text), Some(path)) => {
+                let parent_idx = path.window_index();
+                let anchor_rect = client_context.fields.tooltip_anchor_rect;
+                if !window_context.window_container.is_blocked(parent_idx) {
+                    match client_tooltip_index(client_context, window_context, tooltip_text.as_str()) {
+                        Some(tooltip_idx) => {
+                            client_context.fields.is_tooltip_shown = show_tooltip(window_context, tooltip_idx, parent_idx, anchor_rect, ClientPopupAnchor::BottomLeft).is_some();
+                        },
+                        None => (),
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+}
@@ -19,16 +19,22 @@ pub(crate) fn prepare_event_for_client_touch_down(client_context: &mut ClientCon
 {
     match client_context.window_index_for_surface(surface) {
         Some(window_idx) => {
-            let pos = Pos::new(x / (client_context.fields.scale as f64), y / (client_context.fields.scale as f64));
+            let pos = Pos::new(x, y);
             match client_context.add_event_preparation(window_context, CallOnId::Touch(id), window_idx, pos, None) {
                 Some((call_on_path, pos)) => {
-                    if client_context.fields.touch_ids.is_empty() {
+                    if client_context.fields.seats.values().all(|s| s.touch_ids.is_empty()) {
                         match timer_tx.send(ThreadTimerCommand::Start(ThreadTimer::Touch)) {
                             Ok(()) => (),
                             Err(_) => eprintln!("lwltk: {}", ClientError::Send),
                         }
                     }
-                    client_context.fields.touch_ids.insert(id);
+                    match client_context.fields.current_seat_mut() {
+                        Some(client_seat) => {
+                            client_seat.touch_ids.insert(id);
+                        },
+                        None => (),
+                    }
+                    window_context.window_container.unset_parents_for_outside_popups(call_on_path.window_index());
                     window_context.current_window_index = Some(call_on_path.window_index());
                     window_context.current_pos = Some(pos);
                     queue_context.current_call_on_path = Some(call_on_path);
@@ -51,8 +57,13 @@ pub(crate) fn prepare_event_for_client_touch_up(client_context: &mut ClientConte
 {
     match client_context.remove_event_preparation(window_context, CallOnId::Touch(id)) {
         Some((call_on_path, pos)) => {
-            client_context.fields.touch_ids.remove(&id);
-            if client_context.fields.touch_ids.is_empty() {
+            match client_context.fields.current_seat_mut() {
+                Some(client_seat) => {
+                    client_seat.touch_ids.remove(&id);
+                },
+                None => (),
+            }
+            if client_context.fields.seats.values().all(|s| s.touch_ids.is_empty()) {
                 match timer_tx.send(ThreadTimerCommand::Stop(ThreadTimer::Touch)) {
                     Ok(()) => (),
                     Err(_) => eprintln!("lwltk: {}", ClientError::Send),
@@ -72,7 +83,7 @@ pub(crate) fn prepare_event_for_client_touch_up(client_context: &mut ClientConte
 
 pub(crate) fn prepare_event_for_client_touch_motion(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, time: u32, id: i32, x: f64, y: f64) -> Option<Event>
 {
-    let pos = Pos::new(x / (client_context.fields.scale as f64), y / (client_context.fields.scale as f64));
+    let pos = Pos::new(x, y);
     match client_context.set_event_preparation(window_context, CallOnId::Touch(id), pos) {
         Some((call_on_path, pos)) => {
             window_context.current_window_index = Some(call_on_path.window_index());
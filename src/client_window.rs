@@ -8,29 +8,32 @@
 use std::cell::*;
 use std::cmp::max;
 use std::collections::BTreeSet;
-use std::fs::*;
-use std::os::unix::io::AsRawFd;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::sync::RwLock;
 use std::sync::mpsc;
 use std::rc::*;
-use cairo::Format;
 use cairo::ImageSurface;
 use cairo::Operator;
-use memmap2::MmapOptions;
-use memmap2::MmapMut;
-use tempfile;
-use wayland_client::protocol::wl_buffer;
+use wayland_client::protocol::wl_callback;
 use wayland_client::protocol::wl_shell_surface;
-use wayland_client::protocol::wl_shm;
 use wayland_client::protocol::wl_surface;
 use wayland_client::Main;
+use wayland_protocols::unstable::xdg_decoration::v1::client::zxdg_toplevel_decoration_v1;
+use wayland_protocols::viewporter::client::wp_viewport;
+use wayland_protocols::wlr::unstable::layer_shell::v1::client::zwlr_layer_shell_v1;
+use wayland_protocols::wlr::unstable::layer_shell::v1::client::zwlr_layer_surface_v1;
+use wayland_protocols::xdg_shell::client::xdg_popup;
+use wayland_protocols::xdg_shell::client::xdg_positioner;
+use wayland_protocols::xdg_shell::client::xdg_surface;
+use wayland_protocols::xdg_shell::client::xdg_toplevel;
+use crate::client_buffer::*;
 use crate::client_context::*;
 use crate::client_error::*;
 use crate::client_shell_surface::*;
 use crate::event_handler::*;
 use crate::events::*;
+use crate::protocols::fractional_scale_v1::client::wp_fractional_scale_v1;
 use crate::queue_context::*;
 use crate::window::*;
 use crate::window_context::*;
@@ -38,72 +41,253 @@ use crate::utils::*;
 use crate::theme::*;
 use crate::types::*;
 
+pub(crate) struct ClientWindowParent
+{
+    surface: Main<wl_surface::WlSurface>,
+    xdg_surface: Option<Main<xdg_surface::XdgSurface>>,
+    xdg_toplevel: Option<Main<xdg_toplevel::XdgToplevel>>,
+    layer_surface: Option<Main<zwlr_layer_surface_v1::ZwlrLayerSurfaceV1>>,
+}
+
 pub(crate) struct ClientWindow
 {
     pub(crate) surface: Main<wl_surface::WlSurface>,
-    pub(crate) shell_surface: Main<wl_shell_surface::WlShellSurface>,
-    pub(crate) buffer: Main<wl_buffer::WlBuffer>,
-    pub(crate) file: File,
-    pub(crate) mmap: MmapMut,
-    pub(crate) cairo_surface: ImageSurface,
+    pub(crate) shell_surface: Option<Main<wl_shell_surface::WlShellSurface>>,
+    pub(crate) xdg_surface: Option<Main<xdg_surface::XdgSurface>>,
+    pub(crate) xdg_toplevel: Option<Main<xdg_toplevel::XdgToplevel>>,
+    pub(crate) xdg_popup: Option<Main<xdg_popup::XdgPopup>>,
+    pub(crate) xdg_toplevel_decoration: Option<Main<zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1>>,
+    pub(crate) layer_surface: Option<Main<zwlr_layer_surface_v1::ZwlrLayerSurfaceV1>>,
+    pub(crate) viewport: Option<Main<wp_viewport::WpViewport>>,
+    pub(crate) fractional_scale: Option<Main<wp_fractional_scale_v1::WpFractionalScaleV1>>,
+    pub(crate) buffer_pool: ClientBufferPool,
     pub(crate) size: Size<i32>,
+    pub(crate) scale: f64,
+    pub(crate) preferred_scale: Option<f64>,
+    pub(crate) output_names: BTreeSet<u32>,
     pub(crate) unmaximized_size: Size<i32>,
+    pub(crate) unfullscreen_size: Size<i32>,
     pub(crate) title: Option<String>,
     pub(crate) is_maximized: bool,
+    pub(crate) is_fullscreen: bool,
+    pub(crate) fullscreen_output_name: Option<u32>,
+    pub(crate) decoration_preference: ClientDecorationPreference,
+    pub(crate) layer: Option<ClientLayer>,
+    pub(crate) anchor: ClientAnchor,
+    pub(crate) exclusive_zone: i32,
+    pub(crate) layer_margin_edges: Edges<i32>,
+    pub(crate) keyboard_interactivity: ClientKeyboardInteractivity,
+    pub(crate) is_configured: bool,
+    pub(crate) configure_serial: Option<u32>,
+    pub(crate) is_configure_acked: bool,
+    pub(crate) configure_size: Size<i32>,
+    pub(crate) toplevel_states: ClientToplevelStates,
+    pub(crate) is_frame_pending: Rc<Cell<bool>>,
     pub(crate) parent_index: Option<WindowIndex>,
     pub(crate) child_indices: BTreeSet<WindowIndex>,
 }
 
-fn create_buffer(client_context_fields: &ClientContextFields, window: &dyn Window) -> Result<(Main<wl_buffer::WlBuffer>, File, MmapMut, ImageSurface), ClientError>
+fn buffer_size(window: &dyn Window, scale: f64) -> Size<i32>
+{ Size::new(max(((window.width() as f64) * scale).round() as i32, 1), max(((window.height() as f64) * scale).round() as i32, 1)) }
+
+fn buffer_rect(rect: Rect<i32>, scale: f64) -> Rect<i32>
 {
-    let mut tempfile_builder = tempfile::Builder::new();
-    tempfile_builder.prefix("lwltk-");
-    match tempfile_builder.tempfile_in(client_context_fields.xdg_runtime_dir.as_str()) {
-        Ok(named_temp_file) => {
-            let tmp_file = named_temp_file.into_file();
-            let scale = client_context_fields.scale;
-            let size = window.width() * window.height() * scale * scale * 4;
-            match tmp_file.set_len(size as u64) {
-                Ok(()) => {
-                    let mut mmap_opts = MmapOptions::new();
-                    mmap_opts.len(size as usize);
-                    match unsafe { mmap_opts.map_mut(&tmp_file) } {
-                        Ok(mut mmap) => {
-                            let shm_pool = client_context_fields.shm.create_pool(tmp_file.as_raw_fd(), size);
-                            let buffer = shm_pool.create_buffer(0, window.width() * scale, window.height() * scale, window.width() * scale * 4, wl_shm::Format::Argb8888);
-                            shm_pool.destroy();
-                            match Format::ARgb32.stride_for_width((window.width() * scale) as u32) {
-                                Ok(stride) => {
-                                    match unsafe { ImageSurface::create_for_data_unsafe(mmap.as_mut_ptr(), Format::ARgb32, window.width() * scale, window.height() * scale, stride) } {
-                                        Ok(cairo_surface) => {
-                                            Ok((buffer, tmp_file, mmap, cairo_surface))
-                                        },
-                                        Err(err) => {
-                                            buffer.destroy();
-                                            Err(ClientError::Cairo(err))
-                                        },
-                                    }
-                                },
-                                Err(err) => {
-                                    buffer.destroy();
-                                    Err(ClientError::Cairo(err))
-                                },
-                            }
-                        },
-                        Err(err) => Err(ClientError::Io(err)),
-                    }
-                },
-                Err(err) => Err(ClientError::Io(err)),
+    // The rectangle is rounded outward to device pixels.
+    let x1 = ((rect.x as f64) * scale).floor() as i32;
+    let y1 = ((rect.y as f64) * scale).floor() as i32;
+    let x2 = (((rect.x + rect.width) as f64) * scale).ceil() as i32;
+    let y2 = (((rect.y + rect.height) as f64) * scale).ceil() as i32;
+    Rect::new(x1, y1, x2 - x1, y2 - y1)
+}
+
+fn scale_for_client_window(client_context_fields: &ClientContextFields, output_names: &BTreeSet<u32>, preferred_scale: Option<f64>, has_viewport: bool) -> f64
+{
+    let scale = match (client_context_fields.forced_scale, preferred_scale) {
+        (Some(forced_scale), _) => forced_scale,
+        (None, Some(preferred_scale)) => preferred_scale,
+        (None, None) => client_context_fields.scale_for_output_names(output_names) as f64,
+    };
+    if has_viewport {
+        scale
+    } else {
+        // A fractional scale requires a viewport.
+        scale.ceil()
+    }
+}
+
+fn set_xdg_toplevel_decoration_mode(xdg_toplevel_decoration: &zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1, preference: ClientDecorationPreference)
+{
+    match preference {
+        ClientDecorationPreference::Client => xdg_toplevel_decoration.set_mode(zxdg_toplevel_decoration_v1::Mode::ClientSide),
+        ClientDecorationPreference::Server => xdg_toplevel_decoration.set_mode(zxdg_toplevel_decoration_v1::Mode::ServerSide),
+        ClientDecorationPreference::Compositor => xdg_toplevel_decoration.unset_mode(),
+    }
+}
+
+fn set_layer_surface_size(layer_surface: &zwlr_layer_surface_v1::ZwlrLayerSurfaceV1, window: &dyn Window)
+{
+    let anchor = window.anchor();
+    // A zero width or a zero height means that a compositor stretches the layer window between
+    // the anchored opposite edges.
+    let width = if anchor.contains(ClientAnchor::LEFT | ClientAnchor::RIGHT) {
+        0
+    } else {
+        max(window.width(), 1)
+    };
+    let height = if anchor.contains(ClientAnchor::TOP | ClientAnchor::BOTTOM) {
+        0
+    } else {
+        max(window.height(), 1)
+    };
+    layer_surface.set_size(width as u32, height as u32);
+}
+
+fn wlr_layer_for_client_layer(layer: ClientLayer) -> zwlr_layer_shell_v1::Layer
+{
+    match layer {
+        ClientLayer::Background => zwlr_layer_shell_v1::Layer::Background,
+        ClientLayer::Bottom => zwlr_layer_shell_v1::Layer::Bottom,
+        ClientLayer::Top => zwlr_layer_shell_v1::Layer::Top,
+        ClientLayer::Overlay => zwlr_layer_shell_v1::Layer::Overlay,
+    }
+}
+
+fn set_layer_surface_anchor(layer_surface: &zwlr_layer_surface_v1::ZwlrLayerSurfaceV1, anchor: ClientAnchor)
+{
+    let mut wlr_anchor = zwlr_layer_surface_v1::Anchor::empty();
+    if anchor.contains(ClientAnchor::TOP) {
+        wlr_anchor |= zwlr_layer_surface_v1::Anchor::Top;
+    }
+    if anchor.contains(ClientAnchor::BOTTOM) {
+        wlr_anchor |= zwlr_layer_surface_v1::Anchor::Bottom;
+    }
+    if anchor.contains(ClientAnchor::LEFT) {
+        wlr_anchor |= zwlr_layer_surface_v1::Anchor::Left;
+    }
+    if anchor.contains(ClientAnchor::RIGHT) {
+        wlr_anchor |= zwlr_layer_surface_v1::Anchor::Right;
+    }
+    layer_surface.set_anchor(wlr_anchor);
+}
+
+fn set_layer_surface_margin(layer_surface: &zwlr_layer_surface_v1::ZwlrLayerSurfaceV1, edges: Edges<i32>)
+{ layer_surface.set_margin(edges.top, edges.right, edges.bottom, edges.left); }
+
+fn set_layer_surface_keyboard_interactivity(layer_surface: &zwlr_layer_surface_v1::ZwlrLayerSurfaceV1, interactivity: ClientKeyboardInteractivity)
+{
+    let wlr_interactivity = match interactivity {
+        ClientKeyboardInteractivity::None => zwlr_layer_surface_v1::KeyboardInteractivity::None,
+        ClientKeyboardInteractivity::Exclusive => zwlr_layer_surface_v1::KeyboardInteractivity::Exclusive,
+        ClientKeyboardInteractivity::OnDemand => {
+            if layer_surface.as_ref().version() >= 4 {
+                zwlr_layer_surface_v1::KeyboardInteractivity::OnDemand
+            } else {
+                // Older versions only know whether the layer window wants keyboard events.
+                zwlr_layer_surface_v1::KeyboardInteractivity::Exclusive
             }
         },
-        Err(err) => Err(ClientError::Io(err)),   
+    };
+    layer_surface.set_keyboard_interactivity(wlr_interactivity);
+}
+
+fn set_xdg_positioner(positioner: &xdg_positioner::XdgPositioner, window: &dyn Window, pos_in_parent: Pos<i32>)
+{
+    positioner.set_size(max(window.width(), 1), max(window.height(), 1));
+    match window.popup_anchor_rect() {
+        Some(anchor_rect) => positioner.set_anchor_rect(anchor_rect.x, anchor_rect.y, max(anchor_rect.width, 1), max(anchor_rect.height, 1)),
+        None => positioner.set_anchor_rect(pos_in_parent.x, pos_in_parent.y, 1, 1),
+    }
+    let xdg_anchor = match window.popup_anchor() {
+        ClientPopupAnchor::None => xdg_positioner::Anchor::None,
+        ClientPopupAnchor::Top => xdg_positioner::Anchor::Top,
+        ClientPopupAnchor::Bottom => xdg_positioner::Anchor::Bottom,
+        ClientPopupAnchor::Left => xdg_positioner::Anchor::Left,
+        ClientPopupAnchor::Right => xdg_positioner::Anchor::Right,
+        ClientPopupAnchor::TopLeft => xdg_positioner::Anchor::TopLeft,
+        ClientPopupAnchor::BottomLeft => xdg_positioner::Anchor::BottomLeft,
+        ClientPopupAnchor::TopRight => xdg_positioner::Anchor::TopRight,
+        ClientPopupAnchor::BottomRight => xdg_positioner::Anchor::BottomRight,
+    };
+    positioner.set_anchor(xdg_anchor);
+    let xdg_gravity = match window.popup_gravity() {
+        ClientPopupGravity::None => xdg_positioner::Gravity::None,
+        ClientPopupGravity::Top => xdg_positioner::Gravity::Top,
+        ClientPopupGravity::Bottom => xdg_positioner::Gravity::Bottom,
+        ClientPopupGravity::Left => xdg_positioner::Gravity::Left,
+        ClientPopupGravity::Right => xdg_positioner::Gravity::Right,
+        ClientPopupGravity::TopLeft => xdg_positioner::Gravity::TopLeft,
+        ClientPopupGravity::BottomLeft => xdg_positioner::Gravity::BottomLeft,
+        ClientPopupGravity::TopRight => xdg_positioner::Gravity::TopRight,
+        ClientPopupGravity::BottomRight => xdg_positioner::Gravity::BottomRight,
+    };
+    positioner.set_gravity(xdg_gravity);
+    let adjustment = window.popup_constraint_adjustment();
+    let mut xdg_adjustment = xdg_positioner::ConstraintAdjustment::None;
+    if adjustment.contains(ClientConstraintAdjustment::SLIDE_X) {
+        xdg_adjustment |= xdg_positioner::ConstraintAdjustment::SlideX;
+    }
+    if adjustment.contains(ClientConstraintAdjustment::SLIDE_Y) {
+        xdg_adjustment |= xdg_positioner::ConstraintAdjustment::SlideY;
+    }
+    if adjustment.contains(ClientConstraintAdjustment::FLIP_X) {
+        xdg_adjustment |= xdg_positioner::ConstraintAdjustment::FlipX;
+    }
+    if adjustment.contains(ClientConstraintAdjustment::FLIP_Y) {
+        xdg_adjustment |= xdg_positioner::ConstraintAdjustment::FlipY;
+    }
+    if adjustment.contains(ClientConstraintAdjustment::RESIZE_X) {
+        xdg_adjustment |= xdg_positioner::ConstraintAdjustment::ResizeX;
+    }
+    if adjustment.contains(ClientConstraintAdjustment::RESIZE_Y) {
+        xdg_adjustment |= xdg_positioner::ConstraintAdjustment::ResizeY;
+    }
+    positioner.set_constraint_adjustment(xdg_adjustment.to_raw());
+}
+
+fn destroy_layer_surface(layer_surface: Option<&Main<zwlr_layer_surface_v1::ZwlrLayerSurfaceV1>>)
+{
+    match layer_surface {
+        Some(layer_surface) => layer_surface.destroy(),
+        None => (),
+    }
+}
+
+fn destroy_xdg_surface(xdg_surface: Option<&Main<xdg_surface::XdgSurface>>, xdg_toplevel: Option<&Main<xdg_toplevel::XdgToplevel>>, xdg_popup: Option<&Main<xdg_popup::XdgPopup>>, xdg_toplevel_decoration: Option<&Main<zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1>>)
+{
+    match xdg_toplevel_decoration {
+        Some(xdg_toplevel_decoration) => xdg_toplevel_decoration.destroy(),
+        None => (),
+    }
+    match xdg_popup {
+        Some(xdg_popup) => xdg_popup.destroy(),
+        None => (),
+    }
+    match xdg_toplevel {
+        Some(xdg_toplevel) => xdg_toplevel.destroy(),
+        None => (),
+    }
+    match xdg_surface {
+        Some(xdg_surface) => xdg_surface.destroy(),
+        None => (),
+    }
+}
+
+fn destroy_viewport_and_fractional_scale(viewport: Option<&Main<wp_viewport::WpViewport>>, fractional_scale: Option<&Main<wp_fractional_scale_v1::WpFractionalScaleV1>>)
+{
+    match fractional_scale {
+        Some(fractional_scale) => fractional_scale.destroy(),
+        None => (),
+    }
+    match viewport {
+        Some(viewport) => viewport.destroy(),
+        None => (),
     }
 }
 
 fn update_window_size_and_window_pos(window: &mut dyn Window, theme: &dyn Theme) -> Result<(), CairoError>
 {
     with_dummy_cairo_context(|cairo_context| {
-            theme.set_cairo_context(cairo_context, 1)?; 
+            theme.set_cairo_context(cairo_context, 1.0)?; 
             let area_width = match (window.preferred_width(), window.min_width()) {
                 (Some(preferred_width), Some(min_width)) => Some(max(preferred_width, min_width)),
                 (Some(preferred_width), None) => Some(preferred_width),
@@ -147,22 +331,105 @@ fn update_window_size_and_window_pos(window: &mut dyn Window, theme: &dyn Theme)
 
 impl ClientWindow
 {
-    pub(crate) fn new(client_context_fields: &ClientContextFields, window: &mut dyn Window, theme: &dyn Theme) -> Result<ClientWindow, ClientError>
+    pub(crate) fn new(client_context_fields: &ClientContextFields, window: &mut dyn Window, theme: &dyn Theme, parent: Option<&ClientWindowParent>) -> Result<ClientWindow, ClientError>
     {
         match update_window_size_and_window_pos(window, theme) {
             Ok(()) => {
                 let surface = client_context_fields.compositor.create_surface();
-                let shell_surface = client_context_fields.shell.get_shell_surface(&surface);
-                let size = window.size();
+                let viewport = client_context_fields.viewporter.as_ref().map(|v| v.get_viewport(&surface));
+                let fractional_scale = client_context_fields.fractional_scale_manager.as_ref().map(|m| m.get_fractional_scale(&surface));
+                let scale = scale_for_client_window(client_context_fields, &BTreeSet::new(), None, viewport.is_some());
                 let title = window.title().map(|s| String::from(s));
-                match title.clone() {
-                    Some(title) => shell_surface.set_title(title),
-                    None => (),
+                let mut shell_surface: Option<Main<wl_shell_surface::WlShellSurface>> = None;
+                let mut xdg_surface: Option<Main<xdg_surface::XdgSurface>> = None;
+                let mut xdg_toplevel: Option<Main<xdg_toplevel::XdgToplevel>> = None;
+                let mut xdg_popup: Option<Main<xdg_popup::XdgPopup>> = None;
+                let mut xdg_toplevel_decoration: Option<Main<zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1>> = None;
+                let mut layer_surface: Option<Main<zwlr_layer_surface_v1::ZwlrLayerSurfaceV1>> = None;
+                let decoration_preference = window.decoration_preference();
+                match window.layer() {
+                    Some(layer) => {
+                        match &client_context_fields.layer_shell {
+                            Some(layer_shell) => {
+                                let tmp_layer_surface = layer_shell.get_layer_surface(&surface, None, wlr_layer_for_client_layer(layer), String::from(window.layer_namespace()));
+                                set_layer_surface_size(&tmp_layer_surface, window);
+                                set_layer_surface_anchor(&tmp_layer_surface, window.anchor());
+                                tmp_layer_surface.set_exclusive_zone(window.exclusive_zone());
+                                set_layer_surface_margin(&tmp_layer_surface, window.layer_margin_edges());
+                                set_layer_surface_keyboard_interactivity(&tmp_layer_surface, window.keyboard_interactivity());
+                                layer_surface = Some(tmp_layer_surface);
+                            },
+                            None => {
+                                destroy_viewport_and_fractional_scale(viewport.as_ref(), fractional_scale.as_ref());
+                                surface.destroy();
+                                return Err(ClientError::NoLayerShell);
+                            },
+                        }
+                    },
+                    None => {
+                    match (&client_context_fields.xdg_wm_base, &client_context_fields.shell) {
+                        (Some(xdg_wm_base), _) => {
+                            let tmp_xdg_surface = xdg_wm_base.get_xdg_surface(&surface);
+                            match (window.pos_in_parent(), parent) {
+                                (Some(pos_in_parent), Some(parent)) if window.is_popup() => {
+                                    let positioner = xdg_wm_base.create_positioner();
+                                    set_xdg_positioner(&positioner, window, pos_in_parent);
+                                    let parent_xdg_surface = parent.xdg_surface.as_ref().map(|s| &***s);
+                                    let tmp_xdg_popup = tmp_xdg_surface.get_popup(parent_xdg_surface, &positioner);
+                                    match &parent.layer_surface {
+                                        Some(parent_layer_surface) => parent_layer_surface.get_popup(&tmp_xdg_popup),
+                                        None => (),
+                                    }
+                                    xdg_popup = Some(tmp_xdg_popup);
+                                    positioner.destroy();
+                                },
+                                (_, _) => {
+                                    let tmp_xdg_toplevel = tmp_xdg_surface.get_toplevel();
+                                    match title.clone() {
+                                        Some(title) => tmp_xdg_toplevel.set_title(title),
+                                        None => (),
+                                    }
+                                    match &client_context_fields.xdg_decoration_manager {
+                                        Some(xdg_decoration_manager) => {
+                                            let tmp_xdg_toplevel_decoration = xdg_decoration_manager.get_toplevel_decoration(&tmp_xdg_toplevel);
+                                            set_xdg_toplevel_decoration_mode(&tmp_xdg_toplevel_decoration, decoration_preference);
+                                            xdg_toplevel_decoration = Some(tmp_xdg_toplevel_decoration);
+                                        },
+                                        None => (),
+                                    }
+                                    xdg_toplevel = Some(tmp_xdg_toplevel);
+                                },
+                            }
+                            xdg_surface = Some(tmp_xdg_surface);
+                        },
+                        (None, Some(shell)) => {
+                            let tmp_shell_surface = shell.get_shell_surface(&surface);
+                            match title.clone() {
+                                Some(title) => tmp_shell_surface.set_title(title),
+                                None => (),
+                            }
+                            shell_surface = Some(tmp_shell_surface);
+                        },
+                        (None, None) => {
+                            destroy_viewport_and_fractional_scale(viewport.as_ref(), fractional_scale.as_ref());
+                            surface.destroy();
+                            return Err(ClientError::NoShell);
+                        },
+                    }
+                    },
                 }
+                let size = window.size();
                 let is_maximized = window.is_maximized();
-                let (buffer, file, mmap, cairo_surface) = match create_buffer(client_context_fields, window) {
-                    Ok(tuple) => tuple,
+                let is_fullscreen = window.is_fullscreen();
+                let fullscreen_output_name = window.fullscreen_output_name();
+                let is_configured = xdg_surface.is_none() && layer_surface.is_none();
+                let buffer_size = buffer_size(window, scale);
+                let buffer_pool = match ClientBufferPool::new(client_context_fields, (buffer_size.width as usize) * (buffer_size.height as usize) * 4 * 2) {
+                    Ok(tmp_buffer_pool) => tmp_buffer_pool,
                     Err(err) => {
+                        destroy_xdg_surface(xdg_surface.as_ref(), xdg_toplevel.as_ref(), xdg_popup.as_ref(), xdg_toplevel_decoration.as_ref());
+                        destroy_layer_surface(layer_surface.as_ref());
+                        destroy_viewport_and_fractional_scale(viewport.as_ref(), fractional_scale.as_ref());
                         surface.destroy();
                         return Err(err);
                     }
@@ -170,26 +437,80 @@ impl ClientWindow
                 Ok(ClientWindow {
                         surface,
                         shell_surface,
-                        buffer,
-                        file,
-                        mmap,
-                        cairo_surface,
+                        xdg_surface,
+                        xdg_toplevel,
+                        xdg_popup,
+                        xdg_toplevel_decoration,
+                        layer_surface,
+                        viewport,
+                        fractional_scale,
+                        buffer_pool,
                         size,
+                        scale,
+                        preferred_scale: None,
+                        output_names: BTreeSet::new(),
                         unmaximized_size: size,
+                        unfullscreen_size: size,
                         title,
                         is_maximized,
+                        is_fullscreen,
+                        fullscreen_output_name,
+                        decoration_preference,
+                        layer: window.layer(),
+                        anchor: window.anchor(),
+                        exclusive_zone: window.exclusive_zone(),
+                        layer_margin_edges: window.layer_margin_edges(),
+                        keyboard_interactivity: window.keyboard_interactivity(),
+                        is_configured,
+                        configure_serial: None,
+                        is_configure_acked: false,
+                        configure_size: Size::new(0, 0),
+                        toplevel_states: ClientToplevelStates::EMPTY,
                         parent_index: None,
                         child_indices: BTreeSet::new(),
+                        is_frame_pending: Rc::new(Cell::new(false)),
                 })
             },
             Err(err) => Err(ClientError::Cairo(err)),
         }
     }
 
-    fn draw(&self, client_context_fields: &ClientContextFields, window: &dyn Window, theme: &dyn Theme) -> Result<(), CairoError>
+    pub(crate) fn parent(&self) -> ClientWindowParent
+    {
+        ClientWindowParent {
+            surface: self.surface.clone(),
+            xdg_surface: self.xdg_surface.clone(),
+            xdg_toplevel: self.xdg_toplevel.clone(),
+            layer_surface: self.layer_surface.clone(),
+        }
+    }
+
+    fn set_scale(&self, window: &dyn Window)
+    {
+        match &self.viewport {
+            Some(viewport) => viewport.set_destination(max(window.width(), 1), max(window.height(), 1)),
+            None => {
+                if self.surface.as_ref().version() >= 3 {
+                    self.surface.set_buffer_scale(self.scale as i32);
+                }
+            },
+        }
+    }
+
+    fn draw(&self, cairo_surface: &ImageSurface, window: &dyn Window, theme: &dyn Theme, dirty_rects: Option<&[Rect<i32>]>) -> Result<(), CairoError>
     {
-        with_cairo_context(&self.cairo_surface, |cairo_context| {
-                theme.set_cairo_context(cairo_context, client_context_fields.scale)?; 
+        with_cairo_context(cairo_surface, |cairo_context| {
+                match dirty_rects {
+                    Some(dirty_rects) => {
+                        for dirty_rect in dirty_rects {
+                            let buffer_rect = buffer_rect(*dirty_rect, self.scale);
+                            cairo_context.rectangle(buffer_rect.x as f64, buffer_rect.y as f64, buffer_rect.width as f64, buffer_rect.height as f64);
+                        }
+                        cairo_context.clip();
+                    },
+                    None => (),
+                }
+                theme.set_cairo_context(cairo_context, self.scale)?; 
                 cairo_context.save()?;
                 cairo_context.set_source_rgba(0.0, 0.0, 0.0, 0.0);
                 cairo_context.set_operator(Operator::Clear);
@@ -200,76 +521,425 @@ impl ClientWindow
                 Ok(())
         })
     }
+
+    /// Draws the window and attaches a buffer to the surface.
+    ///
+    /// Only the dirty rectangles are redrawn if the buffer can have a content of the last attached
+    /// buffer. Returns `true` if only the dirty rectangles are redrawn, otherwise `false`.
+    fn draw_and_attach(&mut self, window: &dyn Window, theme: &dyn Theme, dirty_rects: Option<&[Rect<i32>]>) -> Result<bool, ClientError>
+    {
+        let buffer_size = buffer_size(window, self.scale);
+        let buffer_idx = self.buffer_pool.next_buffer(buffer_size.width, buffer_size.height)?;
+        let dirty_rects = match dirty_rects {
+            Some(dirty_rects) if self.buffer_pool.copy_front_buffer(buffer_idx) => Some(dirty_rects),
+            _ => None,
+        };
+        let cairo_surface = self.buffer_pool.create_cairo_surface(buffer_idx)?;
+        match self.draw(&cairo_surface, window, theme, dirty_rects) {
+            Ok(()) => (),
+            Err(err) => println!("lwltk: {}", ClientError::Cairo(err)),
+        }
+        cairo_surface.flush();
+        self.buffer_pool.attach(buffer_idx, &self.surface)?;
+        Ok(dirty_rects.is_some())
+    }
+
+    /// Requests a frame callback for a next commit.
+    ///
+    /// The window isn't redrawn until the compositor notifies that it is a good time to draw a
+    /// next frame. The compositor doesn't notify hidden windows, so these windows aren't
+    /// redrawn.
+    fn request_frame(&self, client_context_fields: &ClientContextFields)
+    {
+        let frame_callback = self.surface.frame();
+        let is_frame_pending2 = self.is_frame_pending.clone();
+        let has_frame_done2 = client_context_fields.has_frame_done.clone();
+        frame_callback.quick_assign(move |_, event, _| {
+                match event {
+                    wl_callback::Event::Done { .. } => {
+                        is_frame_pending2.set(false);
+                        has_frame_done2.set(true);
+                    },
+                    _ => (),
+                }
+        });
+        self.is_frame_pending.set(true);
+    }
+
+    fn damage_dirty_rects(&self, dirty_rects: &[Rect<i32>])
+    {
+        for dirty_rect in dirty_rects {
+            if self.surface.as_ref().version() >= 4 {
+                let buffer_rect = buffer_rect(*dirty_rect, self.scale);
+                self.surface.damage_buffer(buffer_rect.x, buffer_rect.y, buffer_rect.width, buffer_rect.height);
+            } else {
+                self.surface.damage(dirty_rect.x, dirty_rect.y, dirty_rect.width, dirty_rect.height);
+            }
+        }
+    }
     
     pub(crate) fn assign(&self, client_context2: Rc<RefCell<ClientContext>>, window_context2: Arc<RwLock<WindowContext>>, queue_context2: Arc<Mutex<QueueContext>>, timer_tx: &mpsc::Sender<ThreadTimerCommand>)
     {
-        let timer_tx2 = timer_tx.clone();
-        self.shell_surface.quick_assign(move |shell_surface, event, _| {
-                match  event {
-                    wl_shell_surface::Event::Ping { serial, } => {
-                        let mut client_context_r = client_context2.borrow_mut();
-                        client_context_r.fields.serial = Some(serial);
-                        shell_surface.pong(serial);
-                    },
-                    wl_shell_surface::Event::Configure { edges, width, height, } => {
-                        let client_context_fields3 = client_context2.clone();
-                        let window_context3 = window_context2.clone();
-                        let queue_context3 = queue_context2.clone();
-                        let mut client_context_r = client_context2.borrow_mut();
-                        match window_context2.write() {
-                            Ok(mut window_context_g) => {
-                                match queue_context2.lock() {
-                                    Ok(mut queue_context_g) => {
-                                        match prepare_event_for_client_shell_surface_configure(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &shell_surface, edges, width, height) {
-                                            Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
-                                            None => (),
+        {
+            let client_context2 = client_context2.clone();
+            let window_context2 = window_context2.clone();
+            let queue_context2 = queue_context2.clone();
+            let timer_tx2 = timer_tx.clone();
+            self.surface.quick_assign(move |surface, event, _| {
+                    match event {
+                        wl_surface::Event::Enter { output, } => {
+                            let client_context3 = client_context2.clone();
+                            let window_context3 = window_context2.clone();
+                            let queue_context3 = queue_context2.clone();
+                            let mut client_context_r = client_context2.borrow_mut();
+                            client_context_r.add_output_for_surface(&surface, &output);
+                            match window_context2.write() {
+                                Ok(mut window_context_g) => client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context3, window_context3, queue_context3, &timer_tx2),
+                                Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                            }
+                        },
+                        wl_surface::Event::Leave { output, } => {
+                            let client_context3 = client_context2.clone();
+                            let window_context3 = window_context2.clone();
+                            let queue_context3 = queue_context2.clone();
+                            let mut client_context_r = client_context2.borrow_mut();
+                            client_context_r.remove_output_for_surface(&surface, &output);
+                            match window_context2.write() {
+                                Ok(mut window_context_g) => client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context3, window_context3, queue_context3, &timer_tx2),
+                                Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                            }
+                        },
+                        _ => (),
+                    }
+            });
+        }
+        match &self.fractional_scale {
+            Some(fractional_scale) => {
+                let client_context2 = client_context2.clone();
+                let window_context2 = window_context2.clone();
+                let queue_context2 = queue_context2.clone();
+                let timer_tx2 = timer_tx.clone();
+                fractional_scale.quick_assign(move |fractional_scale, event, _| {
+                        match event {
+                            wp_fractional_scale_v1::Event::PreferredScale { scale, } => {
+                                let client_context3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                client_context_r.set_preferred_scale_for_fractional_scale(&fractional_scale, scale);
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context3, window_context3, queue_context3, &timer_tx2),
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                            },
+                        }
+                });
+            },
+            None => (),
+        }
+        match &self.shell_surface {
+            Some(shell_surface) => {
+                let client_context2 = client_context2.clone();
+                let window_context2 = window_context2.clone();
+                let queue_context2 = queue_context2.clone();
+                let timer_tx2 = timer_tx.clone();
+                shell_surface.quick_assign(move |shell_surface, event, _| {
+                        match  event {
+                            wl_shell_surface::Event::Ping { serial, } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                client_context_r.fields.set_serial(serial);
+                                shell_surface.pong(serial);
+                            },
+                            wl_shell_surface::Event::Configure { edges, width, height, } => {
+                                let client_context_fields3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_shell_surface_configure(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &shell_surface, edges, width, height) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                        }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context_fields3, window_context3, queue_context3, &timer_tx2);
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                                client_context_r.update_cursor_surface(&timer_tx2);
+                                client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
+                            },
+                            wl_shell_surface::Event::PopupDone => {
+                                let client_context_fields3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_shell_surface_popup_done(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &shell_surface) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
                                         }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context_fields3, window_context3, queue_context3, &timer_tx2);
                                     },
-                                    Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
                                 }
-                                client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context_fields3, window_context3, queue_context3, &timer_tx2);
+                                client_context_r.update_cursor_surface(&timer_tx2);
+                                client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
-                            Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                            _ => (),
                         }
-                        client_context_r.update_cursor_surface(&timer_tx2);
-                        client_context_r.send_post_button_release(&timer_tx2);
-                        client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
-                    },
-                    wl_shell_surface::Event::PopupDone => {
-                        let client_context_fields3 = client_context2.clone();
-                        let window_context3 = window_context2.clone();
-                        let queue_context3 = queue_context2.clone();
-                        let mut client_context_r = client_context2.borrow_mut();
-                        match window_context2.write() {
-                            Ok(mut window_context_g) => {
-                                match queue_context2.lock() {
-                                    Ok(mut queue_context_g) => {
-                                        match prepare_event_for_client_shell_surface_popup_done(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &shell_surface) {
-                                            Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
-                                            None => (),
+                });
+            },
+            None => (),
+        }
+        match &self.xdg_surface {
+            Some(xdg_surface) => {
+                let client_context2 = client_context2.clone();
+                let window_context2 = window_context2.clone();
+                let queue_context2 = queue_context2.clone();
+                let timer_tx2 = timer_tx.clone();
+                xdg_surface.quick_assign(move |xdg_surface, event, _| {
+                        match event {
+                            xdg_surface::Event::Configure { serial, } => {
+                                let client_context_fields3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_xdg_surface_configure(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &xdg_surface, serial) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
                                         }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context_fields3, window_context3, queue_context3, &timer_tx2);
                                     },
-                                    Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
                                 }
-                                client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context_fields3, window_context3, queue_context3, &timer_tx2);
+                                client_context_r.update_cursor_surface(&timer_tx2);
+                                client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
                             },
-                            Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                            _ => (),
                         }
-                        client_context_r.update_cursor_surface(&timer_tx2);
-                        client_context_r.send_post_button_release(&timer_tx2);
-                        client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
-                    },
-                    _ => (),
-                }
-        });
+                });
+            },
+            None => (),
+        }
+        match &self.xdg_toplevel {
+            Some(xdg_toplevel) => {
+                let client_context2 = client_context2.clone();
+                let window_context2 = window_context2.clone();
+                let queue_context2 = queue_context2.clone();
+                let timer_tx2 = timer_tx.clone();
+                xdg_toplevel.quick_assign(move |xdg_toplevel, event, _| {
+                        match event {
+                            xdg_toplevel::Event::Configure { width, height, states, } => {
+                                let mut client_context_r = client_context2.borrow_mut();
+                                set_configure_for_client_xdg_toplevel(&mut client_context_r, &xdg_toplevel, width, height, states.as_slice());
+                            },
+                            xdg_toplevel::Event::Close => {
+                                let client_context_fields3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_xdg_toplevel_close(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &xdg_toplevel) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                        }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context_fields3, window_context3, queue_context3, &timer_tx2);
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                                client_context_r.update_cursor_surface(&timer_tx2);
+                                client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
+                            },
+                            _ => (),
+                        }
+                });
+            },
+            None => (),
+        }
+        match &self.xdg_toplevel_decoration {
+            Some(xdg_toplevel_decoration) => {
+                let client_context2 = client_context2.clone();
+                let window_context2 = window_context2.clone();
+                let queue_context2 = queue_context2.clone();
+                let timer_tx2 = timer_tx.clone();
+                xdg_toplevel_decoration.quick_assign(move |xdg_toplevel_decoration, event, _| {
+                        match event {
+                            zxdg_toplevel_decoration_v1::Event::Configure { mode, } => {
+                                let client_context_fields3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_xdg_toplevel_decoration_configure(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &xdg_toplevel_decoration, mode) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                        }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context_fields3, window_context3, queue_context3, &timer_tx2);
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                                client_context_r.update_cursor_surface(&timer_tx2);
+                                client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
+                            },
+                            _ => (),
+                        }
+                });
+            },
+            None => (),
+        }
+        match &self.layer_surface {
+            Some(layer_surface) => {
+                let client_context2 = client_context2.clone();
+                let window_context2 = window_context2.clone();
+                let queue_context2 = queue_context2.clone();
+                let timer_tx2 = timer_tx.clone();
+                layer_surface.quick_assign(move |layer_surface, event, _| {
+                        match event {
+                            zwlr_layer_surface_v1::Event::Configure { serial, width, height, } => {
+                                let client_context_fields3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_layer_surface_configure(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &layer_surface, serial, width, height) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                        }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context_fields3, window_context3, queue_context3, &timer_tx2);
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                                client_context_r.update_cursor_surface(&timer_tx2);
+                                client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
+                            },
+                            zwlr_layer_surface_v1::Event::Closed => {
+                                let client_context_fields3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_layer_surface_closed(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &layer_surface) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                        }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context_fields3, window_context3, queue_context3, &timer_tx2);
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                                client_context_r.update_cursor_surface(&timer_tx2);
+                                client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
+                            },
+                            _ => (),
+                        }
+                });
+            },
+            None => (),
+        }
+        match &self.xdg_popup {
+            Some(xdg_popup) => {
+                let timer_tx2 = timer_tx.clone();
+                xdg_popup.quick_assign(move |xdg_popup, event, _| {
+                        match event {
+                            xdg_popup::Event::PopupDone => {
+                                let client_context_fields3 = client_context2.clone();
+                                let window_context3 = window_context2.clone();
+                                let queue_context3 = queue_context2.clone();
+                                let mut client_context_r = client_context2.borrow_mut();
+                                match window_context2.write() {
+                                    Ok(mut window_context_g) => {
+                                        match queue_context2.lock() {
+                                            Ok(mut queue_context_g) => {
+                                                match prepare_event_for_client_xdg_popup_done(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &xdg_popup) {
+                                                    Some(event) => handle_event(&mut client_context_r, &mut *window_context_g, &mut *queue_context_g, &event),
+                                                    None => (),
+                                                }
+                                            },
+                                            Err(_) => eprintln!("lwltk: {}", ClientError::Mutex),
+                                        }
+                                        client_context_r.add_to_destroy_and_create_or_update_client_windows(&mut *window_context_g, client_context_fields3, window_context3, queue_context3, &timer_tx2);
+                                    },
+                                    Err(_) => eprintln!("lwltk: {}", ClientError::RwLock),
+                                }
+                                client_context_r.update_cursor_surface(&timer_tx2);
+                                client_context_r.send_post_button_release(&timer_tx2);
+                                client_context_r.stop_button_timer_and_touch_timer(&timer_tx2);
+                            },
+                            _ => (),
+                        }
+                });
+            },
+            None => (),
+        }
     }
     
+    fn set_minimize(&self, window: &mut dyn Window)
+    {
+        if window.is_minimized() {
+            match &self.xdg_toplevel {
+                Some(xdg_toplevel) => xdg_toplevel.set_minimized(),
+                None => (),
+            }
+            window.clear_minimize_flag();
+        }
+    }
+
     fn set_move(&self, client_context_fields: &mut ClientContextFields, window: &mut dyn Window) -> Result<(), ClientError>
     {
         if window.is_moved() {
-            match client_context_fields.serial {
-                Some(serial) => self.shell_surface._move(&client_context_fields.seat, serial),
-                None => return Err(ClientError::NoSerial),
+            match (client_context_fields.current_seat_and_serial(), &self.xdg_toplevel, &self.shell_surface) {
+                (Some((seat, serial)), Some(xdg_toplevel), _) => xdg_toplevel._move(seat, serial),
+                (Some((seat, serial)), None, Some(shell_surface)) => shell_surface._move(seat, serial),
+                (Some(_), None, None) => (),
+                (None, _, _) => return Err(ClientError::NoSerial),
             }
             window.clear_move_flag();
             client_context_fields.has_button_timer_stop = true;
@@ -282,20 +952,37 @@ impl ClientWindow
     {
         match window.resize_edges() {
             Some(edges) => {
-                let wayland_edges = match edges {
-                    ClientResize::None => wl_shell_surface::Resize::None,
-                    ClientResize::Top => wl_shell_surface::Resize::Top,
-                    ClientResize::Bottom => wl_shell_surface::Resize::Bottom,
-                    ClientResize::Left => wl_shell_surface::Resize::Left,
-                    ClientResize::Right => wl_shell_surface::Resize::Right,
-                    ClientResize::TopLeft => wl_shell_surface::Resize::TopLeft,
-                    ClientResize::TopRight => wl_shell_surface::Resize::TopRight,
-                    ClientResize::BottomLeft => wl_shell_surface::Resize::BottomLeft,
-                    ClientResize::BottomRight => wl_shell_surface::Resize::BottomRight,
-                };
-                match client_context_fields.serial {
-                    Some(serial) => self.shell_surface.resize(&client_context_fields.seat, serial, wayland_edges),
-                    None => return Err(ClientError::NoSerial),
+                match (client_context_fields.current_seat_and_serial(), &self.xdg_toplevel, &self.shell_surface) {
+                    (Some((seat, serial)), Some(xdg_toplevel), _) => {
+                        let xdg_edges = match edges {
+                            ClientResize::None => xdg_toplevel::ResizeEdge::None,
+                            ClientResize::Top => xdg_toplevel::ResizeEdge::Top,
+                            ClientResize::Bottom => xdg_toplevel::ResizeEdge::Bottom,
+                            ClientResize::Left => xdg_toplevel::ResizeEdge::Left,
+                            ClientResize::Right => xdg_toplevel::ResizeEdge::Right,
+                            ClientResize::TopLeft => xdg_toplevel::ResizeEdge::TopLeft,
+                            ClientResize::TopRight => xdg_toplevel::ResizeEdge::TopRight,
+                            ClientResize::BottomLeft => xdg_toplevel::ResizeEdge::BottomLeft,
+                            ClientResize::BottomRight => xdg_toplevel::ResizeEdge::BottomRight,
+                        };
+                        xdg_toplevel.resize(seat, serial, xdg_edges);
+                    },
+                    (Some((seat, serial)), None, Some(shell_surface)) => {
+                        let wayland_edges = match edges {
+                            ClientResize::None => wl_shell_surface::Resize::None,
+                            ClientResize::Top => wl_shell_surface::Resize::Top,
+                            ClientResize::Bottom => wl_shell_surface::Resize::Bottom,
+                            ClientResize::Left => wl_shell_surface::Resize::Left,
+                            ClientResize::Right => wl_shell_surface::Resize::Right,
+                            ClientResize::TopLeft => wl_shell_surface::Resize::TopLeft,
+                            ClientResize::TopRight => wl_shell_surface::Resize::TopRight,
+                            ClientResize::BottomLeft => wl_shell_surface::Resize::BottomLeft,
+                            ClientResize::BottomRight => wl_shell_surface::Resize::BottomRight,
+                        };
+                        shell_surface.resize(seat, serial, wayland_edges);
+                    },
+                    (Some(_), None, None) => (),
+                    (None, _, _) => return Err(ClientError::NoSerial),
                 }
                 window.clear_resize_edges();
                 client_context_fields.has_button_timer_stop = true;
@@ -306,94 +993,252 @@ impl ClientWindow
         Ok(())
     }
     
-    pub(crate) fn set(&mut self, client_context_fields: &mut ClientContextFields, window: &mut dyn Window, theme: &dyn Theme, parent_surface: Option<&wl_surface::WlSurface>) -> Result<(), ClientError>
+    fn update_layer_surface(&mut self, window: &dyn Window)
     {
-        let scale = client_context_fields.scale;
-        match (window.parent_index(), window.pos_in_parent(), parent_surface) {
-            (Some(parent_idx), Some(pos_in_parent), Some(parent_surface)) => {
-                if window.is_popup() {
-                    match client_context_fields.serial {
-                        Some(serial) => self.shell_surface.set_popup(&client_context_fields.seat, serial, parent_surface, pos_in_parent.x * scale, pos_in_parent.y * scale, wl_shell_surface::Transient::empty()),
-                        None => return Err(ClientError::NoSerial),
-                    }
-                } else {
-                    self.shell_surface.set_transient(parent_surface, pos_in_parent.x * scale, pos_in_parent.y * scale, wl_shell_surface::Transient::empty());
+        match &self.layer_surface {
+            Some(layer_surface) => {
+                match window.layer() {
+                    Some(layer) if window.layer() != self.layer && layer_surface.as_ref().version() >= 2 => layer_surface.set_layer(wlr_layer_for_client_layer(layer)),
+                    _ => (),
+                }
+                if window.anchor() != self.anchor {
+                    set_layer_surface_anchor(layer_surface, window.anchor());
+                    set_layer_surface_size(layer_surface, window);
+                }
+                if window.exclusive_zone() != self.exclusive_zone {
+                    layer_surface.set_exclusive_zone(window.exclusive_zone());
+                }
+                if window.layer_margin_edges() != self.layer_margin_edges {
+                    set_layer_surface_margin(layer_surface, window.layer_margin_edges());
+                }
+                if window.keyboard_interactivity() != self.keyboard_interactivity {
+                    set_layer_surface_keyboard_interactivity(layer_surface, window.keyboard_interactivity());
+                }
+            },
+            None => (),
+        }
+        self.layer = window.layer();
+        self.anchor = window.anchor();
+        self.exclusive_zone = window.exclusive_zone();
+        self.layer_margin_edges = window.layer_margin_edges();
+        self.keyboard_interactivity = window.keyboard_interactivity();
+    }
+
+    pub(crate) fn set(&mut self, client_context_fields: &mut ClientContextFields, window: &mut dyn Window, theme: &dyn Theme, parent: Option<&ClientWindowParent>) -> Result<(), ClientError>
+    {
+        match (window.parent_index(), window.pos_in_parent(), parent) {
+            (Some(parent_idx), Some(pos_in_parent), Some(parent)) => {
+                match (&self.xdg_popup, &self.xdg_toplevel, &self.shell_surface) {
+                    (Some(xdg_popup), _, _) => {
+                        if window.has_popup_grab() {
+                            match client_context_fields.current_seat_and_serial() {
+                                Some((seat, serial)) => xdg_popup.grab(seat, serial),
+                                None => return Err(ClientError::NoSerial),
+                            }
+                        }
+                    },
+                    (None, Some(xdg_toplevel), _) => {
+                        let parent_xdg_toplevel = parent.xdg_toplevel.as_ref().map(|t| &***t);
+                        xdg_toplevel.set_parent(parent_xdg_toplevel);
+                    },
+                    (None, None, Some(shell_surface)) => {
+                        if window.is_popup() && window.has_popup_grab() {
+                            match client_context_fields.current_seat_and_serial() {
+                                Some((seat, serial)) => shell_surface.set_popup(seat, serial, &parent.surface, pos_in_parent.x, pos_in_parent.y, wl_shell_surface::Transient::empty()),
+                                None => return Err(ClientError::NoSerial),
+                            }
+                        } else if window.is_popup() {
+                            shell_surface.set_transient(&parent.surface, pos_in_parent.x, pos_in_parent.y, wl_shell_surface::Transient::Inactive);
+                        } else {
+                            shell_surface.set_transient(&parent.surface, pos_in_parent.x, pos_in_parent.y, wl_shell_surface::Transient::empty());
+                        }
+                    },
+                    (None, None, None) => (),
                 }
                 self.parent_index = Some(parent_idx);
             },
             _ => {
-                if window.is_maximized() {
-                    self.shell_surface.set_maximized(None);
-                } else {
-                    self.shell_surface.set_toplevel();
+                let output = match window.fullscreen_output_name() {
+                    Some(output_name) => client_context_fields.output_for_name(output_name),
+                    None => None,
+                };
+                match (&self.xdg_toplevel, &self.shell_surface) {
+                    (Some(xdg_toplevel), _) => {
+                        if window.is_maximized() {
+                            xdg_toplevel.set_maximized();
+                        }
+                        if window.is_fullscreen() {
+                            xdg_toplevel.set_fullscreen(output.as_ref().map(|o| &***o));
+                        }
+                    },
+                    (None, Some(shell_surface)) => {
+                        if window.is_fullscreen() {
+                            shell_surface.set_fullscreen(wl_shell_surface::FullscreenMethod::Default, 0, output.as_ref().map(|o| &***o));
+                        } else if window.is_maximized() {
+                            shell_surface.set_maximized(None);
+                        } else {
+                            shell_surface.set_toplevel();
+                        }
+                    },
+                    (None, None) => (),
                 }
             },
         }
+        self.set_minimize(window);
         self.set_move(client_context_fields, window)?;
         self.set_resize(client_context_fields, window)?;
-        match self.draw(client_context_fields, window, theme) {
-            Ok(()) => (),
-            Err(err) => println!("lwltk: {}", ClientError::Cairo(err)),
+        self.set_scale(window);
+        if self.is_configured {
+            self.draw_and_attach(window, theme, None)?;
+            self.request_frame(client_context_fields);
+            self.surface.commit();
+            window.clear_change_flag();
+            window.clear_dirty_flag();
+            window.clear_descendant_dirty_rects();
+        } else {
+            // A buffer can't be attached before the first configure of the XDG surface.
+            self.surface.commit();
         }
-        self.surface.attach(Some(&self.buffer), 0, 0);
-        self.surface.commit();
-        window.clear_change_flag();
         Ok(())
     }
 
     pub(crate) fn update(&mut self, client_context_fields: &mut ClientContextFields, window: &mut dyn Window, theme: &dyn Theme) -> Result<(), ClientError>
     {
-        let scale = client_context_fields.scale;
+        let scale = scale_for_client_window(client_context_fields, &self.output_names, self.preferred_scale, self.viewport.is_some());
         let new_title = window.title().map(|s| String::from(s));
-        if self.title == new_title {
+        if self.title != new_title {
             self.title = new_title.clone();
             match new_title {
-                Some(new_title) => self.shell_surface.set_title(new_title),
+                Some(new_title) => {
+                    match (&self.xdg_toplevel, &self.shell_surface) {
+                        (Some(xdg_toplevel), _) => xdg_toplevel.set_title(new_title),
+                        (None, Some(shell_surface)) => shell_surface.set_title(new_title),
+                        (None, None) => (),
+                    }
+                },
                 None => (),
             }
         }
         if window.is_maximized() != self.is_maximized {
             if window.is_maximized() {
                 self.unmaximized_size = self.size;
-                self.shell_surface.set_maximized(None);
+                match (&self.xdg_toplevel, &self.shell_surface) {
+                    (Some(xdg_toplevel), _) => xdg_toplevel.set_maximized(),
+                    (None, Some(shell_surface)) => shell_surface.set_maximized(None),
+                    (None, None) => (),
+                }
             } else {
-                self.shell_surface.set_toplevel();
+                match (&self.xdg_toplevel, &self.shell_surface) {
+                    (Some(xdg_toplevel), _) => xdg_toplevel.unset_maximized(),
+                    (None, Some(shell_surface)) => shell_surface.set_toplevel(),
+                    (None, None) => (),
+                }
                 window.set_preferred_size(Size::new(Some(self.unmaximized_size.width), Some(self.unmaximized_size.height)));
             }
             self.is_maximized = window.is_maximized();
         }
+        if window.is_fullscreen() != self.is_fullscreen || (window.is_fullscreen() && window.fullscreen_output_name() != self.fullscreen_output_name) {
+            if window.is_fullscreen() {
+                if !self.is_fullscreen {
+                    self.unfullscreen_size = self.size;
+                }
+                let output = match window.fullscreen_output_name() {
+                    Some(output_name) => client_context_fields.output_for_name(output_name),
+                    None => None,
+                };
+                match (&self.xdg_toplevel, &self.shell_surface) {
+                    (Some(xdg_toplevel), _) => xdg_toplevel.set_fullscreen(output.as_ref().map(|o| &***o)),
+                    (None, Some(shell_surface)) => shell_surface.set_fullscreen(wl_shell_surface::FullscreenMethod::Default, 0, output.as_ref().map(|o| &***o)),
+                    (None, None) => (),
+                }
+            } else {
+                match (&self.xdg_toplevel, &self.shell_surface) {
+                    (Some(xdg_toplevel), _) => xdg_toplevel.unset_fullscreen(),
+                    (None, Some(shell_surface)) => {
+                        if window.is_maximized() {
+                            shell_surface.set_maximized(None);
+                        } else {
+                            shell_surface.set_toplevel();
+                        }
+                    },
+                    (None, None) => (),
+                }
+                window.set_preferred_size(Size::new(Some(self.unfullscreen_size.width), Some(self.unfullscreen_size.height)));
+            }
+            self.is_fullscreen = window.is_fullscreen();
+            self.fullscreen_output_name = window.fullscreen_output_name();
+        }
+        if window.decoration_preference() != self.decoration_preference {
+            match &self.xdg_toplevel_decoration {
+                Some(xdg_toplevel_decoration) => set_xdg_toplevel_decoration_mode(xdg_toplevel_decoration, window.decoration_preference()),
+                None => (),
+            }
+            self.decoration_preference = window.decoration_preference();
+        }
+        self.update_layer_surface(window);
+        self.set_minimize(window);
         self.set_move(client_context_fields, window)?;
         self.set_resize(client_context_fields, window)?;
-        if window.is_changed() {
+        if !self.is_configured {
+            return Ok(());
+        }
+        // A configure is acknowledged at once because the compositor may not send frame callbacks
+        // to a hidden or minimized window.
+        match (self.configure_serial, &self.xdg_surface, &self.layer_surface) {
+            (Some(serial), Some(xdg_surface), _) => {
+                xdg_surface.ack_configure(serial);
+                self.is_configure_acked = true;
+            },
+            (Some(serial), None, Some(layer_surface)) => {
+                layer_surface.ack_configure(serial);
+                self.is_configure_acked = true;
+            },
+            (_, _, _) => (),
+        }
+        self.configure_serial = None;
+        if self.is_frame_pending.get() {
+            // Changes are merged until the compositor notifies about a next frame.
+            return Ok(());
+        }
+        let is_acked = self.is_configure_acked;
+        self.is_configure_acked = false;
+        let is_rescaled = self.scale != scale;
+        if window.is_changed() || is_acked || is_rescaled {
             match update_window_size_and_window_pos(window, theme) {
                 Ok(()) => {
+                    if self.size != window.size() || is_rescaled {
+                        self.scale = scale;
+                        self.set_scale(window);
+                    }
                     if self.size != window.size() {
-                        let (buffer, file, mmap, cairo_surface) = create_buffer(client_context_fields, window)?;
-                        self.buffer = buffer;
-                        self.mmap = mmap;
-                        self.cairo_surface = cairo_surface;
-                        match self.draw(client_context_fields, window, theme) {
-                            Ok(()) => (),
-                            Err(err) => println!("lwltk: {}", ClientError::Cairo(err)),
+                        match &self.layer_surface {
+                            Some(layer_surface) => set_layer_surface_size(layer_surface, window),
+                            None => (),
                         }
-                        self.surface.attach(Some(&self.buffer), 0, 0);
-                        self.surface.damage(0, 0, window.width() * scale, window.height() * scale);
-                        self.surface.commit();
-                        self.file = file;
-                    } else {
-                        match self.draw(client_context_fields, window, theme) {
-                            Ok(()) => (),
-                            Err(err) => println!("lwltk: {}", ClientError::Cairo(err)),
-                        }
-                        self.surface.attach(Some(&self.buffer), 0, 0);
-                        self.surface.damage(0, 0, window.width() * scale, window.height() * scale);
-                        self.surface.commit();
                     }
+                    self.draw_and_attach(window, theme, None)?;
+                    self.surface.damage(0, 0, window.width(), window.height());
+                    self.request_frame(client_context_fields);
+                    self.surface.commit();
                     self.size = window.size();
                 },
                 Err(err) => return Err(ClientError::Cairo(err)),
             }
             window.clear_change_flag();
+            window.clear_dirty_flag();
+            window.clear_descendant_dirty_rects();
+        } else if window.is_dirty() {
+            let mut dirty_rects: Vec<Rect<i32>> = Vec::new();
+            window.add_descendant_dirty_rects(&mut dirty_rects);
+            if self.draw_and_attach(window, theme, Some(dirty_rects.as_slice()))? {
+                self.damage_dirty_rects(dirty_rects.as_slice());
+            } else {
+                self.surface.damage(0, 0, window.width(), window.height());
+            }
+            self.request_frame(client_context_fields);
+            self.surface.commit();
+            window.clear_dirty_flag();
+            window.clear_descendant_dirty_rects();
         }
         Ok(())
     }
@@ -406,7 +1251,10 @@ impl ClientWindow
 
     pub(crate) fn destroy(&self)
     {
-        self.buffer.destroy();
+        self.buffer_pool.destroy();
+        destroy_xdg_surface(self.xdg_surface.as_ref(), self.xdg_toplevel.as_ref(), self.xdg_popup.as_ref(), self.xdg_toplevel_decoration.as_ref());
+        destroy_layer_surface(self.layer_surface.as_ref());
+        destroy_viewport_and_fractional_scale(self.viewport.as_ref(), self.fractional_scale.as_ref());
         self.surface.destroy();
     }
 }
@@ -272,6 +272,72 @@ pub trait Container: Draw + CallOn
             }
         }
     }
+
+    /// Sets the reference-counting pointers to the dirty flag for the descendant widgets.
+    fn set_descendant_dirty_flag_arcs(&mut self, flag_arc: Arc<AtomicBool>)
+    {
+        let mut prev_idx_pair = None;
+        loop {
+            match self.next(prev_idx_pair) {
+                Some(idx_pair) => {
+                    match self.dyn_widget_mut_for_index_pair(idx_pair) {
+                        Some(widget) => {
+                            widget.set_only_dirty_flag_arc(flag_arc.clone());
+                            widget.set_descendant_dirty_flag_arcs(flag_arc.clone());
+                        },
+                        None => (),
+                    }
+                    prev_idx_pair = Some(idx_pair);
+                },
+                None => break,
+            }
+        }
+    }
+
+    /// Adds the dirty rectangles of the descendant widgets to the vector.
+    fn add_descendant_dirty_rects(&self, rects: &mut Vec<Rect<i32>>)
+    {
+        let mut prev_idx_pair = None;
+        loop {
+            match self.next(prev_idx_pair) {
+                Some(idx_pair) => {
+                    match self.dyn_widget_for_index_pair(idx_pair) {
+                        Some(widget) => {
+                            match widget.dirty_rect() {
+                                Some(rect) => rects.push(rect),
+                                None => (),
+                            }
+                            widget.add_descendant_dirty_rects(rects);
+                        },
+                        None => (),
+                    }
+                    prev_idx_pair = Some(idx_pair);
+                },
+                None => break,
+            }
+        }
+    }
+
+    /// Clears the dirty rectangles of the descendant widgets.
+    fn clear_descendant_dirty_rects(&mut self)
+    {
+        let mut prev_idx_pair = None;
+        loop {
+            match self.next(prev_idx_pair) {
+                Some(idx_pair) => {
+                    match self.dyn_widget_mut_for_index_pair(idx_pair) {
+                        Some(widget) => {
+                            widget.clear_dirty_rect();
+                            widget.clear_descendant_dirty_rects();
+                        },
+                        None => (),
+                    }
+                    prev_idx_pair = Some(idx_pair);
+                },
+                None => break,
+            }
+        }
+    }
 }
 
 /// A reversed iterator that iterates over pairs of widget indices.
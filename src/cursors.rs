@@ -8,8 +8,90 @@
 //! A module of cursors.
 //!
 //! The module of cursors contains a cursor enumeration.
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+use crate::types::*;
+
+struct CustomCursorData
+{
+    size: Size<i32>,
+    hotspot: Pos<i32>,
+    drawing_fun: Box<dyn Fn(&CairoContext) -> Result<(), CairoError> + Send + Sync + 'static>,
+}
+
+/// A structure of custom cursor.
+///
+/// The custom cursor has an image that is drawn by a drawing function and a hotspot of this image.
+/// The drawing function draws the image in logical pixels because a cairo context is scaled by
+/// a scale of outputs. Clones of the custom cursor share the image, so two custom cursors are
+/// equal only if one custom cursor is a clone of other custom cursor.
+#[derive(Clone)]
+pub struct CustomCursor
+{
+    data: Arc<CustomCursorData>,
+}
+
+impl CustomCursor
+{
+    /// Creates a custom cursor with the image size, the hotspot, and the drawing function.
+    pub fn new<F>(size: Size<i32>, hotspot: Pos<i32>, f: F) -> Self
+        where F: Fn(&CairoContext) -> Result<(), CairoError> + Send + Sync + 'static
+    { CustomCursor { data: Arc::new(CustomCursorData { size, hotspot, drawing_fun: Box::new(f), }), } }
+
+    /// Returns the size of the image.
+    pub fn size(&self) -> Size<i32>
+    { self.data.size }
+
+    /// Returns the hotspot of the image.
+    pub fn hotspot(&self) -> Pos<i32>
+    { self.data.hotspot }
+
+    /// Draws the image.
+    pub fn draw(&self, cairo_context: &CairoContext) -> Result<(), CairoError>
+    { (self.data.drawing_fun)(cairo_context) }
+
+    fn addr(&self) -> usize
+    { Arc::as_ptr(&self.data) as usize }
+}
+
+impl PartialEq for CustomCursor
+{
+    fn eq(&self, other: &Self) -> bool
+    { Arc::ptr_eq(&self.data, &other.data) }
+}
+
+impl Eq for CustomCursor
+{}
+
+impl PartialOrd for CustomCursor
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    { Some(self.cmp(other)) }
+}
+
+impl Ord for CustomCursor
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    { self.addr().cmp(&other.addr()) }
+}
+
+impl Hash for CustomCursor
+{
+    fn hash<H: Hasher>(&self, state: &mut H)
+    { self.addr().hash(state); }
+}
+
+impl fmt::Debug for CustomCursor
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    { f.debug_struct("CustomCursor").field("size", &self.data.size).field("hotspot", &self.data.hotspot).finish() }
+}
+
 /// A cursor enumeration.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum Cursor
 {
     /// A default cursor.
@@ -44,4 +126,25 @@ pub enum Cursor
     HDoubleArrow,
     /// A cursor of vertical double arrow.
     VDoubleArrow,
+    /// A cursor of grab.
+    Grab,
+    /// A cursor of grabbing.
+    Grabbing,
+    /// A cursor of not allowed action.
+    NotAllowed,
+    /// A cursor of move.
+    Move,
+    /// A cursor of zoom in.
+    ZoomIn,
+    /// A cursor of zoom out.
+    ZoomOut,
+    /// A help cursor.
+    Help,
+    /// A progress cursor.
+    ///
+    /// The progress cursor informs that a program is busy but the user can still interact with
+    /// the program.
+    Progress,
+    /// A custom cursor.
+    Custom(CustomCursor),
 }
@@ -7,10 +7,12 @@
 //
 use crate::client_context::*;
 use crate::client_error::*;
+use crate::client_tooltip::*;
 use crate::event_queue::*;
 use crate::events::*;
 use crate::queue_context::*;
 use crate::window_context::*;
+use crate::windows::*;
 
 fn handle_only_event(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, event: &Event) -> Option<Event>
 {
@@ -56,10 +58,42 @@ fn handle_only_event(client_context: &mut ClientContext, window_context: &mut Wi
     }
 }
 
+fn notify_window_about_click(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, event: &Event)
+{
+    match event {
+        Event::Click | Event::DoubleClick | Event::LongClick => {
+            let window_idx = match &queue_context.current_call_on_path {
+                Some(CallOnPath::Widget(abs_widget_path)) => {
+                    for idx_pair in abs_widget_path.widget_index_pairs().rev() {
+                        queue_context.current_descendant_index_pairs.push(idx_pair);
+                    }
+                    Some(abs_widget_path.window_index())
+                },
+                _ => None,
+            };
+            match window_idx {
+                Some(window_idx) => {
+                    let is_notified = match window_context.window_container.dyn_window(window_idx) {
+                        Some(window) => window.is_notified_about_clicks(),
+                        None => false,
+                    };
+                    if is_notified {
+                        queue_context.current_call_on_path = Some(CallOnPath::Window(window_idx));
+                        handle_only_event(client_context, window_context, queue_context, event);
+                    }
+                },
+                None => (),
+            }
+        },
+        _ => (),
+    }
+}
+
 fn handle_only_event_with_propagation(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, event: &Event)
 {
     queue_context.current_descendant_index_pairs.clear();
     let mut new_event = handle_only_event(client_context, window_context, queue_context, event);
+    let mut is_handled_by_widget = false;
     loop {
         match &new_event {
             Some(tmp_event) => {
@@ -78,9 +112,18 @@ fn handle_only_event_with_propagation(client_context: &mut ClientContext, window
                 }
                 new_event = handle_only_event(client_context, window_context, queue_context, tmp_event);
             },
-            None => break,
+            None => {
+                is_handled_by_widget = match &queue_context.current_call_on_path {
+                    Some(CallOnPath::Widget(_)) => true,
+                    _ => false,
+                };
+                break;
+            },
         }
     }
+    if is_handled_by_widget {
+        notify_window_about_click(client_context, window_context, queue_context, event);
+    }
     queue_context.current_call_on_path = None;
     queue_context.current_descendant_index_pairs.clear();
 }
@@ -98,15 +141,25 @@ pub(crate) fn handle_events_and_callbacks_from_queues(client_context: &mut Clien
         loop {
             match queue_context.event_queue.pop() {
                 Some(event_pair) => {
-                    queue_context.current_call_on_path = Some(event_pair.call_on_path);
-                    handle_only_event_with_propagation(client_context, window_context, queue_context, &event_pair.event);
+                    let is_context_menu = match &event_pair.event {
+                        Event::PopupClick => open_context_menu(window_context, &event_pair.call_on_path, None).is_some(),
+                        _ => false,
+                    };
+                    if !is_context_menu {
+                        client_context.set_current_seat_id(queue_context, event_pair.seat_id);
+                        queue_context.current_call_on_path = Some(event_pair.call_on_path);
+                        handle_only_event_with_propagation(client_context, window_context, queue_context, &event_pair.event);
+                    }
                 },
                 None => break,
             }
         }
         loop {
             match queue_context.callback_queue.pop() {
-                Some(mut callback) => handle_only_callback(client_context, window_context, queue_context, &mut *callback),
+                Some((seat_id, mut callback)) => {
+                    client_context.set_current_seat_id(queue_context, seat_id);
+                    handle_only_callback(client_context, window_context, queue_context, &mut *callback);
+                },
                 None => break,
             }
         }
@@ -115,8 +168,47 @@ pub(crate) fn handle_events_and_callbacks_from_queues(client_context: &mut Clien
     window_context.current_pos = None;
 }
 
+/// Returns `true` if the event is an input event for a window that is blocked by a modal window,
+/// otherwise `false`.
+///
+/// Events that only release a pointer button or a touch or leave the window aren't blocked, so
+/// states of widgets are reset for these events.
+fn is_blocked_event(window_context: &WindowContext, queue_context: &QueueContext, event: &Event) -> bool
+{
+    let is_input_event = match event {
+        Event::Client(ClientEvent::PointerButton(_, _, ClientState::Pressed)) => true,
+        Event::Client(ClientEvent::PointerAxis(_, _, _, _, _)) => true,
+        Event::Client(ClientEvent::PointerSwipeBegin(_, _)) => true,
+        Event::Client(ClientEvent::PointerSwipeUpdate(_, _, _)) => true,
+        Event::Client(ClientEvent::PointerPinchBegin(_, _)) => true,
+        Event::Client(ClientEvent::PointerPinchUpdate(_, _, _, _, _)) => true,
+        Event::Client(ClientEvent::PointerHoldBegin(_, _)) => true,
+        Event::Client(ClientEvent::KeyboardKey(_, _, _, _)) => true,
+        Event::Client(ClientEvent::TouchDown(_, _, _)) => true,
+        Event::Client(ClientEvent::TextInputDone(_, _, _, _)) => true,
+        Event::Client(ClientEvent::RepeatedButton) => true,
+        Event::Client(ClientEvent::RepeatedKey(_, _)) => true,
+        Event::Client(ClientEvent::RepeatedTouch(_)) => true,
+        _ => false,
+    };
+    if is_input_event {
+        match &queue_context.current_call_on_path {
+            Some(call_on_path) => window_context.window_container.is_blocked(call_on_path.window_index()),
+            None => false,
+        }
+    } else {
+        false
+    }
+}
+
 pub(crate) fn handle_event(client_context: &mut ClientContext, window_context: &mut WindowContext, queue_context: &mut QueueContext, event: &Event)
 {
-    handle_only_event_with_propagation(client_context, window_context, queue_context, event);
+    update_client_tooltip_before_event(client_context, window_context, queue_context, event);
+    if !is_blocked_event(window_context, queue_context, event) {
+        handle_only_event_with_propagation(client_context, window_context, queue_context, event);
+    } else {
+        queue_context.current_call_on_path = None;
+    }
     handle_events_and_callbacks_from_queues(client_context, window_context, queue_context);
+    update_client_tooltip_after_event(client_context, window_context, event);
 }
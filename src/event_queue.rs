@@ -55,13 +55,22 @@ pub struct EventPair
     pub call_on_path: CallOnPath,
     /// The event.
     pub event: Event,
+    /// The seat identifier of the event or `None`.
+    ///
+    /// The seat identifier is restored as the seat identifier of the current event when the event
+    /// is handled.
+    pub seat_id: Option<SeatId>,
 }
 
 impl EventPair
 {
-    /// Creates an event pair.
+    /// Creates an event pair without a seat identifier.
     pub fn new(call_on_path: CallOnPath, event: Event) -> EventPair
-    { EventPair { call_on_path, event, } }
+    { EventPair { call_on_path, event, seat_id: None, } }
+
+    /// Creates an event pair with the seat identifier.
+    pub fn new_with_seat_id(call_on_path: CallOnPath, event: Event, seat_id: Option<SeatId>) -> EventPair
+    { EventPair { call_on_path, event, seat_id, } }
 }
 
 /// A structure of event queue.
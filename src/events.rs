@@ -12,6 +12,13 @@
 //! second event enumeration has events which directly called in a Wayland event or a system event.
 //! The first event enumeration is an [`Event`] enumeration and has a variant that has a client
 //! event. The second event enumeration is a [`ClientEvent`] enumeration.
+use std::fmt;
+use std::ops::BitAnd;
+use std::ops::BitAndAssign;
+use std::ops::BitOr;
+use std::ops::BitOrAssign;
+use std::ops::Sub;
+use std::ops::SubAssign;
 use crate::keys::*;
 use crate::types::*;
 
@@ -141,6 +148,55 @@ pub enum Event
     ///
     /// Th maximization event is called when an user selects a window maximization.
     Maximize,
+    /// A minimization event.
+    ///
+    /// The minimization event is called when an user selects a window minimization.
+    Minimize,
+    /// An event of drag enter.
+    ///
+    /// The event of drag enter is called when an user drags data over a widget or a window. The
+    /// following fields are:
+    /// - MIME types of the dragged data
+    /// - a position
+    DragEnter(Vec<String>, Pos<f64>),
+    /// An event of drag motion.
+    ///
+    /// The event of drag motion is called when an user moves dragged data over a widget or a
+    /// window. The field is a position.
+    DragMotion(Pos<f64>),
+    /// An event of drag leave.
+    ///
+    /// The event of drag leave is called when dragged data leave a window.
+    DragLeave,
+    /// A drop event.
+    ///
+    /// The drop event is called when an user drops data on a widget or a window. The following
+    /// fields are:
+    /// - MIME types of the dropped data
+    /// - a position
+    Drop(Vec<String>, Pos<f64>),
+    /// A preedit event.
+    ///
+    /// The preedit event is called when an input method changes the preedit text. The preedit text
+    /// should be drawn underlined at the cursor position of a widget. The following fields are:
+    /// - a preedit text
+    /// - a start index and an end index of a preedit cursor or `None` if the preedit cursor is
+    ///   hidden
+    ///
+    /// The preedit text is empty if the preedit text is removed.
+    Preedit(String, Option<(usize, usize)>),
+    /// An event of text commit.
+    ///
+    /// The event of text commit is called when an input method commits the text. The field is the
+    /// committed text that should be inserted at the cursor position of a widget.
+    TextCommit(String),
+    /// An event of deletion of surrounding text.
+    ///
+    /// The event of deletion of surrounding text is called when an input method deletes the text
+    /// around the cursor of a widget. The following fields are:
+    /// - a length of the deleted text before the cursor in bytes
+    /// - a length of the deleted text after the cursor in bytes
+    DeleteSurroundingText(usize, usize),
     /// An event of client event.
     Client(ClientEvent),
 }
@@ -158,9 +214,21 @@ pub enum ClientEvent
     /// The following fields are:
     /// - a client resize
     /// - a size
-    ShellSurfaceConfigure(ClientResize, Size<i32>),
+    /// - client toplevel states
+    ///
+    /// A width or a height of the size is zero if a window can decide its own width or height.
+    ShellSurfaceConfigure(ClientResize, Size<i32>, ClientToplevelStates),
     /// An event of shell surface popup done.
     ShellSurfacePopupDone,
+    /// An event of shell surface close.
+    ///
+    /// This event is called if a compositor wants to close a window or if a compositor closes a
+    /// layer window.
+    ShellSurfaceClose,
+    /// An event of toplevel decoration configure.
+    ///
+    /// The field is a client decoration mode that is negotiated with a compositor.
+    ToplevelDecorationConfigure(ClientDecorationMode),
     /// An event of pointer enter.
     ///
     /// The field is a position.
@@ -186,7 +254,73 @@ pub enum ClientEvent
     /// - a time
     /// - a client axis
     /// - a value
-    PointerAxis(u32, ClientAxis, f64),
+    /// - a client axis source if it is known
+    /// - a number of discrete steps if the axis source is a scroll wheel
+    ///
+    /// Axis events of one pointer frame are sent together, so a vertical scroll and a horizontal
+    /// scroll of one frame are two events. The number of discrete steps is taken from the
+    /// `axis_discrete` event because the `axis_value120` event of version 8 of `wl_seat` isn't
+    /// supported, so a high-resolution scroll wheel reports whole steps and movements of the wheel
+    /// that are smaller than one step can have no discrete steps.
+    PointerAxis(u32, ClientAxis, f64, Option<ClientAxisSource>, Option<i32>),
+    /// An event of pointer axis stop.
+    ///
+    /// This event is sent if fingers are lifted from a touchpad. The following fields are:
+    /// - a time
+    /// - a client axis
+    PointerAxisStop(u32, ClientAxis),
+    /// An event of pointer swipe begin.
+    ///
+    /// The following fields are:
+    /// - a time
+    /// - a number of fingers
+    PointerSwipeBegin(u32, u32),
+    /// An event of pointer swipe update.
+    ///
+    /// The following fields are:
+    /// - a time
+    /// - a X delta
+    /// - a Y delta
+    PointerSwipeUpdate(u32, f64, f64),
+    /// An event of pointer swipe end.
+    ///
+    /// The following fields are:
+    /// - a time
+    /// - `true` if the swipe is cancelled, otherwise `false`
+    PointerSwipeEnd(u32, bool),
+    /// An event of pointer pinch begin.
+    ///
+    /// The following fields are:
+    /// - a time
+    /// - a number of fingers
+    PointerPinchBegin(u32, u32),
+    /// An event of pointer pinch update.
+    ///
+    /// The following fields are:
+    /// - a time
+    /// - a X delta
+    /// - a Y delta
+    /// - a scale relative to the pinch begin
+    /// - a rotation in degrees relative to the previous event
+    PointerPinchUpdate(u32, f64, f64, f64, f64),
+    /// An event of pointer pinch end.
+    ///
+    /// The following fields are:
+    /// - a time
+    /// - `true` if the pinch is cancelled, otherwise `false`
+    PointerPinchEnd(u32, bool),
+    /// An event of pointer hold begin.
+    ///
+    /// The following fields are:
+    /// - a time
+    /// - a number of fingers
+    PointerHoldBegin(u32, u32),
+    /// An event of pointer hold end.
+    ///
+    /// The following fields are:
+    /// - a time
+    /// - `true` if the hold is cancelled, otherwise `false`
+    PointerHoldEnd(u32, bool),
     /// An event of keyboard enter.
     KeyboardEnter,
     /// An event of keyboard leave.
@@ -226,6 +360,14 @@ pub enum ClientEvent
     /// - a touch identifier
     /// - a position
     TouchMotion(u32, i32, Pos<f64>),
+    /// An event of text input done.
+    ///
+    /// The following fields are:
+    /// - lengths of the deleted text before and after the cursor or `None`
+    /// - a committed text or `None`
+    /// - a preedit text
+    /// - a start index and an end index of a preedit cursor or `None`
+    TextInputDone(Option<(usize, usize)>, Option<String>, String, Option<(usize, usize)>),
     /// An event of repeated button.
     RepeatedButton,
     /// An event of repeated key.
@@ -351,6 +493,534 @@ pub enum ClientResize
     BottomRight,
 }
 
+/// A structure of client toplevel states.
+///
+/// The client toplevel states are states of toplevel window which are sent by a compositor, for
+/// example maximized and activated.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ClientToplevelStates(u32);
+
+impl ClientToplevelStates
+{
+    /// An empty set of client toplevel states.
+    pub const EMPTY: ClientToplevelStates = ClientToplevelStates(0);
+    /// A maximized state.
+    pub const MAXIMIZED: ClientToplevelStates = ClientToplevelStates(1 << 0);
+    /// A fullscreen state.
+    pub const FULLSCREEN: ClientToplevelStates = ClientToplevelStates(1 << 1);
+    /// A resizing state.
+    pub const RESIZING: ClientToplevelStates = ClientToplevelStates(1 << 2);
+    /// An activated state.
+    pub const ACTIVATED: ClientToplevelStates = ClientToplevelStates(1 << 3);
+    /// A tiled state for a left edge.
+    pub const TILED_LEFT: ClientToplevelStates = ClientToplevelStates(1 << 4);
+    /// A tiled state for a right edge.
+    pub const TILED_RIGHT: ClientToplevelStates = ClientToplevelStates(1 << 5);
+    /// A tiled state for a top edge.
+    pub const TILED_TOP: ClientToplevelStates = ClientToplevelStates(1 << 6);
+    /// A tiled state for a bottom edge.
+    pub const TILED_BOTTOM: ClientToplevelStates = ClientToplevelStates(1 << 7);
+    
+    /// Returns an empty set of client toplevel states.
+    pub const fn empty() -> Self
+    { ClientToplevelStates(0) }
+
+    /// Returns a full set of client toplevel states.
+    pub const fn all() -> Self
+    { ClientToplevelStates(255) }
+    
+    /// Returns `true` if set of client toplevel states is empty, otherwise `false`.
+    pub const fn is_empty(&self) -> bool
+    { self.0 == 0 }
+
+    /// Returns `true` if an intersection of two sets of client toplevel states isn't empty,
+    /// otherwise `false`.
+    pub const fn intersects(&self, other: Self) -> bool
+    { self.0 & other.0 != 0 }
+    
+    /// Returns `true` if the set of client toplevel states contains the client toplevel states,
+    /// otherwise `false`.
+    pub const fn contains(&self, other: Self) -> bool
+    { (self.0 & other.0) == other.0 }
+
+    /// Inserts the client toplevel states.
+    pub fn insert(&mut self, other: Self)
+    { self.0 |= other.0; }
+
+    /// Removes the client toplevel states.
+    pub fn remove(&mut self, other: Self)
+    { self.0 &= !other.0; }
+
+    /// Inserts or removes the client toplevel states for the flag.
+    pub fn set(&mut self, other: Self, b: bool)
+    {
+        if b {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+}
+
+impl BitAnd for ClientToplevelStates
+{
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output
+    { ClientToplevelStates(self.0 & rhs.0) }
+}
+
+impl BitAndAssign for ClientToplevelStates
+{
+    fn bitand_assign(&mut self, rhs: Self)
+    { self.0 &= rhs.0; }
+}
+
+impl BitOr for ClientToplevelStates
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output
+    { ClientToplevelStates(self.0 | rhs.0) }
+}
+
+impl BitOrAssign for ClientToplevelStates
+{
+    fn bitor_assign(&mut self, rhs: Self)
+    { self.0 |= rhs.0; }
+}
+
+impl Sub for ClientToplevelStates
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output
+    { ClientToplevelStates(self.0 & !rhs.0) }
+}
+
+impl SubAssign for ClientToplevelStates
+{
+    fn sub_assign(&mut self, rhs: Self)
+    { self.0 &= !rhs.0; }
+}
+
+impl fmt::Debug for ClientToplevelStates
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        if *self == Self::EMPTY {
+            write!(f, "EMPTY")?;
+        } else {
+            let names = [
+                (Self::MAXIMIZED, "MAXIMIZED"),
+                (Self::FULLSCREEN, "FULLSCREEN"),
+                (Self::RESIZING, "RESIZING"),
+                (Self::ACTIVATED, "ACTIVATED"),
+                (Self::TILED_LEFT, "TILED_LEFT"),
+                (Self::TILED_RIGHT, "TILED_RIGHT"),
+                (Self::TILED_TOP, "TILED_TOP"),
+                (Self::TILED_BOTTOM, "TILED_BOTTOM")
+            ];
+            let mut is_first = true;
+            for (states, name) in &names {
+                if self.contains(*states) {
+                    if !is_first {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{}", name)?;
+                    is_first = false;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An enumeration of client decoration mode.
+///
+/// The client decoration mode determines whether a window is decorated by itself or by a
+/// compositor.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ClientDecorationMode
+{
+    /// A window draws its own decorations.
+    Client,
+    /// A compositor draws decorations of a window.
+    Server,
+}
+
+/// An enumeration of client decoration preference.
+///
+/// The client decoration preference is sent to a compositor if the compositor supports
+/// negotiation of decorations.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ClientDecorationPreference
+{
+    /// A window prefers to draw its own decorations.
+    Client,
+    /// A window prefers decorations which are drawn by a compositor.
+    Server,
+    /// A window follows a decoration mode of a compositor.
+    Compositor,
+}
+
+/// An enumeration of client layer.
+///
+/// The client layer is a layer of layer shell that determines a stack order of a layer window.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ClientLayer
+{
+    /// A layer below all windows, for example a wallpaper.
+    Background,
+    /// A layer below toplevel windows, for example a desktop.
+    Bottom,
+    /// A layer above toplevel windows, for example a panel.
+    Top,
+    /// A layer above all windows, for example an on-screen display.
+    Overlay,
+}
+
+/// A structure of client anchor.
+///
+/// The client anchor is a set of output edges to which a layer window is anchored. If a layer
+/// window is anchored to two opposite edges, the layer window is stretched between these edges.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ClientAnchor(u32);
+
+impl ClientAnchor
+{
+    /// An empty set of client anchor.
+    pub const EMPTY: ClientAnchor = ClientAnchor(0);
+    /// A top edge.
+    pub const TOP: ClientAnchor = ClientAnchor(1 << 0);
+    /// A bottom edge.
+    pub const BOTTOM: ClientAnchor = ClientAnchor(1 << 1);
+    /// A left edge.
+    pub const LEFT: ClientAnchor = ClientAnchor(1 << 2);
+    /// A right edge.
+    pub const RIGHT: ClientAnchor = ClientAnchor(1 << 3);
+
+    /// Returns an empty set of client anchor.
+    pub const fn empty() -> Self
+    { ClientAnchor(0) }
+
+    /// Returns a full set of client anchor.
+    pub const fn all() -> Self
+    { ClientAnchor(15) }
+
+    /// Returns the bits of the client anchor.
+    pub const fn bits(&self) -> u32
+    { self.0 }
+
+    /// Returns `true` if set of client anchor is empty, otherwise `false`.
+    pub const fn is_empty(&self) -> bool
+    { self.0 == 0 }
+
+    /// Returns `true` if an intersection of two sets of client anchor isn't empty, otherwise
+    /// `false`.
+    pub const fn intersects(&self, other: Self) -> bool
+    { self.0 & other.0 != 0 }
+
+    /// Returns `true` if the set of client anchor contains the client anchor, otherwise `false`.
+    pub const fn contains(&self, other: Self) -> bool
+    { (self.0 & other.0) == other.0 }
+
+    /// Inserts the client anchor.
+    pub fn insert(&mut self, other: Self)
+    { self.0 |= other.0; }
+
+    /// Removes the client anchor.
+    pub fn remove(&mut self, other: Self)
+    { self.0 &= !other.0; }
+
+    /// Inserts or removes the client anchor for the flag.
+    pub fn set(&mut self, other: Self, b: bool)
+    {
+        if b {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+}
+
+impl BitAnd for ClientAnchor
+{
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output
+    { ClientAnchor(self.0 & rhs.0) }
+}
+
+impl BitAndAssign for ClientAnchor
+{
+    fn bitand_assign(&mut self, rhs: Self)
+    { self.0 &= rhs.0; }
+}
+
+impl BitOr for ClientAnchor
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output
+    { ClientAnchor(self.0 | rhs.0) }
+}
+
+impl BitOrAssign for ClientAnchor
+{
+    fn bitor_assign(&mut self, rhs: Self)
+    { self.0 |= rhs.0; }
+}
+
+impl Sub for ClientAnchor
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output
+    { ClientAnchor(self.0 & !rhs.0) }
+}
+
+impl SubAssign for ClientAnchor
+{
+    fn sub_assign(&mut self, rhs: Self)
+    { self.0 &= !rhs.0; }
+}
+
+impl fmt::Debug for ClientAnchor
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        if *self == Self::EMPTY {
+            write!(f, "EMPTY")?;
+        } else {
+            let names = [
+                (Self::TOP, "TOP"),
+                (Self::BOTTOM, "BOTTOM"),
+                (Self::LEFT, "LEFT"),
+                (Self::RIGHT, "RIGHT")
+            ];
+            let mut is_first = true;
+            for (anchor, name) in &names {
+                if self.contains(*anchor) {
+                    if !is_first {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{}", name)?;
+                    is_first = false;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An enumeration of client keyboard interactivity.
+///
+/// The client keyboard interactivity determines whether a layer window receives keyboard events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ClientKeyboardInteractivity
+{
+    /// A layer window doesn't receive keyboard events.
+    None,
+    /// A layer window exclusively receives keyboard events if the layer window is in the top layer
+    /// or the overlay layer.
+    Exclusive,
+    /// A layer window receives keyboard events if a compositor gives a keyboard focus to the layer
+    /// window.
+    OnDemand,
+}
+
+/// An enumeration of client popup anchor.
+///
+/// The client popup anchor is a point of an anchor rectangle to which a popup window is attached.
+/// The none is a center of the anchor rectangle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ClientPopupAnchor
+{
+    /// A none.
+    None,
+    /// A top edge.
+    Top,
+    /// A bottom edge.
+    Bottom,
+    /// A left edge.
+    Left,
+    /// A right edge.
+    Right,
+    /// A top edge and a left edge.
+    TopLeft,
+    /// A bottom edge and a left edge.
+    BottomLeft,
+    /// A top edge and a right edge.
+    TopRight,
+    /// A bottom edge and a right edge.
+    BottomRight,
+}
+
+/// An enumeration of client popup gravity.
+///
+/// The client popup gravity is a direction in which a popup window is placed from a point of
+/// an anchor rectangle. The none means that the popup window is centered on the point.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ClientPopupGravity
+{
+    /// A none.
+    None,
+    /// A top edge.
+    Top,
+    /// A bottom edge.
+    Bottom,
+    /// A left edge.
+    Left,
+    /// A right edge.
+    Right,
+    /// A top edge and a left edge.
+    TopLeft,
+    /// A bottom edge and a left edge.
+    BottomLeft,
+    /// A top edge and a right edge.
+    TopRight,
+    /// A bottom edge and a right edge.
+    BottomRight,
+}
+
+/// A structure of client constraint adjustment.
+///
+/// The client constraint adjustment is a set of ways in which a compositor can adjust a position
+/// of a popup window if the popup window doesn't fit on an output.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ClientConstraintAdjustment(u32);
+
+impl ClientConstraintAdjustment
+{
+    /// An empty set of client constraint adjustment.
+    pub const EMPTY: ClientConstraintAdjustment = ClientConstraintAdjustment(0);
+    /// A popup window is slid along the X axis.
+    pub const SLIDE_X: ClientConstraintAdjustment = ClientConstraintAdjustment(1 << 0);
+    /// A popup window is slid along the Y axis.
+    pub const SLIDE_Y: ClientConstraintAdjustment = ClientConstraintAdjustment(1 << 1);
+    /// An anchor and a gravity of a popup window are flipped along the X axis.
+    pub const FLIP_X: ClientConstraintAdjustment = ClientConstraintAdjustment(1 << 2);
+    /// An anchor and a gravity of a popup window are flipped along the Y axis.
+    pub const FLIP_Y: ClientConstraintAdjustment = ClientConstraintAdjustment(1 << 3);
+    /// A popup window is horizontally resized.
+    pub const RESIZE_X: ClientConstraintAdjustment = ClientConstraintAdjustment(1 << 4);
+    /// A popup window is vertically resized.
+    pub const RESIZE_Y: ClientConstraintAdjustment = ClientConstraintAdjustment(1 << 5);
+
+    /// Returns an empty set of client constraint adjustment.
+    pub const fn empty() -> Self
+    { ClientConstraintAdjustment(0) }
+
+    /// Returns a full set of client constraint adjustment.
+    pub const fn all() -> Self
+    { ClientConstraintAdjustment(63) }
+
+    /// Returns `true` if set of client constraint adjustment is empty, otherwise `false`.
+    pub const fn is_empty(&self) -> bool
+    { self.0 == 0 }
+
+    /// Returns `true` if an intersection of two sets of client constraint adjustment isn't empty,
+    /// otherwise `false`.
+    pub const fn intersects(&self, other: Self) -> bool
+    { self.0 & other.0 != 0 }
+
+    /// Returns `true` if the set of client constraint adjustment contains the client constraint
+    /// adjustment, otherwise `false`.
+    pub const fn contains(&self, other: Self) -> bool
+    { (self.0 & other.0) == other.0 }
+
+    /// Inserts the client constraint adjustment.
+    pub fn insert(&mut self, other: Self)
+    { self.0 |= other.0; }
+
+    /// Removes the client constraint adjustment.
+    pub fn remove(&mut self, other: Self)
+    { self.0 &= !other.0; }
+
+    /// Inserts or removes the client constraint adjustment for the flag.
+    pub fn set(&mut self, other: Self, b: bool)
+    {
+        if b {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+}
+
+impl BitAnd for ClientConstraintAdjustment
+{
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output
+    { ClientConstraintAdjustment(self.0 & rhs.0) }
+}
+
+impl BitAndAssign for ClientConstraintAdjustment
+{
+    fn bitand_assign(&mut self, rhs: Self)
+    { self.0 &= rhs.0; }
+}
+
+impl BitOr for ClientConstraintAdjustment
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output
+    { ClientConstraintAdjustment(self.0 | rhs.0) }
+}
+
+impl BitOrAssign for ClientConstraintAdjustment
+{
+    fn bitor_assign(&mut self, rhs: Self)
+    { self.0 |= rhs.0; }
+}
+
+impl Sub for ClientConstraintAdjustment
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output
+    { ClientConstraintAdjustment(self.0 & !rhs.0) }
+}
+
+impl SubAssign for ClientConstraintAdjustment
+{
+    fn sub_assign(&mut self, rhs: Self)
+    { self.0 &= !rhs.0; }
+}
+
+impl fmt::Debug for ClientConstraintAdjustment
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        if *self == Self::EMPTY {
+            write!(f, "EMPTY")?;
+        } else {
+            let names = [
+                (Self::SLIDE_X, "SLIDE_X"),
+                (Self::SLIDE_Y, "SLIDE_Y"),
+                (Self::FLIP_X, "FLIP_X"),
+                (Self::FLIP_Y, "FLIP_Y"),
+                (Self::RESIZE_X, "RESIZE_X"),
+                (Self::RESIZE_Y, "RESIZE_Y")
+            ];
+            let mut is_first = true;
+            for (adjustment, name) in &names {
+                if self.contains(*adjustment) {
+                    if !is_first {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{}", name)?;
+                    is_first = false;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 /// An enumeration of client button.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum ClientButton
@@ -382,3 +1052,19 @@ pub enum ClientAxis
     /// A horizontal axis.
     HScroll,
 }
+
+/// An enumeration of client axis source.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ClientAxisSource
+{
+    /// A scroll wheel.
+    Wheel,
+    /// Fingers on a touchpad.
+    Finger,
+    /// A continuous coordinate space, for example a trackpoint.
+    Continuous,
+    /// A tilt of scroll wheel.
+    WheelTilt,
+    /// A kinetic scrolling that continues a finger scrolling after the fingers are lifted.
+    Kinetic,
+}
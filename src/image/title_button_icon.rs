@@ -11,4 +11,5 @@ pub enum TitleButtonIcon
     Close,
     Maximize,
     Menu,
+    Minimize,
 }
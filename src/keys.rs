@@ -489,3 +489,98 @@ pub enum VKey
     RightBrace,
     Tilde,
 }
+
+impl VKey
+{
+    /// Returns the unmodified character of the ASCII key or `None`.
+    ///
+    /// A letter key is returned as a lowercase letter. This method allows to find a mnemonic for
+    /// a key that is pressed with the alt key because the pressed key with the alt key doesn't
+    /// have characters.
+    ///
+    /// # Examples
+    /// ```
+    /// use lwltk::keys::VKey;
+    ///
+    /// assert_eq!(Some('f'), VKey::F.to_char());
+    /// assert_eq!(Some('1'), VKey::Key1.to_char());
+    /// assert_eq!(None, VKey::F1.to_char());
+    /// ```
+    pub fn to_char(&self) -> Option<char>
+    {
+        match self {
+            VKey::Space => Some(' '),
+            VKey::Exclam => Some('!'),
+            VKey::DoubleQuote => Some('"'),
+            VKey::NumberSign => Some('#'),
+            VKey::Dollar => Some('$'),
+            VKey::Percent => Some('%'),
+            VKey::Ampersand => Some('&'),
+            VKey::Apostrophe => Some('\''),
+            VKey::RightQuote => Some('\''),
+            VKey::LeftParen => Some('('),
+            VKey::RightParen => Some(')'),
+            VKey::Asterisk => Some('*'),
+            VKey::Plus => Some('+'),
+            VKey::Comma => Some(','),
+            VKey::Minus => Some('-'),
+            VKey::Period => Some('.'),
+            VKey::Slash => Some('/'),
+            VKey::Key0 => Some('0'),
+            VKey::Key1 => Some('1'),
+            VKey::Key2 => Some('2'),
+            VKey::Key3 => Some('3'),
+            VKey::Key4 => Some('4'),
+            VKey::Key5 => Some('5'),
+            VKey::Key6 => Some('6'),
+            VKey::Key7 => Some('7'),
+            VKey::Key8 => Some('8'),
+            VKey::Key9 => Some('9'),
+            VKey::Colon => Some(':'),
+            VKey::Semicolon => Some(';'),
+            VKey::Less => Some('<'),
+            VKey::Equal => Some('='),
+            VKey::Greater => Some('>'),
+            VKey::Question => Some('?'),
+            VKey::At => Some('@'),
+            VKey::A => Some('a'),
+            VKey::B => Some('b'),
+            VKey::C => Some('c'),
+            VKey::D => Some('d'),
+            VKey::E => Some('e'),
+            VKey::F => Some('f'),
+            VKey::G => Some('g'),
+            VKey::H => Some('h'),
+            VKey::I => Some('i'),
+            VKey::J => Some('j'),
+            VKey::K => Some('k'),
+            VKey::L => Some('l'),
+            VKey::M => Some('m'),
+            VKey::N => Some('n'),
+            VKey::O => Some('o'),
+            VKey::P => Some('p'),
+            VKey::Q => Some('q'),
+            VKey::R => Some('r'),
+            VKey::S => Some('s'),
+            VKey::T => Some('t'),
+            VKey::U => Some('u'),
+            VKey::V => Some('v'),
+            VKey::W => Some('w'),
+            VKey::X => Some('x'),
+            VKey::Y => Some('y'),
+            VKey::Z => Some('z'),
+            VKey::LeftBracket => Some('['),
+            VKey::Backslash => Some('\\'),
+            VKey::RightBracket => Some(']'),
+            VKey::Circum => Some('^'),
+            VKey::Underscore => Some('_'),
+            VKey::Grave => Some('`'),
+            VKey::LeftQuote => Some('`'),
+            VKey::LeftBrace => Some('{'),
+            VKey::Bar => Some('|'),
+            VKey::RightBrace => Some('}'),
+            VKey::Tilde => Some('~'),
+            _ => None,
+        }
+    }
+}
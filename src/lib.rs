@@ -9,11 +9,18 @@ mod app;
 mod as_any;
 mod call_on;
 mod callback_queue;
+mod client_buffer;
 mod client_context;
+mod client_cursor;
+mod client_data_device;
 mod client_error;
 mod client_keyboard;
+mod client_output;
 mod client_pointer;
+mod client_seat;
 mod client_shell_surface;
+mod client_text_input;
+mod client_tooltip;
 mod client_touch;
 mod client_window;
 mod container;
@@ -24,6 +31,7 @@ mod key_map_init;
 mod min_size;
 mod mod_key_set_init;
 mod preferred_size;
+mod protocols;
 mod queue_context;
 mod theme;
 mod thread_signal;
@@ -32,6 +32,8 @@ pub(crate) struct MockChildWindow
     is_visible: bool,
     is_focusable: bool,
     is_focused: bool,
+    is_popup: bool,
+    is_modal: bool,
     change_flag_arc: Arc<AtomicBool>,
     min_size: Size<Option<i32>>,
     preferred_size: Size<Option<i32>>,
@@ -52,6 +54,8 @@ impl MockChildWindow
             is_visible: true,
             is_focusable: true,
             is_focused: false,
+            is_popup: false,
+            is_modal: false,
             change_flag_arc: Arc::new(AtomicBool::new(false)),
             min_size: Size::new(None, None),
             preferred_size: Size::new(None, None),
@@ -80,6 +84,12 @@ impl MockChildWindow
 
     pub(crate) fn set_focusable(&mut self, is_focusable: bool)
     { self.is_focusable = is_focusable; }
+
+    pub(crate) fn set_popup(&mut self, is_popup: bool)
+    { self.is_popup = is_popup; }
+
+    pub(crate) fn set_modal(&mut self, is_modal: bool)
+    { self.is_modal = is_modal; }
     
     pub(crate) fn set_change_flag(&mut self, is_changed: bool)
     { self.change_flag_arc.store(is_changed, Ordering::SeqCst); }
@@ -121,6 +131,15 @@ impl Window for MockChildWindow
     fn title(&self) -> Option<&str>
     { Some(self.title.as_str()) }
 
+    fn is_popup(&self) -> bool
+    { self.is_popup }
+
+    fn is_closed_by_outside_click(&self) -> bool
+    { self.is_popup }
+
+    fn is_modal(&self) -> bool
+    { self.is_modal }
+
     fn parent_index(&self) -> Option<WindowIndex>
     { self.parent_index }
     
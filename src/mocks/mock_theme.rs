@@ -16,6 +16,11 @@ pub(crate) struct MockTheme
     font_size: f64,
     toplevel_window_edges: Edges<i32>,
     toplevel_window_corners: Corners<i32>,
+    layer_window_edges: Edges<i32>,
+    popup_window_edges: Edges<i32>,
+    menu_edges: Edges<i32>,
+    tooltip_edges: Edges<i32>,
+    tooltip_font_size: f64,
     title_margin_edges: Edges<i32>,
     title_padding_edges: Edges<i32>,
     title_font_size: f64,
@@ -37,6 +42,16 @@ pub(crate) struct MockTheme
     radio_margin_edges: Edges<i32>,
     radio_padding_edges: Edges<i32>,
     radio_font_size: f64,
+    menu_bar_item_margin_edges: Edges<i32>,
+    menu_bar_item_padding_edges: Edges<i32>,
+    menu_bar_item_font_size: f64,
+    menu_item_margin_edges: Edges<i32>,
+    menu_item_padding_edges: Edges<i32>,
+    menu_item_sep_width: i32,
+    menu_item_icon_size: Size<i32>,
+    menu_item_arrow_size: Size<i32>,
+    menu_item_separator_height: i32,
+    menu_item_font_size: f64,
     scroll_bar_margin_edges: Edges<i32>,
     scroll_bar_elems: ScrollBarElems,
     h_scroll_bar_height: i32,
@@ -53,6 +68,11 @@ impl MockTheme
             font_size: 0.0,
             toplevel_window_edges: Edges::new(0, 0, 0, 0),
             toplevel_window_corners: Corners::new(0, 0, 0, 0, 0, 0, 0, 0),
+            layer_window_edges: Edges::new(0, 0, 0, 0),
+            popup_window_edges: Edges::new(0, 0, 0, 0),
+            menu_edges: Edges::new(0, 0, 0, 0),
+            tooltip_edges: Edges::new(0, 0, 0, 0),
+            tooltip_font_size: 0.0,
             title_margin_edges: Edges::new(0, 0, 0, 0),
             title_padding_edges: Edges::new(0, 0, 0, 0),
             title_font_size: 0.0,
@@ -74,6 +94,16 @@ impl MockTheme
             radio_margin_edges: Edges::new(0, 0, 0, 0),
             radio_padding_edges: Edges::new(0, 0, 0, 0),
             radio_font_size: 0.0,
+            menu_bar_item_margin_edges: Edges::new(0, 0, 0, 0),
+            menu_bar_item_padding_edges: Edges::new(0, 0, 0, 0),
+            menu_bar_item_font_size: 0.0,
+            menu_item_margin_edges: Edges::new(0, 0, 0, 0),
+            menu_item_padding_edges: Edges::new(0, 0, 0, 0),
+            menu_item_sep_width: 0,
+            menu_item_icon_size: Size::new(0, 0),
+            menu_item_arrow_size: Size::new(0, 0),
+            menu_item_separator_height: 0,
+            menu_item_font_size: 0.0,
             scroll_bar_margin_edges: Edges::new(0, 0, 0, 0),
             scroll_bar_elems: ScrollBarElems::Button1Button2Slider,
             h_scroll_bar_height: 0,
@@ -92,6 +122,21 @@ impl MockTheme
     pub(crate) fn set_toplevel_window_corners(&mut self, corners: Corners<i32>)
     { self.toplevel_window_corners = corners; }
 
+    pub(crate) fn set_layer_window_edges(&mut self, edges: Edges<i32>)
+    { self.layer_window_edges = edges; }
+
+    pub(crate) fn set_popup_window_edges(&mut self, edges: Edges<i32>)
+    { self.popup_window_edges = edges; }
+
+    pub(crate) fn set_menu_edges(&mut self, edges: Edges<i32>)
+    { self.menu_edges = edges; }
+
+    pub(crate) fn set_tooltip_edges(&mut self, edges: Edges<i32>)
+    { self.tooltip_edges = edges; }
+
+    pub(crate) fn set_tooltip_font_size(&mut self, font_size: f64)
+    { self.tooltip_font_size = font_size; }
+
     pub(crate) fn set_title_margin_edges(&mut self, edges: Edges<i32>)
     { self.title_margin_edges = edges; }
     
@@ -155,6 +200,36 @@ impl MockTheme
     pub(crate) fn set_radio_font_size(&mut self, font_size: f64)
     { self.radio_font_size = font_size; }
 
+    pub(crate) fn set_menu_bar_item_margin_edges(&mut self, edges: Edges<i32>)
+    { self.menu_bar_item_margin_edges = edges; }
+
+    pub(crate) fn set_menu_bar_item_padding_edges(&mut self, edges: Edges<i32>)
+    { self.menu_bar_item_padding_edges = edges; }
+
+    pub(crate) fn set_menu_bar_item_font_size(&mut self, font_size: f64)
+    { self.menu_bar_item_font_size = font_size; }
+
+    pub(crate) fn set_menu_item_margin_edges(&mut self, edges: Edges<i32>)
+    { self.menu_item_margin_edges = edges; }
+
+    pub(crate) fn set_menu_item_padding_edges(&mut self, edges: Edges<i32>)
+    { self.menu_item_padding_edges = edges; }
+
+    pub(crate) fn set_menu_item_sep_width(&mut self, width: i32)
+    { self.menu_item_sep_width = width; }
+
+    pub(crate) fn set_menu_item_icon_size(&mut self, size: Size<i32>)
+    { self.menu_item_icon_size = size; }
+
+    pub(crate) fn set_menu_item_arrow_size(&mut self, size: Size<i32>)
+    { self.menu_item_arrow_size = size; }
+
+    pub(crate) fn set_menu_item_separator_height(&mut self, height: i32)
+    { self.menu_item_separator_height = height; }
+
+    pub(crate) fn set_menu_item_font_size(&mut self, font_size: f64)
+    { self.menu_item_font_size = font_size; }
+
     pub(crate) fn set_scroll_bar_margin_edges(&mut self, edges: Edges<i32>)
     { self.scroll_bar_margin_edges = edges; }
     
@@ -176,7 +251,7 @@ impl MockTheme
 
 impl Theme for MockTheme
 {
-    fn set_cairo_context(&self, cairo_context: &CairoContext, _scale: i32) -> Result<(), CairoError>
+    fn set_cairo_context(&self, cairo_context: &CairoContext, _scale: f64) -> Result<(), CairoError>
     { 
         cairo_context.select_font_face("Sans", FontSlant::Normal, FontWeight::Normal);
         cairo_context.set_font_size(self.font_size);
@@ -195,6 +270,40 @@ impl Theme for MockTheme
     fn draw_toplevel_window_content_bg(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _is_focused_window: bool, _is_tool_bar: bool) -> Result<(), CairoError>
     { Ok(()) }
 
+    fn layer_window_edges(&self) -> Edges<i32>
+    { self.layer_window_edges }
+
+    fn draw_layer_window_bg(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn popup_window_edges(&self) -> Edges<i32>
+    { self.popup_window_edges }
+
+    fn draw_popup_window_bg(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn menu_edges(&self) -> Edges<i32>
+    { self.menu_edges }
+
+    fn draw_menu_bg(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn tooltip_edges(&self) -> Edges<i32>
+    { self.tooltip_edges }
+
+    fn draw_tooltip_bg(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn set_tooltip_font(&self, cairo_context: &CairoContext) -> Result<(), CairoError>
+    {
+        cairo_context.select_font_face("Sans", FontSlant::Normal, FontWeight::Normal);
+        cairo_context.set_font_size(self.tooltip_font_size);
+        Ok(())
+    }
+
+    fn draw_tooltip_text(&self, _cairo_context: &CairoContext, _pos: Pos<i32>, _s: &str, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
     fn draw_title_bar_bg(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _state: WidgetState, _is_enabled: bool, _is_focused_window: bool) -> Result<(), CairoError>
     { Ok(()) }
 
@@ -253,6 +362,9 @@ impl Theme for MockTheme
     
     fn draw_label_text(&self, _cairo_context: &CairoContext, _pos: Pos<i32>, _s: &str, _state: WidgetState, _is_enabled: bool, _is_focused_window: bool) -> Result<(), CairoError>    
     { Ok(()) }
+
+    fn draw_preedit_text(&self, _cairo_context: &CairoContext, _pos: Pos<i32>, _s: &str, _is_enabled: bool, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
     
     fn button_margin_edges(&self) -> Edges<i32>
     { self.button_margin_edges }
@@ -320,6 +432,74 @@ impl Theme for MockTheme
     fn draw_radio_text(&self, _cairo_context: &CairoContext, _pos: Pos<i32>, _s: &str, _is_selected: bool, _state: WidgetState, _is_enabled: bool, _is_focused: bool, _is_focused_window: bool) -> Result<(), CairoError>
     { Ok(()) }
     
+    fn draw_menu_bar_bg(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _state: WidgetState, _is_enabled: bool, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn menu_bar_item_margin_edges(&self) -> Edges<i32>
+    { self.menu_bar_item_margin_edges }
+
+    fn menu_bar_item_padding_edges(&self) -> Edges<i32>
+    { self.menu_bar_item_padding_edges }
+
+    fn draw_menu_bar_item_bg(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _state: WidgetState, _is_enabled: bool, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn set_menu_bar_item_font(&self, cairo_context: &CairoContext) -> Result<(), CairoError>
+    { 
+        cairo_context.select_font_face("Sans", FontSlant::Normal, FontWeight::Normal);
+        cairo_context.set_font_size(self.menu_bar_item_font_size);
+        Ok(())
+    }
+
+    fn draw_menu_bar_item_text(&self, _cairo_context: &CairoContext, _pos: Pos<i32>, _s: &str, _mnemonic_idx: Option<usize>, _state: WidgetState, _is_enabled: bool, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn menu_item_margin_edges(&self) -> Edges<i32>
+    { self.menu_item_margin_edges }
+
+    fn menu_item_padding_edges(&self) -> Edges<i32>
+    { self.menu_item_padding_edges }
+
+    fn menu_item_sep_width(&self) -> i32
+    { self.menu_item_sep_width }
+
+    fn menu_item_icon_size(&self) -> Size<i32>
+    { self.menu_item_icon_size }
+
+    fn menu_item_arrow_size(&self) -> Size<i32>
+    { self.menu_item_arrow_size }
+
+    fn menu_item_separator_height(&self) -> i32
+    { self.menu_item_separator_height }
+
+    fn draw_menu_item_bg(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _state: WidgetState, _is_enabled: bool, _is_focused: bool, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn draw_menu_item_separator(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn draw_menu_item_check(&self, _cairo_context: &CairoContext, _pos: Pos<i32>, _is_checked: bool, _state: WidgetState, _is_enabled: bool, _is_focused: bool, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn draw_menu_item_radio(&self, _cairo_context: &CairoContext, _pos: Pos<i32>, _is_selected: bool, _state: WidgetState, _is_enabled: bool, _is_focused: bool, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn draw_menu_item_arrow(&self, _cairo_context: &CairoContext, _pos: Pos<i32>, _state: WidgetState, _is_enabled: bool, _is_focused: bool, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn set_menu_item_font(&self, cairo_context: &CairoContext) -> Result<(), CairoError>
+    { 
+        cairo_context.select_font_face("Sans", FontSlant::Normal, FontWeight::Normal);
+        cairo_context.set_font_size(self.menu_item_font_size);
+        Ok(())
+    }
+
+    fn draw_menu_item_text(&self, _cairo_context: &CairoContext, _pos: Pos<i32>, _s: &str, _mnemonic_idx: Option<usize>, _state: WidgetState, _is_enabled: bool, _is_focused: bool, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn draw_menu_item_shortcut_text(&self, _cairo_context: &CairoContext, _pos: Pos<i32>, _s: &str, _state: WidgetState, _is_enabled: bool, _is_focused: bool, _is_focused_window: bool) -> Result<(), CairoError>
+    { Ok(()) }
+
     fn draw_linear_layout_bg(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _orient: Orient, _state: WidgetState, _is_enabled: bool, _is_focused_window: bool) -> Result<(), CairoError>
     { Ok(()) }
 
@@ -0,0 +1,48 @@
+//
+// Copyright (c) 2022-2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+//! Client code of the Wayland protocols that aren't in the wayland-protocols crate.
+macro_rules! wayland_protocol(
+    ($name: expr, [$($import: ident),*]) => {
+        wayland_protocol!($name, [$($import),*], []);
+    };
+    ($name: expr, [$($import: ident),*], [$(($($prot_name: ident)::+, $prot_import: ident)),*]) => {
+        #[allow(dead_code, non_camel_case_types, unused_unsafe, unused_variables)]
+        #[allow(non_upper_case_globals, non_snake_case, unused_imports)]
+        #[allow(missing_docs, clippy::all)]
+        #[allow(unknown_lints, static_mut_refs)]
+        pub(crate) mod client
+        {
+            pub(crate) use wayland_client::{Main, Attached, Proxy, ProxyMap, AnonymousObject};
+            pub(crate) use wayland_commons::map::{Object, ObjectMetadata};
+            pub(crate) use wayland_commons::{Interface, MessageGroup};
+            pub(crate) use wayland_commons::wire::{Argument, MessageDesc, ArgumentType, Message};
+            pub(crate) use wayland_commons::smallvec;
+            pub(crate) use wayland_client::protocol::{$($import),*};
+            pub(crate) use wayland_client::sys;
+            $(
+                pub(crate) use wayland_protocols::$($prot_name::)*client::$prot_import;
+            )*
+            include!(concat!(env!("OUT_DIR"), "/", $name, "_client_api.rs"));
+        }
+    }
+);
+
+pub(crate) mod cursor_shape_v1
+{
+    wayland_protocol!("cursor-shape-v1", [wl_pointer], [(unstable::tablet::v2, zwp_tablet_tool_v2)]);
+}
+
+pub(crate) mod fractional_scale_v1
+{
+    wayland_protocol!("fractional-scale-v1", [wl_surface]);
+}
+
+pub(crate) mod pointer_gestures_unstable_v1
+{
+    wayland_protocol!("pointer-gestures-unstable-v1", [wl_pointer, wl_surface]);
+}
@@ -28,6 +28,8 @@ pub enum CallOnId
     Pointer,
     /// A touch with an unique touch identifier.
     Touch(i32),
+    /// A pointer of drag and drop.
+    Drag,
 }
 
 /// An enumeration of active identifier.
@@ -47,8 +49,8 @@ pub enum ActiveId
 
 /// An enumeration of call-on element.
 ///
-/// The call-on element is an element of scroll bar with a trough. This enumeration is used to
-/// identify actions of a scroll bar.
+/// The call-on element is an element of scroll bar with a trough or a scrolling by a pointer axis.
+/// This enumeration is used to identify actions of a scroll bar.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum CallOnElem
 {
@@ -56,6 +58,14 @@ pub enum CallOnElem
     ScrollBarElem(ScrollBarElem),
     /// A Trough.
     Trough,
+    /// A previous line on the axis that is scrolled by one step of a scroll wheel.
+    PrevLine(ClientAxis),
+    /// A next line on the axis that is scrolled by one step of a scroll wheel.
+    NextLine(ClientAxis),
+    /// Pixels on the axis that are scrolled by a touchpad.
+    ///
+    /// A number of scrolled pixels is a difference between a new position and an old position.
+    Pixels(ClientAxis),
 }
 
 /// An iterator of queue context that iterates over pairs of widget indices.
@@ -103,16 +113,17 @@ pub struct QueueContext
     pub(crate) event_queue: EventQueue,
     pub(crate) callback_queue: CallbackQueue,
     pub(crate) current_call_on_path: Option<CallOnPath>,
+    pub(crate) current_seat_id: Option<SeatId>,
     pub(crate) current_descendant_index_pairs: Vec<WidgetIndexPair>,
-    pub(crate) motion_call_on_paths: BTreeMap<CallOnId, CallOnPath>,
-    pub(crate) motion_resize_edge_map: BTreeMap<CallOnId, ClientResize>,
-    pub(crate) pressed_call_on_paths: BTreeMap<CallOnId, CallOnPath>,
-    pub(crate) pressed_instants: BTreeMap<CallOnId, Instant>,
-    pub(crate) pressed_call_on_elems: BTreeMap<CallOnId, CallOnElem>,
-    pub(crate) pressed_old_poses: BTreeMap<CallOnId, Pos<f64>>,
-    pub(crate) has_double_click: bool,
-    pub(crate) has_long_click: bool,
-    pub(crate) active_id_sets: BTreeMap<CallOnPath, BTreeSet<ActiveId>>,
+    pub(crate) motion_call_on_paths: BTreeMap<(Option<SeatId>, CallOnId), CallOnPath>,
+    pub(crate) motion_resize_edge_map: BTreeMap<(Option<SeatId>, CallOnId), ClientResize>,
+    pub(crate) pressed_call_on_paths: BTreeMap<(Option<SeatId>, CallOnId), CallOnPath>,
+    pub(crate) pressed_instants: BTreeMap<(Option<SeatId>, CallOnId), Instant>,
+    pub(crate) pressed_call_on_elems: BTreeMap<(Option<SeatId>, CallOnId), CallOnElem>,
+    pub(crate) pressed_old_poses: BTreeMap<(Option<SeatId>, CallOnId), Pos<f64>>,
+    pub(crate) double_click_seat_ids: BTreeSet<Option<SeatId>>,
+    pub(crate) long_click_seat_ids: BTreeSet<Option<SeatId>>,
+    pub(crate) active_id_sets: BTreeMap<CallOnPath, BTreeSet<(Option<SeatId>, ActiveId)>>,
     pub(crate) has_wait_cursor: bool,
 }
 
@@ -124,6 +135,7 @@ impl QueueContext
             event_queue: EventQueue::new(),
             callback_queue: CallbackQueue::new(),
             current_call_on_path: None,
+            current_seat_id: None,
             current_descendant_index_pairs: Vec::new(),
             motion_call_on_paths: BTreeMap::new(),
             motion_resize_edge_map: BTreeMap::new(),
@@ -131,8 +143,8 @@ impl QueueContext
             pressed_instants: BTreeMap::new(),
             pressed_call_on_elems: BTreeMap::new(),
             pressed_old_poses: BTreeMap::new(),
-            has_double_click: false,
-            has_long_click: false,
+            double_click_seat_ids: BTreeSet::new(),
+            long_click_seat_ids: BTreeSet::new(),
             active_id_sets: BTreeMap::new(),
             has_wait_cursor: false,
         }
@@ -165,6 +177,16 @@ impl QueueContext
         }
     }
     
+    /// Returns the seat identifier of the current event or `None`.
+    ///
+    /// The seat identifier of the current event identifies a seat of an input device that sent the
+    /// event. The motion call-on paths, the pressed call-on paths, the click flags, and the active
+    /// identifiers are tracked for each seat, so methods of these things refer to the seat of the
+    /// current event. The events and the callbacks from the queues are handled with the seat
+    /// identifiers that are recorded when they are pushed.
+    pub fn current_seat_id(&self) -> Option<SeatId>
+    { self.current_seat_id }
+
     /// Returns an iterator that iterates over the current  pairs of the indices of the descendant
     /// widgets.
     ///
@@ -180,26 +202,26 @@ impl QueueContext
     /// The motion call-on path refers to the widget or the window that is pointed by the pointer or
     /// the touch.
     pub fn motion_call_on_path(&self, call_on_id: CallOnId) -> Option<&CallOnPath>
-    { self.motion_call_on_paths.get(&call_on_id) }
+    { self.motion_call_on_paths.get(&(self.current_seat_id, call_on_id)) }
 
     /// Sets the motion call-on path for the specified call-on identifier.
     ///
     /// See [`motion_call_on_path`](Self::motion_call_on_path) for more informations.
     pub fn set_motion_call_on_path(&mut self, call_on_id: CallOnId, call_on_path: CallOnPath)
-    { self.motion_call_on_paths.insert(call_on_id, call_on_path); }
+    { self.motion_call_on_paths.insert((self.current_seat_id, call_on_id), call_on_path); }
 
     /// Unsets the motion call-on path for the specified call-on identifier.
     ///
     /// See [`motion_call_on_path`](Self::motion_call_on_path) for more informations.
     pub fn unset_motion_call_on_path(&mut self, call_on_id: CallOnId)
-    { self.motion_call_on_paths.remove(&call_on_id); }
+    { self.motion_call_on_paths.remove(&(self.current_seat_id, call_on_id)); }
 
     /// Returns the motion resize edges for the specified call-on identifier or `None`.
     ///
     /// The motion resize edges are pointed by the pointer or the touch and used to resize a window.
     pub fn motion_resize_edges(&self, call_on_id: CallOnId) -> Option<ClientResize>
     {
-        match self.motion_resize_edge_map.get(&call_on_id) {
+        match self.motion_resize_edge_map.get(&(self.current_seat_id, call_on_id)) {
             Some(edges) => Some(*edges),
             None => None,
         }
@@ -209,13 +231,13 @@ impl QueueContext
     ///
     /// See [`motion_resize_edges`](Self::motion_resize_edges) for more informations.
     pub fn set_motion_resize_edges(&mut self, call_on_id: CallOnId, edges: ClientResize)
-    { self.motion_resize_edge_map.insert(call_on_id, edges); }
+    { self.motion_resize_edge_map.insert((self.current_seat_id, call_on_id), edges); }
     
     /// Unsets the motion resize edges for the specified call-on identifier.
     ///
     /// See [`motion_resize_edges`](Self::motion_resize_edges) for more informations.
     pub fn unset_motion_resize_edges(&mut self, call_on_id: CallOnId)
-    { self.motion_resize_edge_map.remove(&call_on_id); }
+    { self.motion_resize_edge_map.remove(&(self.current_seat_id, call_on_id)); }
     
     /// Returns a reference to the call-on path of the pressed button for the specified call-on
     /// identifier or `None`.
@@ -223,19 +245,19 @@ impl QueueContext
     /// The call-on path of the pressed button refers to the widget or the window that is pressed by
     /// the pointer or touched.
     pub fn pressed_call_on_path(&self, call_on_id: CallOnId) -> Option<&CallOnPath>
-    { self.pressed_call_on_paths.get(&call_on_id) }
+    { self.pressed_call_on_paths.get(&(self.current_seat_id, call_on_id)) }
 
     /// Sets the call-on path of the pressed button for the specified call-on identifier.
     ///
     /// See [`pressed_call_on_path`](Self::pressed_call_on_path) for more informations.
     pub fn set_pressed_call_on_path(&mut self, call_on_id: CallOnId, call_on_path: CallOnPath)
-    { self.pressed_call_on_paths.insert(call_on_id, call_on_path); }
+    { self.pressed_call_on_paths.insert((self.current_seat_id, call_on_id), call_on_path); }
 
     /// Unsets the call-on path of the pressed button for the specified call-on identifier.
     ///
     /// See [`pressed_call_on_path`](Self::pressed_call_on_path) for more informations.
     pub fn unset_pressed_call_on_path(&mut self, call_on_id: CallOnId)
-    { self.pressed_call_on_paths.remove(&call_on_id); }
+    { self.pressed_call_on_paths.remove(&(self.current_seat_id, call_on_id)); }
     
     /// Returns a reference to the clock measurement of the pressed button for the specified call-on
     /// identifier or `None`.
@@ -243,19 +265,19 @@ impl QueueContext
     /// The clock measurement of the pressed button is made when the widget or the window is pressed
     /// by the pointer or touched.
     pub fn pressed_instant(&self, call_on_id: CallOnId) -> Option<&Instant>
-    { self.pressed_instants.get(&call_on_id) }
+    { self.pressed_instants.get(&(self.current_seat_id, call_on_id)) }
 
     /// Sets the clock measurement of the pressed button for the specified call-on identifier.
     ///
     /// See [`pressed_instant`](Self::pressed_instant) for more informations.
     pub fn set_pressed_instant(&mut self, call_on_id: CallOnId, instant: Instant)
-    { self.pressed_instants.insert(call_on_id, instant); }
+    { self.pressed_instants.insert((self.current_seat_id, call_on_id), instant); }
 
     /// Unsets the clock measurement of the pressed button for the specified call-on identifier.
     ///
     /// See [`pressed_instant`](Self::pressed_instant) for more informations.
     pub fn unset_pressed_instant(&mut self, call_on_id: CallOnId)
-    { self.pressed_instants.remove(&call_on_id); }
+    { self.pressed_instants.remove(&(self.current_seat_id, call_on_id)); }
 
     /// Returns the call-on element of the pressed button for the specified call-on identifier or
     /// `None`.
@@ -264,7 +286,7 @@ impl QueueContext
     /// widget is pressed by the pointer or touched.
     pub fn pressed_call_on_elem(&self, call_on_id: CallOnId) -> Option<CallOnElem>
     {
-        match self.pressed_call_on_elems.get(&call_on_id) {
+        match self.pressed_call_on_elems.get(&(self.current_seat_id, call_on_id)) {
             Some(elem) => Some(*elem),
             None => None,
         }
@@ -274,13 +296,13 @@ impl QueueContext
     ///
     /// See [`pressed_call_on_elem`](Self::pressed_call_on_elem) for more informations.
     pub fn set_pressed_call_on_elem(&mut self, call_on_id: CallOnId, elem: CallOnElem)
-    { self.pressed_call_on_elems.insert(call_on_id, elem); }
+    { self.pressed_call_on_elems.insert((self.current_seat_id, call_on_id), elem); }
 
     /// Unsets the call-on element of the pressed button for the specified call-on identifier.
     ///
     /// See [`pressed_call_on_elem`](Self::pressed_call_on_elem) for more informations.
     pub fn unset_pressed_call_on_elem(&mut self, call_on_id: CallOnId)
-    { self.pressed_call_on_elems.remove(&call_on_id); }
+    { self.pressed_call_on_elems.remove(&(self.current_seat_id, call_on_id)); }
 
     /// Returns the old position of the pressed button for the specified call-on
     /// identifier or `None`.
@@ -289,7 +311,7 @@ impl QueueContext
     /// pointer or the touch for the widget that is pressed by the pointer or touched.
     pub fn pressed_old_pos(&self, call_on_id: CallOnId) -> Option<Pos<f64>>
     {
-        match self.pressed_old_poses.get(&call_on_id) {
+        match self.pressed_old_poses.get(&(self.current_seat_id, call_on_id)) {
             Some(elem) => Some(*elem),
             None => None,
         }
@@ -299,45 +321,57 @@ impl QueueContext
     ///
     /// See [`pressed_old_pos`](Self::pressed_old_pos) for more informations.
     pub fn set_pressed_old_pos(&mut self, call_on_id: CallOnId, pos: Pos<f64>)
-    { self.pressed_old_poses.insert(call_on_id, pos); }
+    { self.pressed_old_poses.insert((self.current_seat_id, call_on_id), pos); }
 
     /// Sets the old position of the pressed button for the specified call-on identifier.
     ///
     /// See [`pressed_old_pos`](Self::pressed_old_pos) for more informations.
     pub fn unset_pressed_old_pos(&mut self, call_on_id: CallOnId)
-    { self.pressed_old_poses.remove(&call_on_id); }
+    { self.pressed_old_poses.remove(&(self.current_seat_id, call_on_id)); }
 
     /// Returns `true` if a double click occurred by the pointer, otherwise `false`.
     pub fn has_double_click(&self) -> bool
-    { self.has_double_click }
+    { self.double_click_seat_ids.contains(&self.current_seat_id) }
 
     /// Sets the double click flag for the pointer.
     pub fn set_double_click(&mut self, flag: bool)
-    { self.has_double_click = flag }
+    {
+        if flag {
+            self.double_click_seat_ids.insert(self.current_seat_id);
+        } else {
+            self.double_click_seat_ids.remove(&self.current_seat_id);
+        }
+    }
     
     /// Returns `true` if a long click occurred by the pointer, otherwise `false`.
     pub fn has_long_click(&self) -> bool
-    { self.has_long_click }
+    { self.long_click_seat_ids.contains(&self.current_seat_id) }
 
     /// Sets the long click flag for the pointer.
     pub fn set_long_click(&mut self, flag: bool)
-    { self.has_long_click = flag }
+    {
+        if flag {
+            self.long_click_seat_ids.insert(self.current_seat_id);
+        } else {
+            self.long_click_seat_ids.remove(&self.current_seat_id);
+        }
+    }
     
     /// Adds an active identifier for the specified call-on path.
     ///
     /// This method returns `true` if a new set of active identidfiers is created while add the
     /// active identifier, otherwise `false`. The set of active identifier contains the active
-    /// identifiers for the widget or the window.
+    /// identifiers of all seats for the widget or the window.
     pub fn add_active_id(&mut self, call_on_path: &CallOnPath, active_id: ActiveId) -> bool
     {
         match self.active_id_sets.get_mut(call_on_path) {
             Some(set) => {
-                set.insert(active_id);
+                set.insert((self.current_seat_id, active_id));
                 false
             },
             None => {
-                let mut set: BTreeSet<ActiveId> = BTreeSet::new();
-                set.insert(active_id);
+                let mut set: BTreeSet<(Option<SeatId>, ActiveId)> = BTreeSet::new();
+                set.insert((self.current_seat_id, active_id));
                 self.active_id_sets.insert(call_on_path.clone(), set);
                 true
             },
@@ -348,12 +382,12 @@ impl QueueContext
     ///
     /// This method returns `true` if the set of active identifiers is removed while remove the
     /// active identifier, otherwise `false`. The set of active identifier contains the active
-    /// identifiers for the widget or the window.
+    /// identifiers of all seats for the widget or the window.
     pub fn remove_active_id(&mut self, call_on_path: &CallOnPath, active_id: ActiveId) -> bool
     {
         match self.active_id_sets.get_mut(call_on_path) {
             Some(set) => {
-                set.remove(&active_id);
+                set.remove(&(self.current_seat_id, active_id));
                 if set.is_empty() {
                     self.active_id_sets.remove(call_on_path);
                     true
@@ -368,7 +402,7 @@ impl QueueContext
     pub(crate) fn clear_for_client_windows_to_destroy(&mut self, client_windows_to_destroy: &BTreeMap<WindowIndex, Box<ClientWindow>>)
     {
         if !client_windows_to_destroy.is_empty() {
-            let motion_call_on_ids: Vec<CallOnId> = self.motion_call_on_paths.iter().filter(|p| {
+            let motion_call_on_ids: Vec<(Option<SeatId>, CallOnId)> = self.motion_call_on_paths.iter().filter(|p| {
                     client_windows_to_destroy.keys().any(|i| *i == p.1.window_index())
             }).map(|p| *(p.0)).collect();
             for call_on_id in &motion_call_on_ids {
@@ -377,7 +411,7 @@ impl QueueContext
             for call_on_id in &motion_call_on_ids {
                 self.motion_resize_edge_map.remove(call_on_id);
             }
-            let pressed_call_on_ids: Vec<CallOnId> = self.pressed_call_on_paths.iter().filter(|p| {
+            let pressed_call_on_ids: Vec<(Option<SeatId>, CallOnId)> = self.pressed_call_on_paths.iter().filter(|p| {
                     client_windows_to_destroy.keys().any(|i| *i == p.1.window_index())
             }).map(|p| *(p.0)).collect();
             for call_on_id in &pressed_call_on_ids {
@@ -401,6 +435,44 @@ impl QueueContext
         }
     }
 
+    /// Removes the motion call-on paths, the pressed call-on paths, the click flags, and the
+    /// active identifiers of the removed seat.
+    ///
+    /// This method returns the call-on paths that lost the active identifiers or the motion
+    /// call-on paths of the removed seat, so states of these widgets can be reset.
+    pub(crate) fn clear_for_seat(&mut self, seat_id: SeatId) -> BTreeSet<CallOnPath>
+    {
+        let mut call_on_paths: BTreeSet<CallOnPath> = BTreeSet::new();
+        let seat_id = Some(seat_id);
+        self.motion_call_on_paths.retain(|k, v| {
+                if k.0 == seat_id {
+                    call_on_paths.insert(v.clone());
+                    false
+                } else {
+                    true
+                }
+        });
+        self.motion_resize_edge_map.retain(|k, _| k.0 != seat_id);
+        self.pressed_call_on_paths.retain(|k, _| k.0 != seat_id);
+        self.pressed_instants.retain(|k, _| k.0 != seat_id);
+        self.pressed_call_on_elems.retain(|k, _| k.0 != seat_id);
+        self.pressed_old_poses.retain(|k, _| k.0 != seat_id);
+        self.double_click_seat_ids.remove(&seat_id);
+        self.long_click_seat_ids.remove(&seat_id);
+        self.active_id_sets.retain(|k, set| {
+                let old_len = set.len();
+                set.retain(|p| p.0 != seat_id);
+                if set.len() != old_len {
+                    call_on_paths.insert(k.clone());
+                }
+                !set.is_empty()
+        });
+        if self.current_seat_id == seat_id {
+            self.current_seat_id = None;
+        }
+        call_on_paths
+    }
+
     /// Returns `true` if the wait cursor is set, otherwise `false`.
     pub fn has_wait_cursor(&self) -> bool
     { self.has_wait_cursor }
@@ -411,24 +483,83 @@ impl QueueContext
     
     /// Pushes event to the event queue for the current call-on path.
     ///
-    /// This method returns `Some(())` if the current call-on path exists, otherwise `None`.
+    /// The event is pushed with the seat identifier of the current event. This method returns
+    /// `Some(())` if the current call-on path exists, otherwise `None`.
     pub fn push_event(&mut self, event: Event) -> Option<()>
     {
         match self.current_call_on_path.clone() {
             Some(call_on_path) => {
-                self.event_queue.push(EventPair::new(call_on_path, event));
+                self.event_queue.push(EventPair::new_with_seat_id(call_on_path, event, self.current_seat_id));
                 Some(())
             },
             None => None,
         }
     }
 
-    /// See [`CallbackQueue::push_dyn`].
+    /// Pushes event to the event queue for the call-on path.
+    ///
+    /// The event is pushed with the seat identifier of the current event.
+    pub fn push_event_for_call_on_path(&mut self, call_on_path: CallOnPath, event: Event)
+    { self.event_queue.push(EventPair::new_with_seat_id(call_on_path, event, self.current_seat_id)); }
+
+    /// Pushes a dynamic callback with the seat identifier of the current event to the callback
+    /// queue.
+    ///
+    /// See [`CallbackQueue::push_dyn_with_seat_id`].
     pub fn push_dyn_callback(&mut self, f: Box<dyn FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext) -> Option<()> + Send + Sync + 'static>)
-    { self.callback_queue.push_dyn(f); }
+    { self.callback_queue.push_dyn_with_seat_id(self.current_seat_id, f); }
 
-    /// See [`CallbackQueue::push`].
+    /// Pushes a callback with the seat identifier of the current event to the callback queue.
+    ///
+    /// See [`CallbackQueue::push_with_seat_id`].
     pub fn push_callback<F>(&mut self, f: F)
         where F: FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext) -> Option<()> + Send + Sync + 'static
-    { self.callback_queue.push(f); }
+    { self.callback_queue.push_with_seat_id(self.current_seat_id, f); }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_queue_context_clears_for_seat()
+    {
+        let mut queue_context = QueueContext::new();
+        let call_on_path1 = CallOnPath::Widget(AbsWidgetPath::new(WindowIndex(0), WidgetIndexPair(0, 0)));
+        let call_on_path2 = CallOnPath::Widget(AbsWidgetPath::new(WindowIndex(0), WidgetIndexPair(1, 0)));
+        let call_on_path3 = CallOnPath::Window(WindowIndex(1));
+        queue_context.current_seat_id = Some(SeatId(1));
+        queue_context.set_motion_call_on_path(CallOnId::Pointer, call_on_path1.clone());
+        queue_context.set_pressed_call_on_path(CallOnId::Pointer, call_on_path1.clone());
+        queue_context.set_pressed_call_on_elem(CallOnId::Pointer, CallOnElem::Trough);
+        queue_context.set_double_click(true);
+        queue_context.add_active_id(&call_on_path1, ActiveId::CallOnId(CallOnId::Pointer));
+        queue_context.add_active_id(&call_on_path2, ActiveId::CallOnId(CallOnId::Pointer));
+        queue_context.current_seat_id = Some(SeatId(2));
+        queue_context.set_motion_call_on_path(CallOnId::Pointer, call_on_path3.clone());
+        queue_context.set_pressed_call_on_path(CallOnId::Pointer, call_on_path2.clone());
+        queue_context.add_active_id(&call_on_path2, ActiveId::CallOnId(CallOnId::Pointer));
+        queue_context.current_seat_id = Some(SeatId(1));
+        let call_on_paths = queue_context.clear_for_seat(SeatId(1));
+        let mut expected_call_on_paths: BTreeSet<CallOnPath> = BTreeSet::new();
+        expected_call_on_paths.insert(call_on_path1.clone());
+        expected_call_on_paths.insert(call_on_path2.clone());
+        assert_eq!(expected_call_on_paths, call_on_paths);
+        assert_eq!(None, queue_context.current_seat_id);
+        assert_eq!(false, queue_context.motion_call_on_paths.contains_key(&(Some(SeatId(1)), CallOnId::Pointer)));
+        assert_eq!(false, queue_context.pressed_call_on_paths.contains_key(&(Some(SeatId(1)), CallOnId::Pointer)));
+        assert_eq!(false, queue_context.pressed_call_on_elems.contains_key(&(Some(SeatId(1)), CallOnId::Pointer)));
+        assert_eq!(false, queue_context.double_click_seat_ids.contains(&Some(SeatId(1))));
+        assert_eq!(false, queue_context.active_id_sets.contains_key(&call_on_path1));
+        assert_eq!(Some(&call_on_path3), queue_context.motion_call_on_paths.get(&(Some(SeatId(2)), CallOnId::Pointer)));
+        assert_eq!(Some(&call_on_path2), queue_context.pressed_call_on_paths.get(&(Some(SeatId(2)), CallOnId::Pointer)));
+        match queue_context.active_id_sets.get(&call_on_path2) {
+            Some(set) => {
+                assert_eq!(1, set.len());
+                assert_eq!(true, set.contains(&(Some(SeatId(2)), ActiveId::CallOnId(CallOnId::Pointer))));
+            },
+            None => assert!(false),
+        }
+    }
 }
@@ -266,6 +266,16 @@ impl Text
     
     pub fn max_line_width(&self) -> i32
     { self.lines.iter().fold(0, |w, l| max(w, l.width)) }
+
+    /// Returns an index in the line for the index in the text or `None` if the index in the text
+    /// isn't in the line.
+    pub fn index_in_line(&self, line_idx: usize, idx: usize) -> Option<usize>
+    {
+        match self.lines.get(line_idx) {
+            Some(line) if line.start <= idx && idx < line.end => Some(idx - line.start),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1164,4 +1174,25 @@ mod tests
             Err(_) => assert!(false),
         }
     }    
+
+    #[test]
+    fn test_text_returns_index_in_line()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        cairo_context.select_font_face("Sans", FontSlant::Normal, FontWeight::Normal);
+        cairo_context.set_font_size(16.0);
+        let mut text = Text::new("abc\ndef", TextAlign::Left);
+        match text.update_size(&cairo_context, Size::new(None, None), |_| Ok(())) {
+            Ok(()) => {
+                assert_eq!(2, text.lines.len());
+                assert_eq!(Some(1), text.index_in_line(0, 1));
+                assert_eq!(None, text.index_in_line(0, 5));
+                assert_eq!(Some(1), text.index_in_line(1, 5));
+                assert_eq!(None, text.index_in_line(1, 1));
+                assert_eq!(None, text.index_in_line(2, 1));
+            },
+            Err(_) => assert!(false),
+        }
+    }
 }
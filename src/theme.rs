@@ -38,7 +38,7 @@ impl ScrollBarElems
 
 pub trait Theme: Send + Sync
 {
-    fn set_cairo_context(&self, cairo_context: &CairoContext, scale: i32) -> Result<(), CairoError>;
+    fn set_cairo_context(&self, cairo_context: &CairoContext, scale: f64) -> Result<(), CairoError>;
 
     fn toplevel_window_edges(&self) -> Edges<i32>;
 
@@ -48,6 +48,26 @@ pub trait Theme: Send + Sync
 
     fn draw_toplevel_window_content_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, is_focused_window: bool, is_tool_bar: bool) -> Result<(), CairoError>;
 
+    fn layer_window_edges(&self) -> Edges<i32>;
+
+    fn draw_layer_window_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn popup_window_edges(&self) -> Edges<i32>;
+
+    fn draw_popup_window_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn menu_edges(&self) -> Edges<i32>;
+
+    fn draw_menu_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn tooltip_edges(&self) -> Edges<i32>;
+
+    fn draw_tooltip_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn set_tooltip_font(&self, cairo_context: &CairoContext) -> Result<(), CairoError>;
+
+    fn draw_tooltip_text(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, is_focused_window: bool) -> Result<(), CairoError>;
+
     fn draw_title_bar_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, state: WidgetState, is_enabled: bool, is_focused_window: bool) -> Result<(), CairoError>;
 
     fn title_margin_edges(&self) -> Edges<i32>;
@@ -81,6 +101,8 @@ pub trait Theme: Send + Sync
     fn set_label_font(&self, cairo_context: &CairoContext) -> Result<(), CairoError>;
     
     fn draw_label_text(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, state: WidgetState, is_enabled: bool, is_focused_window: bool) -> Result<(), CairoError>;    
+
+    fn draw_preedit_text(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, is_enabled: bool, is_focused_window: bool) -> Result<(), CairoError>;
     
     fn button_margin_edges(&self) -> Edges<i32>;
 
@@ -117,6 +139,46 @@ pub trait Theme: Send + Sync
     fn set_radio_font(&self, cairo_context: &CairoContext) -> Result<(), CairoError>;
     
     fn draw_radio_text(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, is_selected: bool, state: WidgetState, is_enabled: bool, is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>;    
+
+    fn draw_menu_bar_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, state: WidgetState, is_enabled: bool, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn menu_bar_item_margin_edges(&self) -> Edges<i32>;
+
+    fn menu_bar_item_padding_edges(&self) -> Edges<i32>;
+
+    fn draw_menu_bar_item_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, state: WidgetState, is_enabled: bool, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn set_menu_bar_item_font(&self, cairo_context: &CairoContext) -> Result<(), CairoError>;
+
+    fn draw_menu_bar_item_text(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, mnemonic_idx: Option<usize>, state: WidgetState, is_enabled: bool, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn menu_item_margin_edges(&self) -> Edges<i32>;
+
+    fn menu_item_padding_edges(&self) -> Edges<i32>;
+
+    fn menu_item_sep_width(&self) -> i32;
+
+    fn menu_item_icon_size(&self) -> Size<i32>;
+
+    fn menu_item_arrow_size(&self) -> Size<i32>;
+
+    fn menu_item_separator_height(&self) -> i32;
+
+    fn draw_menu_item_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, state: WidgetState, is_enabled: bool, is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn draw_menu_item_separator(&self, cairo_context: &CairoContext, bounds: Rect<i32>, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn draw_menu_item_check(&self, cairo_context: &CairoContext, pos: Pos<i32>, is_checked: bool, state: WidgetState, is_enabled: bool, is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn draw_menu_item_radio(&self, cairo_context: &CairoContext, pos: Pos<i32>, is_selected: bool, state: WidgetState, is_enabled: bool, is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn draw_menu_item_arrow(&self, cairo_context: &CairoContext, pos: Pos<i32>, state: WidgetState, is_enabled: bool, is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn set_menu_item_font(&self, cairo_context: &CairoContext) -> Result<(), CairoError>;
+
+    fn draw_menu_item_text(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, mnemonic_idx: Option<usize>, state: WidgetState, is_enabled: bool, is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>;
+
+    fn draw_menu_item_shortcut_text(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, state: WidgetState, is_enabled: bool, is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>;
     
     fn draw_linear_layout_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, orient: Orient, state: WidgetState, is_enabled: bool, is_focused_window: bool) -> Result<(), CairoError>;
 
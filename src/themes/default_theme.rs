@@ -5,6 +5,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 //
+use std::cmp::max;
 use std::f64::consts::PI;
 use cairo::FontSlant;
 use cairo::FontWeight;
@@ -22,6 +23,10 @@ const RADIO_SIZE: i32 = 12;
 
 const SCROLL_BAR_BUTTON_ICON_SIZE: i32 = 8;
 
+const MENU_ITEM_ICON_SIZE: i32 = 16;
+
+const MENU_ITEM_ARROW_SIZE: i32 = 8;
+
 pub struct DefaultTheme
 {
     // Background colors.
@@ -501,6 +506,27 @@ impl DefaultTheme
         }
         Ok(())
     }
+
+    fn draw_mnemonic_underline(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, mnemonic_idx: Option<usize>) -> Result<(), CairoError>
+    {
+        match mnemonic_idx {
+            Some(mnemonic_idx) if mnemonic_idx < s.len() => {
+                let c_len = match s[mnemonic_idx..].chars().next() {
+                    Some(c) => c.len_utf8(),
+                    None => return Ok(()),
+                };
+                let font_extents = cairo_context.font_extents()?;
+                let prefix_text_extents = cairo_context.text_extents(&s[..mnemonic_idx])?;
+                let c_text_extents = cairo_context.text_extents(&s[mnemonic_idx..(mnemonic_idx + c_len)])?;
+                cairo_context.set_line_width(1.0);
+                cairo_context.move_to((pos.x as f64) + prefix_text_extents.x_advance, (pos.y as f64) + font_extents.ascent + 1.5);
+                cairo_context.rel_line_to(c_text_extents.x_advance, 0.0);
+                cairo_context.stroke()?;
+            },
+            _ => (),
+        }
+        Ok(())
+    }
     
     fn draw_scroll_bar_first_button_icon(&self, cairo_context: &CairoContext, pos: Pos<i32>, orient: Orient, is_enabled: bool, is_focused_window: bool) -> Result<(), CairoError>
     {
@@ -551,10 +577,11 @@ impl DefaultTheme
 
 impl Theme for DefaultTheme
 {
-    fn set_cairo_context(&self, cairo_context: &CairoContext, scale: i32) -> Result<(), CairoError>
+    fn set_cairo_context(&self, cairo_context: &CairoContext, scale: f64) -> Result<(), CairoError>
     {
-        cairo_context.scale(scale as f64, scale as f64);
-        cairo_context.set_line_width(2.0);
+        cairo_context.scale(scale, scale);
+        // The line width is snapped to device pixels for a fractional scale.
+        cairo_context.set_line_width(max((2.0 * scale).round() as i32, 1) as f64 / scale);
         cairo_context.select_font_face("Sans", FontSlant::Normal, FontWeight::Normal);
         cairo_context.set_font_size(16.0);
         Ok(())
@@ -599,6 +626,74 @@ impl Theme for DefaultTheme
         Ok(())
     }
 
+    fn layer_window_edges(&self) -> Edges<i32>
+    { Edges::new(2, 2, 2, 2) }
+
+    fn draw_layer_window_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        self.set_bg_cairo_color(cairo_context);
+        cairo_context.rectangle(bounds.x as f64, bounds.y as f64, bounds.width as f64, bounds.height as f64);
+        cairo_context.fill()?;
+        self.set_border_cairo_color(cairo_context, true, false, is_focused_window);
+        cairo_context.rectangle((bounds.x as f64) + 1.0, (bounds.y as f64) + 1.0, (bounds.width as f64) - 2.0, (bounds.height as f64) - 2.0);
+        cairo_context.stroke()?;
+        Ok(())
+    }
+
+    fn popup_window_edges(&self) -> Edges<i32>
+    { Edges::new(2, 2, 2, 2) }
+
+    fn draw_popup_window_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        self.set_bg_cairo_color(cairo_context);
+        cairo_context.rectangle(bounds.x as f64, bounds.y as f64, bounds.width as f64, bounds.height as f64);
+        cairo_context.fill()?;
+        self.set_border_cairo_color(cairo_context, true, false, is_focused_window);
+        cairo_context.rectangle((bounds.x as f64) + 1.0, (bounds.y as f64) + 1.0, (bounds.width as f64) - 2.0, (bounds.height as f64) - 2.0);
+        cairo_context.stroke()?;
+        Ok(())
+    }
+
+    fn menu_edges(&self) -> Edges<i32>
+    { Edges::new(2, 2, 2, 2) }
+
+    fn draw_menu_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        self.set_bg_cairo_color(cairo_context);
+        cairo_context.rectangle(bounds.x as f64, bounds.y as f64, bounds.width as f64, bounds.height as f64);
+        cairo_context.fill()?;
+        self.set_border_cairo_color(cairo_context, true, false, is_focused_window);
+        cairo_context.rectangle((bounds.x as f64) + 1.0, (bounds.y as f64) + 1.0, (bounds.width as f64) - 2.0, (bounds.height as f64) - 2.0);
+        cairo_context.stroke()?;
+        Ok(())
+    }
+
+    fn tooltip_edges(&self) -> Edges<i32>
+    { Edges::new(4, 4, 6, 6) }
+
+    fn draw_tooltip_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, _is_focused_window: bool) -> Result<(), CairoError>
+    {
+        self.set_light_bg_cairo_color(cairo_context, true);
+        cairo_context.rectangle(bounds.x as f64, bounds.y as f64, bounds.width as f64, bounds.height as f64);
+        cairo_context.fill()?;
+        self.set_border_cairo_color(cairo_context, true, false, true);
+        cairo_context.rectangle((bounds.x as f64) + 1.0, (bounds.y as f64) + 1.0, (bounds.width as f64) - 2.0, (bounds.height as f64) - 2.0);
+        cairo_context.stroke()?;
+        Ok(())
+    }
+
+    fn set_tooltip_font(&self, _cairo_context: &CairoContext) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn draw_tooltip_text(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, _is_focused_window: bool) -> Result<(), CairoError>
+    {
+        let font_extents = cairo_context.font_extents()?;
+        self.set_fg_cairo_color(cairo_context, true, true);
+        cairo_context.move_to(pos.x as f64, (pos.y as f64) + font_extents.ascent);
+        cairo_context.show_text(s)?;
+        Ok(())
+    }
+
     fn draw_title_bar_bg(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _state: WidgetState, _is_enabled: bool, _is_focused_window: bool) -> Result<(), CairoError>
     { Ok(()) }
 
@@ -683,6 +778,20 @@ impl Theme for DefaultTheme
         cairo_context.show_text(s)?;
         Ok(())
     }
+
+    fn draw_preedit_text(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, is_enabled: bool, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        let font_extents = cairo_context.font_extents()?;
+        let text_extents = cairo_context.text_extents(s)?;
+        self.set_fg_cairo_color(cairo_context, is_enabled, is_focused_window);
+        cairo_context.move_to(pos.x as f64, (pos.y as f64) + font_extents.ascent);
+        cairo_context.show_text(s)?;
+        cairo_context.set_line_width(1.0);
+        cairo_context.move_to(pos.x as f64, (pos.y as f64) + font_extents.ascent + 1.5);
+        cairo_context.rel_line_to(text_extents.x_advance, 0.0);
+        cairo_context.stroke()?;
+        Ok(())
+    }
     
     fn button_margin_edges(&self) -> Edges<i32>
     { Edges::new(2, 2, 2, 2) }
@@ -792,6 +901,126 @@ impl Theme for DefaultTheme
         Ok(())
     }
 
+    fn draw_menu_bar_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, _state: WidgetState, _is_enabled: bool, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        self.set_border_cairo_color(cairo_context, true, false, is_focused_window);
+        cairo_context.set_line_width(1.0);
+        cairo_context.move_to(bounds.x as f64, ((bounds.y + bounds.height) as f64) - 0.5);
+        cairo_context.rel_line_to(bounds.width as f64, 0.0);
+        cairo_context.stroke()?;
+        Ok(())
+    }
+
+    fn menu_bar_item_margin_edges(&self) -> Edges<i32>
+    { Edges::new(0, 0, 0, 0) }
+
+    fn menu_bar_item_padding_edges(&self) -> Edges<i32>
+    { Edges::new(6, 6, 4, 4) }
+
+    fn draw_menu_bar_item_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, state: WidgetState, is_enabled: bool, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        if self.set_state_cairo_color(cairo_context, state, is_enabled, is_focused_window) {
+            cairo_context.rectangle(bounds.x as f64, bounds.y as f64, bounds.width as f64, bounds.height as f64); 
+            cairo_context.fill()?;
+        }
+        Ok(())
+    }
+
+    fn set_menu_bar_item_font(&self, _cairo_context: &CairoContext) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn draw_menu_bar_item_text(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, mnemonic_idx: Option<usize>, _state: WidgetState, is_enabled: bool, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        let font_extents = cairo_context.font_extents()?;
+        self.set_fg_cairo_color(cairo_context, is_enabled, is_focused_window);
+        cairo_context.move_to(pos.x as f64, (pos.y as f64) + font_extents.ascent);
+        cairo_context.show_text(s)?;
+        self.draw_mnemonic_underline(cairo_context, pos, s, mnemonic_idx)?;
+        Ok(())
+    }
+
+    fn menu_item_margin_edges(&self) -> Edges<i32>
+    { Edges::new(0, 0, 0, 0) }
+
+    fn menu_item_padding_edges(&self) -> Edges<i32>
+    { Edges::new(4, 4, 4, 4) }
+
+    fn menu_item_sep_width(&self) -> i32
+    { 8 }
+
+    fn menu_item_icon_size(&self) -> Size<i32>
+    { Size::new(MENU_ITEM_ICON_SIZE, MENU_ITEM_ICON_SIZE) }
+
+    fn menu_item_arrow_size(&self) -> Size<i32>
+    { Size::new(MENU_ITEM_ARROW_SIZE, MENU_ITEM_ARROW_SIZE) }
+
+    fn menu_item_separator_height(&self) -> i32
+    { 8 }
+
+    fn draw_menu_item_bg(&self, cairo_context: &CairoContext, bounds: Rect<i32>, state: WidgetState, is_enabled: bool, is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        if is_enabled && is_focused {
+            set_cairo_color(cairo_context, self.selected_bg_color);
+            cairo_context.rectangle(bounds.x as f64, bounds.y as f64, bounds.width as f64, bounds.height as f64); 
+            cairo_context.fill()?;
+        } else if self.set_state_cairo_color(cairo_context, state, is_enabled, is_focused_window) {
+            cairo_context.rectangle(bounds.x as f64, bounds.y as f64, bounds.width as f64, bounds.height as f64); 
+            cairo_context.fill()?;
+        }
+        Ok(())
+    }
+
+    fn draw_menu_item_separator(&self, cairo_context: &CairoContext, bounds: Rect<i32>, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        self.set_border_cairo_color(cairo_context, true, false, is_focused_window);
+        cairo_context.set_line_width(1.0);
+        cairo_context.move_to(bounds.x as f64, (bounds.y as f64) + ((bounds.height / 2) as f64) + 0.5);
+        cairo_context.rel_line_to(bounds.width as f64, 0.0);
+        cairo_context.stroke()?;
+        Ok(())
+    }
+
+    fn draw_menu_item_check(&self, cairo_context: &CairoContext, pos: Pos<i32>, is_checked: bool, _state: WidgetState, is_enabled: bool, _is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>
+    { self.draw_check(cairo_context, Pos::new(pos.x + (MENU_ITEM_ICON_SIZE - CHECK_SIZE) / 2, pos.y + (MENU_ITEM_ICON_SIZE - CHECK_SIZE) / 2), is_checked, is_enabled, is_focused_window) }
+
+    fn draw_menu_item_radio(&self, cairo_context: &CairoContext, pos: Pos<i32>, is_selected: bool, _state: WidgetState, is_enabled: bool, _is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>
+    { self.draw_radio(cairo_context, Pos::new(pos.x + (MENU_ITEM_ICON_SIZE - RADIO_SIZE) / 2, pos.y + (MENU_ITEM_ICON_SIZE - RADIO_SIZE) / 2), is_selected, is_enabled, is_focused_window) }
+
+    fn draw_menu_item_arrow(&self, cairo_context: &CairoContext, pos: Pos<i32>, _state: WidgetState, is_enabled: bool, _is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        let x = pos.x as f64;
+        let y = pos.y as f64;
+        self.set_fg_cairo_color(cairo_context, is_enabled, is_focused_window);
+        cairo_context.move_to(x + 2.0, y);
+        cairo_context.line_to(x + 6.0, y + 4.0);
+        cairo_context.line_to(x + 2.0, y + 8.0);
+        cairo_context.line_to(x + 2.0, y);
+        cairo_context.fill()?;
+        Ok(())
+    }
+
+    fn set_menu_item_font(&self, _cairo_context: &CairoContext) -> Result<(), CairoError>
+    { Ok(()) }
+
+    fn draw_menu_item_text(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, mnemonic_idx: Option<usize>, _state: WidgetState, is_enabled: bool, _is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        let font_extents = cairo_context.font_extents()?;
+        self.set_fg_cairo_color(cairo_context, is_enabled, is_focused_window);
+        cairo_context.move_to(pos.x as f64, (pos.y as f64) + font_extents.ascent);
+        cairo_context.show_text(s)?;
+        self.draw_mnemonic_underline(cairo_context, pos, s, mnemonic_idx)?;
+        Ok(())
+    }
+
+    fn draw_menu_item_shortcut_text(&self, cairo_context: &CairoContext, pos: Pos<i32>, s: &str, _state: WidgetState, is_enabled: bool, _is_focused: bool, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        let font_extents = cairo_context.font_extents()?;
+        self.set_fg_cairo_color(cairo_context, is_enabled, is_focused_window);
+        cairo_context.move_to(pos.x as f64, (pos.y as f64) + font_extents.ascent);
+        cairo_context.show_text(s)?;
+        Ok(())
+    }
+
     fn draw_linear_layout_bg(&self, _cairo_context: &CairoContext, _bounds: Rect<i32>, _orient: Orient, _state: WidgetState, _is_enabled: bool, _is_focused_window: bool) -> Result<(), CairoError>
     { Ok(()) }
 
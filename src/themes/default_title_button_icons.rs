@@ -49,6 +49,12 @@ pub fn draw_default_title_button_icon(cairo_context: &CairoContext, theme: &dyn
             cairo_context.line_to(x + 12.0, y + 10.0);
             cairo_context.stroke()?;
         },
+        TitleButtonIcon::Minimize => {
+            theme.set_fg(cairo_context, state, is_enabled, is_focused, is_focused_window)?;
+            cairo_context.move_to(x + 1.0, y + 10.0);
+            cairo_context.line_to(x + 11.0, y + 10.0);
+            cairo_context.stroke()?;
+        },
     }
     cairo_context.restore()?;
     Ok(())
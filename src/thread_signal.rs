@@ -22,6 +22,8 @@ pub(crate) enum ThreadTimer
     Touch,
     TextCursor,
     PostButtonRelease,
+    KineticScroll,
+    Tooltip,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -51,6 +53,8 @@ impl ThreadSignalSender
             ThreadTimer::Touch => buf[0] = 3,
             ThreadTimer::TextCursor => buf[0] = 4,
             ThreadTimer::PostButtonRelease => buf[0] = 5,
+            ThreadTimer::KineticScroll => buf[0] = 6,
+            ThreadTimer::Tooltip => buf[0] = 7,
         }
         match write(self.0, &buf) {
             Ok(_) => Ok(()),
@@ -64,7 +68,7 @@ impl ThreadSignalSender
     /// for the graphic thread.
     pub fn commit(&self) -> Result<(), ClientError>
     {
-        let mut buf: [u8; 1] = [4];
+        let mut buf: [u8; 1] = [255];
         match write(self.0, &mut buf) {
             Ok(_) => Ok(()),
             Err(err) => Err(ClientError::Nix(err)),
@@ -103,6 +107,10 @@ impl ThreadSignalReceiver
                     Ok(Some(ThreadSignal::Timer(ThreadTimer::TextCursor)))
                 } else if buf[0] == 5 {
                     Ok(Some(ThreadSignal::Timer(ThreadTimer::PostButtonRelease)))
+                } else if buf[0] == 6 {
+                    Ok(Some(ThreadSignal::Timer(ThreadTimer::KineticScroll)))
+                } else if buf[0] == 7 {
+                    Ok(Some(ThreadSignal::Timer(ThreadTimer::Tooltip)))
                 } else {
                     Ok(Some(ThreadSignal::Other))
                 }
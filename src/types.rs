@@ -564,6 +564,12 @@ impl Corners<f64>
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct WindowIndex(pub usize);
 
+/// A structure of seat identifier.
+///
+/// The seat identifier is a global name of a Wayland seat.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SeatId(pub u32);
+
 /// A structure of pair of widget indices.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct WidgetIndexPair(pub usize, pub usize);
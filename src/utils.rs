@@ -27,6 +27,7 @@ use crate::queue_context::*;
 use crate::types::*;
 use crate::widget::*;
 use crate::window::*;
+use crate::windows::*;
 
 mod call_on_fun;
 
@@ -60,6 +61,17 @@ pub fn with_dummy_cairo_context<T, F>(f: F) -> Result<T, CairoError>
 pub fn set_cairo_color(cairo_context: &CairoContext, color: Color)
 { cairo_context.set_source_rgba(color.red, color.green, color.blue, color.alpha); }
 
+/// Returns `true` if the rectangle intersects the clip area of the Cairo context, otherwise
+/// `false`.
+///
+/// This function can be used to skip drawing of widgets that are outside the dirty rectangles.
+pub fn is_in_cairo_clip(cairo_context: &CairoContext, rect: Rect<i32>) -> Result<bool, CairoError>
+{
+    let (x1, y1, x2, y2) = cairo_context.clip_extents()?;
+    let clip_rect = Rect::new(x1, y1, x2 - x1, y2 - y1);
+    Ok(clip_rect.intersection(rect.to_f64_rect()).is_some())
+}
+
 /// Returns the X offset of the horizontal scroll slider.
 pub fn h_scroll_bar_slider_x(client_x: i32, client_width: i32, viewport_width: i32, trough_width: i32) -> f64
 {
@@ -198,7 +210,19 @@ pub fn set_client_y_for_client_int(client_y: &mut ClientInt, client_height: Clie
 pub fn update_client_y_for_client_int(client_y: &mut ClientInt, client_height: ClientInt, viewport_height: i32) -> bool
 { update_client_x_for_client_int(client_y, client_height, viewport_height) }
 
+/// The MIME types of text that are read from a primary selection in the order of preference.
+const TEXT_MIME_TYPES: [&str; 4] = ["text/plain;charset=utf-8", "UTF8_STRING", "text/plain", "TEXT"];
+
+/// Returns the preferred MIME type of text from the MIME types or `None`.
+fn text_mime_type(mime_types: &[String]) -> Option<&'static str>
+{ TEXT_MIME_TYPES.iter().find(|mt| mime_types.iter().any(|mt2| mt2.as_str() == **mt)).map(|mt| *mt) }
+
 /// A part of default event handler for the widget and the client pointer.
+///
+/// A press of the middle pointer button on an enabled widget that accepts the text from an input
+/// method reads the text from the primary selection. The read text is sent to the widget as
+/// [`Event::TextCommit`]. Nothing is read if the compositor doesn't support primary selection or
+/// the primary selection doesn't have text.
 pub fn default_widget_on_for_client_pointer(widget: &mut dyn Widget, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
 {
     match event {
@@ -268,6 +292,19 @@ pub fn default_widget_on_for_client_pointer(widget: &mut dyn Widget, client_cont
             }
             Some(Some(None))
         },
+        Event::Client(ClientEvent::PointerButton(_, ClientButton::Middle, ClientState::Pressed)) if widget.is_text_input() && widget.is_enabled() => {
+            let call_on_path = queue_context.current_call_on_path()?.clone();
+            match text_mime_type(client_context.primary_selection_mime_types().as_slice()) {
+                Some(mime_type) => {
+                    let _res = client_context.read_primary_selection_data(mime_type, move |_, _, queue_context, data| {
+                            queue_context.push_event_for_call_on_path(call_on_path.clone(), Event::TextCommit(String::from_utf8_lossy(data).into_owned()));
+                            Some(())
+                    });
+                },
+                None => (),
+            }
+            Some(Some(None))
+        },
         Event::Client(ClientEvent::PointerButton(_, ClientButton::Left, ClientState::Pressed)) => {
             queue_context.set_pressed_call_on_path(CallOnId::Pointer, queue_context.current_call_on_path()?.clone());
             queue_context.set_pressed_instant(CallOnId::Pointer, Instant::now());
@@ -357,7 +394,7 @@ pub fn default_widget_on_for_client_pointer(widget: &mut dyn Widget, client_cont
                         None => (),
                     }
                     if is_enabled {
-                        queue_context.event_queue_mut().push(EventPair::new(current_call_on_path.clone(), Event::PopupClick));
+                        queue_context.push_event_for_call_on_path(current_call_on_path.clone(), Event::PopupClick);
                     }
                     Some(())
             });
@@ -386,6 +423,14 @@ pub fn default_widget_on_for_client_pointer(widget: &mut dyn Widget, client_cont
 
 /// A part of default event handler for the widget, the client pointer, the scroll bar, and the
 /// seek bar.
+///
+/// Pointer axis events are passed to the second closure. A scroll wheel scrolls by lines, so the
+/// second closure is called with [`CallOnElem::PrevLine`] or [`CallOnElem::NextLine`] for each
+/// discrete step. A value of the scroll wheel without discrete steps is ignored because such
+/// value is a part of one step of a high-resolution scroll wheel, and the whole step has discrete
+/// steps. Other axis sources such as a touchpad and a kinetic scrolling scroll by pixels,
+/// so the second closure is called with [`CallOnElem::Pixels`] and an old position and a new
+/// position.
 pub fn default_widget_on_for_client_pointer_and_scroll<F, G>(widget: &mut dyn Widget, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event, mut f: F, mut g: G) -> Option<Option<Option<Event>>>
     where F: FnMut(&dyn Widget, &mut ClientContext, &mut QueueContext, Pos<f64>) -> Option<CallOnElem> + Send + Sync + 'static,
           G: FnMut(&mut dyn Widget, &mut ClientContext, &mut QueueContext, CallOnElem, Option<Pos<f64>>, Pos<f64>) -> Option<()>  + Send + Sync + 'static
@@ -576,6 +621,45 @@ pub fn default_widget_on_for_client_pointer_and_scroll<F, G>(widget: &mut dyn Wi
             Some(Some(None))
         },
         Event::Client(ClientEvent::PointerButton(_, ClientButton::Right, ClientState::Released)) => Some(Some(None)),
+        Event::Client(ClientEvent::PointerAxis(_, axis, value, source, discrete)) => {
+            let axis = *axis;
+            let value = *value;
+            let discrete = *discrete;
+            match (source, discrete) {
+                (Some(ClientAxisSource::Wheel | ClientAxisSource::WheelTilt), None) => return Some(Some(None)),
+                (_, _) => (),
+            }
+            match queue_context.current_call_on_path() {
+                Some(CallOnPath::Widget(abs_widget_path)) => {
+                    let tmp_abs_widget_path = abs_widget_path.clone();
+                    queue_context.push_callback(move |client_context, window_context, queue_context| {
+                            let current_pos = window_context.current_pos()?;
+                            match discrete {
+                                Some(discrete) => {
+                                    let call_on_elem = if discrete < 0 {
+                                        CallOnElem::PrevLine(axis)
+                                    } else {
+                                        CallOnElem::NextLine(axis)
+                                    };
+                                    for _ in 0..discrete.abs() {
+                                        g(window_context.dyn_widget_mut(&tmp_abs_widget_path)?, client_context, queue_context, call_on_elem, None, current_pos)?;
+                                    }
+                                },
+                                None => {
+                                    let new_pos = match axis {
+                                        ClientAxis::VScroll => Pos::new(current_pos.x, current_pos.y + value),
+                                        ClientAxis::HScroll => Pos::new(current_pos.x + value, current_pos.y),
+                                    };
+                                    g(window_context.dyn_widget_mut(&tmp_abs_widget_path)?, client_context, queue_context, CallOnElem::Pixels(axis), Some(current_pos), new_pos)?;
+                                },
+                            }
+                            Some(())
+                    });
+                },
+                _ => return None,
+            }
+            Some(Some(None))
+        },
         Event::Client(ClientEvent::RepeatedButton) => {
             match queue_context.current_call_on_path() {
                 Some(CallOnPath::Widget(abs_widget_path)) => {
@@ -599,6 +683,23 @@ pub fn default_widget_on_for_client_pointer_and_scroll<F, G>(widget: &mut dyn Wi
     }
 }
 
+/// Pushes the events of text input for the text input done event.
+///
+/// The surrounding text is deleted before a commit of the committed text, and the preedit text is
+/// set after the commit as the text input protocol describes.
+fn push_events_for_text_input_done(queue_context: &mut QueueContext, deleted_lens: Option<(usize, usize)>, committed_text: Option<&str>, preedit_text: &str, preedit_cursor: Option<(usize, usize)>) -> Option<()>
+{
+    match deleted_lens {
+        Some((before_len, after_len)) => queue_context.push_event(Event::DeleteSurroundingText(before_len, after_len))?,
+        None => (),
+    }
+    match committed_text {
+        Some(committed_text) => queue_context.push_event(Event::TextCommit(String::from(committed_text)))?,
+        None => (),
+    }
+    queue_context.push_event(Event::Preedit(String::from(preedit_text), preedit_cursor))
+}
+
 /// A part of default event handler for the widget and the client keyboard.
 pub fn default_widget_on_for_client_keyboard(widget: &mut dyn Widget, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
 {
@@ -680,6 +781,10 @@ pub fn default_widget_on_for_client_keyboard(widget: &mut dyn Widget, client_con
             }
             Some(Some(None))
         },
+        Event::Client(ClientEvent::TextInputDone(deleted_lens, committed_text, preedit_text, preedit_cursor)) => {
+            push_events_for_text_input_done(queue_context, *deleted_lens, committed_text.as_ref().map(|s| s.as_str()), preedit_text.as_str(), *preedit_cursor)?;
+            Some(Some(None))
+        },
         _ => Some(None),
     }
 }
@@ -1000,7 +1105,7 @@ pub fn default_widget_on_for_key_and_char(widget: &mut dyn Widget, client_contex
 pub fn default_widget_on_for_window_events(widget: &mut dyn Widget, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
 {
     match event {
-        Event::Menu | Event::Close | Event::Maximize=> Some(Some(Some(event.clone()))),
+        Event::Menu | Event::Close | Event::Maximize | Event::Minimize => Some(Some(Some(event.clone()))),
         _ => Some(None),
     }
 }
@@ -1105,8 +1210,33 @@ pub fn cursor_for_client_resize_and_resizable(_edges: Option<ClientResize>, _is_
 pub fn default_window_on_for_client_shell_surface(window: &mut dyn Window, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
 {
     match event {
-        Event::Client(ClientEvent::ShellSurfaceConfigure(_, size)) => {
-            window.set_preferred_size(Size::new(Some(size.width), Some(size.height)));
+        Event::Client(ClientEvent::ShellSurfaceConfigure(_, size, states)) => {
+            let preferred_width = if size.width > 0 {
+                Some(size.width)
+            } else {
+                window.preferred_width()
+            };
+            let preferred_height = if size.height > 0 {
+                Some(size.height)
+            } else {
+                window.preferred_height()
+            };
+            window.set_preferred_size(Size::new(preferred_width, preferred_height));
+            if window.is_maximizable() {
+                window.set_maximized(states.contains(ClientToplevelStates::MAXIMIZED));
+            }
+            if window.is_fullscreenable() {
+                let output_name = window.fullscreen_output_name();
+                window.set_fullscreen(states.contains(ClientToplevelStates::FULLSCREEN), output_name);
+            }
+            window.set_toplevel_states(*states);
+            if states.contains(ClientToplevelStates::ACTIVATED) && !window.is_focused() {
+                queue_context.push_callback(move |_, window_context, _| {
+                        let current_window_idx = window_context.current_window_index()?;
+                        window_context.set_focused_window_index(Some(current_window_idx));
+                        Some(())
+                });
+            }
             Some(Some(None))
         },
         Event::Client(ClientEvent::ShellSurfacePopupDone) => {
@@ -1117,6 +1247,14 @@ pub fn default_window_on_for_client_shell_surface(window: &mut dyn Window, clien
             });
             Some(Some(None))
         },
+        Event::Client(ClientEvent::ShellSurfaceClose) => {
+            queue_context.push_event(Event::Close)?;
+            Some(Some(None))
+        },
+        Event::Client(ClientEvent::ToplevelDecorationConfigure(mode)) => {
+            window.set_decoration_mode(*mode);
+            Some(Some(None))
+        },
         _ => Some(None),
     }
 }
@@ -1265,7 +1403,7 @@ pub fn default_window_on_for_client_pointer(window: &mut dyn Window, client_cont
                         window_context.set_focused_window_index(Some(current_window_idx));
                     }
                     if !are_motion_resize_edges {
-                        queue_context.event_queue_mut().push(EventPair::new(current_call_on_path.clone(), Event::PopupClick));
+                        queue_context.push_event_for_call_on_path(current_call_on_path.clone(), Event::PopupClick);
                     }
                     Some(())
             });
@@ -1325,6 +1463,7 @@ pub fn default_window_on_for_client_keyboard(window: &mut dyn Window, client_con
             }
             Some(Some(None))
         },
+        Event::Client(ClientEvent::TextInputDone(_, _, _, _)) => Some(Some(None)),
         _ => Some(None),
     }
 }
@@ -1441,7 +1580,28 @@ pub fn default_window_on_for_clicks(window: &mut dyn Window, client_context: &mu
     }
 }
 
+fn push_context_menu_opening_for_focused_widget(window: &mut dyn Window, queue_context: &mut QueueContext) -> Option<()>
+{
+    let current_window_idx = queue_context.current_call_on_path()?.window_index();
+    window.update_focused_rel_widget_path();
+    let (call_on_path, anchor_rect) = match window.focused_rel_widget_path() {
+        Some(rel_widget_path) => {
+            let bounds = window.dyn_widget(rel_widget_path)?.bounds();
+            (CallOnPath::Widget(rel_widget_path.to_abs_widget_path(current_window_idx)), bounds)
+        },
+        None => (CallOnPath::Window(current_window_idx), window.padding_bounds()),
+    };
+    queue_context.push_callback(move |_, window_context, _| {
+            open_context_menu(window_context, &call_on_path, Some(anchor_rect));
+            Some(())
+    });
+    Some(())
+}
+
 /// A part of default event handler for the window and the key.
+///
+/// The menu key or the shift key with the F10 key opens the context menu for the focused widget
+/// or the window at the bounds of the focused widget.
 #[allow(unused_variables)]
 pub fn default_window_on_for_key(window: &mut dyn Window, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
 {
@@ -1469,6 +1629,18 @@ pub fn default_window_on_for_key(window: &mut dyn Window, client_context: &mut C
                         window.next_focused_widget()?;
                         Some(Some(None))
                     },
+                    VKey::Menu => {
+                        push_context_menu_opening_for_focused_widget(window, queue_context)?;
+                        Some(Some(None))
+                    },
+                    _ => Some(None),
+                }
+            } else if *modifiers == KeyModifiers::SHIFT {
+                match *key {
+                    VKey::F10 => {
+                        push_context_menu_opening_for_focused_widget(window, queue_context)?;
+                        Some(Some(None))
+                    },
                     _ => Some(None),
                 }
             } else if *modifiers == KeyModifiers::ALT {
@@ -1506,6 +1678,21 @@ pub fn default_window_on_for_maximize(window: &mut dyn Window, client_context: &
     }
 }
 
+/// A part of default event handler for the window and the window minimization.
+#[allow(unused_variables)]
+pub fn default_window_on_for_minimize(window: &mut dyn Window, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
+{
+    match event {
+        Event::Minimize => {
+            if window.is_minimizable() {
+                window.minimize();
+            }
+            Some(Some(None))
+        },
+        _ => Some(None),
+    }
+}
+
 /// A default event handler for the window.
 pub fn default_window_on(window: &mut dyn Window, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
 {
@@ -1523,6 +1710,8 @@ pub fn default_window_on(window: &mut dyn Window, client_context: &mut ClientCon
         Some(Some(res))
     } else if let Some(res) = default_window_on_for_maximize(window, client_context, queue_context, event)? {
         Some(Some(res))
+    } else if let Some(res) = default_window_on_for_minimize(window, client_context, queue_context, event)? {
+        Some(Some(res))
     } else {
         Some(None)
     }
@@ -1540,6 +1729,68 @@ pub fn is_mark_char(c: char) -> bool
 pub fn is_mark_char2(c: char) -> bool
 { c >= '\u{035c}' && c <= '\u{0362}' }
 
+/// Returns a text without underscores and an index of the mnemonic character for the text.
+///
+/// An underscore marks the next character as the mnemonic character and two underscores are
+/// replaced by one underscore. Only the first marked character is the mnemonic character. The
+/// index of the mnemonic character is a byte index in the returned text.
+///
+/// # Examples
+/// ```
+/// use lwltk::utils::mnemonic_text;
+///
+/// assert_eq!((String::from("File"), Some(0)), mnemonic_text("_File"));
+/// assert_eq!((String::from("Save as"), Some(5)), mnemonic_text("Save _as"));
+/// assert_eq!((String::from("a_b"), None), mnemonic_text("a__b"));
+/// assert_eq!((String::from("Edit"), None), mnemonic_text("Edit"));
+/// ```
+pub fn mnemonic_text(s: &str) -> (String, Option<usize>)
+{
+    let mut text = String::new();
+    let mut mnemonic_idx: Option<usize> = None;
+    let mut is_underscore = false;
+    for c in s.chars() {
+        if is_underscore {
+            if c != '_' && mnemonic_idx.is_none() {
+                mnemonic_idx = Some(text.len());
+            }
+            text.push(c);
+            is_underscore = false;
+        } else if c == '_' {
+            is_underscore = true;
+        } else {
+            text.push(c);
+        }
+    }
+    if is_underscore {
+        text.push('_');
+    }
+    (text, mnemonic_idx)
+}
+
+/// Returns the lowercase mnemonic character for the text and the index of the mnemonic character.
+///
+/// # Examples
+/// ```
+/// use lwltk::utils::mnemonic_char;
+///
+/// assert_eq!(Some('f'), mnemonic_char("File", Some(0)));
+/// assert_eq!(Some('a'), mnemonic_char("Save as", Some(5)));
+/// assert_eq!(None, mnemonic_char("Edit", None));
+/// ```
+pub fn mnemonic_char(s: &str, idx: Option<usize>) -> Option<char>
+{
+    match idx {
+        Some(idx) => {
+            match s.get(idx..) {
+                Some(t) => t.chars().next()?.to_lowercase().next(),
+                None => None,
+            }
+        },
+        None => None,
+    }
+}
+
 /// Returns a position of inner rectangle for the rectangle and the edges.
 ///
 /// # Examples
@@ -2606,3 +2857,68 @@ pub fn set_orient_rect_height<T>(rect: &mut Rect<T>, height: T, orient: Orient)
         Orient::Vertical => rect.width = height,
     }
 }
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_text_mime_type_returns_preferred_mime_type()
+    {
+        let mime_types = vec![String::from("text/html"), String::from("text/plain"), String::from("UTF8_STRING")];
+        assert_eq!(Some("UTF8_STRING"), text_mime_type(mime_types.as_slice()));
+        let mime_types = vec![String::from("text/plain;charset=utf-8"), String::from("text/plain")];
+        assert_eq!(Some("text/plain;charset=utf-8"), text_mime_type(mime_types.as_slice()));
+        let mime_types = vec![String::from("image/png")];
+        assert_eq!(None, text_mime_type(mime_types.as_slice()));
+    }
+
+    #[test]
+    fn test_push_events_for_text_input_done_pushes_events_in_order()
+    {
+        let mut queue_context = QueueContext::new();
+        let path = AbsWidgetPath::new(WindowIndex(1), WidgetIndexPair(0, 0));
+        queue_context.current_call_on_path = Some(CallOnPath::Widget(path.clone()));
+        match push_events_for_text_input_done(&mut queue_context, Some((2, 1)), Some("abc"), "de", Some((1, 2))) {
+            Some(()) => (),
+            None => assert!(false),
+        }
+        match queue_context.event_queue_mut().pop() {
+            Some(EventPair { call_on_path, event: Event::DeleteSurroundingText(2, 1), .. }) => assert_eq!(CallOnPath::Widget(path.clone()), call_on_path),
+            _ => assert!(false),
+        }
+        match queue_context.event_queue_mut().pop() {
+            Some(EventPair { call_on_path, event: Event::TextCommit(text), .. }) => {
+                assert_eq!(CallOnPath::Widget(path.clone()), call_on_path);
+                assert_eq!(String::from("abc"), text);
+            },
+            _ => assert!(false),
+        }
+        match queue_context.event_queue_mut().pop() {
+            Some(EventPair { call_on_path, event: Event::Preedit(text, Some((1, 2))), .. }) => {
+                assert_eq!(CallOnPath::Widget(path.clone()), call_on_path);
+                assert_eq!(String::from("de"), text);
+            },
+            _ => assert!(false),
+        }
+        assert_eq!(true, queue_context.event_queue().is_empty());
+    }
+
+    #[test]
+    fn test_push_events_for_text_input_done_pushes_only_preedit_without_deleted_lengths_and_committed_text()
+    {
+        let mut queue_context = QueueContext::new();
+        let path = AbsWidgetPath::new(WindowIndex(1), WidgetIndexPair(0, 0));
+        queue_context.current_call_on_path = Some(CallOnPath::Widget(path));
+        match push_events_for_text_input_done(&mut queue_context, None, None, "", None) {
+            Some(()) => (),
+            None => assert!(false),
+        }
+        match queue_context.event_queue_mut().pop() {
+            Some(EventPair { event: Event::Preedit(text, None), .. }) => assert_eq!(String::new(), text),
+            _ => assert!(false),
+        }
+        assert_eq!(true, queue_context.event_queue().is_empty());
+    }
+}
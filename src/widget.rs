@@ -76,6 +76,47 @@ pub trait Widget: Container + PreferredSize
     /// This method defaultly returns a result of the `is_clickable` method.
     fn is_clickable_by_key(&self) -> bool
     { self.is_clickable() }    
+
+    /// Returns `true` if the widget accepts the text from an input method, otherwise `false`.
+    ///
+    /// An input method is enabled if the focused widget accepts the text from the input method.
+    /// This method defaultly returns `false`.
+    fn is_text_input(&self) -> bool
+    { false }
+
+    /// Returns the rectangle of the text cursor in the window or `None`.
+    ///
+    /// The rectangle of the text cursor is used by an input method to place a candidate window.
+    /// This method defaultly returns `None`.
+    fn text_cursor_rect(&self) -> Option<Rect<i32>>
+    { None }
+
+    /// Returns the surrounding text of the text cursor or `None`.
+    ///
+    /// The following values are returned:
+    /// - the surrounding text
+    /// - an index of the text cursor in bytes
+    /// - an index of the selection anchor in bytes
+    ///
+    /// This method defaultly returns `None`.
+    fn surrounding_text(&self) -> Option<(String, usize, usize)>
+    { None }
+
+    /// Returns the index of the context menu for the widget or `None`.
+    ///
+    /// The context menu is opened for the widget and its descendants that don't have own context
+    /// menus. The index of the context menu is stored in the widget, so the context menu is moved
+    /// with the widget if the widget is moved in a layout. This method defaultly returns `None`.
+    fn context_menu_index(&self) -> Option<WindowIndex>
+    { None }
+
+    /// Returns the tooltip text for the widget or `None`.
+    ///
+    /// The tooltip with this text is shown for the widget and its descendants that don't have own
+    /// tooltip texts. The tooltip text is stored in the widget, so the tooltip is moved with the
+    /// widget if the widget is moved in a layout. This method defaultly returns `None`.
+    fn tooltip_text(&self) -> Option<&str>
+    { None }
     
     /// Returns the viewport size of the widget.
     ///
@@ -119,6 +160,30 @@ pub trait Widget: Container + PreferredSize
     /// This method doesn't set the referernce-counting pointer to the change flag for descendant
     /// widgets. This method shouldn't be direclty used by an application. 
     fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>);
+
+    /// Sets only the reference-counting pointer to the dirty flag.
+    ///
+    /// The dirty flag is set instead of the change flag if only the appearance of the widget is
+    /// changed. This method doesn't set the reference-counting pointer to the dirty flag for
+    /// descendant widgets. This method shouldn't be direclty used by an application. This method
+    /// defaultly doesn't set the reference-counting pointer to the dirty flag.
+    #[allow(unused_variables)]
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    {}
+
+    /// Returns the dirty rectangle of the widget or `None`.
+    ///
+    /// The dirty rectangle is an area of the window that should be redrawn. This method defaultly
+    /// returns `None`.
+    fn dirty_rect(&self) -> Option<Rect<i32>>
+    { None }
+
+    /// Clears the dirty rectangle of the widget.
+    ///
+    /// This method is called after redraw the window. This method defaultly doesn't clear the
+    /// dirty rectangle.
+    fn clear_dirty_rect(&mut self)
+    {}
     
     /// Returns the cursor of the widget.
     ///
@@ -185,13 +250,31 @@ pub trait Widget: Container + PreferredSize
     /// Sets the reference-counting pointer to the change flag.
     ///
     /// This method sets the referernce-counting pointer to the change flag for descendant widgets.
-    /// The change flag is used to checks whether the window should be redrawn. This method
+    /// The change flag is used to checks whether the window should be redrawn. This method also
+    /// sets the reference-counting pointer to the dirty flag to the change flag so that the window
+    /// without the dirty flag is entirely redrawn if only the appearance of the widget is changed.
+    /// The window with the dirty flag should call the
+    /// [`set_dirty_flag_arc`](Self::set_dirty_flag_arc) method after this method. This method
     /// shouldn't be direclty used by an application instead the
     /// [`set_only_change_flag_arc`](Self::set_only_change_flag_arc) method.
     fn set_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
     {
         self.set_only_change_flag_arc(flag_arc.clone());
-        self.set_descendant_change_flag_arcs(flag_arc);
+        self.set_descendant_change_flag_arcs(flag_arc.clone());
+        self.set_only_dirty_flag_arc(flag_arc.clone());
+        self.set_descendant_dirty_flag_arcs(flag_arc);
+    }
+
+    /// Sets the reference-counting pointer to the dirty flag.
+    ///
+    /// This method sets the reference-counting pointer to the dirty flag for descendant widgets.
+    /// The dirty flag is used to check whether the dirty rectangles of the window should be
+    /// redrawn. This method shouldn't be direclty used by an application instead the
+    /// [`set_only_dirty_flag_arc`](Self::set_only_dirty_flag_arc) method.
+    fn set_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    {
+        self.set_only_dirty_flag_arc(flag_arc.clone());
+        self.set_descendant_dirty_flag_arcs(flag_arc);
     }
 }
 
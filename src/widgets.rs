@@ -13,6 +13,9 @@ mod grid_layout_widgets;
 mod label;
 mod linear_layout;
 mod linear_layout_widgets;
+mod menu_bar;
+mod menu_bar_item;
+mod menu_item;
 mod radio;
 mod radio_group;
 mod title;
@@ -27,6 +30,9 @@ pub use grid_layout_widgets::*;
 pub use label::*;
 pub use linear_layout::*;
 pub use linear_layout_widgets::*;
+pub use menu_bar::*;
+pub use menu_bar_item::*;
+pub use menu_item::*;
 pub use radio::*;
 pub use radio_group::*;
 pub use title::*;
@@ -38,10 +38,14 @@ pub struct Button
     is_enabled: bool,
     is_focused: bool,
     change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    dirty_rect: Option<Rect<i32>>,
     preferred_size: Size<Option<i32>>,
     call_on_fun: CallOnFun,
     image: Option<Image>,
     text: Text,
+    context_menu_index: Option<WindowIndex>,
+    tooltip_text: Option<String>,
 }
 
 impl Button
@@ -59,6 +63,8 @@ impl Button
             is_enabled: true,
             is_focused: false,
             change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_rect: None,
             preferred_size: Size::new(None, None),
             call_on_fun: CallOnFun::new(),
             image: opt_icon.map(|i| {
@@ -69,6 +75,8 @@ impl Button
                     })
             }),
             text: Text::new(s, TextAlign::Center),
+            context_menu_index: None,
+            tooltip_text: None,
         }
     }
 
@@ -193,6 +201,23 @@ impl Button
 
     pub fn set_trim(&mut self, is_trimmed: bool)
     { self.text.is_trimmed = is_trimmed; }
+
+    /// Sets the index of the context menu for the widget.
+    ///
+    /// See [`Widget::context_menu_index`] for more informations.
+    pub fn set_context_menu_index(&mut self, idx: Option<WindowIndex>)
+    { self.context_menu_index = idx; }
+
+    /// Sets the tooltip text for the widget.
+    ///
+    /// See [`Widget::tooltip_text`] for more informations.
+    pub fn set_tooltip_text(&mut self, s: Option<&str>)
+    {
+        match s {
+            Some(s) => self.tooltip_text = Some(String::from(s)),
+            None => self.tooltip_text = None,
+        }
+    }
 }
 
 impl Widget for Button
@@ -220,7 +245,8 @@ impl Widget for Button
         let old_state = self.state;
         self.state = state;
         if old_state != self.state {
-            self.change_flag_arc.store(true, Ordering::SeqCst);
+            self.dirty_rect = Some(self.margin_bounds);
+            self.dirty_flag_arc.store(true, Ordering::SeqCst);
         }
     }
 
@@ -239,7 +265,8 @@ impl Widget for Button
             let old_focus_flag = self.is_focused;
             self.is_focused = is_focused;
             if old_focus_flag != self.is_focused {
-                self.change_flag_arc.store(true, Ordering::SeqCst);
+                self.dirty_rect = Some(self.margin_bounds);
+                self.dirty_flag_arc.store(true, Ordering::SeqCst);
             }
             true
         } else {
@@ -247,6 +274,17 @@ impl Widget for Button
         }
     }
     
+    fn context_menu_index(&self) -> Option<WindowIndex>
+    { self.context_menu_index }
+
+    fn tooltip_text(&self) -> Option<&str>
+    {
+        match &self.tooltip_text {
+            Some(tooltip_text) => Some(tooltip_text.as_str()),
+            None => None,
+        }
+    }
+
     fn h_scroll_bar_slider_x(&self, viewport_width: i32, trough_width: i32) -> f64
     { h_scroll_bar_slider_x(self.client_pos.x, self.margin_bounds.width, viewport_width, trough_width) }
 
@@ -285,6 +323,15 @@ impl Widget for Button
     
     fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
     { self.change_flag_arc = flag_arc; }
+
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.dirty_flag_arc = flag_arc; }
+
+    fn dirty_rect(&self) -> Option<Rect<i32>>
+    { self.dirty_rect }
+
+    fn clear_dirty_rect(&mut self)
+    { self.dirty_rect = None; }
 }
 
 impl Container for Button
@@ -414,6 +461,7 @@ mod tests
 {
     use super::*;
     use crate::mocks::*;
+    use crate::window::*;
 
     #[test]
     fn test_button_updates_size_and_position()
@@ -434,7 +482,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -478,7 +526,7 @@ mod tests
         let o = cairo_context.text_extents("O").unwrap().x_advance;
         let k = cairo_context.text_extents("K").unwrap().x_advance;
         let text_width = o + k;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -522,7 +570,7 @@ mod tests
         let o = cairo_context.text_extents("O").unwrap().x_advance;
         let k = cairo_context.text_extents("K").unwrap().x_advance;
         let text_width = o + k;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -568,7 +616,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_size = Size::new(Some(area_width), None);
         match button.update_size(&cairo_context, &theme, area_size) {
@@ -615,7 +663,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 - 10;
         let area_size = Size::new(Some(area_width), None);
         match button.update_size(&cairo_context, &theme, area_size) {
@@ -664,7 +712,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 + 10;
         button.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -712,7 +760,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 - 10;
         button.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -760,7 +808,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 + 10;
         button.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 - 10;
         let area_size = Size::new(Some(area_width), None);
         match button.update_size(&cairo_context, &theme, area_size) {
@@ -807,7 +855,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(None, Some(area_height));
         match button.update_size(&cairo_context, &theme, area_size) {
@@ -854,7 +902,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 - 10;
         let area_size = Size::new(None, Some(area_height));
         match button.update_size(&cairo_context, &theme, area_size) {
@@ -903,7 +951,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 + 10;
         button.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -951,7 +999,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 - 10;
         button.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -999,7 +1047,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 + 10;
         button.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 - 10;
         let area_size = Size::new(None, Some(area_height));
         match button.update_size(&cairo_context, &theme, area_size) {
@@ -1047,7 +1095,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1096,7 +1144,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1145,7 +1193,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1194,7 +1242,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1243,7 +1291,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1292,7 +1340,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1341,7 +1389,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1390,7 +1438,7 @@ mod tests
         let n = cairo_context.text_extents("n").unwrap().x_advance;
         let text_width = b + u + t + t + o + n;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1418,4 +1466,47 @@ mod tests
         assert_eq!(Size::new(expected_width, expected_height), button.bounds.size());
         assert_eq!(Size::new(expected_margin_width, expected_margin_height), button.margin_bounds.size());
     }
+
+    #[test]
+    fn test_button_sets_dirty_rect_for_changed_state()
+    {
+        let mut button = Button::new("Button");
+        let change_flag_arc = Arc::new(AtomicBool::new(false));
+        let dirty_flag_arc = Arc::new(AtomicBool::new(false));
+        button.set_change_flag_arc(change_flag_arc.clone());
+        button.set_dirty_flag_arc(dirty_flag_arc.clone());
+        button.margin_bounds = Rect::new(1, 2, 3, 4);
+        button.set_state(WidgetState::Hover);
+        assert_eq!(false, change_flag_arc.load(Ordering::SeqCst));
+        assert_eq!(true, dirty_flag_arc.load(Ordering::SeqCst));
+        assert_eq!(Some(Rect::new(1, 2, 3, 4)), button.dirty_rect());
+        button.clear_dirty_rect();
+        assert_eq!(None, button.dirty_rect());
+    }
+
+    #[test]
+    fn test_button_sets_change_flag_for_changed_state_in_window_without_dirty_flag()
+    {
+        let mut window = MockWindow::new("test");
+        let path = match container_rel_widget_path1(&mut window, |w: &mut MockWindow| w.set(Button::new("Button"))) {
+            Some(tmp_path) => tmp_path,
+            None => {
+                assert!(false);
+                unreachable!()
+            },
+        };
+        assert_eq!(false, window.is_changed());
+        match window.dyn_widget_mut(&path) {
+            Some(widget) => widget.set_state(WidgetState::Hover),
+            None => assert!(false),
+        }
+        assert_eq!(true, window.is_changed());
+        assert_eq!(false, window.is_dirty());
+        window.clear_change_flag();
+        match window.dyn_widget_mut(&path) {
+            Some(widget) => assert_eq!(true, widget.set_focus(true)),
+            None => assert!(false),
+        }
+        assert_eq!(true, window.is_changed());
+    }
 }
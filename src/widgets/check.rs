@@ -35,10 +35,14 @@ pub struct Check
     is_enabled: bool,
     is_focused: bool,
     change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    dirty_rect: Option<Rect<i32>>,
     preferred_size: Size<Option<i32>>,
     call_on_fun: CallOnFun,
     text: Text,
     is_checked: bool,
+    context_menu_index: Option<WindowIndex>,
+    tooltip_text: Option<String>,
 }
 
 impl Check
@@ -56,10 +60,14 @@ impl Check
             is_enabled: true,
             is_focused: false,
             change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_rect: None,
             preferred_size: Size::new(None, None),
             call_on_fun: CallOnFun::new(),
             text: Text::new(s, TextAlign::Left),
             is_checked: false,
+            context_menu_index: None,
+            tooltip_text: None,
         }
     }
 
@@ -156,6 +164,23 @@ impl Check
             self.change_flag_arc.store(true, Ordering::SeqCst);
         }
     }
+
+    /// Sets the index of the context menu for the widget.
+    ///
+    /// See [`Widget::context_menu_index`] for more informations.
+    pub fn set_context_menu_index(&mut self, idx: Option<WindowIndex>)
+    { self.context_menu_index = idx; }
+
+    /// Sets the tooltip text for the widget.
+    ///
+    /// See [`Widget::tooltip_text`] for more informations.
+    pub fn set_tooltip_text(&mut self, s: Option<&str>)
+    {
+        match s {
+            Some(s) => self.tooltip_text = Some(String::from(s)),
+            None => self.tooltip_text = None,
+        }
+    }
 }
 
 impl Widget for Check
@@ -183,7 +208,8 @@ impl Widget for Check
         let old_state = self.state;
         self.state = state;
         if old_state != self.state {
-            self.change_flag_arc.store(true, Ordering::SeqCst);
+            self.dirty_rect = Some(self.margin_bounds);
+            self.dirty_flag_arc.store(true, Ordering::SeqCst);
         }
     }
 
@@ -202,7 +228,8 @@ impl Widget for Check
             let old_focus_flag = self.is_focused;
             self.is_focused = is_focused;
             if old_focus_flag != self.is_focused {
-                self.change_flag_arc.store(true, Ordering::SeqCst);
+                self.dirty_rect = Some(self.margin_bounds);
+                self.dirty_flag_arc.store(true, Ordering::SeqCst);
             }
             true
         } else {
@@ -210,6 +237,17 @@ impl Widget for Check
         }
     }
     
+    fn context_menu_index(&self) -> Option<WindowIndex>
+    { self.context_menu_index }
+
+    fn tooltip_text(&self) -> Option<&str>
+    {
+        match &self.tooltip_text {
+            Some(tooltip_text) => Some(tooltip_text.as_str()),
+            None => None,
+        }
+    }
+
     fn h_scroll_bar_slider_x(&self, viewport_width: i32, trough_width: i32) -> f64
     { h_scroll_bar_slider_x(self.client_pos.x, self.margin_bounds.width, viewport_width, trough_width) }
 
@@ -248,6 +286,15 @@ impl Widget for Check
     
     fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
     { self.change_flag_arc = flag_arc; }
+
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.dirty_flag_arc = flag_arc; }
+
+    fn dirty_rect(&self) -> Option<Rect<i32>>
+    { self.dirty_rect }
+
+    fn clear_dirty_rect(&mut self)
+    { self.dirty_rect = None; }
 }
 
 impl Container for Check
@@ -393,7 +440,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match check.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -439,7 +486,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_size = Size::new(Some(area_width), None);
         match check.update_size(&cairo_context, &theme, area_size) {
@@ -486,7 +533,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 - 10;
         let area_size = Size::new(Some(area_width), None);
         match check.update_size(&cairo_context, &theme, area_size) {
@@ -535,7 +582,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 + 10;
         check.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match check.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -583,7 +630,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 - 10;
         check.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match check.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -631,7 +678,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 + 10;
         check.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 - 10;
         let area_size = Size::new(Some(area_width), None);
         match check.update_size(&cairo_context, &theme, area_size) {
@@ -678,7 +725,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(None, Some(area_height));
         match check.update_size(&cairo_context, &theme, area_size) {
@@ -725,7 +772,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 - 10;
         let area_size = Size::new(None, Some(area_height));
         match check.update_size(&cairo_context, &theme, area_size) {
@@ -774,7 +821,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 + 10;
         check.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match check.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -822,7 +869,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 - 10;
         check.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match check.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -870,7 +917,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 + 10;
         check.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 - 10;
         let area_size = Size::new(None, Some(area_height));
         match check.update_size(&cairo_context, &theme, area_size) {
@@ -918,7 +965,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -967,7 +1014,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1016,7 +1063,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1065,7 +1112,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1114,7 +1161,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1163,7 +1210,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1212,7 +1259,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1261,7 +1308,7 @@ mod tests
         let k = cairo_context.text_extents("k").unwrap().x_advance;
         let text_width = c + h + e + c2 + k;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -33,8 +33,12 @@ pub struct Empty
     state: WidgetState,
     is_enabled: bool,
     change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    dirty_rect: Option<Rect<i32>>,
     preferred_size: Size<Option<i32>>,
     call_on_fun: CallOnFun,
+    context_menu_index: Option<WindowIndex>,
+    tooltip_text: Option<String>,
 }
 
 impl Empty
@@ -51,8 +55,12 @@ impl Empty
             state: WidgetState::None,
             is_enabled: true,
             change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_rect: None,
             preferred_size: Size::new(None, None),
             call_on_fun: CallOnFun::new(),
+            context_menu_index: None,
+            tooltip_text: None,
         }
     }
 
@@ -98,6 +106,23 @@ impl Empty
     pub fn set_on<F>(&mut self, f: F)
         where F: FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static
     { self.set_dyn_on(Box::new(f)) }
+
+    /// Sets the index of the context menu for the widget.
+    ///
+    /// See [`Widget::context_menu_index`] for more informations.
+    pub fn set_context_menu_index(&mut self, idx: Option<WindowIndex>)
+    { self.context_menu_index = idx; }
+
+    /// Sets the tooltip text for the widget.
+    ///
+    /// See [`Widget::tooltip_text`] for more informations.
+    pub fn set_tooltip_text(&mut self, s: Option<&str>)
+    {
+        match s {
+            Some(s) => self.tooltip_text = Some(String::from(s)),
+            None => self.tooltip_text = None,
+        }
+    }
 }
 
 impl Widget for Empty
@@ -125,13 +150,25 @@ impl Widget for Empty
         let old_state = self.state;
         self.state = state;
         if old_state != self.state {
-            self.change_flag_arc.store(true, Ordering::SeqCst);
+            self.dirty_rect = Some(self.margin_bounds);
+            self.dirty_flag_arc.store(true, Ordering::SeqCst);
         }
     }
 
     fn is_enabled(&self) -> bool
     { self.is_enabled }
         
+    fn context_menu_index(&self) -> Option<WindowIndex>
+    { self.context_menu_index }
+
+    fn tooltip_text(&self) -> Option<&str>
+    {
+        match &self.tooltip_text {
+            Some(tooltip_text) => Some(tooltip_text.as_str()),
+            None => None,
+        }
+    }
+
     fn h_scroll_bar_slider_x(&self, viewport_width: i32, trough_width: i32) -> f64
     { h_scroll_bar_slider_x(self.client_pos.x, self.margin_bounds.width, viewport_width, trough_width) }
 
@@ -170,6 +207,15 @@ impl Widget for Empty
     
     fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
     { self.change_flag_arc = flag_arc; }
+
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.dirty_flag_arc = flag_arc; }
+
+    fn dirty_rect(&self) -> Option<Rect<i32>>
+    { self.dirty_rect }
+
+    fn clear_dirty_rect(&mut self)
+    { self.dirty_rect = None; }
 }
 
 impl Container for Empty
@@ -252,7 +298,7 @@ mod tests
         theme.set_font_size(32.0);
         theme.set_empty_margin_edges(Edges::new(1, 2, 3, 4));
         let mut empty = Empty::new();
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match empty.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -288,7 +334,7 @@ mod tests
         theme.set_font_size(32.0);
         theme.set_empty_margin_edges(Edges::new(1, 2, 3, 4));
         let mut empty = Empty::new();
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 10 + 4;
         let area_size = Size::new(Some(area_width), None);
         match empty.update_size(&cairo_context, &theme, area_size) {
@@ -327,7 +373,7 @@ mod tests
         let mut empty = Empty::new();
         let preferred_width = 10;
         empty.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match empty.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -365,7 +411,7 @@ mod tests
         let mut empty = Empty::new();
         let preferred_width = 20;
         empty.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 10 + 4;
         let area_size = Size::new(Some(area_width), None);
         match empty.update_size(&cairo_context, &theme, area_size) {
@@ -402,7 +448,7 @@ mod tests
         theme.set_font_size(32.0);
         theme.set_empty_margin_edges(Edges::new(1, 2, 3, 4));
         let mut empty = Empty::new();
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 10 + 2;
         let area_size = Size::new(None, Some(area_height));
         match empty.update_size(&cairo_context, &theme, area_size) {
@@ -441,7 +487,7 @@ mod tests
         let mut empty = Empty::new();
         let preferred_height = 10;
         empty.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match empty.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -479,7 +525,7 @@ mod tests
         let mut empty = Empty::new();
         let preferred_height = 20;
         empty.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 10 + 2;
         let area_size = Size::new(None, Some(area_height));
         match empty.update_size(&cairo_context, &theme, area_size) {
@@ -517,7 +563,7 @@ mod tests
         theme.set_empty_margin_edges(Edges::new(1, 2, 3, 4));
         let mut empty = Empty::new();
         empty.set_h_align(HAlign::Left);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 10 + 4;
         let area_height = 1 + 10 + 2;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -556,7 +602,7 @@ mod tests
         theme.set_empty_margin_edges(Edges::new(1, 2, 3, 4));
         let mut empty = Empty::new();
         empty.set_h_align(HAlign::Center);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 10 + 4;
         let area_height = 1 + 10 + 2;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -595,7 +641,7 @@ mod tests
         theme.set_empty_margin_edges(Edges::new(1, 2, 3, 4));
         let mut empty = Empty::new();
         empty.set_h_align(HAlign::Right);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 10 + 4;
         let area_height = 1 + 10 + 2;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -634,7 +680,7 @@ mod tests
         theme.set_empty_margin_edges(Edges::new(1, 2, 3, 4));
         let mut empty = Empty::new();
         empty.set_h_align(HAlign::Fill);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 10 + 4;
         let area_height = 1 + 10 + 2;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -673,7 +719,7 @@ mod tests
         theme.set_empty_margin_edges(Edges::new(1, 2, 3, 4));
         let mut empty = Empty::new();
         empty.set_v_align(VAlign::Top);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 10 + 4;
         let area_height = 1 + 10 + 2;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -712,7 +758,7 @@ mod tests
         theme.set_empty_margin_edges(Edges::new(1, 2, 3, 4));
         let mut empty = Empty::new();
         empty.set_v_align(VAlign::Center);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 10 + 4;
         let area_height = 1 + 10 + 2;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -751,7 +797,7 @@ mod tests
         theme.set_empty_margin_edges(Edges::new(1, 2, 3, 4));
         let mut empty = Empty::new();
         empty.set_v_align(VAlign::Bottom);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 10 + 4;
         let area_height = 1 + 10 + 2;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -790,7 +836,7 @@ mod tests
         theme.set_empty_margin_edges(Edges::new(1, 2, 3, 4));
         let mut empty = Empty::new();
         empty.set_v_align(VAlign::Fill);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 10 + 4;
         let area_height = 1 + 10 + 2;
         let area_size = Size::new(Some(area_width), Some(area_height));
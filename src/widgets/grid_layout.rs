@@ -34,9 +34,12 @@ pub struct GridLayout
     state: WidgetState,
     is_enabled: bool,
     change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
     preferred_size: Size<Option<i32>>,
     call_on_fun: CallOnFun,
     widgets: GridLayoutWidgets,
+    context_menu_index: Option<WindowIndex>,
+    tooltip_text: Option<String>,
 }
 
 impl GridLayout
@@ -53,9 +56,12 @@ impl GridLayout
             state: WidgetState::None,
             is_enabled: true,
             change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
             preferred_size: Size::new(None, None),
             call_on_fun: CallOnFun::new(),
             widgets: GridLayoutWidgets::new(max_column_count),
+            context_menu_index: None,
+            tooltip_text: None,
         }
     }
 
@@ -123,6 +129,7 @@ impl GridLayout
     pub fn add_dyn(&mut self, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
     {
         widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
         match self.widgets.add_dyn(widget) {
             Some(idx_pair) => {
                 self.change_flag_arc.store(true, Ordering::SeqCst);
@@ -149,6 +156,7 @@ impl GridLayout
     pub fn insert_dyn(&mut self, idx_pair: WidgetIndexPair, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
     {
         widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
         match self.widgets.insert_dyn(idx_pair, widget) {
             Some(idx_pair) => {
                 self.change_flag_arc.store(true, Ordering::SeqCst);
@@ -167,6 +175,7 @@ impl GridLayout
             Some(mut widget) => {
                 self.change_flag_arc.store(true, Ordering::SeqCst);
                 widget.set_change_flag_arc(Arc::new(AtomicBool::new(false)));
+                widget.set_dirty_flag_arc(Arc::new(AtomicBool::new(false)));
                 Some(widget)
             },
             None => None,
@@ -179,6 +188,7 @@ impl GridLayout
             Some(mut widget) => {
                 self.change_flag_arc.store(true, Ordering::SeqCst);
                 widget.set_change_flag_arc(Arc::new(AtomicBool::new(false)));
+                widget.set_dirty_flag_arc(Arc::new(AtomicBool::new(false)));
                 Some(widget)
             },
             None => None,
@@ -190,6 +200,23 @@ impl GridLayout
         self.widgets.widgets.clear();
         self.change_flag_arc.store(true, Ordering::SeqCst);
     }
+
+    /// Sets the index of the context menu for the widget.
+    ///
+    /// See [`Widget::context_menu_index`] for more informations.
+    pub fn set_context_menu_index(&mut self, idx: Option<WindowIndex>)
+    { self.context_menu_index = idx; }
+
+    /// Sets the tooltip text for the widget.
+    ///
+    /// See [`Widget::tooltip_text`] for more informations.
+    pub fn set_tooltip_text(&mut self, s: Option<&str>)
+    {
+        match s {
+            Some(s) => self.tooltip_text = Some(String::from(s)),
+            None => self.tooltip_text = None,
+        }
+    }
 }
 
 impl Widget for GridLayout
@@ -218,6 +245,17 @@ impl Widget for GridLayout
     fn is_enabled(&self) -> bool
     { self.is_enabled }
     
+    fn context_menu_index(&self) -> Option<WindowIndex>
+    { self.context_menu_index }
+
+    fn tooltip_text(&self) -> Option<&str>
+    {
+        match &self.tooltip_text {
+            Some(tooltip_text) => Some(tooltip_text.as_str()),
+            None => None,
+        }
+    }
+
     fn h_scroll_bar_slider_x(&self, viewport_width: i32, trough_width: i32) -> f64
     { h_scroll_bar_slider_x(self.client_pos.x, self.bounds.width, viewport_width, trough_width) }
 
@@ -256,6 +294,9 @@ impl Widget for GridLayout
     
     fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
     { self.change_flag_arc = flag_arc; }
+
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.dirty_flag_arc = flag_arc; }
 }
 
 impl Container for GridLayout
@@ -393,7 +434,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(137), Some(30)));
         grid_layout.add(button8);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match grid_layout.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -501,7 +542,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(40), Some(107)));
         grid_layout.add(button8);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match grid_layout.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -745,7 +745,9 @@ impl GridLayoutWidgets
     {
         for row in &self.widgets {
             for widget in row {
-                widget.draw(cairo_context, theme, is_focused_window)?;
+                if is_in_cairo_clip(cairo_context, widget.margin_bounds())? {
+                    widget.draw(cairo_context, theme, is_focused_window)?;
+                }
             }
         }
         Ok(())
@@ -1308,7 +1310,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -1399,7 +1401,7 @@ mod tests
         button6.set_v_align(VAlign::Fill);
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -1496,7 +1498,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(137), Some(30)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -1607,7 +1609,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(137), Some(30)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -1715,7 +1717,7 @@ mod tests
         button9.set_weight(1);
         button9.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button9));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -1834,7 +1836,7 @@ mod tests
         button9.set_weight(1);
         button9.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button9));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -1943,7 +1945,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(137), Some(30)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 60 * (1 + 3 + 2) + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -2055,7 +2057,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(137), Some(30)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 60 * (1 + 3 + 2) + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -2161,7 +2163,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(137), Some(30)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 60 * (1 + 3 + 2) + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -2273,7 +2275,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(137), Some(30)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 60 * (1 + 3 + 2) + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -2379,7 +2381,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(137), Some(30)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 60 * (1 + 3 + 2) + 4;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -2491,7 +2493,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(137), Some(30)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 60 * (1 + 3 + 2) + 4;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -2597,7 +2599,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(137), Some(30)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 70 * (1 + 3 + 2) + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -2710,7 +2712,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(137), Some(30)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 60 * (1 + 3 + 2) + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -2805,7 +2807,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 50 * 3 + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Horizontal;
@@ -2897,7 +2899,7 @@ mod tests
         button6.set_v_align(VAlign::Fill);
         button6.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 50 * 3 + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Horizontal;
@@ -2983,7 +2985,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 50 * 3 + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Horizontal;
@@ -3075,7 +3077,7 @@ mod tests
         button6.set_v_align(VAlign::Fill);
         button6.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 50 * 3 + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Horizontal;
@@ -3161,7 +3163,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 60 * 3 + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Horizontal;
@@ -3254,7 +3256,7 @@ mod tests
         button6.set_v_align(VAlign::Fill);
         button6.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 60 * 3 + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Horizontal;
@@ -3323,7 +3325,7 @@ mod tests
         theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
         theme.set_button_font_size(16.0);
         let mut widgets = GridLayoutWidgets::new(3);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -3384,7 +3386,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -3475,7 +3477,7 @@ mod tests
         button6.set_h_align(HAlign::Fill);
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -3572,7 +3574,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(40), Some(107)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -3683,7 +3685,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(40), Some(107)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -3791,7 +3793,7 @@ mod tests
         button9.set_weight(1);
         button9.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button9));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -3910,7 +3912,7 @@ mod tests
         button9.set_weight(1);
         button9.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button9));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -4019,7 +4021,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(40), Some(107)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 50 * (1 + 3 + 2) + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -4131,7 +4133,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(40), Some(107)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 50 * (1 + 3 + 2) + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -4237,7 +4239,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(40), Some(107)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 50 * (1 + 3 + 2) + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -4349,7 +4351,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(40), Some(107)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 50 * (1 + 3 + 2) + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -4455,7 +4457,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(40), Some(107)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 50 * (1 + 3 + 2) + 4;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -4567,7 +4569,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(40), Some(107)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 50 * (1 + 3 + 2) + 4;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -4673,7 +4675,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(40), Some(107)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 60 * (1 + 3 + 2) + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -4786,7 +4788,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(40), Some(107)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 60 * (1 + 3 + 2) + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -4881,7 +4883,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 60 * 3 + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Vertical;
@@ -4973,7 +4975,7 @@ mod tests
         button6.set_h_align(HAlign::Fill);
         button6.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 60 * 3 + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Vertical;
@@ -5059,7 +5061,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 60 * 3 + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Vertical;
@@ -5151,7 +5153,7 @@ mod tests
         button6.set_h_align(HAlign::Fill);
         button6.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 60 * 3 + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Vertical;
@@ -5237,7 +5239,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 70 * 3 + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Vertical;
@@ -5330,7 +5332,7 @@ mod tests
         button6.set_h_align(HAlign::Fill);
         button6.set_preferred_size(Size::new(Some(40), Some(30)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 70 * 3 + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Vertical;
@@ -5399,7 +5401,7 @@ mod tests
         theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
         theme.set_button_font_size(16.0);
         let mut widgets = GridLayoutWidgets::new(3);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -5460,7 +5462,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 64 + 54 + 64 + 10;
         let area_height = 54 * 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -5546,7 +5548,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 64 + 54 + 64 + 10;
         let area_height = 54 * 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -5632,7 +5634,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 64 + 54 + 64 + 10;
         let area_height = 54 * 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -5718,7 +5720,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 64 + 54 + 64 + 10;
         let area_height = 54 * 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -5804,7 +5806,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 64 + 54 + 64 + 10;
         let area_height = 54 * 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -5890,7 +5892,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 64 + 54 + 64 + 10;
         let area_height = 54 * 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -5976,7 +5978,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 64 + 54 + 64 + 10;
         let area_height = 54 * 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -6062,7 +6064,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 64 + 54 + 64 + 10;
         let area_height = 54 * 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -6148,7 +6150,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -6202,7 +6204,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(137), Some(30)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -6244,7 +6246,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 60 + 4 + 50 + 4 + 60 + 4 + 10;
         let area_height = (50 + 4) * 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -6288,7 +6290,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 60 + 4 + 50 + 4 + 60 + 4 + 10;
         let area_height = (50 + 4) * 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -6332,7 +6334,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 60 + 4 + 50 + 4 + 60 + 4 + 20;
         let area_height = (50 + 4) * 2 + 20;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -6378,7 +6380,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 60 + 4 + 50 + 4 + 60 + 4 + 20;
         let area_height = (50 + 4) * 2 + 20;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -6424,7 +6426,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -6478,7 +6480,7 @@ mod tests
         button8.set_weight(3);
         button8.set_preferred_size(Size::new(Some(40), Some(107)));
         widgets.add_dyn(Box::new(button8));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -6520,7 +6522,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = (60 + 4) * 2 + 10;
         let area_height = 50 + 4 + 40 + 4 + 50 + 4 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -6564,7 +6566,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = (60 + 4) * 2 + 10;
         let area_height = 50 + 4 + 40 + 4 + 50 + 4 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -6608,7 +6610,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = (60 + 4) * 2 + 20;
         let area_height = 50 + 4 + 40 + 4 + 50 + 4 + 20;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -6654,7 +6656,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = (60 + 4) * 2 + 20;
         let area_height = 50 + 4 + 40 + 4 + 50 + 4 + 20;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -6700,7 +6702,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -6765,7 +6767,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -6814,7 +6816,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -6879,7 +6881,7 @@ mod tests
         let mut button6 = Button::new("B6");
         button6.set_preferred_size(Size::new(Some(40), Some(50)));
         widgets.add_dyn(Box::new(button6));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
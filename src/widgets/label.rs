@@ -34,9 +34,13 @@ pub struct Label
     state: WidgetState,
     is_enabled: bool,
     change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    dirty_rect: Option<Rect<i32>>,
     preferred_size: Size<Option<i32>>,
     call_on_fun: CallOnFun,
     text: Text,
+    context_menu_index: Option<WindowIndex>,
+    tooltip_text: Option<String>,
 }
 
 impl Label
@@ -53,9 +57,13 @@ impl Label
             state: WidgetState::None,
             is_enabled: true,
             change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_rect: None,
             preferred_size: Size::new(None, None),
             call_on_fun: CallOnFun::new(),
             text: Text::new(s, TextAlign::Left),
+            context_menu_index: None,
+            tooltip_text: None,
         }
     }
 
@@ -140,6 +148,23 @@ impl Label
 
     pub fn set_trim(&mut self, is_trimmed: bool)
     { self.text.is_trimmed = is_trimmed; }
+
+    /// Sets the index of the context menu for the widget.
+    ///
+    /// See [`Widget::context_menu_index`] for more informations.
+    pub fn set_context_menu_index(&mut self, idx: Option<WindowIndex>)
+    { self.context_menu_index = idx; }
+
+    /// Sets the tooltip text for the widget.
+    ///
+    /// See [`Widget::tooltip_text`] for more informations.
+    pub fn set_tooltip_text(&mut self, s: Option<&str>)
+    {
+        match s {
+            Some(s) => self.tooltip_text = Some(String::from(s)),
+            None => self.tooltip_text = None,
+        }
+    }
 }
 
 impl Widget for Label
@@ -167,13 +192,25 @@ impl Widget for Label
         let old_state = self.state;
         self.state = state;
         if old_state != self.state {
-            self.change_flag_arc.store(true, Ordering::SeqCst);
+            self.dirty_rect = Some(self.margin_bounds);
+            self.dirty_flag_arc.store(true, Ordering::SeqCst);
         }
     }
 
     fn is_enabled(&self) -> bool
     { self.is_enabled }
         
+    fn context_menu_index(&self) -> Option<WindowIndex>
+    { self.context_menu_index }
+
+    fn tooltip_text(&self) -> Option<&str>
+    {
+        match &self.tooltip_text {
+            Some(tooltip_text) => Some(tooltip_text.as_str()),
+            None => None,
+        }
+    }
+
     fn h_scroll_bar_slider_x(&self, viewport_width: i32, trough_width: i32) -> f64
     { h_scroll_bar_slider_x(self.client_pos.x, self.margin_bounds.width, viewport_width, trough_width) }
 
@@ -212,6 +249,15 @@ impl Widget for Label
     
     fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
     { self.change_flag_arc = flag_arc; }
+
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.dirty_flag_arc = flag_arc; }
+
+    fn dirty_rect(&self) -> Option<Rect<i32>>
+    { self.dirty_rect }
+
+    fn clear_dirty_rect(&mut self)
+    { self.dirty_rect = None; }
 }
 
 impl Container for Label
@@ -325,7 +371,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match label.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -371,7 +417,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_size = Size::new(Some(area_width), None);
         match label.update_size(&cairo_context, &theme, area_size) {
@@ -418,7 +464,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 - 10;
         let area_size = Size::new(Some(area_width), None);
         match label.update_size(&cairo_context, &theme, area_size) {
@@ -467,7 +513,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 + 10;
         label.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match label.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -515,7 +561,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 - 10;
         label.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match label.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -563,7 +609,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 + 10;
         label.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 - 10;
         let area_size = Size::new(Some(area_width), None);
         match label.update_size(&cairo_context, &theme, area_size) {
@@ -610,7 +656,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(None, Some(area_height));
         match label.update_size(&cairo_context, &theme, area_size) {
@@ -657,7 +703,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 - 10;
         let area_size = Size::new(None, Some(area_height));
         match label.update_size(&cairo_context, &theme, area_size) {
@@ -706,7 +752,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 + 10;
         label.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match label.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -754,7 +800,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 - 10;
         label.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match label.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -802,7 +848,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 + 10;
         label.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 - 10;
         let area_size = Size::new(None, Some(area_height));
         match label.update_size(&cairo_context, &theme, area_size) {
@@ -850,7 +896,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -899,7 +945,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -948,7 +994,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -997,7 +1043,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1046,7 +1092,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1095,7 +1141,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1144,7 +1190,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1193,7 +1239,7 @@ mod tests
         let l2 = cairo_context.text_extents("l").unwrap().x_advance;
         let text_width = l + a + b + e + l2;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
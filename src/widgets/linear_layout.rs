@@ -34,9 +34,12 @@ pub struct LinearLayout
     state: WidgetState,
     is_enabled: bool,
     change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
     preferred_size: Size<Option<i32>>,
     call_on_fun: CallOnFun,
     widgets: LinearLayoutWidgets,
+    context_menu_index: Option<WindowIndex>,
+    tooltip_text: Option<String>,
 }
 
 impl LinearLayout
@@ -53,9 +56,12 @@ impl LinearLayout
             state: WidgetState::None,
             is_enabled: true,
             change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
             preferred_size: Size::new(None, None),
             call_on_fun: CallOnFun::new(),
             widgets: LinearLayoutWidgets::new(),
+            context_menu_index: None,
+            tooltip_text: None,
         }
     }
 
@@ -117,6 +123,7 @@ impl LinearLayout
     pub fn add_dyn(&mut self, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
     {
         widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
         match self.widgets.add_dyn(widget) {
             Some(idx_pair) => {
                 self.change_flag_arc.store(true, Ordering::SeqCst);
@@ -132,6 +139,7 @@ impl LinearLayout
     pub fn insert_dyn(&mut self, idx_pair: WidgetIndexPair, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
     {
         widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
         match self.widgets.insert_dyn(idx_pair, widget) {
             Some(idx_pair) => {
                 self.change_flag_arc.store(true, Ordering::SeqCst);
@@ -150,6 +158,7 @@ impl LinearLayout
             Some(mut widget) => {
                 self.change_flag_arc.store(true, Ordering::SeqCst);
                 widget.set_change_flag_arc(Arc::new(AtomicBool::new(false)));
+                widget.set_dirty_flag_arc(Arc::new(AtomicBool::new(false)));
                 Some(widget)
             },
             None => None,
@@ -162,6 +171,7 @@ impl LinearLayout
             Some(mut widget) => {
                 self.change_flag_arc.store(true, Ordering::SeqCst);
                 widget.set_change_flag_arc(Arc::new(AtomicBool::new(false)));
+                widget.set_dirty_flag_arc(Arc::new(AtomicBool::new(false)));
                 Some(widget)
             },
             None => None,
@@ -173,6 +183,23 @@ impl LinearLayout
         self.widgets.widgets.clear();
         self.change_flag_arc.store(true, Ordering::SeqCst);
     }
+
+    /// Sets the index of the context menu for the widget.
+    ///
+    /// See [`Widget::context_menu_index`] for more informations.
+    pub fn set_context_menu_index(&mut self, idx: Option<WindowIndex>)
+    { self.context_menu_index = idx; }
+
+    /// Sets the tooltip text for the widget.
+    ///
+    /// See [`Widget::tooltip_text`] for more informations.
+    pub fn set_tooltip_text(&mut self, s: Option<&str>)
+    {
+        match s {
+            Some(s) => self.tooltip_text = Some(String::from(s)),
+            None => self.tooltip_text = None,
+        }
+    }
 }
 
 impl Widget for LinearLayout
@@ -201,6 +228,17 @@ impl Widget for LinearLayout
     fn is_enabled(&self) -> bool
     { self.is_enabled }
     
+    fn context_menu_index(&self) -> Option<WindowIndex>
+    { self.context_menu_index }
+
+    fn tooltip_text(&self) -> Option<&str>
+    {
+        match &self.tooltip_text {
+            Some(tooltip_text) => Some(tooltip_text.as_str()),
+            None => None,
+        }
+    }
+
     fn h_scroll_bar_slider_x(&self, viewport_width: i32, trough_width: i32) -> f64
     { h_scroll_bar_slider_x(self.client_pos.x, self.bounds.width, viewport_width, trough_width) }
 
@@ -239,6 +277,9 @@ impl Widget for LinearLayout
     
     fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
     { self.change_flag_arc = flag_arc; }
+
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.dirty_flag_arc = flag_arc; }
 }
 
 impl Container for LinearLayout
@@ -361,7 +402,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(90), Some(30)));
         linear_layout.add(button4);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match linear_layout.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
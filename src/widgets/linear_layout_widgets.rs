@@ -331,7 +331,9 @@ impl LinearLayoutWidgets
     pub fn draw(&self, cairo_context: &CairoContext, theme: &dyn Theme, is_focused_window: bool) -> Result<(), CairoError>
     {
         for widget in &self.widgets {
-            widget.draw(cairo_context, theme, is_focused_window)?;
+            if is_in_cairo_clip(cairo_context, widget.margin_bounds())? {
+                widget.draw(cairo_context, theme, is_focused_window)?;
+            }
         }
         Ok(())
     }
@@ -643,7 +645,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -704,7 +706,7 @@ mod tests
         button3.set_v_align(VAlign::Fill);
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -768,7 +770,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(90), Some(30)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -839,7 +841,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(90), Some(30)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -907,7 +909,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(90), Some(30)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 50 * (1 + 3 + 2) + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -979,7 +981,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(90), Some(30)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 50 * (1 + 3 + 2) + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -1048,7 +1050,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(90), Some(30)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 50 * (1 + 3 + 2) + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -1120,7 +1122,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(90), Some(30)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 50 * (1 + 3 + 2) + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -1189,7 +1191,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(90), Some(30)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 50 * (1 + 3 + 2) + 4;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -1261,7 +1263,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(90), Some(30)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 50 * (1 + 3 + 2) + 4;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -1330,7 +1332,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(90), Some(30)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 60 * (1 + 3 + 2) + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -1403,7 +1405,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(90), Some(30)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 50 + 4 + 60 * (1 + 3 + 2) + 2;
         let area_size = Size::new(Some(area_width), None);
         let orient = Orient::Horizontal;
@@ -1458,7 +1460,7 @@ mod tests
         theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
         theme.set_button_font_size(16.0);
         let mut widgets = LinearLayoutWidgets::new();
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -1504,7 +1506,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -1565,7 +1567,7 @@ mod tests
         button3.set_h_align(HAlign::Fill);
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -1629,7 +1631,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(40), Some(70)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -1700,7 +1702,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(40), Some(70)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -1768,7 +1770,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(40), Some(70)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 40 * (1 + 3 + 2) + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -1840,7 +1842,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(40), Some(70)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 40 * (1 + 3 + 2) + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -1909,7 +1911,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(40), Some(70)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 40 * (1 + 3 + 2) + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -1981,7 +1983,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(40), Some(70)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 40 * (1 + 3 + 2) + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -2050,7 +2052,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(40), Some(70)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 40 * (1 + 3 + 2) + 4;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -2122,7 +2124,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(40), Some(70)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 40 * (1 + 3 + 2) + 4;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -2191,7 +2193,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(40), Some(70)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 50 * (1 + 3 + 2) + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -2264,7 +2266,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(40), Some(70)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 40 + 4 + 50 * (1 + 3 + 2) + 2;
         let area_size = Size::new(None, Some(area_height));
         let orient = Orient::Vertical;
@@ -2319,7 +2321,7 @@ mod tests
         theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
         theme.set_button_font_size(16.0);
         let mut widgets = LinearLayoutWidgets::new();
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -2365,7 +2367,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 44 + 54 + 64 + 10;
         let area_height = 54 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -2424,7 +2426,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 44 + 54 + 64 + 10;
         let area_height = 54 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -2483,7 +2485,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 44 + 54 + 64 + 10;
         let area_height = 54 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -2542,7 +2544,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 44 + 54 + 64 + 10;
         let area_height = 54 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -2601,7 +2603,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 44 + 54 + 64 + 10;
         let area_height = 54 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -2660,7 +2662,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 44 + 54 + 64 + 10;
         let area_height = 54 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -2719,7 +2721,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 44 + 54 + 64 + 10;
         let area_height = 54 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -2778,7 +2780,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 44 + 54 + 64 + 10;
         let area_height = 54 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -2837,7 +2839,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -2876,7 +2878,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(90), Some(30)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -2909,7 +2911,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 40 + 4 + 50 + 4 + 60 + 4 + 10;
         let area_height = 50 + 4 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -2944,7 +2946,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 40 + 4 + 50 + 4 + 60 + 4 + 10;
         let area_height = 50 + 4 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -2979,7 +2981,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 40 + 4 + 50 + 4 + 60 + 4 + 20;
         let area_height = 50 + 4 + 20;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -3016,7 +3018,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 40 + 4 + 50 + 4 + 60 + 4 + 20;
         let area_height = 50 + 4 + 20;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -3053,7 +3055,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -3092,7 +3094,7 @@ mod tests
         button4.set_weight(2);
         button4.set_preferred_size(Size::new(Some(40), Some(70)));
         widgets.add_dyn(Box::new(button4));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -3125,7 +3127,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 60 + 4 + 10;
         let area_height = 50 + 4 + 40 + 4 + 30 + 4 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -3160,7 +3162,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 60 + 4 + 10;
         let area_height = 50 + 4 + 40 + 4 + 30 + 4 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -3195,7 +3197,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 60 + 4 + 20;
         let area_height = 50 + 4 + 40 + 4 + 30 + 4 + 20;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -3232,7 +3234,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 60 + 4 + 20;
         let area_height = 50 + 4 + 40 + 4 + 30 + 4 + 20;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -3269,7 +3271,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -3313,7 +3315,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
@@ -3349,7 +3351,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Vertical;
         let h_align = HAlign::Left;
@@ -3393,7 +3395,7 @@ mod tests
         let mut button3 = Button::new("B3");
         button3.set_preferred_size(Size::new(Some(60), Some(30)));
         widgets.add_dyn(Box::new(button3));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         let orient = Orient::Horizontal;
         let h_align = HAlign::Left;
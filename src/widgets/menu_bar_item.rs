@@ -0,0 +1,491 @@
+//
+// Copyright (c) 2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::any::Any;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use crate::as_any::*;
+use crate::call_on::*;
+use crate::client_context::*;
+use crate::container::*;
+use crate::draw::*;
+use crate::event_queue::*;
+use crate::events::*;
+use crate::preferred_size::*;
+use crate::queue_context::*;
+use crate::text::*;
+use crate::theme::*;
+use crate::types::*;
+use crate::utils::*;
+use crate::widget::*;
+use crate::windows::*;
+
+/// A structure of menu bar item.
+///
+/// The menu bar item opens a menu when the menu bar item is clicked. An underscore in the text
+/// marks the next character as a mnemonic character, so the menu can be opened by the mnemonic
+/// character with the Alt key.
+pub struct MenuBarItem
+{
+    margin_bounds: Rect<i32>,
+    bounds: Rect<i32>,
+    client_pos: Pos<i32>,
+    weight: u32,
+    h_align: HAlign,
+    v_align: VAlign,
+    state: WidgetState,
+    is_enabled: bool,
+    change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    dirty_rect: Option<Rect<i32>>,
+    preferred_size: Size<Option<i32>>,
+    call_on_fun: CallOnFun,
+    text: Text,
+    mnemonic_index: Option<usize>,
+    menu_index: Option<WindowIndex>,
+}
+
+impl MenuBarItem
+{
+    pub fn new(s: &str) -> Self
+    {
+        let (text, mnemonic_idx) = mnemonic_text(s);
+        MenuBarItem {
+            margin_bounds: Rect::new(0, 0, 0, 0),
+            bounds: Rect::new(0, 0, 0, 0),
+            client_pos: Pos::new(0, 0),
+            weight: 0,
+            h_align: HAlign::Left,
+            v_align: VAlign::Top,
+            state: WidgetState::None,
+            is_enabled: true,
+            change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_rect: None,
+            preferred_size: Size::new(None, None),
+            call_on_fun: CallOnFun::new(),
+            text: Text::new(text.as_str(), TextAlign::Left),
+            mnemonic_index: mnemonic_idx,
+            menu_index: None,
+        }
+    }
+
+    pub fn set_weight(&mut self, weight: u32)
+    {
+        let old_weight = self.weight;
+        self.weight = weight;
+        if old_weight != self.weight {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+    
+    pub fn set_h_align(&mut self, align: HAlign)
+    {
+        let old_h_align = self.h_align;
+        self.h_align = align;
+        if old_h_align != self.h_align {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_v_align(&mut self, align: VAlign)
+    {
+        let old_v_align = self.v_align;
+        self.v_align = align;
+        if old_v_align != self.v_align {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_enabled(&mut self, is_enabled: bool)
+    {
+        let old_enabled_flag = self.is_enabled;
+        self.is_enabled = is_enabled;
+        if old_enabled_flag != self.is_enabled {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_dyn_on(&mut self, f: Box<dyn FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static>)
+    { self.call_on_fun.fun = f; }
+
+    pub fn set_on<F>(&mut self, f: F)
+        where F: FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static
+    { self.set_dyn_on(Box::new(f)) }
+
+    pub fn text(&self) -> &str
+    { self.text.text.as_str() }
+    
+    /// Sets the text with an optional mnemonic character.
+    ///
+    /// An underscore marks the next character as the mnemonic character and two underscores are
+    /// replaced by one underscore.
+    pub fn set_text(&mut self, s: &str)
+    {
+        let (text, mnemonic_idx) = mnemonic_text(s);
+        self.text.text = text;
+        self.mnemonic_index = mnemonic_idx;
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+    }
+
+    pub fn mnemonic_index(&self) -> Option<usize>
+    { self.mnemonic_index }
+
+    /// Returns the lowercase mnemonic character or `None`.
+    pub fn mnemonic_char(&self) -> Option<char>
+    { mnemonic_char(self.text.text.as_str(), self.mnemonic_index) }
+
+    pub fn menu_index(&self) -> Option<WindowIndex>
+    { self.menu_index }
+
+    /// Sets the index of a menu window that is opened by the menu bar item.
+    pub fn set_menu_index(&mut self, idx: Option<WindowIndex>)
+    { self.menu_index = idx; }
+
+    pub fn text_align(&self) -> TextAlign
+    { self.text.align }
+    
+    pub fn set_text_align(&mut self, align: TextAlign)
+    {
+        let old_align = self.text.align;
+        self.text.align = align;
+        if old_align != self.text.align {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn ellipsize_count(&self) -> Option<usize>
+    { self.text.ellipsize_count }
+    
+    pub fn set_ellipsize_count(&mut self, ellipsize_count: Option<usize>)
+    {
+        let old_ellipsize_count = self.text.ellipsize_count;
+        self.text.ellipsize_count = ellipsize_count;
+        if old_ellipsize_count != self.text.ellipsize_count {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn is_trimmed(&self) -> bool
+    { self.text.is_trimmed }
+
+    pub fn set_trim(&mut self, is_trimmed: bool)
+    { self.text.is_trimmed = is_trimmed; }
+}
+
+impl Widget for MenuBarItem
+{
+    fn margin_bounds(&self) -> Rect<i32>
+    { self.margin_bounds }
+    
+    fn bounds(&self) -> Rect<i32>
+    { self.bounds }
+
+    fn weight(&self) -> u32
+    { self.weight }
+    
+    fn h_align(&self) -> HAlign
+    { self.h_align }
+    
+    fn v_align(&self) -> VAlign
+    { self.v_align }
+
+    fn state(&self) -> WidgetState
+    { self.state }
+    
+    fn set_state(&mut self, state: WidgetState)
+    {
+        let old_state = self.state;
+        self.state = state;
+        if old_state != self.state {
+            self.dirty_rect = Some(self.margin_bounds);
+            self.dirty_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn is_enabled(&self) -> bool
+    { self.is_enabled }
+
+    fn is_clickable(&self) -> bool
+    { self.is_enabled }
+    
+    fn h_scroll_bar_slider_x(&self, viewport_width: i32, trough_width: i32) -> f64
+    { h_scroll_bar_slider_x(self.client_pos.x, self.margin_bounds.width, viewport_width, trough_width) }
+
+    fn h_scroll_bar_slider_width(&self, viewport_width: i32, trough_width: i32) -> f64
+    { h_scroll_bar_slider_width(self.margin_bounds.width, viewport_width, trough_width) }
+
+    fn set_client_x(&mut self, viewport_width: i32, slider_x: f64, trough_width: i32)
+    {
+        let old_client_x = self.client_pos.x;
+        set_client_x(&mut self.client_pos.x, self.margin_bounds.width, viewport_width, slider_x, trough_width);
+        if old_client_x != self.client_pos.x {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+    
+    fn update_client_x(&mut self, viewport_width: i32) -> bool
+    { update_client_x(&mut self.client_pos.x, self.margin_bounds.width, viewport_width) }
+    
+    fn v_scroll_bar_slider_y(&self, viewport_height: i32, trough_height: i32) -> f64
+    { v_scroll_bar_slider_y(self.client_pos.y, self.margin_bounds.height, viewport_height, trough_height) }
+    
+    fn v_scroll_bar_slider_height(&self, viewport_height: i32, trough_height: i32) -> f64
+    { v_scroll_bar_slider_height(self.margin_bounds.height, viewport_height, trough_height) }
+
+    fn set_client_y(&mut self, viewport_height: i32, slider_y: f64, trough_height: i32)
+    {
+        let old_client_y = self.client_pos.y;
+        set_client_y(&mut self.client_pos.y, self.margin_bounds.height, viewport_height, slider_y, trough_height);
+        if old_client_y != self.client_pos.y {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn update_client_y(&mut self, viewport_height: i32) -> bool
+    { update_client_y(&mut self.client_pos.y, self.margin_bounds.height, viewport_height) }
+    
+    fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.change_flag_arc = flag_arc; }
+
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.dirty_flag_arc = flag_arc; }
+
+    fn dirty_rect(&self) -> Option<Rect<i32>>
+    { self.dirty_rect }
+
+    fn clear_dirty_rect(&mut self)
+    { self.dirty_rect = None; }
+}
+
+impl Container for MenuBarItem
+{}
+
+impl PreferredSize for MenuBarItem
+{
+    fn preferred_size(&self) -> Size<Option<i32>>
+    { self.preferred_size }
+    
+    fn set_preferred_size(&mut self, size: Size<Option<i32>>)
+    {
+        let old_preferred_size = self.preferred_size;
+        self.preferred_size = size;
+        if old_preferred_size != self.preferred_size {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Draw for MenuBarItem
+{
+    fn update_size(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_size: Size<Option<i32>>) -> Result<(), CairoError>
+    {
+        let inner_area_size = inner_opt_size(area_size, theme.menu_bar_item_margin_edges());
+        let padding_area_size = inner_opt_size(inner_area_size, theme.menu_bar_item_padding_edges());
+        self.text.update_size(cairo_context, padding_area_size, |cairo_context| {
+                theme.set_menu_bar_item_font(cairo_context)
+        })?;
+        let padding_size = Size::new(self.text.max_line_width(), self.text.line_height * self.text.lines.len() as i32);
+        self.bounds.set_size(outer_size(padding_size, theme.menu_bar_item_padding_edges()));
+        self.bounds.set_size(max_size_for_opt_size(self.bounds.size(), self.preferred_size));
+        self.margin_bounds.set_size(outer_size(self.bounds.size(), theme.menu_bar_item_margin_edges()));
+        self.margin_bounds.set_size(size_for_h_align_and_v_align(self.margin_bounds.size(), area_size, self.h_align, self.v_align));
+        self.bounds.set_size(inner_size(self.margin_bounds.size(), theme.menu_bar_item_margin_edges()));
+        Ok(())
+    }
+    
+    fn update_pos(&mut self, _cairo_context: &CairoContext, theme: &dyn Theme, area_bounds: Rect<i32>) -> Result<(), CairoError>
+    {
+        self.margin_bounds.set_pos(pos_for_h_align_and_v_align(self.margin_bounds.size(), area_bounds, self.h_align, self.v_align));
+        self.margin_bounds.x -= self.client_pos.x;
+        self.margin_bounds.y -= self.client_pos.y;
+        self.bounds.set_pos(inner_pos(self.margin_bounds, theme.menu_bar_item_margin_edges()));
+        Ok(())
+    }
+
+    fn draw(&self, cairo_context: &CairoContext, theme: &dyn Theme, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        cairo_context.save()?;
+        cairo_context.rectangle(self.bounds.x as f64, self.bounds.y as f64, self.bounds.width as f64, self.bounds.height as f64);
+        cairo_context.clip();
+        theme.draw_menu_bar_item_bg(cairo_context, self.bounds, self.state, self.is_enabled, is_focused_window)?;
+        let padding_bounds = inner_rect(self.bounds, theme.menu_bar_item_padding_edges());
+        cairo_context.rectangle(padding_bounds.x as f64, padding_bounds.y as f64,  padding_bounds.width as f64, padding_bounds.height as f64);
+        cairo_context.clip();
+        let mut line_idx = 0;
+        self.text.draw(cairo_context, padding_bounds, |cairo_context| {
+                theme.set_menu_bar_item_font(cairo_context)
+        }, |cairo_context, pos, s| {
+                let mnemonic_idx = match self.mnemonic_index {
+                    Some(idx) => self.text.index_in_line(line_idx, idx),
+                    None => None,
+                };
+                line_idx += 1;
+                theme.draw_menu_bar_item_text(cairo_context, pos, s, mnemonic_idx, self.state, self.is_enabled, is_focused_window)
+        })?;
+        cairo_context.restore()?;
+        Ok(())
+    }
+}
+
+impl CallOn for MenuBarItem
+{
+    fn call_on(&mut self, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Event>>
+    {
+        let default_event = if let Some(tmp_default_event) = default_menu_bar_item_on(self, client_context, queue_context, event)? {
+            tmp_default_event
+        } else {
+            None
+        };
+        self.call_on_fun.call_on(client_context, queue_context, event, default_event)
+    }
+}
+
+impl AsAny for MenuBarItem
+{
+    fn as_any(&self) -> &dyn Any
+    { self }
+    
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    { self }
+}
+
+fn default_menu_bar_item_on_for_client_pointer(widget: &mut dyn Widget, _client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
+{
+    let is_entered = match event {
+        Event::Client(ClientEvent::PointerEnter(_)) => true,
+        Event::Client(ClientEvent::PointerMotion(_, _)) => queue_context.motion_call_on_path(CallOnId::Pointer) != queue_context.current_call_on_path(),
+        _ => false,
+    };
+    if is_entered && widget.is_enabled() {
+        match queue_context.current_call_on_path()? {
+            CallOnPath::Widget(abs_widget_path) => {
+                let tmp_abs_widget_path = abs_widget_path.clone();
+                queue_context.push_callback(move |_, window_context, _| {
+                        switch_menu_bar_item_menu_for_hover(window_context, &tmp_abs_widget_path);
+                        Some(())
+                });
+            },
+            _ => (),
+        }
+    }
+    Some(None)
+}
+
+fn default_menu_bar_item_on_for_clicks(widget: &mut dyn Widget, _client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
+{
+    match event {
+        Event::Click | Event::DoubleClick | Event::LongClick | Event::PopupClick => {
+            if widget.is_enabled() {
+                match queue_context.current_call_on_path()? {
+                    CallOnPath::Widget(abs_widget_path) => {
+                        let tmp_abs_widget_path = abs_widget_path.clone();
+                        queue_context.push_callback(move |_, window_context, _| {
+                                open_menu_bar_item_menu(window_context, &tmp_abs_widget_path, false);
+                                Some(())
+                        });
+                    },
+                    _ => (),
+                }
+            }
+            Some(Some(None))
+        },
+        _ => Some(None),
+    }
+}
+
+fn default_menu_bar_item_on(widget: &mut dyn Widget, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
+{
+    if let Some(res) = default_menu_bar_item_on_for_client_pointer(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else if let Some(res) = default_widget_on_for_client_pointer(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else if let Some(res) = default_widget_on_for_client_keyboard(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else if let Some(res) = default_widget_on_for_client_touch(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else if let Some(res) = default_menu_bar_item_on_for_clicks(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else if let Some(res) = default_widget_on_for_key_and_char(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else if let Some(res) = default_widget_on_for_window_events(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else {
+        Some(None)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::mocks::*;
+
+    #[test]
+    fn test_menu_bar_item_updates_size_and_position()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_menu_bar_item_margin_edges(Edges::new(1, 2, 3, 4));
+        theme.set_menu_bar_item_padding_edges(Edges::new(2, 3, 4, 5));
+        theme.set_menu_bar_item_font_size(16.0);
+        let mut menu_bar_item = MenuBarItem::new("_Label");
+        theme.set_menu_bar_item_font(&cairo_context).unwrap();
+        let l = cairo_context.text_extents("L").unwrap().x_advance;
+        let a = cairo_context.text_extents("a").unwrap().x_advance;
+        let b = cairo_context.text_extents("b").unwrap().x_advance;
+        let e = cairo_context.text_extents("e").unwrap().x_advance;
+        let l2 = cairo_context.text_extents("l").unwrap().x_advance;
+        let text_width = l + a + b + e + l2;
+        let font_height = cairo_context.font_extents().unwrap().height;
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match menu_bar_item.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        let expected_width = 4 + (text_width.ceil() as i32) + 5;
+        let expected_height = 2 + (font_height.ceil() as i32) + 3;
+        assert_eq!(Size::new(expected_width, expected_height), menu_bar_item.bounds.size());
+        let expected_margin_width = 3 + expected_width + 4;
+        let expected_margin_height = 1 + expected_height + 2;
+        assert_eq!(Size::new(expected_margin_width, expected_margin_height), menu_bar_item.margin_bounds.size());
+        let area_bounds = Rect::new(6, 7, menu_bar_item.margin_bounds.width, menu_bar_item.margin_bounds.height);
+        match menu_bar_item.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        let expected_margin_x = 6;
+        let expected_margin_y = 7;
+        assert_eq!(Pos::new(expected_margin_x, expected_margin_y), menu_bar_item.margin_bounds.pos());
+        let expected_x = expected_margin_x + 3;
+        let expected_y = expected_margin_y + 1;
+        assert_eq!(Pos::new(expected_x, expected_y), menu_bar_item.bounds.pos());
+        assert_eq!(Size::new(expected_width, expected_height), menu_bar_item.bounds.size());
+        assert_eq!(Size::new(expected_margin_width, expected_margin_height), menu_bar_item.margin_bounds.size());
+    }
+
+    #[test]
+    fn test_menu_bar_item_parses_mnemonic_in_text()
+    {
+        let mut menu_bar_item = MenuBarItem::new("_File");
+        assert_eq!("File", menu_bar_item.text());
+        assert_eq!(Some(0), menu_bar_item.mnemonic_index());
+        assert_eq!(Some('f'), menu_bar_item.mnemonic_char());
+        menu_bar_item.set_text("Sa_ve__as");
+        assert_eq!("Save_as", menu_bar_item.text());
+        assert_eq!(Some(2), menu_bar_item.mnemonic_index());
+        assert_eq!(Some('v'), menu_bar_item.mnemonic_char());
+        menu_bar_item.set_text("Edit");
+        assert_eq!("Edit", menu_bar_item.text());
+        assert_eq!(None, menu_bar_item.mnemonic_index());
+        assert_eq!(None, menu_bar_item.mnemonic_char());
+    }
+}
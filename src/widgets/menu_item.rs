@@ -0,0 +1,748 @@
+//
+// Copyright (c) 2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::any::Any;
+use std::cmp::max;
+use std::cmp::min;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use crate::as_any::*;
+use crate::call_on::*;
+use crate::client_context::*;
+use crate::container::*;
+use crate::draw::*;
+use crate::event_queue::*;
+use crate::events::*;
+use crate::image::*;
+use crate::keys::*;
+use crate::preferred_size::*;
+use crate::queue_context::*;
+use crate::text::*;
+use crate::theme::*;
+use crate::types::*;
+use crate::utils::*;
+use crate::widget::*;
+use crate::widgets::radio_group::*;
+use crate::windows::*;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum MenuItemType
+{
+    Plain,
+    Check,
+    Radio,
+    Separator,
+}
+
+/// A structure of menu item.
+///
+/// The menu item can be a plain item, a check item, a radio item, or a separator. The menu item
+/// can have an icon, a shortcut, and a submenu that is opened when the menu item is clicked. An
+/// underscore in the text marks the next character as a mnemonic character.
+pub struct MenuItem
+{
+    margin_bounds: Rect<i32>,
+    bounds: Rect<i32>,
+    client_pos: Pos<i32>,
+    weight: u32,
+    h_align: HAlign,
+    v_align: VAlign,
+    state: WidgetState,
+    is_enabled: bool,
+    is_focused: bool,
+    change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    dirty_rect: Option<Rect<i32>>,
+    preferred_size: Size<Option<i32>>,
+    call_on_fun: CallOnFun,
+    item_type: MenuItemType,
+    text: Text,
+    mnemonic_index: Option<usize>,
+    shortcut: Option<(VKey, KeyModifiers)>,
+    shortcut_text: Text,
+    image: Option<Image>,
+    is_checked: bool,
+    selection_number: usize,
+    group: Option<Arc<RadioGroup>>,
+    submenu_index: Option<WindowIndex>,
+}
+
+impl MenuItem
+{
+    pub fn new(s: &str) -> Self
+    { Self::new_with_type(s, MenuItemType::Plain, None) }
+
+    pub fn new_check(s: &str) -> Self
+    { Self::new_with_type(s, MenuItemType::Check, None) }
+
+    pub fn new_radio(s: &str) -> Self
+    { Self::new_radio_with_group(s, Arc::new(RadioGroup::new())) }
+
+    pub fn new_radio_with_group(s: &str, group: Arc<RadioGroup>) -> Self
+    { Self::new_with_type(s, MenuItemType::Radio, Some(group)) }
+
+    pub fn new_separator() -> Self
+    { Self::new_with_type("", MenuItemType::Separator, None) }
+
+    fn new_with_type(s: &str, item_type: MenuItemType, group: Option<Arc<RadioGroup>>) -> Self
+    {
+        let (text, mnemonic_idx) = mnemonic_text(s);
+        let selection_number = match &group {
+            Some(group) => group.increase_count(),
+            None => 0,
+        };
+        MenuItem {
+            margin_bounds: Rect::new(0, 0, 0, 0),
+            bounds: Rect::new(0, 0, 0, 0),
+            client_pos: Pos::new(0, 0),
+            weight: 0,
+            h_align: HAlign::Fill,
+            v_align: VAlign::Top,
+            state: WidgetState::None,
+            is_enabled: true,
+            is_focused: false,
+            change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_rect: None,
+            preferred_size: Size::new(None, None),
+            call_on_fun: CallOnFun::new(),
+            item_type,
+            text: Text::new(text.as_str(), TextAlign::Left),
+            mnemonic_index: mnemonic_idx,
+            shortcut: None,
+            shortcut_text: Text::new("", TextAlign::Right),
+            image: None,
+            is_checked: false,
+            selection_number,
+            group,
+            submenu_index: None,
+        }
+    }
+
+    pub fn set_weight(&mut self, weight: u32)
+    {
+        let old_weight = self.weight;
+        self.weight = weight;
+        if old_weight != self.weight {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_h_align(&mut self, align: HAlign)
+    {
+        let old_h_align = self.h_align;
+        self.h_align = align;
+        if old_h_align != self.h_align {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_v_align(&mut self, align: VAlign)
+    {
+        let old_v_align = self.v_align;
+        self.v_align = align;
+        if old_v_align != self.v_align {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_enabled(&mut self, is_enabled: bool)
+    {
+        let old_enabled_flag = self.is_enabled;
+        self.is_enabled = is_enabled;
+        if old_enabled_flag != self.is_enabled {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_dyn_on(&mut self, f: Box<dyn FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static>)
+    { self.call_on_fun.fun = f; }
+
+    pub fn set_on<F>(&mut self, f: F)
+        where F: FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static
+    { self.set_dyn_on(Box::new(f)) }
+
+    pub fn item_type(&self) -> MenuItemType
+    { self.item_type }
+
+    pub fn text(&self) -> &str
+    { self.text.text.as_str() }
+
+    /// Sets the text with an optional mnemonic character.
+    ///
+    /// An underscore marks the next character as the mnemonic character and two underscores are
+    /// replaced by one underscore.
+    pub fn set_text(&mut self, s: &str)
+    {
+        let (text, mnemonic_idx) = mnemonic_text(s);
+        self.text.text = text;
+        self.mnemonic_index = mnemonic_idx;
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+    }
+
+    pub fn mnemonic_index(&self) -> Option<usize>
+    { self.mnemonic_index }
+
+    /// Returns the lowercase mnemonic character or `None`.
+    pub fn mnemonic_char(&self) -> Option<char>
+    { mnemonic_char(self.text.text.as_str(), self.mnemonic_index) }
+
+    pub fn shortcut(&self) -> Option<(VKey, KeyModifiers)>
+    { self.shortcut }
+
+    pub fn shortcut_text(&self) -> Option<&str>
+    {
+        match self.shortcut {
+            Some(_) => Some(self.shortcut_text.text.as_str()),
+            None => None,
+        }
+    }
+
+    /// Sets the shortcut and the shortcut label.
+    ///
+    /// The menu item is clicked if the key with the key modifiers is pressed in a toplevel window
+    /// with a menu bar that leads to the menu item. The shortcut label is displayed on the right
+    /// side of the menu item.
+    pub fn set_shortcut(&mut self, key: VKey, modifiers: KeyModifiers, s: &str)
+    {
+        self.shortcut = Some((key, modifiers));
+        self.shortcut_text.text = String::from(s);
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+    }
+
+    pub fn unset_shortcut(&mut self)
+    {
+        self.shortcut = None;
+        self.shortcut_text.text = String::new();
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+    }
+
+    pub fn set_dyn_icon_image(&mut self, size_f: Box<dyn Fn(&dyn Theme) -> Size<i32> + Send + Sync + 'static>, drawing_f: Box<dyn Fn(&CairoContext, &dyn Theme, Pos<i32>, WidgetState, bool, bool, bool) -> Result<(), CairoError> + Send + Sync + 'static>)
+    {
+        match &mut self.image {
+            Some(image) => {
+                image.size_fun = size_f;
+                image.drawing_fun = drawing_f;
+            },
+            None => {
+                self.image = Some(Image::new(size_f, drawing_f));
+            },
+        }
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+    }
+
+    pub fn set_icon_image<F, G>(&mut self, size_f: F, drawing_f: G)
+        where F: Fn(&dyn Theme) -> Size<i32> + Send + Sync + 'static,
+              G: Fn(&CairoContext, &dyn Theme, Pos<i32>, WidgetState, bool, bool, bool) -> Result<(), CairoError> + Send + Sync + 'static
+    { self.set_dyn_icon_image(Box::new(size_f), Box::new(drawing_f)) }
+
+    pub fn set_icon(&mut self, icon: ButtonIcon)
+    {
+        self.set_icon_image(move |theme| {
+                theme.button_icon_size()
+        }, move |cairo_context, theme, pos, state, is_enabled, is_focused, is_focused_window| {
+                theme.draw_button_icon(cairo_context, pos, icon, state, is_enabled, is_focused, is_focused_window)
+        })
+    }
+
+    pub fn unset_icon(&mut self)
+    {
+        self.image = None;
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_checked(&self) -> bool
+    { self.is_checked }
+
+    pub fn set_check(&mut self, is_checked: bool)
+    {
+        let old_check_flag = self.is_checked;
+        self.is_checked = is_checked;
+        if old_check_flag != self.is_checked {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn is_selected(&self) -> bool
+    {
+        match &self.group {
+            Some(group) => group.selected() == self.selection_number,
+            None => false,
+        }
+    }
+
+    pub fn selection_number(&self) -> usize
+    { self.selection_number }
+
+    /// Selects the radio menu item and returns the selection number.
+    ///
+    /// This method returns zero and doesn't select anything if the menu item isn't a radio menu
+    /// item.
+    pub fn select(&self) -> usize
+    {
+        match &self.group {
+            Some(group) => group.select(self.selection_number),
+            None => 0,
+        }
+    }
+
+    pub fn group(&self) -> Option<Arc<RadioGroup>>
+    { self.group.clone() }
+
+    pub fn submenu_index(&self) -> Option<WindowIndex>
+    { self.submenu_index }
+
+    /// Sets the index of a menu window that is opened as a submenu by the menu item.
+    pub fn set_submenu_index(&mut self, idx: Option<WindowIndex>)
+    {
+        let old_submenu_index = self.submenu_index;
+        self.submenu_index = idx;
+        if old_submenu_index != self.submenu_index {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Widget for MenuItem
+{
+    fn margin_bounds(&self) -> Rect<i32>
+    { self.margin_bounds }
+
+    fn bounds(&self) -> Rect<i32>
+    { self.bounds }
+
+    fn weight(&self) -> u32
+    { self.weight }
+
+    fn h_align(&self) -> HAlign
+    { self.h_align }
+
+    fn v_align(&self) -> VAlign
+    { self.v_align }
+
+    fn state(&self) -> WidgetState
+    { self.state }
+
+    fn set_state(&mut self, state: WidgetState)
+    {
+        let old_state = self.state;
+        self.state = state;
+        if old_state != self.state {
+            self.dirty_rect = Some(self.margin_bounds);
+            self.dirty_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn is_enabled(&self) -> bool
+    { self.is_enabled }
+
+    fn is_focusable(&self) -> bool
+    { self.is_enabled && self.item_type != MenuItemType::Separator }
+
+    fn is_focused(&self) -> bool
+    { self.is_focusable() && self.is_focused }
+
+    fn set_focus(&mut self, is_focused: bool) -> bool
+    {
+        if self.is_focusable() {
+            let old_focus_flag = self.is_focused;
+            self.is_focused = is_focused;
+            if old_focus_flag != self.is_focused {
+                self.dirty_rect = Some(self.margin_bounds);
+                self.dirty_flag_arc.store(true, Ordering::SeqCst);
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    fn h_scroll_bar_slider_x(&self, viewport_width: i32, trough_width: i32) -> f64
+    { h_scroll_bar_slider_x(self.client_pos.x, self.margin_bounds.width, viewport_width, trough_width) }
+
+    fn h_scroll_bar_slider_width(&self, viewport_width: i32, trough_width: i32) -> f64
+    { h_scroll_bar_slider_width(self.margin_bounds.width, viewport_width, trough_width) }
+
+    fn set_client_x(&mut self, viewport_width: i32, slider_x: f64, trough_width: i32)
+    {
+        let old_client_x = self.client_pos.x;
+        set_client_x(&mut self.client_pos.x, self.margin_bounds.width, viewport_width, slider_x, trough_width);
+        if old_client_x != self.client_pos.x {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn update_client_x(&mut self, viewport_width: i32) -> bool
+    { update_client_x(&mut self.client_pos.x, self.margin_bounds.width, viewport_width) }
+
+    fn v_scroll_bar_slider_y(&self, viewport_height: i32, trough_height: i32) -> f64
+    { v_scroll_bar_slider_y(self.client_pos.y, self.margin_bounds.height, viewport_height, trough_height) }
+
+    fn v_scroll_bar_slider_height(&self, viewport_height: i32, trough_height: i32) -> f64
+    { v_scroll_bar_slider_height(self.margin_bounds.height, viewport_height, trough_height) }
+
+    fn set_client_y(&mut self, viewport_height: i32, slider_y: f64, trough_height: i32)
+    {
+        let old_client_y = self.client_pos.y;
+        set_client_y(&mut self.client_pos.y, self.margin_bounds.height, viewport_height, slider_y, trough_height);
+        if old_client_y != self.client_pos.y {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn update_client_y(&mut self, viewport_height: i32) -> bool
+    { update_client_y(&mut self.client_pos.y, self.margin_bounds.height, viewport_height) }
+
+    fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.change_flag_arc = flag_arc; }
+
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.dirty_flag_arc = flag_arc; }
+
+    fn dirty_rect(&self) -> Option<Rect<i32>>
+    { self.dirty_rect }
+
+    fn clear_dirty_rect(&mut self)
+    { self.dirty_rect = None; }
+}
+
+impl Container for MenuItem
+{}
+
+impl PreferredSize for MenuItem
+{
+    fn preferred_size(&self) -> Size<Option<i32>>
+    { self.preferred_size }
+
+    fn set_preferred_size(&mut self, size: Size<Option<i32>>)
+    {
+        let old_preferred_size = self.preferred_size;
+        self.preferred_size = size;
+        if old_preferred_size != self.preferred_size {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Draw for MenuItem
+{
+    fn update_size(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_size: Size<Option<i32>>) -> Result<(), CairoError>
+    {
+        match self.item_type {
+            MenuItemType::Separator => {
+                self.bounds.set_size(Size::new(0, theme.menu_item_separator_height()));
+            },
+            _ => {
+                let inner_area_size = inner_opt_size(area_size, theme.menu_item_margin_edges());
+                let mut padding_area_size = inner_opt_size(inner_area_size, theme.menu_item_padding_edges());
+                let icon_size = theme.menu_item_icon_size();
+                let arrow_size = theme.menu_item_arrow_size();
+                let sep_width = theme.menu_item_sep_width();
+                let mut padding_size = Size::new(icon_size.width + sep_width, max(icon_size.height, arrow_size.height));
+                if self.shortcut.is_some() {
+                    self.shortcut_text.update_size(cairo_context, Size::new(None, None), |cairo_context| {
+                            theme.set_menu_item_font(cairo_context)
+                    })?;
+                    padding_size.width += sep_width + self.shortcut_text.max_line_width();
+                    padding_size.height = max(padding_size.height, self.shortcut_text.line_height * self.shortcut_text.lines.len() as i32);
+                }
+                padding_size.width += sep_width + arrow_size.width;
+                match padding_area_size.width {
+                    Some(padding_area_width) => {
+                        if padding_area_width > padding_size.width {
+                            padding_area_size.width = Some(padding_area_width - padding_size.width);
+                        } else {
+                            padding_area_size.width = Some(0);
+                        }
+                    },
+                    None => (),
+                }
+                self.text.update_size(cairo_context, padding_area_size, |cairo_context| {
+                        theme.set_menu_item_font(cairo_context)
+                })?;
+                padding_size.width += self.text.max_line_width();
+                padding_size.height = max(padding_size.height, self.text.line_height * self.text.lines.len() as i32);
+                self.bounds.set_size(outer_size(padding_size, theme.menu_item_padding_edges()));
+            },
+        }
+        self.bounds.set_size(max_size_for_opt_size(self.bounds.size(), self.preferred_size));
+        self.margin_bounds.set_size(outer_size(self.bounds.size(), theme.menu_item_margin_edges()));
+        self.margin_bounds.set_size(size_for_h_align_and_v_align(self.margin_bounds.size(), area_size, self.h_align, self.v_align));
+        self.bounds.set_size(inner_size(self.margin_bounds.size(), theme.menu_item_margin_edges()));
+        Ok(())
+    }
+
+    fn update_pos(&mut self, _cairo_context: &CairoContext, theme: &dyn Theme, area_bounds: Rect<i32>) -> Result<(), CairoError>
+    {
+        self.margin_bounds.set_pos(pos_for_h_align_and_v_align(self.margin_bounds.size(), area_bounds, self.h_align, self.v_align));
+        self.margin_bounds.x -= self.client_pos.x;
+        self.margin_bounds.y -= self.client_pos.y;
+        self.bounds.set_pos(inner_pos(self.margin_bounds, theme.menu_item_margin_edges()));
+        Ok(())
+    }
+
+    fn draw(&self, cairo_context: &CairoContext, theme: &dyn Theme, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        cairo_context.save()?;
+        cairo_context.rectangle(self.bounds.x as f64, self.bounds.y as f64, self.bounds.width as f64, self.bounds.height as f64);
+        cairo_context.clip();
+        match self.item_type {
+            MenuItemType::Separator => {
+                theme.draw_menu_item_separator(cairo_context, self.bounds, is_focused_window)?;
+                cairo_context.restore()?;
+                return Ok(());
+            },
+            _ => (),
+        }
+        theme.draw_menu_item_bg(cairo_context, self.bounds, self.state, self.is_enabled, self.is_focused(), is_focused_window)?;
+        let padding_bounds = inner_rect(self.bounds, theme.menu_item_padding_edges());
+        cairo_context.rectangle(padding_bounds.x as f64, padding_bounds.y as f64,  padding_bounds.width as f64, padding_bounds.height as f64);
+        cairo_context.clip();
+        let icon_size = theme.menu_item_icon_size();
+        let arrow_size = theme.menu_item_arrow_size();
+        let sep_width = theme.menu_item_sep_width();
+        let icon_pos = Pos::new(padding_bounds.x, padding_bounds.y + (padding_bounds.height - icon_size.height) / 2);
+        match self.item_type {
+            MenuItemType::Check => theme.draw_menu_item_check(cairo_context, icon_pos, self.is_checked, self.state, self.is_enabled, self.is_focused(), is_focused_window)?,
+            MenuItemType::Radio => theme.draw_menu_item_radio(cairo_context, icon_pos, self.is_selected(), self.state, self.is_enabled, self.is_focused(), is_focused_window)?,
+            _ => {
+                match &self.image {
+                    Some(image) => {
+                        let area_bounds = Rect::new(icon_pos.x, padding_bounds.y, min(icon_size.width, padding_bounds.width), padding_bounds.height);
+                        image.draw(cairo_context, theme, area_bounds, self.state, self.is_enabled, self.is_focused(), is_focused_window)?;
+                    },
+                    None => (),
+                }
+            },
+        }
+        let x = padding_bounds.x + icon_size.width + sep_width;
+        let mut right_x = padding_bounds.x + padding_bounds.width - arrow_size.width;
+        if self.submenu_index.is_some() {
+            let arrow_pos = Pos::new(right_x, padding_bounds.y + (padding_bounds.height - arrow_size.height) / 2);
+            theme.draw_menu_item_arrow(cairo_context, arrow_pos, self.state, self.is_enabled, self.is_focused(), is_focused_window)?;
+        }
+        right_x -= sep_width;
+        if self.shortcut.is_some() {
+            let shortcut_width = self.shortcut_text.max_line_width();
+            let area_bounds = Rect::new(right_x - shortcut_width, padding_bounds.y, shortcut_width, padding_bounds.height);
+            self.shortcut_text.draw(cairo_context, area_bounds, |cairo_context| {
+                    theme.set_menu_item_font(cairo_context)
+            }, |cairo_context, pos, s| {
+                    theme.draw_menu_item_shortcut_text(cairo_context, pos, s, self.state, self.is_enabled, self.is_focused(), is_focused_window)
+            })?;
+            right_x -= shortcut_width + sep_width;
+        }
+        let area_bounds = Rect::new(x, padding_bounds.y, max(right_x - x, 0), padding_bounds.height);
+        let mut line_idx = 0;
+        self.text.draw(cairo_context, area_bounds, |cairo_context| {
+                theme.set_menu_item_font(cairo_context)
+        }, |cairo_context, pos, s| {
+                let mnemonic_idx = match self.mnemonic_index {
+                    Some(idx) => self.text.index_in_line(line_idx, idx),
+                    None => None,
+                };
+                line_idx += 1;
+                theme.draw_menu_item_text(cairo_context, pos, s, mnemonic_idx, self.state, self.is_enabled, self.is_focused(), is_focused_window)
+        })?;
+        cairo_context.restore()?;
+        Ok(())
+    }
+}
+
+impl CallOn for MenuItem
+{
+    fn call_on(&mut self, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Event>>
+    {
+        let default_event = if let Some(tmp_default_event) = default_menu_item_on(self, client_context, queue_context, event)? {
+            tmp_default_event
+        } else {
+            None
+        };
+        self.call_on_fun.call_on(client_context, queue_context, event, default_event)
+    }
+}
+
+impl AsAny for MenuItem
+{
+    fn as_any(&self) -> &dyn Any
+    { self }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    { self }
+}
+
+fn default_menu_item_on_for_client_pointer(widget: &mut dyn Widget, _client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
+{
+    let is_entered = match event {
+        Event::Client(ClientEvent::PointerEnter(_)) => true,
+        Event::Client(ClientEvent::PointerMotion(_, _)) => queue_context.motion_call_on_path(CallOnId::Pointer) != queue_context.current_call_on_path(),
+        _ => false,
+    };
+    if is_entered && widget.is_focusable() {
+        match queue_context.current_call_on_path()? {
+            CallOnPath::Widget(abs_widget_path) => {
+                let tmp_abs_widget_path = abs_widget_path.clone();
+                queue_context.push_callback(move |_, window_context, _| {
+                        let window = window_context.dyn_window_mut(tmp_abs_widget_path.window_index())?;
+                        window.set_focused_rel_widget_path(Some(tmp_abs_widget_path.as_rel_widget_path().clone()));
+                        Some(())
+                });
+            },
+            _ => (),
+        }
+    }
+    Some(None)
+}
+
+fn default_menu_item_on_for_clicks(widget: &mut dyn Widget, _client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
+{
+    let menu_item: &mut MenuItem = dyn_widget_mut_as_widget_mut(widget)?;
+    match event {
+        Event::Click | Event::DoubleClick | Event::LongClick | Event::PopupClick => {
+            if menu_item.is_focusable() {
+                match menu_item.item_type() {
+                    MenuItemType::Check => {
+                        menu_item.set_check(!menu_item.is_checked());
+                        queue_context.push_event(Event::CheckChange(menu_item.is_checked()));
+                    },
+                    MenuItemType::Radio => {
+                        let selected = menu_item.select();
+                        queue_context.push_event(Event::RadioSelection(selected));
+                    },
+                    _ => (),
+                }
+                let abs_widget_path = match queue_context.current_call_on_path()? {
+                    CallOnPath::Widget(abs_widget_path) => abs_widget_path.clone(),
+                    _ => return Some(Some(None)),
+                };
+                let bounds = menu_item.bounds();
+                match menu_item.submenu_index() {
+                    Some(submenu_idx) => {
+                        queue_context.push_callback(move |_, window_context, _| {
+                                open_menu(window_context, submenu_idx, abs_widget_path.window_index(), bounds, ClientPopupAnchor::TopRight, Some(abs_widget_path.clone()), false);
+                                Some(())
+                        });
+                    },
+                    None => {
+                        queue_context.push_callback(move |_, window_context, _| {
+                                close_menu_chain(window_context, abs_widget_path.window_index());
+                                Some(())
+                        });
+                    },
+                }
+            }
+            Some(Some(None))
+        },
+        _ => Some(None),
+    }
+}
+
+fn default_menu_item_on(widget: &mut dyn Widget, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
+{
+    if let Some(res) = default_menu_item_on_for_client_pointer(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else if let Some(res) = default_widget_on_for_client_pointer(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else if let Some(res) = default_widget_on_for_client_keyboard(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else if let Some(res) = default_widget_on_for_client_touch(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else if let Some(res) = default_menu_item_on_for_clicks(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else if let Some(res) = default_widget_on_for_key_and_char(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else if let Some(res) = default_widget_on_for_window_events(widget, client_context, queue_context, event)? {
+        Some(Some(res))
+    } else {
+        Some(None)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::mocks::*;
+
+    #[test]
+    fn test_menu_item_updates_size_and_position()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_menu_item_margin_edges(Edges::new(1, 2, 3, 4));
+        theme.set_menu_item_padding_edges(Edges::new(2, 3, 4, 5));
+        theme.set_menu_item_sep_width(6);
+        theme.set_menu_item_icon_size(Size::new(16, 16));
+        theme.set_menu_item_arrow_size(Size::new(8, 8));
+        theme.set_menu_item_font_size(16.0);
+        let mut menu_item = MenuItem::new("_Open");
+        menu_item.set_shortcut(VKey::O, KeyModifiers::CTRL, "Ctrl+O");
+        theme.set_menu_item_font(&cairo_context).unwrap();
+        let text_width = cairo_context.text_extents("Open").unwrap().x_advance;
+        let shortcut_text_width = cairo_context.text_extents("Ctrl+O").unwrap().x_advance;
+        let font_height = cairo_context.font_extents().unwrap().height;
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match menu_item.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        let expected_width = 4 + 16 + 6 + (text_width.ceil() as i32) + 6 + (shortcut_text_width.ceil() as i32) + 6 + 8 + 5;
+        let expected_height = 2 + max(font_height.ceil() as i32, 16) + 3;
+        assert_eq!(Size::new(expected_width, expected_height), menu_item.bounds.size());
+        let expected_margin_width = 3 + expected_width + 4;
+        let expected_margin_height = 1 + expected_height + 2;
+        assert_eq!(Size::new(expected_margin_width, expected_margin_height), menu_item.margin_bounds.size());
+        let area_bounds = Rect::new(6, 7, menu_item.margin_bounds.width, menu_item.margin_bounds.height);
+        match menu_item.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Pos::new(6, 7), menu_item.margin_bounds.pos());
+        assert_eq!(Pos::new(9, 8), menu_item.bounds.pos());
+        assert_eq!(Size::new(expected_width, expected_height), menu_item.bounds.size());
+    }
+
+    #[test]
+    fn test_menu_item_updates_size_for_separator()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_menu_item_margin_edges(Edges::new(1, 2, 3, 4));
+        theme.set_menu_item_separator_height(8);
+        let mut menu_item = MenuItem::new_separator();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        match menu_item.update_size(&cairo_context, &theme, Size::new(Some(100), None)) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Size::new(100 - 7, 8), menu_item.bounds.size());
+        assert_eq!(Size::new(100, 11), menu_item.margin_bounds.size());
+        assert_eq!(false, menu_item.is_focusable());
+    }
+
+    #[test]
+    fn test_menu_item_selects_radio_menu_items()
+    {
+        let group = Arc::new(RadioGroup::new());
+        let menu_item1 = MenuItem::new_radio_with_group("A", group.clone());
+        let menu_item2 = MenuItem::new_radio_with_group("B", group.clone());
+        assert_eq!(false, menu_item1.is_selected());
+        assert_eq!(false, menu_item2.is_selected());
+        assert_eq!(2, menu_item2.select());
+        assert_eq!(false, menu_item1.is_selected());
+        assert_eq!(true, menu_item2.is_selected());
+        let menu_item3 = MenuItem::new("C");
+        assert_eq!(0, menu_item3.select());
+        assert_eq!(false, menu_item3.is_selected());
+    }
+}
@@ -36,11 +36,15 @@ pub struct Radio
     is_enabled: bool,
     is_focused: bool,
     change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    dirty_rect: Option<Rect<i32>>,
     preferred_size: Size<Option<i32>>,
     call_on_fun: CallOnFun,
     text: Text,
     selection_number: usize,
     group: Arc<RadioGroup>,
+    context_menu_index: Option<WindowIndex>,
+    tooltip_text: Option<String>,
 }
 
 impl Radio
@@ -61,11 +65,15 @@ impl Radio
             is_enabled: true,
             is_focused: false,
             change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_rect: None,
             preferred_size: Size::new(None, None),
             call_on_fun: CallOnFun::new(),
             text: Text::new(s, TextAlign::Left),
             selection_number: group.increase_count(),
             group,
+            context_menu_index: None,
+            tooltip_text: None,
         }
     }
 
@@ -169,6 +177,23 @@ impl Radio
         self.group = group;
         self.change_flag_arc.store(true, Ordering::SeqCst);
     }
+
+    /// Sets the index of the context menu for the widget.
+    ///
+    /// See [`Widget::context_menu_index`] for more informations.
+    pub fn set_context_menu_index(&mut self, idx: Option<WindowIndex>)
+    { self.context_menu_index = idx; }
+
+    /// Sets the tooltip text for the widget.
+    ///
+    /// See [`Widget::tooltip_text`] for more informations.
+    pub fn set_tooltip_text(&mut self, s: Option<&str>)
+    {
+        match s {
+            Some(s) => self.tooltip_text = Some(String::from(s)),
+            None => self.tooltip_text = None,
+        }
+    }
 }
 
 impl Widget for Radio
@@ -196,7 +221,8 @@ impl Widget for Radio
         let old_state = self.state;
         self.state = state;
         if old_state != self.state {
-            self.change_flag_arc.store(true, Ordering::SeqCst);
+            self.dirty_rect = Some(self.margin_bounds);
+            self.dirty_flag_arc.store(true, Ordering::SeqCst);
         }
     }
 
@@ -215,7 +241,8 @@ impl Widget for Radio
             let old_focus_flag = self.is_focused;
             self.is_focused = is_focused;
             if old_focus_flag != self.is_focused {
-                self.change_flag_arc.store(true, Ordering::SeqCst);
+                self.dirty_rect = Some(self.margin_bounds);
+                self.dirty_flag_arc.store(true, Ordering::SeqCst);
             }
             true
         } else {
@@ -223,6 +250,17 @@ impl Widget for Radio
         }
     }
     
+    fn context_menu_index(&self) -> Option<WindowIndex>
+    { self.context_menu_index }
+
+    fn tooltip_text(&self) -> Option<&str>
+    {
+        match &self.tooltip_text {
+            Some(tooltip_text) => Some(tooltip_text.as_str()),
+            None => None,
+        }
+    }
+
     fn h_scroll_bar_slider_x(&self, viewport_width: i32, trough_width: i32) -> f64
     { h_scroll_bar_slider_x(self.client_pos.x, self.margin_bounds.width, viewport_width, trough_width) }
 
@@ -261,6 +299,15 @@ impl Widget for Radio
     
     fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
     { self.change_flag_arc = flag_arc; }
+
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.dirty_flag_arc = flag_arc; }
+
+    fn dirty_rect(&self) -> Option<Rect<i32>>
+    { self.dirty_rect }
+
+    fn clear_dirty_rect(&mut self)
+    { self.dirty_rect = None; }
 }
 
 impl Container for Radio
@@ -407,7 +454,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match radio.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -453,7 +500,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_size = Size::new(Some(area_width), None);
         match radio.update_size(&cairo_context, &theme, area_size) {
@@ -500,7 +547,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 - 10;
         let area_size = Size::new(Some(area_width), None);
         match radio.update_size(&cairo_context, &theme, area_size) {
@@ -549,7 +596,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 + 10;
         radio.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match radio.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -597,7 +644,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 - 10;
         radio.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match radio.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -645,7 +692,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 + 10;
         radio.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 - 10;
         let area_size = Size::new(Some(area_width), None);
         match radio.update_size(&cairo_context, &theme, area_size) {
@@ -692,7 +739,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(None, Some(area_height));
         match radio.update_size(&cairo_context, &theme, area_size) {
@@ -739,7 +786,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 - 10;
         let area_size = Size::new(None, Some(area_height));
         match radio.update_size(&cairo_context, &theme, area_size) {
@@ -788,7 +835,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 + 10;
         radio.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match radio.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -836,7 +883,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 - 10;
         radio.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match radio.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -884,7 +931,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 + 10;
         radio.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 - 10;
         let area_size = Size::new(None, Some(area_height));
         match radio.update_size(&cairo_context, &theme, area_size) {
@@ -932,7 +979,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -981,7 +1028,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1030,7 +1077,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1079,7 +1126,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1128,7 +1175,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1177,7 +1224,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1226,7 +1273,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1275,7 +1322,7 @@ mod tests
         let o = cairo_context.text_extents("o").unwrap().x_advance;
         let text_width = r + a + d + i + o;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
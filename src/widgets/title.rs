@@ -34,6 +34,8 @@ pub struct Title
     state: WidgetState,
     is_enabled: bool,
     change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    dirty_rect: Option<Rect<i32>>,
     preferred_size: Size<Option<i32>>,
     call_on_fun: CallOnFun,
     text: Text,
@@ -55,6 +57,8 @@ impl Title
             state: WidgetState::None,
             is_enabled: true,
             change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_rect: None,
             preferred_size: Size::new(None, None),
             call_on_fun: CallOnFun::new(),
             text,
@@ -169,7 +173,8 @@ impl Widget for Title
         let old_state = self.state;
         self.state = state;
         if old_state != self.state {
-            self.change_flag_arc.store(true, Ordering::SeqCst);
+            self.dirty_rect = Some(self.margin_bounds);
+            self.dirty_flag_arc.store(true, Ordering::SeqCst);
         }
     }
 
@@ -217,6 +222,15 @@ impl Widget for Title
     
     fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
     { self.change_flag_arc = flag_arc; }
+
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.dirty_flag_arc = flag_arc; }
+
+    fn dirty_rect(&self) -> Option<Rect<i32>>
+    { self.dirty_rect }
+
+    fn clear_dirty_rect(&mut self)
+    { self.dirty_rect = None; }
 }
 
 impl Container for Title
@@ -330,7 +344,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match title.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -376,7 +390,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_size = Size::new(Some(area_width), None);
         match title.update_size(&cairo_context, &theme, area_size) {
@@ -423,7 +437,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 - 10;
         let area_size = Size::new(Some(area_width), None);
         match title.update_size(&cairo_context, &theme, area_size) {
@@ -472,7 +486,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 + 10;
         title.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match title.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -520,7 +534,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 - 10;
         title.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match title.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -568,7 +582,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_width = 4 + (text_width.ceil() as i32) + 5 + 10;
         title.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 - 10;
         let area_size = Size::new(Some(area_width), None);
         match title.update_size(&cairo_context, &theme, area_size) {
@@ -615,7 +629,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(None, Some(area_height));
         match title.update_size(&cairo_context, &theme, area_size) {
@@ -662,7 +676,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 - 10;
         let area_size = Size::new(None, Some(area_height));
         match title.update_size(&cairo_context, &theme, area_size) {
@@ -711,7 +725,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 + 10;
         title.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match title.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -759,7 +773,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 - 10;
         title.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match title.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -807,7 +821,7 @@ mod tests
         let font_height = cairo_context.font_extents().unwrap().height;
         let preferred_height = 2 + (font_height.ceil() as i32) + 3 + 10;
         title.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 - 10;
         let area_size = Size::new(None, Some(area_height));
         match title.update_size(&cairo_context, &theme, area_size) {
@@ -855,7 +869,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -904,7 +918,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -953,7 +967,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1002,7 +1016,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1051,7 +1065,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1100,7 +1114,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1149,7 +1163,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1198,7 +1212,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + (text_width.ceil() as i32) + 5 + 4 + 10;
         let area_height = 1 + 2 + (font_height.ceil() as i32) + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
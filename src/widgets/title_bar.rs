@@ -33,6 +33,7 @@ pub struct TitleBar
     state: WidgetState,
     is_enabled: bool,
     change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
     preferred_size: Size<Option<i32>>,
     call_on_fun: CallOnFun,
     widgets: LinearLayoutWidgets,
@@ -51,6 +52,7 @@ impl TitleBar
             state: WidgetState::None,
             is_enabled: true,
             change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
             preferred_size: Size::new(None, None),
             call_on_fun: CallOnFun::new(),
             widgets: LinearLayoutWidgets::new(),
@@ -103,6 +105,7 @@ impl TitleBar
     pub fn add_dyn(&mut self, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
     {
         widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
         match self.widgets.add_dyn(widget) {
             Some(idx_pair) => {
                 self.change_flag_arc.store(true, Ordering::SeqCst);
@@ -118,6 +121,7 @@ impl TitleBar
     pub fn insert_dyn(&mut self, idx_pair: WidgetIndexPair, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
     {
         widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
         match self.widgets.insert_dyn(idx_pair, widget) {
             Some(idx_pair) => {
                 self.change_flag_arc.store(true, Ordering::SeqCst);
@@ -136,6 +140,7 @@ impl TitleBar
             Some(mut widget) => {
                 self.change_flag_arc.store(true, Ordering::SeqCst);
                 widget.set_change_flag_arc(Arc::new(AtomicBool::new(false)));
+                widget.set_dirty_flag_arc(Arc::new(AtomicBool::new(false)));
                 Some(widget)
             },
             None => None,
@@ -148,6 +153,7 @@ impl TitleBar
             Some(mut widget) => {
                 self.change_flag_arc.store(true, Ordering::SeqCst);
                 widget.set_change_flag_arc(Arc::new(AtomicBool::new(false)));
+                widget.set_dirty_flag_arc(Arc::new(AtomicBool::new(false)));
                 Some(widget)
             },
             None => None,
@@ -225,6 +231,9 @@ impl Widget for TitleBar
     
     fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
     { self.change_flag_arc = flag_arc; }
+
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.dirty_flag_arc = flag_arc; }
 }
 
 impl Container for TitleBar
@@ -348,7 +357,7 @@ mod tests
         let e = cairo_context.text_extents("e").unwrap().x_advance;
         let text_width = t + i + t2 + l + e;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match title_bar.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
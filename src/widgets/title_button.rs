@@ -34,6 +34,8 @@ pub struct TitleButton
     state: WidgetState,
     is_enabled: bool,
     change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    dirty_rect: Option<Rect<i32>>,
     preferred_size: Size<Option<i32>>,
     call_on_fun: CallOnFun,
     image: Image,
@@ -53,6 +55,8 @@ impl TitleButton
             state: WidgetState::None,
             is_enabled: true,
             change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_rect: None,
             preferred_size: Size::new(None, None),
             call_on_fun: CallOnFun::new(),
             image: Image::new(move |theme| {
@@ -153,7 +157,8 @@ impl Widget for TitleButton
         let old_state = self.state;
         self.state = state;
         if old_state != self.state {
-            self.change_flag_arc.store(true, Ordering::SeqCst);
+            self.dirty_rect = Some(self.margin_bounds);
+            self.dirty_flag_arc.store(true, Ordering::SeqCst);
         }
     }
 
@@ -201,6 +206,15 @@ impl Widget for TitleButton
     
     fn set_only_change_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
     { self.change_flag_arc = flag_arc; }
+
+    fn set_only_dirty_flag_arc(&mut self, flag_arc: Arc<AtomicBool>)
+    { self.dirty_flag_arc = flag_arc; }
+
+    fn dirty_rect(&self) -> Option<Rect<i32>>
+    { self.dirty_rect }
+
+    fn clear_dirty_rect(&mut self)
+    { self.dirty_rect = None; }
 }
 
 impl Container for TitleButton
@@ -297,7 +311,7 @@ mod tests
         theme.set_title_button_padding_edges(Edges::new(2, 3, 4, 5));
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match title_button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -335,7 +349,7 @@ mod tests
         theme.set_title_button_padding_edges(Edges::new(2, 3, 4, 5));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         title_button.set_icon_image(|_theme| Size::new(14, 12), |_cairo_context, _theme, _pos, _state, _is_enabled, _is_focused, _is_focused_window| Ok(()));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match title_button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -373,7 +387,7 @@ mod tests
         theme.set_title_button_padding_edges(Edges::new(2, 3, 4, 5));
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + 14 + 5 + 4 + 10;
         let area_size = Size::new(Some(area_width), None);
         match title_button.update_size(&cairo_context, &theme, area_size) {
@@ -412,7 +426,7 @@ mod tests
         theme.set_title_button_padding_edges(Edges::new(2, 3, 4, 5));
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + 14 + 5 + 4 - 10;
         let area_size = Size::new(Some(area_width), None);
         match title_button.update_size(&cairo_context, &theme, area_size) {
@@ -453,7 +467,7 @@ mod tests
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         let preferred_width = 4 + 14 + 5 + 10; 
         title_button.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match title_button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -493,7 +507,7 @@ mod tests
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         let preferred_width = 4 + 14 + 5 - 10; 
         title_button.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match title_button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -533,7 +547,7 @@ mod tests
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         let preferred_width = 4 + 14 + 5 + 10;
         title_button.set_preferred_size(Size::new(Some(preferred_width), None));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + 14 + 5 + 4 - 10;
         let area_size = Size::new(Some(area_width), None);
         match title_button.update_size(&cairo_context, &theme, area_size) {
@@ -572,7 +586,7 @@ mod tests
         theme.set_title_button_padding_edges(Edges::new(2, 3, 4, 5));
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + 12 + 3 + 2 + 10;
         let area_size = Size::new(None, Some(area_height));
         match title_button.update_size(&cairo_context, &theme, area_size) {
@@ -611,7 +625,7 @@ mod tests
         theme.set_title_button_padding_edges(Edges::new(2, 3, 4, 5));
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + 12 + 3 + 2 - 10;
         let area_size = Size::new(None, Some(area_height));
         match title_button.update_size(&cairo_context, &theme, area_size) {
@@ -652,7 +666,7 @@ mod tests
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         let preferred_height = 2 + 12 + 3 + 10;
         title_button.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match title_button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -692,7 +706,7 @@ mod tests
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         let preferred_height = 2 + 12 + 3 - 10;
         title_button.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match title_button.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -732,7 +746,7 @@ mod tests
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         let preferred_height = 2 + 12 + 3 + 10;
         title_button.set_preferred_size(Size::new(None, Some(preferred_height)));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = 1 + 2 + 12 + 3 + 2 - 10;
         let area_size = Size::new(None, Some(area_height));
         match title_button.update_size(&cairo_context, &theme, area_size) {
@@ -772,7 +786,7 @@ mod tests
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         title_button.set_h_align(HAlign::Left);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + 14 + 5 + 4 + 10;
         let area_height = 1 + 2 + 12 + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -813,7 +827,7 @@ mod tests
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         title_button.set_h_align(HAlign::Center);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + 14 + 5 + 4 + 10;
         let area_height = 1 + 2 + 12 + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -854,7 +868,7 @@ mod tests
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         title_button.set_h_align(HAlign::Right);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + 14 + 5 + 4 + 10;
         let area_height = 1 + 2 + 12 + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -895,7 +909,7 @@ mod tests
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         title_button.set_h_align(HAlign::Fill);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + 14 + 5 + 4 + 10;
         let area_height = 1 + 2 + 12 + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -936,7 +950,7 @@ mod tests
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         title_button.set_v_align(VAlign::Top);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + 14 + 5 + 4 + 10;
         let area_height = 1 + 2 + 12 + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -977,7 +991,7 @@ mod tests
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         title_button.set_v_align(VAlign::Center);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + 14 + 5 + 4 + 10;
         let area_height = 1 + 2 + 12 + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1018,7 +1032,7 @@ mod tests
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         title_button.set_v_align(VAlign::Bottom);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + 14 + 5 + 4 + 10;
         let area_height = 1 + 2 + 12 + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
@@ -1059,7 +1073,7 @@ mod tests
         theme.set_title_button_icon_size(Size::new(14, 12));
         let mut title_button = TitleButton::new(TitleButtonIcon::Menu);
         title_button.set_v_align(VAlign::Fill);
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 3 + 4 + 14 + 5 + 4 + 10;
         let area_height = 1 + 2 + 12 + 3 + 2 + 10;
         let area_size = Size::new(Some(area_width), Some(area_height));
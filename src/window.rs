@@ -151,12 +151,115 @@ pub trait Window: Container + MinSize + PreferredSize
     fn is_popup(&self) -> bool
     { false }
     
+    /// Returns the anchor rectangle of the popup window or `None`.
+    ///
+    /// The anchor rectangle is a rectangle in the parent window, for example bounds of a widget,
+    /// relative to which the popup window is placed. If the anchor rectangle is `None`, the popup
+    /// window is placed relative to the position in the parent window. This method defaultly
+    /// returns `None`.
+    fn popup_anchor_rect(&self) -> Option<Rect<i32>>
+    { None }
+
+    /// Returns the client popup anchor of the popup window.
+    ///
+    /// This method defaultly returns `ClientPopupAnchor::TopLeft`.
+    fn popup_anchor(&self) -> ClientPopupAnchor
+    { ClientPopupAnchor::TopLeft }
+
+    /// Returns the client popup gravity of the popup window.
+    ///
+    /// This method defaultly returns `ClientPopupGravity::BottomRight`.
+    fn popup_gravity(&self) -> ClientPopupGravity
+    { ClientPopupGravity::BottomRight }
+
+    /// Returns the client constraint adjustment of the popup window.
+    ///
+    /// This method defaultly returns `ClientConstraintAdjustment::SLIDE_X` with
+    /// `ClientConstraintAdjustment::SLIDE_Y`.
+    fn popup_constraint_adjustment(&self) -> ClientConstraintAdjustment
+    { ClientConstraintAdjustment::SLIDE_X | ClientConstraintAdjustment::SLIDE_Y }
+
+    /// Returns `true` if the popup window grabs a pointer and a keyboard, otherwise `false`.
+    ///
+    /// A compositor closes the grabbing popup window if an user clicks a window of other client.
+    /// This method defaultly returns `true`.
+    fn has_popup_grab(&self) -> bool
+    { true }
+
+    /// Returns `true` if the popup window is closed by a click outside the popup window, otherwise
+    /// `false`.
+    ///
+    /// The popup window isn't closed if the click is in a child popup window. This method
+    /// defaultly returns `false`.
+    fn is_closed_by_outside_click(&self) -> bool
+    { false }
+
     /// Returns `true` if the window is transient, otherwise `false`.
     ///
     /// This method defaultly returns `false`.
     fn is_transient(&self) -> bool
     { false }
+
+    /// Returns `true` if the window is modal, otherwise `false`.
+    ///
+    /// The modal window blocks an input from a pointer, a keyboard, and a touch to the parent
+    /// window, ancestors of the parent window, and descendants of the parent window while the
+    /// modal window has the parent window. The modal window and its descendants aren't blocked.
+    /// This method defaultly returns `false`.
+    fn is_modal(&self) -> bool
+    { false }
+
+    /// Returns `true` if the window is notified about clicks on own widgets, otherwise `false`.
+    ///
+    /// If the window is notified, a click that is handled by the widget is also passed to the
+    /// window. The clicked widget can be found by the descendant index pairs of the queue
+    /// context. This method defaultly returns `false`.
+    fn is_notified_about_clicks(&self) -> bool
+    { false }
     
+    /// Returns the client layer if the window is a layer window, otherwise `None`.
+    ///
+    /// The layer window is a window of layer shell, for example a panel or an on-screen display.
+    /// The layer window is placed by a compositor on an output. This method defaultly returns
+    /// `None`.
+    fn layer(&self) -> Option<ClientLayer>
+    { None }
+
+    /// Returns the namespace of the layer window.
+    ///
+    /// The namespace is sent to a compositor when the layer window is created. This method
+    /// defaultly returns `"lwltk"`.
+    fn layer_namespace(&self) -> &str
+    { "lwltk" }
+
+    /// Returns the anchor of the layer window.
+    ///
+    /// This method defaultly returns `ClientAnchor::EMPTY`.
+    fn anchor(&self) -> ClientAnchor
+    { ClientAnchor::EMPTY }
+
+    /// Returns the exclusive zone of the layer window.
+    ///
+    /// The exclusive zone is a size of an area from the anchored edge which isn't covered by other
+    /// windows. A zero means that the layer window can be moved by other exclusive zones and a
+    /// negative number means that the layer window isn't moved by other exclusive zones. This
+    /// method defaultly returns zero.
+    fn exclusive_zone(&self) -> i32
+    { 0 }
+
+    /// Returns the margin edges of the layer window.
+    ///
+    /// The margin edges are distances from the anchored edges. This method defaultly returns zero
+    /// edges.
+    fn layer_margin_edges(&self) -> Edges<i32>
+    { Edges::new(0, 0, 0, 0) }
+
+    /// Returns the client keyboard interactivity of the layer window.
+    ///
+    /// This method defaultly returns `ClientKeyboardInteractivity::None`.
+    fn keyboard_interactivity(&self) -> ClientKeyboardInteractivity
+    { ClientKeyboardInteractivity::None }
+
     /// Returns `true` if the window is maximizable, otherwise `false`.
     ///
     /// This method defaultly returns `false`.
@@ -189,6 +292,97 @@ pub trait Window: Container + MinSize + PreferredSize
     fn unmaximize(&mut self) -> bool
     { self.set_maximized(false) }
 
+    /// Returns `true` if the window is minimizable, otherwise `false`.
+    ///
+    /// This method defaultly returns `false`.
+    fn is_minimizable(&self) -> bool
+    { false }
+
+    /// Returns `true` if the window is minimized, otherwise `false`.
+    ///
+    /// The window is minimized if the window has the minimize flag. A compositor doesn't report
+    /// whether the window is still minimized, so the minimize flag is cleared after a minimization
+    /// request. This method defaultly returns `false`.
+    fn is_minimized(&self) -> bool
+    { false }
+
+    /// Minimizes the window if the window is minimizable.
+    ///
+    /// This method should return `true` if the window is minimizable, otherwise `false`. This
+    /// method defaultly returns `false`.
+    fn minimize(&mut self) -> bool
+    { false }
+
+    /// Clears the minimize flag of the window if the window is minimizable.
+    ///
+    /// This method should return `true` if the window is minimizable, otherwise `false`. This
+    /// method defaultly returns `false`.
+    fn clear_minimize_flag(&mut self) -> bool
+    { false }
+
+    /// Returns `true` if the window can be fullscreen, otherwise `false`.
+    ///
+    /// This method defaultly returns `false`.
+    fn is_fullscreenable(&self) -> bool
+    { false }
+
+    /// Returns `true` if the window is fullscreen, otherwise `false`.
+    ///
+    /// This method defaultly returns `false`.
+    fn is_fullscreen(&self) -> bool
+    { false }
+
+    /// Returns the output name for the fullscreen window or `None`.
+    ///
+    /// The output name is a name of the output global. If the output name is `None`, a compositor
+    /// chooses an output for the fullscreen window. This method defaultly returns `None`.
+    fn fullscreen_output_name(&self) -> Option<u32>
+    { None }
+
+    /// Sets the fullscreen and the output name if the window can be fullscreen.
+    ///
+    /// The fullscreen window shouldn't draw its own decorations. The previous size of the window is
+    /// restored after the fullscreen. This method should return `true` if the window can be
+    /// fullscreen, otherwise `false`. This method defaultly returns `false`.
+    #[allow(unused_variables)]
+    fn set_fullscreen(&mut self, is_fullscreen: bool, output_name: Option<u32>) -> bool
+    { false }
+
+    /// Returns the client toplevel states of the window that are reported by a compositor.
+    ///
+    /// This method defaultly returns `ClientToplevelStates::EMPTY`.
+    fn toplevel_states(&self) -> ClientToplevelStates
+    { ClientToplevelStates::EMPTY }
+
+    /// Sets the client toplevel states of the window that are reported by a compositor.
+    ///
+    /// This method should return `true` if the window has the toplevel states, otherwise `false`.
+    /// This method defaultly returns `false`.
+    #[allow(unused_variables)]
+    fn set_toplevel_states(&mut self, states: ClientToplevelStates) -> bool
+    { false }
+
+    /// Returns the client decoration preference of the window.
+    ///
+    /// This method defaultly returns `ClientDecorationPreference::Client`.
+    fn decoration_preference(&self) -> ClientDecorationPreference
+    { ClientDecorationPreference::Client }
+    
+    /// Returns the client decoration mode of the window.
+    ///
+    /// This method defaultly returns `ClientDecorationMode::Client`.
+    fn decoration_mode(&self) -> ClientDecorationMode
+    { ClientDecorationMode::Client }
+    
+    /// Sets the client decoration mode of the window.
+    ///
+    /// The window with the server decoration mode shouldn't draw its own decorations. This method
+    /// should return `true` if the client decoration mode is set, otherwise `false`. This method
+    /// defaultly returns `false`.
+    #[allow(unused_variables)]
+    fn set_decoration_mode(&mut self, mode: ClientDecorationMode) -> bool
+    { false }
+
     /// Returns `true` if the window is moveable, otherwise `false`.
     ///
     /// This method defaultly returns `false`.
@@ -201,6 +395,13 @@ pub trait Window: Container + MinSize + PreferredSize
     fn is_resizable(&self) -> bool
     { false }    
 
+    /// Returns the index of the context menu for the window content or `None`.
+    ///
+    /// The context menu for the window content is opened if the widget or its ancestors in the
+    /// content don't have own context menus. This method defaultly returns `None`.
+    fn context_menu_index(&self) -> Option<WindowIndex>
+    { None }
+
     /// Sets the window index for the window.
     #[allow(unused_variables)]
     fn set_index(&mut self, idx: SelfWindowIndex)
@@ -269,6 +470,20 @@ pub trait Window: Container + MinSize + PreferredSize
     /// This method is called after redraw the window.
     fn clear_change_flag(&mut self);
 
+    /// Returns `true` if the window has the dirty rectangles, otherwise `false`.
+    ///
+    /// If the window has the dirty rectangles and the window isn't changed, only the dirty
+    /// rectangles are redrawn. This method defaultly returns `false`.
+    fn is_dirty(&self) -> bool
+    { false }
+
+    /// Clears the dirty flag of the window.
+    ///
+    /// This method is called after redraw the window. This method defaultly doesn't clear the dirty
+    /// flag.
+    fn clear_dirty_flag(&mut self)
+    {}
+
     /// Returns `true` if the window is moved, otherwise `false`.
     ///
     /// This method defaultly returns `false`.
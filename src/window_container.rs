@@ -418,6 +418,78 @@ impl WindowContainer
             None => None,
         }
     }
+
+    /// Unsets parents for popup windows which are outside a window.
+    ///
+    /// This method closes the popup windows that are closed by a click outside these popup windows
+    /// if the window is clicked. The window and ancestors of the window aren't closed.
+    pub fn unset_parents_for_outside_popups(&mut self, idx: WindowIndex)
+    {
+        let mut ancestor_idxs: BTreeSet<WindowIndex> = BTreeSet::new();
+        let mut tmp_idx = Some(idx);
+        loop {
+            match tmp_idx {
+                Some(tmp_idx2) if !ancestor_idxs.contains(&tmp_idx2) => {
+                    ancestor_idxs.insert(tmp_idx2);
+                    tmp_idx = match self.dyn_window(tmp_idx2) {
+                        Some(window) => window.parent_index(),
+                        None => None,
+                    };
+                },
+                _ => break,
+            }
+        }
+        let popup_idxs: Vec<WindowIndex> = self.windows.iter().filter(|p| {
+                p.1.is_popup() && p.1.is_closed_by_outside_click() && p.1.parent_index().is_some() && !ancestor_idxs.contains(p.0)
+        }).map(|p| *p.0).collect();
+        for popup_idx in &popup_idxs {
+            self.unset_parent(*popup_idx);
+        }
+    }
+
+    fn is_self_or_descendant(&self, idx: WindowIndex, ancestor_idx: WindowIndex) -> bool
+    {
+        let mut visiteds: BTreeSet<WindowIndex> = BTreeSet::new();
+        let mut tmp_idx = Some(idx);
+        loop {
+            match tmp_idx {
+                Some(tmp_idx2) if tmp_idx2 == ancestor_idx => return true,
+                Some(tmp_idx2) if !visiteds.contains(&tmp_idx2) => {
+                    visiteds.insert(tmp_idx2);
+                    tmp_idx = match self.dyn_window(tmp_idx2) {
+                        Some(window) => window.parent_index(),
+                        None => None,
+                    };
+                },
+                _ => break,
+            }
+        }
+        false
+    }
+
+    /// Returns `true` if the window is blocked by a modal window, otherwise `false`.
+    ///
+    /// The window is blocked if the window is the parent or an ancestor of the visible modal
+    /// window or the window is a descendant of the parent of the visible modal window. The modal
+    /// window and its descendants aren't blocked by the modal window.
+    pub fn is_blocked(&self, idx: WindowIndex) -> bool
+    {
+        for (modal_idx, modal_window) in &self.windows {
+            if modal_window.is_modal() && modal_window.is_visible() {
+                match modal_window.parent_index() {
+                    Some(parent_idx) => {
+                        if !self.is_self_or_descendant(idx, *modal_idx) {
+                            if self.is_self_or_descendant(parent_idx, idx) || self.is_self_or_descendant(idx, parent_idx) {
+                                return true;
+                            }
+                        }
+                    },
+                    None => (),
+                }
+            }
+        }
+        false
+    }
 }
 
 #[cfg(test)]
@@ -1253,6 +1325,60 @@ mod tests
         }
     }    
 
+    #[test]
+    fn test_window_container_unsets_parents_for_outside_popups()
+    {
+        let mut window_container = WindowContainer::new();
+        let mut popup1 = MockChildWindow::new("popup1");
+        popup1.set_popup(true);
+        window_container.add(popup1);
+        let mut popup2 = MockChildWindow::new("popup2");
+        popup2.set_popup(true);
+        window_container.add(popup2);
+        window_container.add(MockChildWindow::new("child"));
+        window_container.add(MockParentWindow::new("parent"));
+        window_container.set_parent(WindowIndex(0), WindowIndex(3), Pos::new(1, 2));
+        window_container.set_parent(WindowIndex(1), WindowIndex(3), Pos::new(3, 4));
+        window_container.set_parent(WindowIndex(2), WindowIndex(3), Pos::new(5, 6));
+        window_container.unset_parents_for_outside_popups(WindowIndex(0));
+        assert_eq!(Some(WindowIndex(3)), window_container.dyn_window(WindowIndex(0)).unwrap().parent_index());
+        assert_eq!(None, window_container.dyn_window(WindowIndex(1)).unwrap().parent_index());
+        assert_eq!(Some(WindowIndex(3)), window_container.dyn_window(WindowIndex(2)).unwrap().parent_index());
+        window_container.unset_parents_for_outside_popups(WindowIndex(3));
+        assert_eq!(None, window_container.dyn_window(WindowIndex(0)).unwrap().parent_index());
+        assert_eq!(None, window_container.dyn_window(WindowIndex(1)).unwrap().parent_index());
+        assert_eq!(Some(WindowIndex(3)), window_container.dyn_window(WindowIndex(2)).unwrap().parent_index());
+        match window_container.windows.get(&WindowIndex(3)) {
+            Some(parent) => {
+                let mut child_indices = parent.child_indices();
+                assert_eq!(Some(WindowIndex(2)), child_indices.next());
+                assert_eq!(None, child_indices.next());
+            },
+            None => assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_window_container_blocks_parent_of_modal_window()
+    {
+        let mut window_container = WindowContainer::new();
+        let mut modal = MockChildWindow::new("modal");
+        modal.set_modal(true);
+        window_container.add(modal);
+        window_container.add(MockChildWindow::new("child"));
+        window_container.add(MockParentWindow::new("parent1"));
+        window_container.add(MockParentWindow::new("parent2"));
+        window_container.set_parent(WindowIndex(0), WindowIndex(2), Pos::new(1, 2));
+        window_container.set_parent(WindowIndex(1), WindowIndex(2), Pos::new(3, 4));
+        assert_eq!(false, window_container.is_blocked(WindowIndex(0)));
+        assert_eq!(true, window_container.is_blocked(WindowIndex(1)));
+        assert_eq!(true, window_container.is_blocked(WindowIndex(2)));
+        assert_eq!(false, window_container.is_blocked(WindowIndex(3)));
+        window_container.unset_parent(WindowIndex(0));
+        assert_eq!(false, window_container.is_blocked(WindowIndex(1)));
+        assert_eq!(false, window_container.is_blocked(WindowIndex(2)));
+    }
+
     #[test]
     fn test_window_container_unsets_many_parent()
     {
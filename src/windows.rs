@@ -6,9 +6,19 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 //
 mod child_index_set;
+mod dialog_window;
+mod layer_window;
+mod menu;
+mod popup_window;
 mod toplevel_window;
+mod tooltip;
 mod two_window_widgets;
 
 pub use child_index_set::*;
+pub use dialog_window::*;
+pub use layer_window::*;
+pub use menu::*;
+pub use popup_window::*;
 pub use toplevel_window::*;
+pub use tooltip::*;
 pub use two_window_widgets::*;
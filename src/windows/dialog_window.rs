@@ -0,0 +1,900 @@
+//
+// Copyright (c) 2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::any::Any;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use crate::as_any::*;
+use crate::call_on::*;
+use crate::client_context::*;
+use crate::container::*;
+use crate::draw::*;
+use crate::event_queue::*;
+use crate::events::*;
+use crate::image::*;
+use crate::keys::*;
+use crate::min_size::*;
+use crate::preferred_size::*;
+use crate::queue_context::*;
+use crate::theme::*;
+use crate::types::*;
+use crate::utils::*;
+use crate::widget::*;
+use crate::widgets::*;
+use crate::window::*;
+use crate::window_context::*;
+use crate::windows::child_index_set::*;
+use crate::windows::two_window_widgets::*;
+
+/// A structure of dialog window.
+///
+/// The dialog window is a transient window that is shown if the dialog window has a parent window.
+/// The modal dialog window blocks an input to the parent window and ancestors of the parent
+/// window. The dialog window is closed by a button that has a result, the close button, or the
+/// escape key. After closing, the result or `None` is passed to the result callback that is pushed
+/// on the callback queue.
+pub struct DialogWindow<T: Clone + Send + Sync + 'static>
+{
+    title: Option<String>,
+    size: Size<i32>,
+    padding_bounds: Rect<i32>,
+    edges: Edges<i32>,
+    corners: Corners<i32>,
+    is_visible: bool,
+    is_focused: bool,
+    is_resizable: bool,
+    is_modal: bool,
+    decoration_preference: ClientDecorationPreference,
+    decoration_mode: ClientDecorationMode,
+    change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    is_moved: bool,
+    resize_edges: Option<ClientResize>,
+    min_size: Size<Option<i32>>,
+    preferred_size: Size<Option<i32>>,
+    parent_index: Option<WindowIndex>,
+    pos_in_parent: Option<Pos<i32>>,
+    child_index_set: ChildIndexSet,
+    call_on_fun: CallOnFun,
+    result_fun: Option<Box<dyn FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext, Option<T>) -> Option<()> + Send + Sync + 'static>>,
+    widgets: TwoWindowWidgets,
+    focused_rel_widget_path: Option<RelWidgetPath>,
+    title_path: Option<RelWidgetPath>,
+    close_button_path: Option<RelWidgetPath>,
+    default_button_path: Option<RelWidgetPath>,
+    cancel_button_path: Option<RelWidgetPath>,
+    button_results: Vec<(RelWidgetPath, T)>,
+}
+
+impl<T: Clone + Send + Sync + 'static> DialogWindow<T>
+{
+    pub fn new() -> Option<Self>
+    {
+        let mut window = DialogWindow {
+            title: None,
+            size: Size::new(0, 0),
+            padding_bounds: Rect::new(0, 0, 0, 0),
+            edges: Edges::new(0, 0, 0, 0),
+            corners: Corners::new(0, 0, 0, 0, 0, 0, 0, 0),
+            is_visible: true,
+            is_focused: false,
+            is_resizable: false,
+            is_modal: true,
+            decoration_preference: ClientDecorationPreference::Server,
+            decoration_mode: ClientDecorationMode::Client,
+            change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            is_moved: false,
+            resize_edges: None,
+            min_size: Size::new(None, None),
+            preferred_size: Size::new(None, None),
+            parent_index: None,
+            pos_in_parent: None,
+            child_index_set: ChildIndexSet::new(),
+            call_on_fun: CallOnFun::new(),
+            result_fun: None,
+            widgets: TwoWindowWidgets::new(),
+            focused_rel_widget_path: None,
+            title_path: None,
+            close_button_path: None,
+            default_button_path: None,
+            cancel_button_path: None,
+            button_results: Vec::new(),
+        };
+        let title_bar = TitleBar::new();
+        let title_bar_path = container_rel_widget_path1(&mut window, |window: &mut DialogWindow<T>| window.set_title_bar(title_bar))?;
+        let title = Title::new("");
+        let title_path = container_rel_widget_path(&mut window, &title_bar_path, |title_bar: &mut TitleBar| title_bar.add(title))?;
+        let close_button = TitleButton::new(TitleButtonIcon::Close);
+        let close_button_path = container_rel_widget_path(&mut window, &title_bar_path, |title_bar: &mut TitleBar| title_bar.add(close_button))?;
+        let title: &mut Title = container_widget_mut(&mut window, &title_path)?;
+        title.set_on(move |_, queue_context, event| {
+                match event {
+                     Event::Client(ClientEvent::PointerButton(_, ClientButton::Left, ClientState::Pressed)) |
+                     Event::Client(ClientEvent::TouchDown(_, _, _)) => {
+                         let current_window_idx = queue_context.current_call_on_path()?.window_index();
+                         queue_context.push_callback(move |_, window_context, _| {
+                                 window_context.dyn_window_mut(current_window_idx)?._move();
+                                 Some(())
+                         });
+                    }
+                    _ => (),
+                }
+                Some(EventOption::Default)
+        });
+        let close_button: &mut TitleButton = container_widget_mut(&mut window, &close_button_path)?;
+        close_button.set_on(move |_, _, event| {
+                match event {
+                    Event::Click | Event::DoubleClick | Event::LongClick => Some(EventOption::Some(Event::Close)),
+                    _ => Some(EventOption::Default),
+                }
+        });
+        window.set_title_path(Some(title_path));
+        window.set_close_button_path(Some(close_button_path));
+        Some(window)
+    }
+
+    pub fn set_title(&mut self, title: &str)
+    {
+        self.title = Some(String::from(title));
+        match self.title_path.clone() {
+            Some(title_path) => {
+                let title_widget: Option<&mut Title> = container_widget_mut(self, &title_path);
+                match title_widget {
+                    Some(title_widget) => title_widget.set_text(title),
+                    None => (),
+                }
+            },
+            None => (),
+        }
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+    }
+
+    pub fn unset_title(&mut self)
+    {
+        self.title = None;
+        match self.title_path.clone() {
+            Some(title_path) => {
+                let title_widget: Option<&mut Title> = container_widget_mut(self, &title_path);
+                match title_widget {
+                    Some(title_widget) => title_widget.set_text(""),
+                    None => (),
+                }
+            },
+            None => (),
+        }
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+    }
+
+    pub fn set_visible(&mut self, is_visible: bool)
+    {
+        let old_visible_flag = self.is_visible;
+        self.is_visible = is_visible;
+        if old_visible_flag != self.is_visible {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+        self.reset_descendant_states();
+    }
+
+    pub fn set_resizable(&mut self, is_resizable: bool)
+    {
+        let old_resizable_flag = self.is_resizable;
+        self.is_resizable = is_resizable;
+        if old_resizable_flag != self.is_resizable {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// Sets the flag of the modal dialog window.
+    ///
+    /// The dialog window is defaultly modal.
+    pub fn set_modal(&mut self, is_modal: bool)
+    { self.is_modal = is_modal; }
+
+    pub fn set_decoration_preference(&mut self, preference: ClientDecorationPreference)
+    {
+        let old_decoration_preference = self.decoration_preference;
+        self.decoration_preference = preference;
+        if old_decoration_preference != self.decoration_preference {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_dyn_on(&mut self, f: Box<dyn FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static>)
+    { self.call_on_fun.fun = f; }
+
+    pub fn set_on<F>(&mut self, f: F)
+        where F: FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static
+    { self.set_dyn_on(Box::new(f)) }
+
+    /// Sets the dynamic result callback.
+    ///
+    /// The result callback is called with the result of the button that closed the dialog window
+    /// or `None` if the dialog window is closed by other way.
+    pub fn set_dyn_on_result(&mut self, f: Box<dyn FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext, Option<T>) -> Option<()> + Send + Sync + 'static>)
+    { self.result_fun = Some(f); }
+
+    /// Sets the result callback.
+    ///
+    /// See [`set_dyn_on_result`](Self::set_dyn_on_result) for more informations.
+    pub fn set_on_result<F>(&mut self, f: F)
+        where F: FnMut(&mut ClientContext, &mut WindowContext, &mut QueueContext, Option<T>) -> Option<()> + Send + Sync + 'static
+    { self.set_dyn_on_result(Box::new(f)) }
+
+    pub fn has_trimmed_width(&self) -> bool
+    { self.widgets.has_trimmed_width }
+
+    pub fn set_trimmed_width(&mut self, is_trimmed_width: bool)
+    {
+        let old_trimmed_width_flag = self.widgets.has_trimmed_width;
+        self.widgets.has_trimmed_width = is_trimmed_width;
+        if old_trimmed_width_flag != self.widgets.has_trimmed_width {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn has_trimmed_height(&self) -> bool
+    { self.widgets.has_trimmed_height }
+
+    pub fn set_trimmed_height(&mut self, is_trimmed_height: bool)
+    {
+        let old_trimmed_height_flag = self.widgets.has_trimmed_height;
+        self.widgets.has_trimmed_height = is_trimmed_height;
+        if old_trimmed_height_flag != self.widgets.has_trimmed_height {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_dyn_title_bar(&mut self, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
+    {
+        widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
+        self.widgets.title_bar = Some(widget);
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+        Some(WidgetIndexPair(0, 0))
+    }
+
+    pub fn set_title_bar<U: Widget + 'static>(&mut self, widget: U) -> Option<WidgetIndexPair>
+    { self.set_dyn_title_bar(Box::new(widget)) }
+
+    pub fn unset_title_bar(&mut self) -> Option<Box<dyn Widget>>
+    {
+        let title_bar = self.widgets.title_bar.take();
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+        title_bar
+    }
+
+    pub fn set_dyn(&mut self, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
+    {
+        widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
+        self.widgets.content = Some(widget);
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+        Some(WidgetIndexPair(1, 0))
+    }
+
+    pub fn set<U: Widget + 'static>(&mut self, widget: U) -> Option<WidgetIndexPair>
+    { self.set_dyn(Box::new(widget)) }
+
+    pub fn unset(&mut self) -> Option<Box<dyn Widget>>
+    {
+        let content = self.widgets.content.take();
+        self.button_results.clear();
+        self.default_button_path = None;
+        self.cancel_button_path = None;
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+        content
+    }
+
+    pub fn title_path(&self) -> Option<&RelWidgetPath>
+    {
+        match &self.title_path {
+            Some(path) => Some(path),
+            None => None,
+        }
+    }
+
+    pub fn set_title_path(&mut self, path: Option<RelWidgetPath>)
+    { self.title_path = path; }
+
+    pub fn close_button_path(&self) -> Option<&RelWidgetPath>
+    {
+        match &self.close_button_path {
+            Some(path) => Some(path),
+            None => None,
+        }
+    }
+
+    pub fn set_close_button_path(&mut self, path: Option<RelWidgetPath>)
+    { self.close_button_path = path; }
+
+    /// Returns a reference to the path of the default button or `None`.
+    ///
+    /// The default button is clicked by the return key if the focused widget isn't clickable by
+    /// this key.
+    pub fn default_button_path(&self) -> Option<&RelWidgetPath>
+    {
+        match &self.default_button_path {
+            Some(path) => Some(path),
+            None => None,
+        }
+    }
+
+    /// Sets the path of the default button.
+    ///
+    /// See [`default_button_path`](Self::default_button_path) for more informations.
+    pub fn set_default_button_path(&mut self, path: Option<RelWidgetPath>)
+    { self.default_button_path = path; }
+
+    /// Returns a reference to the path of the cancel button or `None`.
+    ///
+    /// The cancel button is clicked by the escape key. If the dialog window hasn't the cancel
+    /// button, the escape key closes the dialog window without a result.
+    pub fn cancel_button_path(&self) -> Option<&RelWidgetPath>
+    {
+        match &self.cancel_button_path {
+            Some(path) => Some(path),
+            None => None,
+        }
+    }
+
+    /// Sets the path of the cancel button.
+    ///
+    /// See [`cancel_button_path`](Self::cancel_button_path) for more informations.
+    pub fn set_cancel_button_path(&mut self, path: Option<RelWidgetPath>)
+    { self.cancel_button_path = path; }
+
+    /// Sets the result for the button.
+    ///
+    /// A click on the button closes the dialog window with the result. An event handler of the
+    /// button isn't changed by this method, so the click is handled by the button and then the
+    /// dialog window is notified about the click. This method returns `Some(())` if the path
+    /// refers to the button, otherwise `None`.
+    pub fn set_button_result(&mut self, path: RelWidgetPath, result: T) -> Option<()>
+    {
+        let _button: &Button = container_widget(self, &path)?;
+        self.button_results.retain(|p| p.0 != path);
+        self.button_results.push((path, result));
+        Some(())
+    }
+
+    /// Returns the result for the button or `None`.
+    pub fn button_result(&self, path: &RelWidgetPath) -> Option<T>
+    {
+        match self.button_results.iter().find(|p| &p.0 == path) {
+            Some((_, result)) => Some(result.clone()),
+            None => None,
+        }
+    }
+
+    fn is_focused_widget_clickable_by_key(&self) -> bool
+    {
+        match &self.focused_rel_widget_path {
+            Some(rel_widget_path) => {
+                match self.dyn_widget(rel_widget_path) {
+                    Some(widget) => widget.is_clickable_by_key(),
+                    None => false,
+                }
+            },
+            None => false,
+        }
+    }
+
+    fn descendant_button_result(&self, queue_context: &QueueContext) -> Option<T>
+    {
+        let mut descendant_idx_pairs = queue_context.current_descendant_index_pairs().rev();
+        match descendant_idx_pairs.next() {
+            Some(idx_pair) => {
+                let mut path = RelWidgetPath::new(idx_pair);
+                for idx_pair in descendant_idx_pairs {
+                    path.push(idx_pair);
+                }
+                self.button_result(&path)
+            },
+            None => None,
+        }
+    }
+
+    fn push_closing(queue_context: &mut QueueContext, result: Option<T>) -> Option<()>
+    {
+        let mut result = result;
+        let current_window_idx = queue_context.current_call_on_path()?.window_index();
+        queue_context.push_callback(move |client_context, window_context, queue_context| {
+                window_context.unset_parent_window(current_window_idx);
+                let window: &mut DialogWindow<T> = window_context.window_mut(current_window_idx)?;
+                match window.result_fun.take() {
+                    Some(mut result_fun) => {
+                        let res = result_fun(client_context, window_context, queue_context, result.take());
+                        let window: &mut DialogWindow<T> = window_context.window_mut(current_window_idx)?;
+                        if window.result_fun.is_none() {
+                            window.result_fun = Some(result_fun);
+                        }
+                        res
+                    },
+                    None => Some(()),
+                }
+        });
+        Some(())
+    }
+
+    fn default_dialog_window_on(&mut self, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
+    {
+        match event {
+            Event::Key(VKey::Return, modifiers) if *modifiers == KeyModifiers::EMPTY => {
+                match &self.default_button_path {
+                    Some(default_button_path) if !self.is_focused_widget_clickable_by_key() => {
+                        let current_window_idx = queue_context.current_call_on_path()?.window_index();
+                        let call_on_path = CallOnPath::Widget(default_button_path.to_abs_widget_path(current_window_idx));
+                        queue_context.push_event_for_call_on_path(call_on_path, Event::Click);
+                        Some(Some(None))
+                    },
+                    _ => Some(None),
+                }
+            },
+            Event::Key(VKey::Escape, modifiers) if *modifiers == KeyModifiers::EMPTY => {
+                match &self.cancel_button_path {
+                    Some(cancel_button_path) => {
+                        let current_window_idx = queue_context.current_call_on_path()?.window_index();
+                        let call_on_path = CallOnPath::Widget(cancel_button_path.to_abs_widget_path(current_window_idx));
+                        queue_context.push_event_for_call_on_path(call_on_path, Event::Click);
+                    },
+                    None => queue_context.push_event(Event::Close)?,
+                }
+                Some(Some(None))
+            },
+            Event::Click | Event::DoubleClick | Event::LongClick => {
+                match self.descendant_button_result(queue_context) {
+                    Some(result) => {
+                        Self::push_closing(queue_context, Some(result))?;
+                        Some(Some(None))
+                    },
+                    None => Some(None),
+                }
+            },
+            Event::Close => {
+                let result = self.descendant_button_result(queue_context);
+                Self::push_closing(queue_context, result)?;
+                Some(Some(None))
+            },
+            _ => Some(None),
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Window for DialogWindow<T>
+{
+    fn size(&self) -> Size<i32>
+    { self.size }
+
+    fn padding_bounds(&self) -> Rect<i32>
+    { self.padding_bounds }
+
+    fn edges(&self) -> Edges<i32>
+    { self.edges }
+
+    fn corners(&self) -> Corners<i32>
+    { self.corners }
+
+    fn is_visible(&self) -> bool
+    { self.is_visible }
+
+    fn is_focused(&self) -> bool
+    { self.is_focused }
+
+    fn set_focus(&mut self, is_focused: bool) -> bool
+    {
+        let old_focus_flag = self.is_focused;
+        self.is_focused = is_focused;
+        if old_focus_flag != self.is_focused {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+        true
+    }
+
+    fn title(&self) -> Option<&str>
+    {
+        match &self.title {
+            Some(title) => Some(title.as_str()),
+            None => None,
+        }
+    }
+
+    fn is_transient(&self) -> bool
+    { true }
+
+    fn is_modal(&self) -> bool
+    { self.is_modal }
+
+    fn is_notified_about_clicks(&self) -> bool
+    { !self.button_results.is_empty() }
+
+    fn decoration_preference(&self) -> ClientDecorationPreference
+    { self.decoration_preference }
+
+    fn decoration_mode(&self) -> ClientDecorationMode
+    { self.decoration_mode }
+
+    fn set_decoration_mode(&mut self, mode: ClientDecorationMode) -> bool
+    {
+        let old_decoration_mode = self.decoration_mode;
+        self.decoration_mode = mode;
+        self.widgets.has_hidden_title_bar = self.decoration_mode == ClientDecorationMode::Server;
+        if old_decoration_mode != self.decoration_mode {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+        true
+    }
+
+    fn is_moveable(&self) -> bool
+    { true }
+
+    fn is_resizable(&self) -> bool
+    { self.is_resizable }
+
+    fn parent_index(&self) -> Option<WindowIndex>
+    { self.parent_index }
+
+    fn pos_in_parent(&self) -> Option<Pos<i32>>
+    { self.pos_in_parent }
+
+    fn set_parent(&mut self, idx: ParentWindowIndex, pos: Pos<i32>) -> Option<()>
+    {
+        match (self.parent_index, self.pos_in_parent) {
+            (None, None) => {
+                self.parent_index = Some(idx.window_index());
+                self.pos_in_parent = Some(pos);
+                self.change_flag_arc.store(true, Ordering::SeqCst);
+                Some(())
+            },
+            _ => None,
+        }
+    }
+
+    fn unset_parent(&mut self, _tag: ParentWindowTag) -> Option<()>
+    {
+        match (self.parent_index, self.pos_in_parent) {
+            (None, None) => None,
+            _ => {
+                self.parent_index = None;
+                self.pos_in_parent = None;
+                self.reset_descendant_states();
+                self.change_flag_arc.store(true, Ordering::SeqCst);
+                Some(())
+            },
+        }
+    }
+
+    fn is_changed(&self) -> bool
+    { self.change_flag_arc.load(Ordering::SeqCst) }
+
+    fn clear_change_flag(&mut self)
+    { self.change_flag_arc.store(false, Ordering::SeqCst); }
+
+    fn is_dirty(&self) -> bool
+    { self.dirty_flag_arc.load(Ordering::SeqCst) }
+
+    fn clear_dirty_flag(&mut self)
+    { self.dirty_flag_arc.store(false, Ordering::SeqCst); }
+
+    fn is_moved(&self) -> bool
+    { self.is_moved }
+
+    fn _move(&mut self) -> bool
+    {
+        self.is_moved = true;
+        true
+    }
+
+    fn clear_move_flag(&mut self) -> bool
+    {
+        self.is_moved = false;
+        true
+    }
+
+    fn resize_edges(&self) -> Option<ClientResize>
+    { self.resize_edges }
+
+    fn resize(&mut self, edges: ClientResize) -> bool
+    {
+        self.resize_edges = Some(edges);
+        true
+    }
+
+    fn clear_resize_edges(&mut self) -> bool
+    {
+        self.resize_edges = None;
+        true
+    }
+
+    fn content_index_pair(&self) -> Option<WidgetIndexPair>
+    {
+        if self.widgets.content.is_some() {
+            Some(WidgetIndexPair(1, 0))
+        } else {
+            None
+        }
+    }
+
+    fn child_index_iter(&self) -> Option<Box<dyn WindowIterator + '_>>
+    { self.child_index_set.child_index_iter() }
+
+    fn add_child(&mut self, idx: ChildWindowIndex) -> Option<()>
+    { self.child_index_set.add(idx) }
+
+    fn remove_child(&mut self, idx: ChildWindowIndex) -> Option<()>
+    { self.child_index_set.remove(idx) }
+
+    fn focused_rel_widget_path(&self) -> Option<&RelWidgetPath>
+    {
+        match &self.focused_rel_widget_path {
+            Some(rel_widget_path) => Some(rel_widget_path),
+            None => None,
+        }
+    }
+
+    fn set_only_focused_rel_widget_path(&mut self, rel_widget_path: Option<RelWidgetPath>) -> bool
+    {
+        self.focused_rel_widget_path = rel_widget_path;
+        true
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Container for DialogWindow<T>
+{
+    fn prev(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
+    { self.widgets.prev(idx_pair) }
+
+    fn next(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
+    { self.widgets.next(idx_pair) }
+
+    fn dyn_widget_for_index_pair(&self, idx_pair: WidgetIndexPair) -> Option<&dyn Widget>
+    { self.widgets.dyn_widget(idx_pair) }
+
+    fn dyn_widget_mut_for_index_pair(&mut self, idx_pair: WidgetIndexPair) -> Option<&mut dyn Widget>
+    { self.widgets.dyn_widget_mut(idx_pair) }
+
+    fn point_for_index_pair(&self, pos: Pos<f64>) -> Option<WidgetIndexPair>
+    { self.widgets.point(pos) }
+}
+
+impl<T: Clone + Send + Sync + 'static> MinSize for DialogWindow<T>
+{
+    fn min_size(&self) -> Size<Option<i32>>
+    { self.min_size }
+
+    fn set_min_size(&mut self, size: Size<Option<i32>>)
+    {
+        let old_min_size = self.min_size;
+        self.min_size = size;
+        if old_min_size != self.min_size {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> PreferredSize for DialogWindow<T>
+{
+    fn preferred_size(&self) -> Size<Option<i32>>
+    { self.preferred_size }
+
+    fn set_preferred_size(&mut self, size: Size<Option<i32>>)
+    {
+        let old_preferred_size = self.preferred_size;
+        self.preferred_size = size;
+        if old_preferred_size != self.preferred_size {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Draw for DialogWindow<T>
+{
+    fn update_size(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_size: Size<Option<i32>>) -> Result<(), CairoError>
+    {
+        match self.decoration_mode {
+            ClientDecorationMode::Client => {
+                self.edges = theme.toplevel_window_edges();
+                self.corners = theme.toplevel_window_corners();
+            },
+            ClientDecorationMode::Server => {
+                self.edges = Edges::new(0, 0, 0, 0);
+                self.corners = Corners::new(0, 0, 0, 0, 0, 0, 0, 0);
+            },
+        }
+        let padding_area_size = inner_opt_size(area_size, self.edges);
+        self.widgets.update_size(cairo_context, theme, padding_area_size)?;
+        self.padding_bounds.set_size(self.widgets.padding_size(padding_area_size));
+        self.size = outer_size(self.padding_bounds.size(), self.edges);
+        Ok(())
+    }
+
+    fn update_pos(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_bounds: Rect<i32>) -> Result<(), CairoError>
+    {
+        self.padding_bounds.set_pos(inner_pos(area_bounds, self.edges));
+        self.widgets.update_pos(cairo_context, theme, inner_rect(area_bounds, self.edges))?;
+        Ok(())
+    }
+
+    fn draw(&self, cairo_context: &CairoContext, theme: &dyn Theme, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        let mut is_title_bar = false;
+        let mut bounds = Rect::new(0, 0, self.size.width, self.size.height);
+        match &self.widgets.title_bar {
+            Some(title_bar) if !self.widgets.has_hidden_title_bar => {
+                theme.draw_toplevel_window_title_bar_bg(cairo_context, Rect::new(0, 0, self.size.width, title_bar.margin_y() + title_bar.margin_height()), is_focused_window)?;
+                bounds.y += title_bar.margin_y() + title_bar.margin_height();
+                bounds.height -= title_bar.margin_y() + title_bar.margin_height();
+                is_title_bar = true;
+            },
+            _ => (),
+        }
+        theme.draw_toplevel_window_content_bg(cairo_context, bounds, is_focused_window, is_title_bar)?;
+        self.widgets.draw(cairo_context, theme, is_focused_window)?;
+        Ok(())
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> CallOn for DialogWindow<T>
+{
+    fn call_on(&mut self, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Event>>
+    {
+        let default_event = if let Some(tmp_default_event) = self.default_dialog_window_on(queue_context, event)? {
+            tmp_default_event
+        } else if let Some(tmp_default_event) = default_window_on(self, client_context, queue_context, event)? {
+            tmp_default_event
+        } else {
+            None
+        };
+        self.call_on_fun.call_on(client_context, queue_context, event, default_event)
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> AsAny for DialogWindow<T>
+{
+    fn as_any(&self) -> &dyn Any
+    { self }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    { self }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::mocks::*;
+    use crate::window_container::*;
+
+    #[test]
+    fn test_dialog_window_update_size_and_position_for_server_decoration_mode()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_toplevel_window_edges(Edges::new(4, 4, 4, 4));
+        theme.set_button_margin_edges(Edges::new(2, 2, 2, 2));
+        theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
+        theme.set_button_font_size(16.0);
+        let mut window: DialogWindow<bool> = DialogWindow::new().unwrap();
+        window.set_title("T");
+        let mut button = Button::new("B");
+        button.set_preferred_size(Size::new(Some(120), Some(60)));
+        window.set(button);
+        window.set_decoration_mode(ClientDecorationMode::Server);
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match window.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Edges::new(0, 0, 0, 0), window.edges);
+        assert_eq!(Size::new(124, 64), window.padding_bounds.size());
+        assert_eq!(Size::new(124, 64), window.size);
+        let area_bounds = Rect::new(0, 0, window.size.width, window.size.height);
+        match window.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Pos::new(0, 0), window.padding_bounds.pos());
+        assert_eq!(Pos::new(2, 2), window.widgets.content.as_ref().unwrap().pos());
+    }
+
+    #[test]
+    fn test_dialog_window_sets_button_results()
+    {
+        let mut window: DialogWindow<u32> = DialogWindow::new().unwrap();
+        let mut layout = LinearLayout::new();
+        layout.add(Button::new("Yes"));
+        layout.add(Button::new("No"));
+        layout.add(Label::new("L"));
+        window.set(layout);
+        let mut yes_path = RelWidgetPath::new(WidgetIndexPair(1, 0));
+        yes_path.push(WidgetIndexPair(0, 0));
+        let mut no_path = RelWidgetPath::new(WidgetIndexPair(1, 0));
+        no_path.push(WidgetIndexPair(1, 0));
+        let mut label_path = RelWidgetPath::new(WidgetIndexPair(1, 0));
+        label_path.push(WidgetIndexPair(2, 0));
+        assert_eq!(Some(()), window.set_button_result(yes_path.clone(), 1));
+        assert_eq!(Some(()), window.set_button_result(no_path.clone(), 2));
+        assert_eq!(None, window.set_button_result(label_path.clone(), 3));
+        assert_eq!(Some(()), window.set_button_result(no_path.clone(), 4));
+        assert_eq!(Some(1), window.button_result(&yes_path));
+        assert_eq!(Some(4), window.button_result(&no_path));
+        assert_eq!(None, window.button_result(&label_path));
+        assert_eq!(true, window.is_notified_about_clicks());
+    }
+
+    #[test]
+    fn test_dialog_window_closes_for_click_on_button_with_result()
+    {
+        let mut window: DialogWindow<u32> = DialogWindow::new().unwrap();
+        let mut layout = LinearLayout::new();
+        layout.add(Button::new("Yes"));
+        layout.add(Button::new("Other"));
+        window.set(layout);
+        let mut yes_path = RelWidgetPath::new(WidgetIndexPair(1, 0));
+        yes_path.push(WidgetIndexPair(0, 0));
+        assert_eq!(Some(()), window.set_button_result(yes_path.clone(), 1));
+        let mut queue_context = QueueContext::new();
+        queue_context.current_call_on_path = Some(CallOnPath::Window(WindowIndex(0)));
+        queue_context.current_descendant_index_pairs.push(WidgetIndexPair(1, 0));
+        queue_context.current_descendant_index_pairs.push(WidgetIndexPair(1, 0));
+        match window.default_dialog_window_on(&mut queue_context, &Event::Click) {
+            Some(None) => (),
+            _ => assert!(false),
+        }
+        assert_eq!(true, queue_context.callback_queue().is_empty());
+        queue_context.current_descendant_index_pairs.clear();
+        queue_context.current_descendant_index_pairs.push(WidgetIndexPair(0, 0));
+        queue_context.current_descendant_index_pairs.push(WidgetIndexPair(1, 0));
+        match window.default_dialog_window_on(&mut queue_context, &Event::Click) {
+            Some(Some(None)) => (),
+            _ => assert!(false),
+        }
+        assert_eq!(false, queue_context.callback_queue().is_empty());
+    }
+
+    #[test]
+    fn test_dialog_window_blocks_parent_window()
+    {
+        let mut window_container = WindowContainer::new();
+        let window: DialogWindow<u32> = DialogWindow::new().unwrap();
+        window_container.add(window);
+        window_container.add(MockParentWindow::new("parent"));
+        assert_eq!(false, window_container.is_blocked(WindowIndex(1)));
+        window_container.set_parent(WindowIndex(0), WindowIndex(1), Pos::new(0, 0));
+        assert_eq!(true, window_container.is_blocked(WindowIndex(1)));
+        let window: &mut DialogWindow<u32> = window_container.window_mut(WindowIndex(0)).unwrap();
+        window.set_modal(false);
+        assert_eq!(false, window_container.is_blocked(WindowIndex(1)));
+    }
+
+    #[test]
+    fn test_dialog_window_blocks_descendants_of_parent_window_except_own_descendants()
+    {
+        let mut window_container = WindowContainer::new();
+        let window: DialogWindow<u32> = DialogWindow::new().unwrap();
+        window_container.add(window);
+        window_container.add(MockParentWindow::new("parent"));
+        let mut window: DialogWindow<u32> = DialogWindow::new().unwrap();
+        window.set_modal(false);
+        window_container.add(window);
+        window_container.add(MockChildWindow::new("child1"));
+        window_container.add(MockChildWindow::new("child2"));
+        window_container.set_parent(WindowIndex(0), WindowIndex(1), Pos::new(0, 0));
+        window_container.set_parent(WindowIndex(2), WindowIndex(1), Pos::new(0, 0));
+        window_container.set_parent(WindowIndex(3), WindowIndex(2), Pos::new(0, 0));
+        window_container.set_parent(WindowIndex(4), WindowIndex(0), Pos::new(0, 0));
+        assert_eq!(false, window_container.is_blocked(WindowIndex(0)));
+        assert_eq!(true, window_container.is_blocked(WindowIndex(1)));
+        assert_eq!(true, window_container.is_blocked(WindowIndex(2)));
+        assert_eq!(true, window_container.is_blocked(WindowIndex(3)));
+        assert_eq!(false, window_container.is_blocked(WindowIndex(4)));
+    }
+}
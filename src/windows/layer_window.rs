@@ -0,0 +1,445 @@
+//
+// Copyright (c) 2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::any::Any;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use crate::as_any::*;
+use crate::call_on::*;
+use crate::client_context::*;
+use crate::container::*;
+use crate::draw::*;
+use crate::events::*;
+use crate::min_size::*;
+use crate::preferred_size::*;
+use crate::queue_context::*;
+use crate::theme::*;
+use crate::types::*;
+use crate::utils::*;
+use crate::widget::*;
+use crate::window::*;
+use crate::windows::child_index_set::*;
+use crate::windows::two_window_widgets::*;
+
+pub struct LayerWindow
+{
+    layer: ClientLayer,
+    namespace: String,
+    anchor: ClientAnchor,
+    exclusive_zone: i32,
+    layer_margin_edges: Edges<i32>,
+    keyboard_interactivity: ClientKeyboardInteractivity,
+    size: Size<i32>,
+    padding_bounds: Rect<i32>,
+    edges: Edges<i32>,
+    is_visible: bool,
+    is_focused: bool,
+    change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    min_size: Size<Option<i32>>,
+    preferred_size: Size<Option<i32>>,
+    child_index_set: ChildIndexSet,
+    call_on_fun: CallOnFun,
+    widgets: TwoWindowWidgets,
+    focused_rel_widget_path: Option<RelWidgetPath>,
+}
+
+impl LayerWindow
+{
+    pub fn new(layer: ClientLayer) -> Self
+    {
+        LayerWindow {
+            layer,
+            namespace: String::from("lwltk"),
+            anchor: ClientAnchor::EMPTY,
+            exclusive_zone: 0,
+            layer_margin_edges: Edges::new(0, 0, 0, 0),
+            keyboard_interactivity: ClientKeyboardInteractivity::None,
+            size: Size::new(0, 0),
+            padding_bounds: Rect::new(0, 0, 0, 0),
+            edges: Edges::new(0, 0, 0, 0),
+            is_visible: true,
+            is_focused: false,
+            change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            min_size: Size::new(None, None),
+            preferred_size: Size::new(None, None),
+            child_index_set: ChildIndexSet::new(),
+            call_on_fun: CallOnFun::new(),
+            widgets: TwoWindowWidgets::new(),
+            focused_rel_widget_path: None,
+        }
+    }
+
+    pub fn set_layer(&mut self, layer: ClientLayer)
+    {
+        let old_layer = self.layer;
+        self.layer = layer;
+        if old_layer != self.layer {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// Sets the namespace of the layer window.
+    ///
+    /// The namespace is only sent to a compositor when the layer window is created, so the
+    /// namespace should be set before the layer window is added to a window context.
+    pub fn set_namespace(&mut self, namespace: &str)
+    { self.namespace = String::from(namespace); }
+
+    pub fn set_anchor(&mut self, anchor: ClientAnchor)
+    {
+        let old_anchor = self.anchor;
+        self.anchor = anchor;
+        if old_anchor != self.anchor {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_exclusive_zone(&mut self, zone: i32)
+    {
+        let old_exclusive_zone = self.exclusive_zone;
+        self.exclusive_zone = zone;
+        if old_exclusive_zone != self.exclusive_zone {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_layer_margin_edges(&mut self, edges: Edges<i32>)
+    {
+        let old_layer_margin_edges = self.layer_margin_edges;
+        self.layer_margin_edges = edges;
+        if old_layer_margin_edges != self.layer_margin_edges {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_keyboard_interactivity(&mut self, interactivity: ClientKeyboardInteractivity)
+    {
+        let old_keyboard_interactivity = self.keyboard_interactivity;
+        self.keyboard_interactivity = interactivity;
+        if old_keyboard_interactivity != self.keyboard_interactivity {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_visible(&mut self, is_visible: bool)
+    {
+        let old_visible_flag = self.is_visible;
+        self.is_visible = is_visible;
+        if old_visible_flag != self.is_visible {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+        self.reset_descendant_states();
+    }
+
+    pub fn set_dyn_on(&mut self, f: Box<dyn FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static>)
+    { self.call_on_fun.fun = f; }
+
+    pub fn set_on<F>(&mut self, f: F)
+        where F: FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static
+    { self.set_dyn_on(Box::new(f)) }
+
+    pub fn has_trimmed_width(&self) -> bool
+    { self.widgets.has_trimmed_width }
+
+    pub fn set_trimmed_width(&mut self, is_trimmed_width: bool)
+    {
+        let old_trimmed_width_flag = self.widgets.has_trimmed_width;
+        self.widgets.has_trimmed_width = is_trimmed_width;
+        if old_trimmed_width_flag != self.widgets.has_trimmed_width {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn has_trimmed_height(&self) -> bool
+    { self.widgets.has_trimmed_height }
+
+    pub fn set_trimmed_height(&mut self, is_trimmed_height: bool)
+    {
+        let old_trimmed_height_flag = self.widgets.has_trimmed_height;
+        self.widgets.has_trimmed_height = is_trimmed_height;
+        if old_trimmed_height_flag != self.widgets.has_trimmed_height {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_dyn(&mut self, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
+    {
+        widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
+        self.widgets.content = Some(widget);
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+        Some(WidgetIndexPair(1, 0))
+    }
+
+    pub fn set<T: Widget + 'static>(&mut self, widget: T) -> Option<WidgetIndexPair>
+    { self.set_dyn(Box::new(widget)) }
+
+    pub fn unset(&mut self) -> Option<Box<dyn Widget>>
+    {
+        let content = self.widgets.content.take();
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+        content
+    }
+}
+
+impl Window for LayerWindow
+{
+    fn size(&self) -> Size<i32>
+    { self.size }
+
+    fn padding_bounds(&self) -> Rect<i32>
+    { self.padding_bounds }
+
+    fn edges(&self) -> Edges<i32>
+    { self.edges }
+
+    fn corners(&self) -> Corners<i32>
+    { Corners::new(0, 0, 0, 0, 0, 0, 0, 0) }
+
+    fn is_visible(&self) -> bool
+    { self.is_visible }
+
+    fn is_focusable(&self) -> bool
+    { self.keyboard_interactivity != ClientKeyboardInteractivity::None }
+
+    fn is_focused(&self) -> bool
+    { self.is_focused }
+
+    fn set_focus(&mut self, is_focused: bool) -> bool
+    {
+        if self.keyboard_interactivity != ClientKeyboardInteractivity::None {
+            let old_focus_flag = self.is_focused;
+            self.is_focused = is_focused;
+            if old_focus_flag != self.is_focused {
+                self.change_flag_arc.store(true, Ordering::SeqCst);
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    fn layer(&self) -> Option<ClientLayer>
+    { Some(self.layer) }
+
+    fn layer_namespace(&self) -> &str
+    { self.namespace.as_str() }
+
+    fn anchor(&self) -> ClientAnchor
+    { self.anchor }
+
+    fn exclusive_zone(&self) -> i32
+    { self.exclusive_zone }
+
+    fn layer_margin_edges(&self) -> Edges<i32>
+    { self.layer_margin_edges }
+
+    fn keyboard_interactivity(&self) -> ClientKeyboardInteractivity
+    { self.keyboard_interactivity }
+
+    fn is_changed(&self) -> bool
+    { self.change_flag_arc.load(Ordering::SeqCst) }
+
+    fn clear_change_flag(&mut self)
+    { self.change_flag_arc.store(false, Ordering::SeqCst); }
+
+    fn is_dirty(&self) -> bool
+    { self.dirty_flag_arc.load(Ordering::SeqCst) }
+
+    fn clear_dirty_flag(&mut self)
+    { self.dirty_flag_arc.store(false, Ordering::SeqCst); }
+
+    fn content_index_pair(&self) -> Option<WidgetIndexPair>
+    {
+        if self.widgets.content.is_some() {
+            Some(WidgetIndexPair(1, 0))
+        } else {
+            None
+        }
+    }
+
+    fn child_index_iter(&self) -> Option<Box<dyn WindowIterator + '_>>
+    { self.child_index_set.child_index_iter() }
+
+    fn add_child(&mut self, idx: ChildWindowIndex) -> Option<()>
+    { self.child_index_set.add(idx) }
+
+    fn remove_child(&mut self, idx: ChildWindowIndex) -> Option<()>
+    { self.child_index_set.remove(idx) }
+
+    fn focused_rel_widget_path(&self) -> Option<&RelWidgetPath>
+    {
+        match &self.focused_rel_widget_path {
+            Some(rel_widget_path) => Some(rel_widget_path),
+            None => None,
+        }
+    }
+
+    fn set_only_focused_rel_widget_path(&mut self, rel_widget_path: Option<RelWidgetPath>) -> bool
+    {
+        self.focused_rel_widget_path = rel_widget_path;
+        true
+    }
+}
+
+impl Container for LayerWindow
+{
+    fn prev(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
+    { self.widgets.prev(idx_pair) }
+
+    fn next(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
+    { self.widgets.next(idx_pair) }
+
+    fn dyn_widget_for_index_pair(&self, idx_pair: WidgetIndexPair) -> Option<&dyn Widget>
+    { self.widgets.dyn_widget(idx_pair) }
+
+    fn dyn_widget_mut_for_index_pair(&mut self, idx_pair: WidgetIndexPair) -> Option<&mut dyn Widget>
+    { self.widgets.dyn_widget_mut(idx_pair) }
+
+    fn point_for_index_pair(&self, pos: Pos<f64>) -> Option<WidgetIndexPair>
+    { self.widgets.point(pos) }
+}
+
+impl MinSize for LayerWindow
+{
+    fn min_size(&self) -> Size<Option<i32>>
+    { self.min_size }
+
+    fn set_min_size(&mut self, size: Size<Option<i32>>)
+    {
+        let old_min_size = self.min_size;
+        self.min_size = size;
+        if old_min_size != self.min_size {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl PreferredSize for LayerWindow
+{
+    fn preferred_size(&self) -> Size<Option<i32>>
+    { self.preferred_size }
+
+    fn set_preferred_size(&mut self, size: Size<Option<i32>>)
+    {
+        let old_preferred_size = self.preferred_size;
+        self.preferred_size = size;
+        if old_preferred_size != self.preferred_size {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Draw for LayerWindow
+{
+    fn update_size(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_size: Size<Option<i32>>) -> Result<(), CairoError>
+    {
+        self.edges = theme.layer_window_edges();
+        let padding_area_size = inner_opt_size(area_size, self.edges);
+        self.widgets.update_size(cairo_context, theme, padding_area_size)?;
+        self.padding_bounds.set_size(self.widgets.padding_size(padding_area_size));
+        self.size = outer_size(self.padding_bounds.size(), self.edges);
+        Ok(())
+    }
+
+    fn update_pos(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_bounds: Rect<i32>) -> Result<(), CairoError>
+    {
+        self.padding_bounds.set_pos(inner_pos(area_bounds, self.edges));
+        self.widgets.update_pos(cairo_context, theme, inner_rect(area_bounds, self.edges))?;
+        Ok(())
+    }
+
+    fn draw(&self, cairo_context: &CairoContext, theme: &dyn Theme, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        theme.draw_layer_window_bg(cairo_context, Rect::new(0, 0, self.size.width, self.size.height), is_focused_window)?;
+        self.widgets.draw(cairo_context, theme, is_focused_window)?;
+        Ok(())
+    }
+}
+
+impl CallOn for LayerWindow
+{
+    fn call_on(&mut self, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Event>>
+    {
+        let default_event = if let Some(tmp_default_event) = default_window_on(self, client_context, queue_context, event)? {
+            tmp_default_event
+        } else {
+            None
+        };
+        self.call_on_fun.call_on(client_context, queue_context, event, default_event)
+    }
+}
+
+impl AsAny for LayerWindow
+{
+    fn as_any(&self) -> &dyn Any
+    { self }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    { self }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::mocks::*;
+    use crate::widgets::*;
+
+    #[test]
+    fn test_layer_window_update_size_and_position()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_layer_window_edges(Edges::new(2, 2, 2, 2));
+        theme.set_button_margin_edges(Edges::new(2, 2, 2, 2));
+        theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
+        theme.set_button_font_size(16.0);
+        let mut window = LayerWindow::new(ClientLayer::Top);
+        window.set_anchor(ClientAnchor::TOP | ClientAnchor::LEFT | ClientAnchor::RIGHT);
+        let mut button = Button::new("B");
+        button.set_preferred_size(Size::new(Some(120), Some(60)));
+        window.set(button);
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match window.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Edges::new(2, 2, 2, 2), window.edges);
+        assert_eq!(Size::new(124, 64), window.padding_bounds.size());
+        assert_eq!(Size::new(128, 68), window.size);
+        assert_eq!(Size::new(124, 64), window.widgets.content.as_ref().unwrap().margin_size());
+        assert_eq!(Size::new(120, 60), window.widgets.content.as_ref().unwrap().size());
+        let area_bounds = Rect::new(0, 0, window.size.width, window.size.height);
+        match window.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Pos::new(2, 2), window.padding_bounds.pos());
+        assert_eq!(Pos::new(2, 2), window.widgets.content.as_ref().unwrap().margin_pos());
+        assert_eq!(Pos::new(4, 4), window.widgets.content.as_ref().unwrap().pos());
+    }
+
+    #[test]
+    fn test_layer_window_is_focusable_only_for_keyboard_interactivity()
+    {
+        let mut window = LayerWindow::new(ClientLayer::Overlay);
+        assert_eq!(false, window.is_focusable());
+        assert_eq!(false, window.set_focus(true));
+        assert_eq!(false, window.is_focused());
+        window.set_keyboard_interactivity(ClientKeyboardInteractivity::OnDemand);
+        assert_eq!(true, window.is_focusable());
+        assert_eq!(true, window.set_focus(true));
+        assert_eq!(true, window.is_focused());
+    }
+}
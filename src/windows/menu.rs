@@ -0,0 +1,894 @@
+//
+// Copyright (c) 2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::any::Any;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use crate::as_any::*;
+use crate::call_on::*;
+use crate::client_context::*;
+use crate::container::*;
+use crate::draw::*;
+use crate::event_queue::*;
+use crate::events::*;
+use crate::keys::*;
+use crate::min_size::*;
+use crate::preferred_size::*;
+use crate::queue_context::*;
+use crate::theme::*;
+use crate::types::*;
+use crate::utils::*;
+use crate::widget::*;
+use crate::widgets::*;
+use crate::window::*;
+use crate::window_context::*;
+use crate::windows::child_index_set::*;
+use crate::windows::two_window_widgets::*;
+
+const MAX_MENU_DEPTH: usize = 32;
+
+/// A structure of menu.
+///
+/// The menu is a popup window that contains menu items in a vertical layout. The menu is opened by
+/// a menu bar item, a menu item of other menu as a submenu, or an application. The menu is closed
+/// with its submenus when an user clicks outside the menu, clicks a menu item without a submenu,
+/// or presses the escape key.
+pub struct Menu
+{
+    size: Size<i32>,
+    padding_bounds: Rect<i32>,
+    edges: Edges<i32>,
+    is_visible: bool,
+    is_focused: bool,
+    change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    min_size: Size<Option<i32>>,
+    preferred_size: Size<Option<i32>>,
+    parent_index: Option<WindowIndex>,
+    pos_in_parent: Option<Pos<i32>>,
+    anchor_rect: Option<Rect<i32>>,
+    anchor: ClientPopupAnchor,
+    gravity: ClientPopupGravity,
+    constraint_adjustment: ClientConstraintAdjustment,
+    child_index_set: ChildIndexSet,
+    call_on_fun: CallOnFun,
+    widgets: TwoWindowWidgets,
+    focused_rel_widget_path: Option<RelWidgetPath>,
+    opener_path: Option<AbsWidgetPath>,
+}
+
+impl Menu
+{
+    pub fn new() -> Self
+    {
+        let change_flag_arc = Arc::new(AtomicBool::new(false));
+        let dirty_flag_arc = Arc::new(AtomicBool::new(false));
+        let mut layout = LinearLayout::new();
+        layout.set_orient(Orient::Vertical);
+        layout.set_h_align(HAlign::Fill);
+        layout.set_change_flag_arc(change_flag_arc.clone());
+        layout.set_dirty_flag_arc(dirty_flag_arc.clone());
+        let mut widgets = TwoWindowWidgets::new();
+        widgets.content = Some(Box::new(layout));
+        Menu {
+            size: Size::new(0, 0),
+            padding_bounds: Rect::new(0, 0, 0, 0),
+            edges: Edges::new(0, 0, 0, 0),
+            is_visible: true,
+            is_focused: false,
+            change_flag_arc,
+            dirty_flag_arc,
+            min_size: Size::new(None, None),
+            preferred_size: Size::new(None, None),
+            parent_index: None,
+            pos_in_parent: None,
+            anchor_rect: None,
+            anchor: ClientPopupAnchor::BottomLeft,
+            gravity: ClientPopupGravity::BottomRight,
+            constraint_adjustment: ClientConstraintAdjustment::SLIDE_X | ClientConstraintAdjustment::SLIDE_Y | ClientConstraintAdjustment::FLIP_X | ClientConstraintAdjustment::FLIP_Y,
+            child_index_set: ChildIndexSet::new(),
+            call_on_fun: CallOnFun::new(),
+            widgets,
+            focused_rel_widget_path: None,
+            opener_path: None,
+        }
+    }
+
+    /// Sets the anchor rectangle in the parent window.
+    ///
+    /// The anchor rectangle is set by the [`open_menu`] function, so this method is used if the
+    /// menu is opened by an application in other way.
+    pub fn set_anchor_rect(&mut self, rect: Option<Rect<i32>>)
+    {
+        let old_anchor_rect = self.anchor_rect;
+        self.anchor_rect = rect;
+        if old_anchor_rect != self.anchor_rect {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_anchor(&mut self, anchor: ClientPopupAnchor)
+    {
+        let old_anchor = self.anchor;
+        self.anchor = anchor;
+        if old_anchor != self.anchor {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_gravity(&mut self, gravity: ClientPopupGravity)
+    {
+        let old_gravity = self.gravity;
+        self.gravity = gravity;
+        if old_gravity != self.gravity {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_constraint_adjustment(&mut self, adjustment: ClientConstraintAdjustment)
+    {
+        let old_constraint_adjustment = self.constraint_adjustment;
+        self.constraint_adjustment = adjustment;
+        if old_constraint_adjustment != self.constraint_adjustment {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_visible(&mut self, is_visible: bool)
+    {
+        let old_visible_flag = self.is_visible;
+        self.is_visible = is_visible;
+        if old_visible_flag != self.is_visible {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+        self.reset_descendant_states();
+    }
+
+    pub fn set_dyn_on(&mut self, f: Box<dyn FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static>)
+    { self.call_on_fun.fun = f; }
+
+    pub fn set_on<F>(&mut self, f: F)
+        where F: FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static
+    { self.set_dyn_on(Box::new(f)) }
+
+    /// Returns a reference to the absolute path of the widget that opened the menu or `None`.
+    pub fn opener_path(&self) -> Option<&AbsWidgetPath>
+    {
+        match &self.opener_path {
+            Some(opener_path) => Some(opener_path),
+            None => None,
+        }
+    }
+
+    pub fn set_opener_path(&mut self, path: Option<AbsWidgetPath>)
+    { self.opener_path = path; }
+
+    fn layout(&self) -> Option<&LinearLayout>
+    {
+        match &self.widgets.content {
+            Some(content) => dyn_widget_as_widget(&**content),
+            None => None,
+        }
+    }
+
+    fn layout_mut(&mut self) -> Option<&mut LinearLayout>
+    {
+        match &mut self.widgets.content {
+            Some(content) => dyn_widget_mut_as_widget_mut(&mut **content),
+            None => None,
+        }
+    }
+
+    pub fn add_dyn(&mut self, widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
+    { self.layout_mut()?.add_dyn(widget) }
+
+    pub fn add<T: Widget + 'static>(&mut self, widget: T) -> Option<WidgetIndexPair>
+    { self.add_dyn(Box::new(widget)) }
+
+    pub fn insert_dyn(&mut self, idx_pair: WidgetIndexPair, widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
+    { self.layout_mut()?.insert_dyn(idx_pair, widget) }
+
+    pub fn insert<T: Widget + 'static>(&mut self, idx_pair: WidgetIndexPair, widget: T) -> Option<WidgetIndexPair>
+    { self.insert_dyn(idx_pair, Box::new(widget)) }
+
+    pub fn remove(&mut self, idx_pair: WidgetIndexPair) -> Option<Box<dyn Widget>>
+    { self.layout_mut()?.remove(idx_pair) }
+
+    pub fn remove_last(&mut self) -> Option<Box<dyn Widget>>
+    { self.layout_mut()?.remove_last() }
+
+    pub fn clear(&mut self)
+    {
+        match self.layout_mut() {
+            Some(layout) => layout.clear(),
+            None => (),
+        }
+    }
+
+    /// Returns the relative path of the menu item that is enabled and has the mnemonic character
+    /// or `None`.
+    ///
+    /// The character is compared with the mnemonic character without case sensitivity.
+    pub fn item_path_for_mnemonic(&self, c: char) -> Option<RelWidgetPath>
+    {
+        let lowercase_c = c.to_lowercase().next();
+        let layout = self.layout()?;
+        let mut i = 0;
+        loop {
+            match layout.dyn_widget_for_index_pair(WidgetIndexPair(i, 0)) {
+                Some(widget) => {
+                    match dyn_widget_as_widget::<MenuItem>(widget) {
+                        Some(item) if item.is_focusable() && item.mnemonic_char() == lowercase_c => {
+                            let mut rel_widget_path = RelWidgetPath::new(WidgetIndexPair(1, 0));
+                            rel_widget_path.push(WidgetIndexPair(i, 0));
+                            return Some(rel_widget_path);
+                        },
+                        _ => (),
+                    }
+                },
+                None => break,
+            }
+            i += 1;
+        }
+        None
+    }
+
+    fn default_menu_on(&mut self, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
+    {
+        match event {
+            Event::Key(VKey::Escape, modifiers) if *modifiers == KeyModifiers::EMPTY => {
+                let current_window_idx = queue_context.current_call_on_path()?.window_index();
+                queue_context.push_callback(move |_, window_context, _| {
+                        close_menu(window_context, current_window_idx)
+                });
+                Some(Some(None))
+            },
+            Event::Key(VKey::Left, modifiers) if *modifiers == KeyModifiers::EMPTY => {
+                let current_window_idx = queue_context.current_call_on_path()?.window_index();
+                queue_context.push_callback(move |_, window_context, _| {
+                        let parent_idx = window_context.dyn_window(current_window_idx)?.parent_index()?;
+                        if window_context.window::<Menu>(parent_idx).is_some() {
+                            close_menu(window_context, current_window_idx)
+                        } else {
+                            switch_menu_bar_item_menu(window_context, current_window_idx, Dir::Prev)
+                        }
+                });
+                Some(Some(None))
+            },
+            Event::Key(VKey::Right, modifiers) if *modifiers == KeyModifiers::EMPTY => {
+                let current_window_idx = queue_context.current_call_on_path()?.window_index();
+                let submenu_pair = match &self.focused_rel_widget_path {
+                    Some(rel_widget_path) => {
+                        match self.dyn_widget(rel_widget_path) {
+                            Some(widget) => {
+                                match dyn_widget_as_widget::<MenuItem>(widget) {
+                                    Some(item) if item.is_focusable() => {
+                                        match item.submenu_index() {
+                                            Some(submenu_idx) => Some((submenu_idx, rel_widget_path.to_abs_widget_path(current_window_idx), item.bounds())),
+                                            None => None,
+                                        }
+                                    },
+                                    _ => None,
+                                }
+                            },
+                            None => None,
+                        }
+                    },
+                    None => None,
+                };
+                match submenu_pair {
+                    Some((submenu_idx, abs_widget_path, bounds)) => {
+                        queue_context.push_callback(move |_, window_context, _| {
+                                open_menu(window_context, submenu_idx, current_window_idx, bounds, ClientPopupAnchor::TopRight, Some(abs_widget_path.clone()), true)
+                        });
+                    },
+                    None => {
+                        queue_context.push_callback(move |_, window_context, _| {
+                                switch_menu_bar_item_menu(window_context, current_window_idx, Dir::Next)
+                        });
+                    },
+                }
+                Some(Some(None))
+            },
+            Event::Char(c) if client_context.key_modifiers() == KeyModifiers::EMPTY || client_context.key_modifiers() == KeyModifiers::SHIFT => {
+                match self.item_path_for_mnemonic(*c) {
+                    Some(rel_widget_path) => {
+                        let current_window_idx = queue_context.current_call_on_path()?.window_index();
+                        let call_on_path = CallOnPath::Widget(rel_widget_path.to_abs_widget_path(current_window_idx));
+                        queue_context.push_event_for_call_on_path(call_on_path, Event::Click);
+                        Some(Some(None))
+                    },
+                    None => Some(None),
+                }
+            },
+            _ => Some(None),
+        }
+    }
+}
+
+impl Window for Menu
+{
+    fn size(&self) -> Size<i32>
+    { self.size }
+
+    fn padding_bounds(&self) -> Rect<i32>
+    { self.padding_bounds }
+
+    fn edges(&self) -> Edges<i32>
+    { self.edges }
+
+    fn corners(&self) -> Corners<i32>
+    { Corners::new(0, 0, 0, 0, 0, 0, 0, 0) }
+
+    fn is_visible(&self) -> bool
+    { self.is_visible }
+
+    fn is_focused(&self) -> bool
+    { self.is_focused }
+
+    fn set_focus(&mut self, is_focused: bool) -> bool
+    {
+        let old_focus_flag = self.is_focused;
+        self.is_focused = is_focused;
+        if old_focus_flag != self.is_focused {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+        true
+    }
+
+    fn is_popup(&self) -> bool
+    { true }
+
+    fn popup_anchor_rect(&self) -> Option<Rect<i32>>
+    { self.anchor_rect }
+
+    fn popup_anchor(&self) -> ClientPopupAnchor
+    { self.anchor }
+
+    fn popup_gravity(&self) -> ClientPopupGravity
+    { self.gravity }
+
+    fn popup_constraint_adjustment(&self) -> ClientConstraintAdjustment
+    { self.constraint_adjustment }
+
+    fn has_popup_grab(&self) -> bool
+    { true }
+
+    fn is_closed_by_outside_click(&self) -> bool
+    { true }
+
+    fn parent_index(&self) -> Option<WindowIndex>
+    { self.parent_index }
+
+    fn pos_in_parent(&self) -> Option<Pos<i32>>
+    { self.pos_in_parent }
+
+    fn set_parent(&mut self, idx: ParentWindowIndex, pos: Pos<i32>) -> Option<()>
+    {
+        match (self.parent_index, self.pos_in_parent) {
+            (None, None) => {
+                self.parent_index = Some(idx.window_index());
+                self.pos_in_parent = Some(pos);
+                self.change_flag_arc.store(true, Ordering::SeqCst);
+                Some(())
+            },
+            _ => None,
+        }
+    }
+
+    fn unset_parent(&mut self, _tag: ParentWindowTag) -> Option<()>
+    {
+        match (self.parent_index, self.pos_in_parent) {
+            (None, None) => None,
+            _ => {
+                self.parent_index = None;
+                self.pos_in_parent = None;
+                self.reset_descendant_states();
+                self.change_flag_arc.store(true, Ordering::SeqCst);
+                Some(())
+            },
+        }
+    }
+
+    fn is_changed(&self) -> bool
+    { self.change_flag_arc.load(Ordering::SeqCst) }
+
+    fn clear_change_flag(&mut self)
+    { self.change_flag_arc.store(false, Ordering::SeqCst); }
+
+    fn is_dirty(&self) -> bool
+    { self.dirty_flag_arc.load(Ordering::SeqCst) }
+
+    fn clear_dirty_flag(&mut self)
+    { self.dirty_flag_arc.store(false, Ordering::SeqCst); }
+
+    fn content_index_pair(&self) -> Option<WidgetIndexPair>
+    {
+        if self.widgets.content.is_some() {
+            Some(WidgetIndexPair(1, 0))
+        } else {
+            None
+        }
+    }
+
+    fn child_index_iter(&self) -> Option<Box<dyn WindowIterator + '_>>
+    { self.child_index_set.child_index_iter() }
+
+    fn add_child(&mut self, idx: ChildWindowIndex) -> Option<()>
+    { self.child_index_set.add(idx) }
+
+    fn remove_child(&mut self, idx: ChildWindowIndex) -> Option<()>
+    { self.child_index_set.remove(idx) }
+
+    fn focused_rel_widget_path(&self) -> Option<&RelWidgetPath>
+    {
+        match &self.focused_rel_widget_path {
+            Some(rel_widget_path) => Some(rel_widget_path),
+            None => None,
+        }
+    }
+
+    fn set_only_focused_rel_widget_path(&mut self, rel_widget_path: Option<RelWidgetPath>) -> bool
+    {
+        self.focused_rel_widget_path = rel_widget_path;
+        true
+    }
+}
+
+impl Container for Menu
+{
+    fn prev(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
+    { self.widgets.prev(idx_pair) }
+
+    fn next(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
+    { self.widgets.next(idx_pair) }
+
+    fn dyn_widget_for_index_pair(&self, idx_pair: WidgetIndexPair) -> Option<&dyn Widget>
+    { self.widgets.dyn_widget(idx_pair) }
+
+    fn dyn_widget_mut_for_index_pair(&mut self, idx_pair: WidgetIndexPair) -> Option<&mut dyn Widget>
+    { self.widgets.dyn_widget_mut(idx_pair) }
+
+    fn point_for_index_pair(&self, pos: Pos<f64>) -> Option<WidgetIndexPair>
+    { self.widgets.point(pos) }
+}
+
+impl MinSize for Menu
+{
+    fn min_size(&self) -> Size<Option<i32>>
+    { self.min_size }
+
+    fn set_min_size(&mut self, size: Size<Option<i32>>)
+    {
+        let old_min_size = self.min_size;
+        self.min_size = size;
+        if old_min_size != self.min_size {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl PreferredSize for Menu
+{
+    fn preferred_size(&self) -> Size<Option<i32>>
+    { self.preferred_size }
+
+    fn set_preferred_size(&mut self, size: Size<Option<i32>>)
+    {
+        let old_preferred_size = self.preferred_size;
+        self.preferred_size = size;
+        if old_preferred_size != self.preferred_size {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Draw for Menu
+{
+    fn update_size(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_size: Size<Option<i32>>) -> Result<(), CairoError>
+    {
+        self.edges = theme.menu_edges();
+        let padding_area_size = inner_opt_size(area_size, self.edges);
+        self.widgets.update_size(cairo_context, theme, padding_area_size)?;
+        self.padding_bounds.set_size(self.widgets.padding_size(padding_area_size));
+        self.size = outer_size(self.padding_bounds.size(), self.edges);
+        Ok(())
+    }
+
+    fn update_pos(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_bounds: Rect<i32>) -> Result<(), CairoError>
+    {
+        self.padding_bounds.set_pos(inner_pos(area_bounds, self.edges));
+        self.widgets.update_pos(cairo_context, theme, inner_rect(area_bounds, self.edges))?;
+        Ok(())
+    }
+
+    fn draw(&self, cairo_context: &CairoContext, theme: &dyn Theme, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        theme.draw_menu_bg(cairo_context, Rect::new(0, 0, self.size.width, self.size.height), is_focused_window)?;
+        self.widgets.draw(cairo_context, theme, is_focused_window)?;
+        Ok(())
+    }
+}
+
+impl CallOn for Menu
+{
+    fn call_on(&mut self, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Event>>
+    {
+        let default_event = if let Some(tmp_default_event) = self.default_menu_on(client_context, queue_context, event)? {
+            tmp_default_event
+        } else if let Some(tmp_default_event) = default_window_on(self, client_context, queue_context, event)? {
+            tmp_default_event
+        } else {
+            None
+        };
+        self.call_on_fun.call_on(client_context, queue_context, event, default_event)
+    }
+}
+
+impl AsAny for Menu
+{
+    fn as_any(&self) -> &dyn Any
+    { self }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    { self }
+}
+
+fn child_menu_indices(window_context: &WindowContext, idx: WindowIndex) -> Option<Vec<WindowIndex>>
+{
+    let window = window_context.dyn_window(idx)?;
+    Some(window.child_indices().filter(|child_idx| window_context.window::<Menu>(*child_idx).is_some()).collect())
+}
+
+fn root_menu_index(window_context: &WindowContext, menu_idx: WindowIndex) -> Option<WindowIndex>
+{
+    let mut root_idx = menu_idx;
+    window_context.window::<Menu>(root_idx)?;
+    loop {
+        match window_context.dyn_window(root_idx)?.parent_index() {
+            Some(parent_idx) if window_context.window::<Menu>(parent_idx).is_some() => root_idx = parent_idx,
+            _ => break,
+        }
+    }
+    Some(root_idx)
+}
+
+/// Opens the menu as a child of the parent window.
+///
+/// The menu is placed relative to the anchor rectangle in the parent window. Other opened menus of
+/// the parent window are closed before. If the flag of first item focus is `true`, the first
+/// focusable menu item is focused. This function returns `Some(())` if the menu is opened,
+/// otherwise `None`.
+pub fn open_menu(window_context: &mut WindowContext, menu_idx: WindowIndex, parent_idx: WindowIndex, anchor_rect: Rect<i32>, anchor: ClientPopupAnchor, opener_path: Option<AbsWidgetPath>, is_first_item_focused: bool) -> Option<()>
+{
+    let menu: &Menu = window_context.window(menu_idx)?;
+    if menu.parent_index() == Some(parent_idx) && menu.popup_anchor_rect() == Some(anchor_rect) && menu.opener_path() == opener_path.as_ref() {
+        if is_first_item_focused {
+            let menu: &mut Menu = window_context.window_mut(menu_idx)?;
+            menu.set_focused_rel_widget_path(None);
+            menu.next_focused_widget();
+        }
+        return Some(());
+    }
+    for child_idx in child_menu_indices(window_context, parent_idx)? {
+        close_menu(window_context, child_idx);
+    }
+    if window_context.dyn_window(menu_idx)?.parent_index().is_some() {
+        close_menu(window_context, menu_idx);
+    }
+    let menu: &mut Menu = window_context.window_mut(menu_idx)?;
+    menu.set_anchor_rect(Some(anchor_rect));
+    menu.set_anchor(anchor);
+    menu.set_opener_path(opener_path);
+    menu.set_focused_rel_widget_path(None);
+    if is_first_item_focused {
+        menu.next_focused_widget();
+    }
+    window_context.set_parent_window(menu_idx, parent_idx, anchor_rect.pos())
+}
+
+/// Closes the menu with its opened submenus.
+///
+/// This function returns `Some(())` if the menu is closed, otherwise `None`.
+pub fn close_menu(window_context: &mut WindowContext, menu_idx: WindowIndex) -> Option<()>
+{
+    for child_idx in child_menu_indices(window_context, menu_idx)? {
+        close_menu(window_context, child_idx);
+    }
+    window_context.window_mut::<Menu>(menu_idx)?.set_opener_path(None);
+    window_context.unset_parent_window(menu_idx)
+}
+
+/// Closes the menu with its parent menus and its opened submenus.
+///
+/// This function returns `Some(())` if the menus are closed, otherwise `None`.
+pub fn close_menu_chain(window_context: &mut WindowContext, menu_idx: WindowIndex) -> Option<()>
+{
+    let root_idx = root_menu_index(window_context, menu_idx)?;
+    close_menu(window_context, root_idx)
+}
+
+/// Opens the menu of the menu bar item below the menu bar item.
+///
+/// This function returns `Some(())` if the menu is opened, otherwise `None`.
+pub fn open_menu_bar_item_menu(window_context: &mut WindowContext, path: &AbsWidgetPath, is_first_item_focused: bool) -> Option<()>
+{
+    let item: &MenuBarItem = window_context.widget(path)?;
+    if !item.is_enabled() {
+        return None;
+    }
+    let menu_idx = item.menu_index()?;
+    let bounds = item.bounds();
+    open_menu(window_context, menu_idx, path.window_index(), bounds, ClientPopupAnchor::BottomLeft, Some(path.clone()), is_first_item_focused)
+}
+
+/// Switches an opened menu of other menu bar item to the menu of the menu bar item.
+///
+/// This function is called when a pointer enters the menu bar item. The menu of the menu bar item
+/// is opened only if a menu of other menu bar item in the same window is opened. This function
+/// returns `Some(())` if the menu is opened, otherwise `None`.
+pub fn switch_menu_bar_item_menu_for_hover(window_context: &mut WindowContext, path: &AbsWidgetPath) -> Option<()>
+{
+    let mut is_switched = false;
+    for child_idx in child_menu_indices(window_context, path.window_index())? {
+        match window_context.window::<Menu>(child_idx)?.opener_path() {
+            Some(opener_path) if opener_path != path => {
+                if window_context.widget::<MenuBarItem>(opener_path).is_some() {
+                    is_switched = true;
+                }
+            },
+            _ => (),
+        }
+    }
+    if is_switched {
+        open_menu_bar_item_menu(window_context, path, false)
+    } else {
+        None
+    }
+}
+
+fn context_menu_index_and_opener_path(window_context: &WindowContext, call_on_path: &CallOnPath) -> Option<(WindowIndex, Option<AbsWidgetPath>)>
+{
+    let window = window_context.dyn_window(call_on_path.window_index())?;
+    match call_on_path {
+        CallOnPath::Widget(abs_widget_path) => {
+            let mut tmp_abs_widget_path = abs_widget_path.clone();
+            loop {
+                match window_context.dyn_widget(&tmp_abs_widget_path).and_then(|w| w.context_menu_index()) {
+                    Some(menu_idx) => return Some((menu_idx, Some(abs_widget_path.clone()))),
+                    None => (),
+                }
+                if tmp_abs_widget_path.pop().is_none() {
+                    break;
+                }
+            }
+            if abs_widget_path.widget_index_pairs().next() == window.content_index_pair() {
+                Some((window.context_menu_index()?, Some(abs_widget_path.clone())))
+            } else {
+                None
+            }
+        },
+        CallOnPath::Window(_) => Some((window.context_menu_index()?, None)),
+    }
+}
+
+/// Opens the context menu for the widget or the window.
+///
+/// The context menu is searched for the widget and its ancestors, and then for the window content.
+/// If the anchor rectangle is `None`, the context menu is placed at the current pointer or touch
+/// position. Otherwise, the context menu is placed below the anchor rectangle and the first menu
+/// item is focused, because the anchor rectangle is passed for a key. This function returns
+/// `Some(())` if the context menu is opened, otherwise `None`.
+pub fn open_context_menu(window_context: &mut WindowContext, call_on_path: &CallOnPath, anchor_rect: Option<Rect<i32>>) -> Option<()>
+{
+    let (menu_idx, opener_path) = context_menu_index_and_opener_path(window_context, call_on_path)?;
+    let (tmp_anchor_rect, anchor, is_first_item_focused) = match anchor_rect {
+        Some(anchor_rect) => (anchor_rect, ClientPopupAnchor::BottomLeft, true),
+        None => {
+            let pos = window_context.current_pos()?;
+            (Rect::new(pos.x as i32, pos.y as i32, 1, 1), ClientPopupAnchor::TopLeft, false)
+        },
+    };
+    open_menu(window_context, menu_idx, call_on_path.window_index(), tmp_anchor_rect, anchor, opener_path, is_first_item_focused)
+}
+
+pub(crate) fn switch_menu_bar_item_menu(window_context: &mut WindowContext, menu_idx: WindowIndex, dir: Dir) -> Option<()>
+{
+    let root_idx = root_menu_index(window_context, menu_idx)?;
+    let opener_path = window_context.window::<Menu>(root_idx)?.opener_path()?.clone();
+    window_context.widget::<MenuBarItem>(&opener_path)?;
+    let idx_pair = opener_path.widget_index_pairs().next_back()?;
+    let mut menu_bar_path = opener_path.clone();
+    menu_bar_path.pop()?;
+    let menu_bar: &MenuBar = window_context.widget(&menu_bar_path)?;
+    let new_idx_pair = match dir {
+        Dir::Prev => menu_bar.prev_item_index_pair(idx_pair)?,
+        Dir::Next => menu_bar.next_item_index_pair(idx_pair)?,
+    };
+    let mut new_path = menu_bar_path;
+    new_path.push(new_idx_pair);
+    open_menu_bar_item_menu(window_context, &new_path, true)
+}
+
+fn menu_item_path_for_shortcut(window_context: &WindowContext, menu_idx: WindowIndex, key: VKey, modifiers: KeyModifiers, depth: usize) -> Option<AbsWidgetPath>
+{
+    if depth == 0 {
+        return None;
+    }
+    let mut i = 0;
+    loop {
+        let mut path = AbsWidgetPath::new(menu_idx, WidgetIndexPair(1, 0));
+        path.push(WidgetIndexPair(i, 0));
+        match window_context.dyn_widget(&path) {
+            Some(widget) => {
+                match dyn_widget_as_widget::<MenuItem>(widget) {
+                    Some(item) if item.is_focusable() => {
+                        if item.shortcut() == Some((key, modifiers)) {
+                            return Some(path);
+                        }
+                        match item.submenu_index() {
+                            Some(submenu_idx) => {
+                                match menu_item_path_for_shortcut(window_context, submenu_idx, key, modifiers, depth - 1) {
+                                    Some(submenu_item_path) => return Some(submenu_item_path),
+                                    None => (),
+                                }
+                            },
+                            None => (),
+                        }
+                    },
+                    _ => (),
+                }
+            },
+            None => break,
+        }
+        i += 1;
+    }
+    None
+}
+
+pub(crate) fn menu_bar_item_path_for_shortcut(window_context: &WindowContext, menu_bar_path: &AbsWidgetPath, key: VKey, modifiers: KeyModifiers) -> Option<AbsWidgetPath>
+{
+    let mut i = 0;
+    loop {
+        let mut path = menu_bar_path.clone();
+        path.push(WidgetIndexPair(i, 0));
+        match window_context.dyn_widget(&path) {
+            Some(widget) => {
+                match dyn_widget_as_widget::<MenuBarItem>(widget) {
+                    Some(item) if item.is_enabled() => {
+                        match item.menu_index() {
+                            Some(menu_idx) => {
+                                match menu_item_path_for_shortcut(window_context, menu_idx, key, modifiers, MAX_MENU_DEPTH) {
+                                    Some(menu_item_path) => return Some(menu_item_path),
+                                    None => (),
+                                }
+                            },
+                            None => (),
+                        }
+                    },
+                    _ => (),
+                }
+            },
+            None => break,
+        }
+        i += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::mocks::*;
+    use crate::windows::*;
+
+    #[test]
+    fn test_menu_updates_size_and_position()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_menu_edges(Edges::new(2, 2, 2, 2));
+        theme.set_menu_item_margin_edges(Edges::new(0, 0, 0, 0));
+        theme.set_menu_item_padding_edges(Edges::new(4, 4, 4, 4));
+        theme.set_menu_item_sep_width(8);
+        theme.set_menu_item_icon_size(Size::new(16, 16));
+        theme.set_menu_item_arrow_size(Size::new(8, 8));
+        theme.set_menu_item_separator_height(8);
+        theme.set_menu_item_font_size(16.0);
+        let mut menu = Menu::new();
+        let mut item1 = MenuItem::new("_Open");
+        item1.set_preferred_size(Size::new(Some(100), Some(32)));
+        menu.add(item1);
+        menu.add(MenuItem::new_separator());
+        let mut item2 = MenuItem::new("_Quit");
+        item2.set_preferred_size(Size::new(Some(120), Some(32)));
+        menu.add(item2);
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match menu.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Edges::new(2, 2, 2, 2), menu.edges);
+        assert_eq!(Size::new(120, 32 + 8 + 32), menu.padding_bounds.size());
+        assert_eq!(Size::new(124, 76), menu.size);
+        let area_bounds = Rect::new(0, 0, menu.size.width, menu.size.height);
+        match menu.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Pos::new(2, 2), menu.padding_bounds.pos());
+        let layout = menu.layout().unwrap();
+        let item1 = layout.dyn_widget_for_index_pair(WidgetIndexPair(0, 0)).unwrap();
+        assert_eq!(Rect::new(2, 2, 120, 32), item1.bounds());
+        let separator = layout.dyn_widget_for_index_pair(WidgetIndexPair(1, 0)).unwrap();
+        assert_eq!(Rect::new(2, 34, 120, 8), separator.bounds());
+        let item2 = layout.dyn_widget_for_index_pair(WidgetIndexPair(2, 0)).unwrap();
+        assert_eq!(Rect::new(2, 42, 120, 32), item2.bounds());
+    }
+
+    #[test]
+    fn test_menu_returns_item_path_for_mnemonic()
+    {
+        let mut menu = Menu::new();
+        menu.add(MenuItem::new("_Open"));
+        menu.add(MenuItem::new_separator());
+        let mut item = MenuItem::new("_Save");
+        item.set_enabled(false);
+        menu.add(item);
+        menu.add(MenuItem::new("_Quit"));
+        let mut expected_path = RelWidgetPath::new(WidgetIndexPair(1, 0));
+        expected_path.push(WidgetIndexPair(3, 0));
+        assert_eq!(Some(expected_path), menu.item_path_for_mnemonic('Q'));
+        assert_eq!(None, menu.item_path_for_mnemonic('s'));
+        assert_eq!(None, menu.item_path_for_mnemonic('x'));
+    }
+
+    #[test]
+    fn test_context_menu_index_is_moved_with_widget_in_layout()
+    {
+        let mut window_context = WindowContext::new(Box::new(MockTheme::new()));
+        let mut window = ToplevelWindow::new().unwrap();
+        let mut layout = LinearLayout::new();
+        let mut button = Button::new("B");
+        button.set_context_menu_index(Some(WindowIndex(1)));
+        layout.add(button);
+        window.set(layout);
+        let window_idx = window_context.window_container_mut().add(window).unwrap();
+        let mut menu = Menu::new();
+        menu.add(MenuItem::new("_Copy"));
+        let menu_idx = window_context.window_container_mut().add(menu).unwrap();
+        let mut layout_path = AbsWidgetPath::new(window_idx, WidgetIndexPair(1, 0));
+        let mut button_path = layout_path.clone();
+        button_path.push(WidgetIndexPair(0, 0));
+        match context_menu_index_and_opener_path(&window_context, &CallOnPath::Widget(button_path.clone())) {
+            Some((tmp_menu_idx, opener_path)) => {
+                assert_eq!(menu_idx, tmp_menu_idx);
+                assert_eq!(Some(button_path.clone()), opener_path);
+            },
+            None => assert!(false),
+        }
+        let layout: &mut LinearLayout = window_context.widget_mut(&layout_path).unwrap();
+        layout.insert(WidgetIndexPair(0, 0), Label::new("L"));
+        assert_eq!(true, context_menu_index_and_opener_path(&window_context, &CallOnPath::Widget(button_path.clone())).is_none());
+        button_path = layout_path.clone();
+        button_path.push(WidgetIndexPair(1, 0));
+        match context_menu_index_and_opener_path(&window_context, &CallOnPath::Widget(button_path.clone())) {
+            Some((tmp_menu_idx, opener_path)) => {
+                assert_eq!(menu_idx, tmp_menu_idx);
+                assert_eq!(Some(button_path.clone()), opener_path);
+            },
+            None => assert!(false),
+        }
+        layout_path.push(WidgetIndexPair(0, 0));
+        assert_eq!(true, context_menu_index_and_opener_path(&window_context, &CallOnPath::Widget(layout_path)).is_none());
+    }
+}
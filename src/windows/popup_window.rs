@@ -0,0 +1,481 @@
+//
+// Copyright (c) 2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::any::Any;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use crate::as_any::*;
+use crate::call_on::*;
+use crate::client_context::*;
+use crate::container::*;
+use crate::draw::*;
+use crate::events::*;
+use crate::min_size::*;
+use crate::preferred_size::*;
+use crate::queue_context::*;
+use crate::theme::*;
+use crate::types::*;
+use crate::utils::*;
+use crate::widget::*;
+use crate::window::*;
+use crate::windows::child_index_set::*;
+use crate::windows::two_window_widgets::*;
+
+/// A structure of popup window.
+///
+/// The popup window is shown if the popup window has a parent window. The popup window is placed
+/// relative to an anchor rectangle in the parent window, for example bounds of a widget. The
+/// popup window is closed by unsetting the parent window when a compositor sends popup done or
+/// when an user clicks outside the popup window.
+pub struct PopupWindow
+{
+    size: Size<i32>,
+    padding_bounds: Rect<i32>,
+    edges: Edges<i32>,
+    is_visible: bool,
+    is_focused: bool,
+    change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    min_size: Size<Option<i32>>,
+    preferred_size: Size<Option<i32>>,
+    parent_index: Option<WindowIndex>,
+    pos_in_parent: Option<Pos<i32>>,
+    anchor_rect: Option<Rect<i32>>,
+    anchor: ClientPopupAnchor,
+    gravity: ClientPopupGravity,
+    constraint_adjustment: ClientConstraintAdjustment,
+    has_grab: bool,
+    is_closed_by_outside_click: bool,
+    child_index_set: ChildIndexSet,
+    call_on_fun: CallOnFun,
+    widgets: TwoWindowWidgets,
+    focused_rel_widget_path: Option<RelWidgetPath>,
+}
+
+impl PopupWindow
+{
+    pub fn new() -> Self
+    {
+        PopupWindow {
+            size: Size::new(0, 0),
+            padding_bounds: Rect::new(0, 0, 0, 0),
+            edges: Edges::new(0, 0, 0, 0),
+            is_visible: true,
+            is_focused: false,
+            change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            min_size: Size::new(None, None),
+            preferred_size: Size::new(None, None),
+            parent_index: None,
+            pos_in_parent: None,
+            anchor_rect: None,
+            anchor: ClientPopupAnchor::BottomLeft,
+            gravity: ClientPopupGravity::BottomRight,
+            constraint_adjustment: ClientConstraintAdjustment::SLIDE_X | ClientConstraintAdjustment::FLIP_Y,
+            has_grab: true,
+            is_closed_by_outside_click: true,
+            child_index_set: ChildIndexSet::new(),
+            call_on_fun: CallOnFun::new(),
+            widgets: TwoWindowWidgets::new(),
+            focused_rel_widget_path: None,
+        }
+    }
+
+    /// Sets the anchor rectangle in the parent window.
+    ///
+    /// The anchor rectangle should be set before the parent window is set, because the popup
+    /// window is placed when the popup window is shown.
+    pub fn set_anchor_rect(&mut self, rect: Option<Rect<i32>>)
+    {
+        let old_anchor_rect = self.anchor_rect;
+        self.anchor_rect = rect;
+        if old_anchor_rect != self.anchor_rect {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_anchor(&mut self, anchor: ClientPopupAnchor)
+    {
+        let old_anchor = self.anchor;
+        self.anchor = anchor;
+        if old_anchor != self.anchor {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_gravity(&mut self, gravity: ClientPopupGravity)
+    {
+        let old_gravity = self.gravity;
+        self.gravity = gravity;
+        if old_gravity != self.gravity {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_constraint_adjustment(&mut self, adjustment: ClientConstraintAdjustment)
+    {
+        let old_constraint_adjustment = self.constraint_adjustment;
+        self.constraint_adjustment = adjustment;
+        if old_constraint_adjustment != self.constraint_adjustment {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_grab(&mut self, has_grab: bool)
+    { self.has_grab = has_grab; }
+
+    pub fn set_closed_by_outside_click(&mut self, is_closed_by_outside_click: bool)
+    { self.is_closed_by_outside_click = is_closed_by_outside_click; }
+
+    pub fn set_visible(&mut self, is_visible: bool)
+    {
+        let old_visible_flag = self.is_visible;
+        self.is_visible = is_visible;
+        if old_visible_flag != self.is_visible {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+        self.reset_descendant_states();
+    }
+
+    pub fn set_dyn_on(&mut self, f: Box<dyn FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static>)
+    { self.call_on_fun.fun = f; }
+
+    pub fn set_on<F>(&mut self, f: F)
+        where F: FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static
+    { self.set_dyn_on(Box::new(f)) }
+
+    pub fn has_trimmed_width(&self) -> bool
+    { self.widgets.has_trimmed_width }
+
+    pub fn set_trimmed_width(&mut self, is_trimmed_width: bool)
+    {
+        let old_trimmed_width_flag = self.widgets.has_trimmed_width;
+        self.widgets.has_trimmed_width = is_trimmed_width;
+        if old_trimmed_width_flag != self.widgets.has_trimmed_width {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn has_trimmed_height(&self) -> bool
+    { self.widgets.has_trimmed_height }
+
+    pub fn set_trimmed_height(&mut self, is_trimmed_height: bool)
+    {
+        let old_trimmed_height_flag = self.widgets.has_trimmed_height;
+        self.widgets.has_trimmed_height = is_trimmed_height;
+        if old_trimmed_height_flag != self.widgets.has_trimmed_height {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_dyn(&mut self, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
+    {
+        widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
+        self.widgets.content = Some(widget);
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+        Some(WidgetIndexPair(1, 0))
+    }
+
+    pub fn set<T: Widget + 'static>(&mut self, widget: T) -> Option<WidgetIndexPair>
+    { self.set_dyn(Box::new(widget)) }
+
+    pub fn unset(&mut self) -> Option<Box<dyn Widget>>
+    {
+        let content = self.widgets.content.take();
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+        content
+    }
+}
+
+impl Window for PopupWindow
+{
+    fn size(&self) -> Size<i32>
+    { self.size }
+
+    fn padding_bounds(&self) -> Rect<i32>
+    { self.padding_bounds }
+
+    fn edges(&self) -> Edges<i32>
+    { self.edges }
+
+    fn corners(&self) -> Corners<i32>
+    { Corners::new(0, 0, 0, 0, 0, 0, 0, 0) }
+
+    fn is_visible(&self) -> bool
+    { self.is_visible }
+
+    fn is_focused(&self) -> bool
+    { self.is_focused }
+
+    fn set_focus(&mut self, is_focused: bool) -> bool
+    {
+        let old_focus_flag = self.is_focused;
+        self.is_focused = is_focused;
+        if old_focus_flag != self.is_focused {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+        true
+    }
+
+    fn is_popup(&self) -> bool
+    { true }
+
+    fn popup_anchor_rect(&self) -> Option<Rect<i32>>
+    { self.anchor_rect }
+
+    fn popup_anchor(&self) -> ClientPopupAnchor
+    { self.anchor }
+
+    fn popup_gravity(&self) -> ClientPopupGravity
+    { self.gravity }
+
+    fn popup_constraint_adjustment(&self) -> ClientConstraintAdjustment
+    { self.constraint_adjustment }
+
+    fn has_popup_grab(&self) -> bool
+    { self.has_grab }
+
+    fn is_closed_by_outside_click(&self) -> bool
+    { self.is_closed_by_outside_click }
+
+    fn parent_index(&self) -> Option<WindowIndex>
+    { self.parent_index }
+
+    fn pos_in_parent(&self) -> Option<Pos<i32>>
+    { self.pos_in_parent }
+
+    fn set_parent(&mut self, idx: ParentWindowIndex, pos: Pos<i32>) -> Option<()>
+    {
+        match (self.parent_index, self.pos_in_parent) {
+            (None, None) => {
+                self.parent_index = Some(idx.window_index());
+                self.pos_in_parent = Some(pos);
+                self.change_flag_arc.store(true, Ordering::SeqCst);
+                Some(())
+            },
+            _ => None,
+        }
+    }
+
+    fn unset_parent(&mut self, _tag: ParentWindowTag) -> Option<()>
+    {
+        match (self.parent_index, self.pos_in_parent) {
+            (None, None) => None,
+            _ => {
+                self.parent_index = None;
+                self.pos_in_parent = None;
+                self.reset_descendant_states();
+                self.change_flag_arc.store(true, Ordering::SeqCst);
+                Some(())
+            },
+        }
+    }
+
+    fn is_changed(&self) -> bool
+    { self.change_flag_arc.load(Ordering::SeqCst) }
+
+    fn clear_change_flag(&mut self)
+    { self.change_flag_arc.store(false, Ordering::SeqCst); }
+
+    fn is_dirty(&self) -> bool
+    { self.dirty_flag_arc.load(Ordering::SeqCst) }
+
+    fn clear_dirty_flag(&mut self)
+    { self.dirty_flag_arc.store(false, Ordering::SeqCst); }
+
+    fn content_index_pair(&self) -> Option<WidgetIndexPair>
+    {
+        if self.widgets.content.is_some() {
+            Some(WidgetIndexPair(1, 0))
+        } else {
+            None
+        }
+    }
+
+    fn child_index_iter(&self) -> Option<Box<dyn WindowIterator + '_>>
+    { self.child_index_set.child_index_iter() }
+
+    fn add_child(&mut self, idx: ChildWindowIndex) -> Option<()>
+    { self.child_index_set.add(idx) }
+
+    fn remove_child(&mut self, idx: ChildWindowIndex) -> Option<()>
+    { self.child_index_set.remove(idx) }
+
+    fn focused_rel_widget_path(&self) -> Option<&RelWidgetPath>
+    {
+        match &self.focused_rel_widget_path {
+            Some(rel_widget_path) => Some(rel_widget_path),
+            None => None,
+        }
+    }
+
+    fn set_only_focused_rel_widget_path(&mut self, rel_widget_path: Option<RelWidgetPath>) -> bool
+    {
+        self.focused_rel_widget_path = rel_widget_path;
+        true
+    }
+}
+
+impl Container for PopupWindow
+{
+    fn prev(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
+    { self.widgets.prev(idx_pair) }
+
+    fn next(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
+    { self.widgets.next(idx_pair) }
+
+    fn dyn_widget_for_index_pair(&self, idx_pair: WidgetIndexPair) -> Option<&dyn Widget>
+    { self.widgets.dyn_widget(idx_pair) }
+
+    fn dyn_widget_mut_for_index_pair(&mut self, idx_pair: WidgetIndexPair) -> Option<&mut dyn Widget>
+    { self.widgets.dyn_widget_mut(idx_pair) }
+
+    fn point_for_index_pair(&self, pos: Pos<f64>) -> Option<WidgetIndexPair>
+    { self.widgets.point(pos) }
+}
+
+impl MinSize for PopupWindow
+{
+    fn min_size(&self) -> Size<Option<i32>>
+    { self.min_size }
+
+    fn set_min_size(&mut self, size: Size<Option<i32>>)
+    {
+        let old_min_size = self.min_size;
+        self.min_size = size;
+        if old_min_size != self.min_size {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl PreferredSize for PopupWindow
+{
+    fn preferred_size(&self) -> Size<Option<i32>>
+    { self.preferred_size }
+
+    fn set_preferred_size(&mut self, size: Size<Option<i32>>)
+    {
+        let old_preferred_size = self.preferred_size;
+        self.preferred_size = size;
+        if old_preferred_size != self.preferred_size {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Draw for PopupWindow
+{
+    fn update_size(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_size: Size<Option<i32>>) -> Result<(), CairoError>
+    {
+        self.edges = theme.popup_window_edges();
+        let padding_area_size = inner_opt_size(area_size, self.edges);
+        self.widgets.update_size(cairo_context, theme, padding_area_size)?;
+        self.padding_bounds.set_size(self.widgets.padding_size(padding_area_size));
+        self.size = outer_size(self.padding_bounds.size(), self.edges);
+        Ok(())
+    }
+
+    fn update_pos(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_bounds: Rect<i32>) -> Result<(), CairoError>
+    {
+        self.padding_bounds.set_pos(inner_pos(area_bounds, self.edges));
+        self.widgets.update_pos(cairo_context, theme, inner_rect(area_bounds, self.edges))?;
+        Ok(())
+    }
+
+    fn draw(&self, cairo_context: &CairoContext, theme: &dyn Theme, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        theme.draw_popup_window_bg(cairo_context, Rect::new(0, 0, self.size.width, self.size.height), is_focused_window)?;
+        self.widgets.draw(cairo_context, theme, is_focused_window)?;
+        Ok(())
+    }
+}
+
+impl CallOn for PopupWindow
+{
+    fn call_on(&mut self, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Event>>
+    {
+        let default_event = if let Some(tmp_default_event) = default_window_on(self, client_context, queue_context, event)? {
+            tmp_default_event
+        } else {
+            None
+        };
+        self.call_on_fun.call_on(client_context, queue_context, event, default_event)
+    }
+}
+
+impl AsAny for PopupWindow
+{
+    fn as_any(&self) -> &dyn Any
+    { self }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    { self }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::mocks::*;
+    use crate::widgets::*;
+    use crate::window_container::*;
+
+    #[test]
+    fn test_popup_window_update_size_and_position()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_popup_window_edges(Edges::new(2, 2, 2, 2));
+        theme.set_button_margin_edges(Edges::new(2, 2, 2, 2));
+        theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
+        theme.set_button_font_size(16.0);
+        let mut window = PopupWindow::new();
+        let mut button = Button::new("B");
+        button.set_preferred_size(Size::new(Some(120), Some(60)));
+        window.set(button);
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match window.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Edges::new(2, 2, 2, 2), window.edges);
+        assert_eq!(Size::new(124, 64), window.padding_bounds.size());
+        assert_eq!(Size::new(128, 68), window.size);
+        let area_bounds = Rect::new(0, 0, window.size.width, window.size.height);
+        match window.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Pos::new(2, 2), window.padding_bounds.pos());
+        assert_eq!(Pos::new(2, 2), window.widgets.content.as_ref().unwrap().margin_pos());
+        assert_eq!(Pos::new(4, 4), window.widgets.content.as_ref().unwrap().pos());
+    }
+
+    #[test]
+    fn test_popup_window_is_closed_by_outside_click()
+    {
+        let mut window_container = WindowContainer::new();
+        let mut popup = PopupWindow::new();
+        popup.set_anchor_rect(Some(Rect::new(10, 20, 30, 40)));
+        window_container.add(popup);
+        window_container.add(MockParentWindow::new("parent"));
+        window_container.set_parent(WindowIndex(0), WindowIndex(1), Pos::new(10, 20));
+        let popup = window_container.dyn_window(WindowIndex(0)).unwrap();
+        assert_eq!(Some(WindowIndex(1)), popup.parent_index());
+        assert_eq!(Some(Rect::new(10, 20, 30, 40)), popup.popup_anchor_rect());
+        window_container.unset_parents_for_outside_popups(WindowIndex(0));
+        assert_eq!(Some(WindowIndex(1)), window_container.dyn_window(WindowIndex(0)).unwrap().parent_index());
+        window_container.unset_parents_for_outside_popups(WindowIndex(1));
+        assert_eq!(None, window_container.dyn_window(WindowIndex(0)).unwrap().parent_index());
+    }
+}
@@ -0,0 +1,532 @@
+//
+// Copyright (c) 2023 Łukasz Szpakowski
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+//
+use std::any::Any;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use crate::as_any::*;
+use crate::call_on::*;
+use crate::client_context::*;
+use crate::container::*;
+use crate::draw::*;
+use crate::event_queue::*;
+use crate::events::*;
+use crate::min_size::*;
+use crate::preferred_size::*;
+use crate::queue_context::*;
+use crate::text::*;
+use crate::theme::*;
+use crate::types::*;
+use crate::utils::*;
+use crate::widget::*;
+use crate::window::*;
+use crate::window_context::*;
+use crate::windows::two_window_widgets::*;
+
+/// A structure of tooltip.
+///
+/// The tooltip is a popup window without a grab that shows a text or a custom widget. The toolkit
+/// creates the tooltip for a widget that has a tooltip text (see [`Widget::tooltip_text`]) and
+/// shows the tooltip with this text. The tooltip is shown near a pointer after a hover delay when
+/// the pointer is over the widget, or below the widget after the same delay when the widget is
+/// focused by a key. The tooltip is hidden when the pointer leaves the widget, or when an user
+/// clicks or presses a key.
+pub struct Tooltip
+{
+    size: Size<i32>,
+    padding_bounds: Rect<i32>,
+    edges: Edges<i32>,
+    is_visible: bool,
+    change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
+    min_size: Size<Option<i32>>,
+    preferred_size: Size<Option<i32>>,
+    parent_index: Option<WindowIndex>,
+    pos_in_parent: Option<Pos<i32>>,
+    anchor_rect: Option<Rect<i32>>,
+    anchor: ClientPopupAnchor,
+    call_on_fun: CallOnFun,
+    widgets: TwoWindowWidgets,
+    text: Text,
+}
+
+impl Tooltip
+{
+    pub fn new(s: &str) -> Self
+    {
+        Tooltip {
+            size: Size::new(0, 0),
+            padding_bounds: Rect::new(0, 0, 0, 0),
+            edges: Edges::new(0, 0, 0, 0),
+            is_visible: true,
+            change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
+            min_size: Size::new(None, None),
+            preferred_size: Size::new(None, None),
+            parent_index: None,
+            pos_in_parent: None,
+            anchor_rect: None,
+            anchor: ClientPopupAnchor::BottomLeft,
+            call_on_fun: CallOnFun::new(),
+            widgets: TwoWindowWidgets::new(),
+            text: Text::new(s, TextAlign::Left),
+        }
+    }
+
+    pub fn text(&self) -> &str
+    { self.text.text.as_str() }
+
+    pub fn set_text(&mut self, s: &str)
+    {
+        self.text.text = String::from(s);
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+    }
+
+    /// Sets the anchor rectangle in the parent window.
+    ///
+    /// The anchor rectangle is set by the [`show_tooltip`] function, so this method is used if the
+    /// tooltip is shown by an application in other way.
+    pub fn set_anchor_rect(&mut self, rect: Option<Rect<i32>>)
+    {
+        let old_anchor_rect = self.anchor_rect;
+        self.anchor_rect = rect;
+        if old_anchor_rect != self.anchor_rect {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_anchor(&mut self, anchor: ClientPopupAnchor)
+    {
+        let old_anchor = self.anchor;
+        self.anchor = anchor;
+        if old_anchor != self.anchor {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub fn set_visible(&mut self, is_visible: bool)
+    {
+        let old_visible_flag = self.is_visible;
+        self.is_visible = is_visible;
+        if old_visible_flag != self.is_visible {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+        self.reset_descendant_states();
+    }
+
+    pub fn set_dyn_on(&mut self, f: Box<dyn FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static>)
+    { self.call_on_fun.fun = f; }
+
+    pub fn set_on<F>(&mut self, f: F)
+        where F: FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static
+    { self.set_dyn_on(Box::new(f)) }
+
+    /// Sets the custom widget that is shown instead of the text.
+    pub fn set_dyn(&mut self, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
+    {
+        widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
+        self.widgets.content = Some(widget);
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+        Some(WidgetIndexPair(1, 0))
+    }
+
+    /// See [`set_dyn`](Self::set_dyn).
+    pub fn set<T: Widget + 'static>(&mut self, widget: T) -> Option<WidgetIndexPair>
+    { self.set_dyn(Box::new(widget)) }
+
+    pub fn unset(&mut self) -> Option<Box<dyn Widget>>
+    {
+        let content = self.widgets.content.take();
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+        content
+    }
+}
+
+impl Window for Tooltip
+{
+    fn size(&self) -> Size<i32>
+    { self.size }
+
+    fn padding_bounds(&self) -> Rect<i32>
+    { self.padding_bounds }
+
+    fn edges(&self) -> Edges<i32>
+    { self.edges }
+
+    fn corners(&self) -> Corners<i32>
+    { Corners::new(0, 0, 0, 0, 0, 0, 0, 0) }
+
+    fn is_visible(&self) -> bool
+    { self.is_visible }
+
+    fn is_focusable(&self) -> bool
+    { false }
+
+    fn is_focused(&self) -> bool
+    { false }
+
+    fn set_focus(&mut self, _is_focused: bool) -> bool
+    { false }
+
+    fn is_popup(&self) -> bool
+    { true }
+
+    fn popup_anchor_rect(&self) -> Option<Rect<i32>>
+    { self.anchor_rect }
+
+    fn popup_anchor(&self) -> ClientPopupAnchor
+    { self.anchor }
+
+    fn popup_constraint_adjustment(&self) -> ClientConstraintAdjustment
+    { ClientConstraintAdjustment::SLIDE_X | ClientConstraintAdjustment::FLIP_Y }
+
+    fn has_popup_grab(&self) -> bool
+    { false }
+
+    fn parent_index(&self) -> Option<WindowIndex>
+    { self.parent_index }
+
+    fn pos_in_parent(&self) -> Option<Pos<i32>>
+    { self.pos_in_parent }
+
+    fn set_parent(&mut self, idx: ParentWindowIndex, pos: Pos<i32>) -> Option<()>
+    {
+        match (self.parent_index, self.pos_in_parent) {
+            (None, None) => {
+                self.parent_index = Some(idx.window_index());
+                self.pos_in_parent = Some(pos);
+                self.change_flag_arc.store(true, Ordering::SeqCst);
+                Some(())
+            },
+            _ => None,
+        }
+    }
+
+    fn unset_parent(&mut self, _tag: ParentWindowTag) -> Option<()>
+    {
+        match (self.parent_index, self.pos_in_parent) {
+            (None, None) => None,
+            _ => {
+                self.parent_index = None;
+                self.pos_in_parent = None;
+                self.reset_descendant_states();
+                self.change_flag_arc.store(true, Ordering::SeqCst);
+                Some(())
+            },
+        }
+    }
+
+    fn is_changed(&self) -> bool
+    { self.change_flag_arc.load(Ordering::SeqCst) }
+
+    fn clear_change_flag(&mut self)
+    { self.change_flag_arc.store(false, Ordering::SeqCst); }
+
+    fn is_dirty(&self) -> bool
+    { self.dirty_flag_arc.load(Ordering::SeqCst) }
+
+    fn clear_dirty_flag(&mut self)
+    { self.dirty_flag_arc.store(false, Ordering::SeqCst); }
+
+    fn content_index_pair(&self) -> Option<WidgetIndexPair>
+    {
+        if self.widgets.content.is_some() {
+            Some(WidgetIndexPair(1, 0))
+        } else {
+            None
+        }
+    }
+}
+
+impl Container for Tooltip
+{
+    fn prev(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
+    { self.widgets.prev(idx_pair) }
+
+    fn next(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
+    { self.widgets.next(idx_pair) }
+
+    fn dyn_widget_for_index_pair(&self, idx_pair: WidgetIndexPair) -> Option<&dyn Widget>
+    { self.widgets.dyn_widget(idx_pair) }
+
+    fn dyn_widget_mut_for_index_pair(&mut self, idx_pair: WidgetIndexPair) -> Option<&mut dyn Widget>
+    { self.widgets.dyn_widget_mut(idx_pair) }
+
+    fn point_for_index_pair(&self, pos: Pos<f64>) -> Option<WidgetIndexPair>
+    { self.widgets.point(pos) }
+}
+
+impl MinSize for Tooltip
+{
+    fn min_size(&self) -> Size<Option<i32>>
+    { self.min_size }
+
+    fn set_min_size(&mut self, size: Size<Option<i32>>)
+    {
+        let old_min_size = self.min_size;
+        self.min_size = size;
+        if old_min_size != self.min_size {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl PreferredSize for Tooltip
+{
+    fn preferred_size(&self) -> Size<Option<i32>>
+    { self.preferred_size }
+
+    fn set_preferred_size(&mut self, size: Size<Option<i32>>)
+    {
+        let old_preferred_size = self.preferred_size;
+        self.preferred_size = size;
+        if old_preferred_size != self.preferred_size {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Draw for Tooltip
+{
+    fn update_size(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_size: Size<Option<i32>>) -> Result<(), CairoError>
+    {
+        self.edges = theme.tooltip_edges();
+        let padding_area_size = inner_opt_size(area_size, self.edges);
+        if self.widgets.content.is_some() {
+            self.widgets.update_size(cairo_context, theme, padding_area_size)?;
+            self.padding_bounds.set_size(self.widgets.padding_size(padding_area_size));
+        } else {
+            self.text.update_size(cairo_context, padding_area_size, |cairo_context| {
+                    theme.set_tooltip_font(cairo_context)
+            })?;
+            self.padding_bounds.set_size(Size::new(self.text.max_line_width(), self.text.line_height * self.text.lines.len() as i32));
+        }
+        self.size = outer_size(self.padding_bounds.size(), self.edges);
+        Ok(())
+    }
+
+    fn update_pos(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_bounds: Rect<i32>) -> Result<(), CairoError>
+    {
+        self.padding_bounds.set_pos(inner_pos(area_bounds, self.edges));
+        self.widgets.update_pos(cairo_context, theme, inner_rect(area_bounds, self.edges))?;
+        Ok(())
+    }
+
+    fn draw(&self, cairo_context: &CairoContext, theme: &dyn Theme, is_focused_window: bool) -> Result<(), CairoError>
+    {
+        theme.draw_tooltip_bg(cairo_context, Rect::new(0, 0, self.size.width, self.size.height), is_focused_window)?;
+        if self.widgets.content.is_some() {
+            self.widgets.draw(cairo_context, theme, is_focused_window)?;
+        } else {
+            cairo_context.save()?;
+            cairo_context.rectangle(self.padding_bounds.x as f64, self.padding_bounds.y as f64, self.padding_bounds.width as f64, self.padding_bounds.height as f64);
+            cairo_context.clip();
+            self.text.draw(cairo_context, self.padding_bounds, |cairo_context| {
+                    theme.set_tooltip_font(cairo_context)
+            }, |cairo_context, pos, s| {
+                    theme.draw_tooltip_text(cairo_context, pos, s, is_focused_window)
+            })?;
+            cairo_context.restore()?;
+        }
+        Ok(())
+    }
+}
+
+impl CallOn for Tooltip
+{
+    fn call_on(&mut self, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Event>>
+    {
+        let default_event = if let Some(tmp_default_event) = default_window_on(self, client_context, queue_context, event)? {
+            tmp_default_event
+        } else {
+            None
+        };
+        self.call_on_fun.call_on(client_context, queue_context, event, default_event)
+    }
+}
+
+impl AsAny for Tooltip
+{
+    fn as_any(&self) -> &dyn Any
+    { self }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    { self }
+}
+
+/// Returns the tooltip text and the widget path that has the tooltip text for the call-on path or
+/// `None`.
+///
+/// The tooltip text is searched for the widget and its ancestors.
+pub fn tooltip_text_and_path(window_context: &WindowContext, call_on_path: &CallOnPath) -> Option<(String, AbsWidgetPath)>
+{
+    match call_on_path {
+        CallOnPath::Widget(abs_widget_path) => {
+            let mut tmp_abs_widget_path = abs_widget_path.clone();
+            loop {
+                match window_context.dyn_widget(&tmp_abs_widget_path) {
+                    Some(widget) => {
+                        match widget.tooltip_text() {
+                            Some(tooltip_text) => return Some((String::from(tooltip_text), tmp_abs_widget_path)),
+                            None => (),
+                        }
+                    },
+                    None => (),
+                }
+                if tmp_abs_widget_path.pop().is_none() {
+                    break;
+                }
+            }
+            None
+        },
+        CallOnPath::Window(_) => None,
+    }
+}
+
+/// Shows the tooltip as a child of the parent window.
+///
+/// The tooltip is placed relative to the anchor rectangle in the parent window. This function
+/// returns `Some(())` if the tooltip is shown, otherwise `None`.
+pub fn show_tooltip(window_context: &mut WindowContext, tooltip_idx: WindowIndex, parent_idx: WindowIndex, anchor_rect: Rect<i32>, anchor: ClientPopupAnchor) -> Option<()>
+{
+    let tooltip: &Tooltip = window_context.window(tooltip_idx)?;
+    if tooltip.parent_index().is_some() {
+        window_context.unset_parent_window(tooltip_idx)?;
+    }
+    let tooltip: &mut Tooltip = window_context.window_mut(tooltip_idx)?;
+    tooltip.set_anchor_rect(Some(anchor_rect));
+    tooltip.set_anchor(anchor);
+    window_context.set_parent_window(tooltip_idx, parent_idx, anchor_rect.pos())
+}
+
+/// Hides the tooltip.
+///
+/// This function returns `Some(())` if the tooltip is hidden, otherwise `None`.
+pub fn hide_tooltip(window_context: &mut WindowContext, tooltip_idx: WindowIndex) -> Option<()>
+{
+    window_context.window::<Tooltip>(tooltip_idx)?;
+    window_context.unset_parent_window(tooltip_idx)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::mocks::*;
+    use crate::widgets::*;
+    use crate::windows::*;
+
+    #[test]
+    fn test_tooltip_updates_size_and_position_for_text()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_tooltip_edges(Edges::new(2, 2, 4, 4));
+        theme.set_tooltip_font_size(16.0);
+        let mut window = Tooltip::new("Close");
+        theme.set_tooltip_font(&cairo_context).unwrap();
+        let text_width = cairo_context.text_extents("Close").unwrap().x_advance;
+        let font_height = cairo_context.font_extents().unwrap().height;
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match window.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        let expected_padding_width = text_width.ceil() as i32;
+        let expected_padding_height = font_height.ceil() as i32;
+        assert_eq!(Edges::new(2, 2, 4, 4), window.edges);
+        assert_eq!(Size::new(expected_padding_width, expected_padding_height), window.padding_bounds.size());
+        assert_eq!(Size::new(expected_padding_width + 8, expected_padding_height + 4), window.size);
+        let area_bounds = Rect::new(0, 0, window.size.width, window.size.height);
+        match window.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Pos::new(4, 2), window.padding_bounds.pos());
+    }
+
+    #[test]
+    fn test_tooltip_updates_size_and_position_for_widget()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_tooltip_edges(Edges::new(2, 2, 4, 4));
+        theme.set_tooltip_font_size(16.0);
+        theme.set_label_margin_edges(Edges::new(1, 1, 1, 1));
+        theme.set_label_font_size(16.0);
+        let mut window = Tooltip::new("Close");
+        let mut label = Label::new("L");
+        label.set_preferred_size(Size::new(Some(40), Some(20)));
+        window.set(label);
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match window.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Size::new(42, 22), window.padding_bounds.size());
+        assert_eq!(Size::new(50, 26), window.size);
+        let area_bounds = Rect::new(0, 0, window.size.width, window.size.height);
+        match window.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Pos::new(4, 2), window.padding_bounds.pos());
+        assert_eq!(Pos::new(4, 2), window.widgets.content.as_ref().unwrap().margin_pos());
+        assert_eq!(Pos::new(5, 3), window.widgets.content.as_ref().unwrap().pos());
+    }
+
+    #[test]
+    fn test_tooltip_text_is_moved_with_widget_in_layout()
+    {
+        let mut window_context = WindowContext::new(Box::new(MockTheme::new()));
+        let mut window = ToplevelWindow::new().unwrap();
+        let mut layout = LinearLayout::new();
+        layout.set_tooltip_text(Some("Layout"));
+        let mut button = Button::new("B");
+        button.set_tooltip_text(Some("Button"));
+        layout.add(button);
+        window.set(layout);
+        let window_idx = window_context.window_container_mut().add(window).unwrap();
+        let layout_path = AbsWidgetPath::new(window_idx, WidgetIndexPair(1, 0));
+        let mut button_path = layout_path.clone();
+        button_path.push(WidgetIndexPair(0, 0));
+        match tooltip_text_and_path(&window_context, &CallOnPath::Widget(button_path.clone())) {
+            Some((tooltip_text, tooltip_path)) => {
+                assert_eq!(String::from("Button"), tooltip_text);
+                assert_eq!(button_path, tooltip_path);
+            },
+            None => assert!(false),
+        }
+        let layout: &mut LinearLayout = window_context.widget_mut(&layout_path).unwrap();
+        layout.insert(WidgetIndexPair(0, 0), Label::new("L"));
+        match tooltip_text_and_path(&window_context, &CallOnPath::Widget(button_path.clone())) {
+            Some((tooltip_text, tooltip_path)) => {
+                assert_eq!(String::from("Layout"), tooltip_text);
+                assert_eq!(layout_path, tooltip_path);
+            },
+            None => assert!(false),
+        }
+        button_path = layout_path.clone();
+        button_path.push(WidgetIndexPair(1, 0));
+        match tooltip_text_and_path(&window_context, &CallOnPath::Widget(button_path.clone())) {
+            Some((tooltip_text, tooltip_path)) => {
+                assert_eq!(String::from("Button"), tooltip_text);
+                assert_eq!(button_path, tooltip_path);
+            },
+            None => assert!(false),
+        }
+        assert_eq!(true, tooltip_text_and_path(&window_context, &CallOnPath::Window(window_idx)).is_none());
+    }
+}
@@ -14,8 +14,10 @@ use crate::call_on::*;
 use crate::client_context::*;
 use crate::container::*;
 use crate::draw::*;
+use crate::event_queue::*;
 use crate::events::*;
 use crate::image::*;
+use crate::keys::*;
 use crate::min_size::*;
 use crate::preferred_size::*;
 use crate::queue_context::*;
@@ -26,6 +28,7 @@ use crate::widget::*;
 use crate::widgets::*;
 use crate::window::*;
 use crate::windows::child_index_set::*;
+use crate::windows::menu::*;
 use crate::windows::two_window_widgets::*;
 
 pub struct ToplevelWindow
@@ -38,8 +41,15 @@ pub struct ToplevelWindow
     is_visible: bool,
     is_focused: bool,
     is_maximized: bool,
+    is_minimized: bool,
+    is_fullscreen: bool,
+    fullscreen_output_name: Option<u32>,
+    toplevel_states: ClientToplevelStates,
     is_resizable: bool,
+    decoration_preference: ClientDecorationPreference,
+    decoration_mode: ClientDecorationMode,
     change_flag_arc: Arc<AtomicBool>,
+    dirty_flag_arc: Arc<AtomicBool>,
     is_moved: bool,
     resize_edges: Option<ClientResize>,
     min_size: Size<Option<i32>>,
@@ -50,8 +60,10 @@ pub struct ToplevelWindow
     focused_rel_widget_path: Option<RelWidgetPath>,
     menu_button_path: Option<RelWidgetPath>,
     title_path: Option<RelWidgetPath>,
+    minimize_button_path: Option<RelWidgetPath>,
     maximize_button_path: Option<RelWidgetPath>,
     close_button_path: Option<RelWidgetPath>,
+    context_menu_index: Option<WindowIndex>,
 }
 
 impl ToplevelWindow
@@ -67,8 +79,15 @@ impl ToplevelWindow
             is_visible: true,
             is_focused: false,
             is_maximized: false,
+            is_minimized: false,
+            is_fullscreen: false,
+            fullscreen_output_name: None,
+            toplevel_states: ClientToplevelStates::EMPTY,
             is_resizable: true,
+            decoration_preference: ClientDecorationPreference::Server,
+            decoration_mode: ClientDecorationMode::Client,
             change_flag_arc: Arc::new(AtomicBool::new(false)),
+            dirty_flag_arc: Arc::new(AtomicBool::new(false)),
             is_moved: false,
             resize_edges: None,
             min_size: Size::new(None, None),
@@ -79,8 +98,10 @@ impl ToplevelWindow
             focused_rel_widget_path: None,
             menu_button_path: None,
             title_path: None,
+            minimize_button_path: None,
             maximize_button_path: None,
             close_button_path: None,
+            context_menu_index: None,
         };
         let title_bar = TitleBar::new();
         let title_bar_path = container_rel_widget_path1(&mut window, |window: &mut ToplevelWindow| window.set_title_bar(title_bar))?;
@@ -89,6 +110,8 @@ impl ToplevelWindow
         let menu_button_path = container_rel_widget_path(&mut window, &title_bar_path, |title_bar: &mut TitleBar| title_bar.add(menu_button))?;
         let title = Title::new("");
         let title_path = container_rel_widget_path(&mut window, &title_bar_path, |title_bar: &mut TitleBar| title_bar.add(title))?;
+        let minimize_button = TitleButton::new(TitleButtonIcon::Minimize);
+        let minimize_button_path = container_rel_widget_path(&mut window, &title_bar_path, |title_bar: &mut TitleBar| title_bar.add(minimize_button))?;
         let maximize_button = TitleButton::new(TitleButtonIcon::Maximize);
         let maximize_button_path = container_rel_widget_path(&mut window, &title_bar_path, |title_bar: &mut TitleBar| title_bar.add(maximize_button))?;
         let close_button = TitleButton::new(TitleButtonIcon::Close);
@@ -108,6 +131,13 @@ impl ToplevelWindow
                 }
                 Some(EventOption::Default)
         });
+        let minimize_button: &mut TitleButton = container_widget_mut(&mut window, &minimize_button_path)?;
+        minimize_button.set_on(move |_, _, event| {
+                match event {
+                    Event::Click | Event::DoubleClick | Event::LongClick => Some(EventOption::Some(Event::Minimize)),
+                    _ => Some(EventOption::Default),
+                }
+        });
         let maximize_button: &mut TitleButton = container_widget_mut(&mut window, &maximize_button_path)?;
         maximize_button.set_on(move |_, _, event| {
                 match event {
@@ -124,8 +154,9 @@ impl ToplevelWindow
         });
         window.set_menu_button_path(Some(menu_button_path));
         window.set_title_path(Some(title_path));
+        window.set_minimize_button_path(Some(minimize_button_path));
         window.set_maximize_button_path(Some(maximize_button_path));
-        window.set_maximize_button_path(Some(close_button_path));
+        window.set_close_button_path(Some(close_button_path));
         Some(window)
     }
     
@@ -182,6 +213,15 @@ impl ToplevelWindow
         }
     }
 
+    pub fn set_decoration_preference(&mut self, preference: ClientDecorationPreference)
+    {
+        let old_decoration_preference = self.decoration_preference;
+        self.decoration_preference = preference;
+        if old_decoration_preference != self.decoration_preference {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+    }
+
     pub fn set_dyn_on(&mut self, f: Box<dyn FnMut(&mut ClientContext, &mut QueueContext, &Event) -> Option<EventOption> + Send + Sync + 'static>)
     { self.call_on_fun.fun = f; }
 
@@ -216,6 +256,7 @@ impl ToplevelWindow
     pub fn set_dyn_title_bar(&mut self, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
     {
         widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
         self.widgets.title_bar = Some(widget);
         self.change_flag_arc.store(true, Ordering::SeqCst);
         Some(WidgetIndexPair(0, 0))
@@ -230,10 +271,35 @@ impl ToplevelWindow
         self.change_flag_arc.store(true, Ordering::SeqCst);
         title_bar
     }    
+
+    /// Sets the menu bar that is placed between the title bar and the content.
+    ///
+    /// If the menu bar is a [`MenuBar`], the menus of the menu bar items can be opened by the
+    /// mnemonic characters with the alt key or the F10 key, and the menu items can be clicked by
+    /// their shortcuts.
+    pub fn set_dyn_menu_bar(&mut self, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
+    {
+        widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
+        self.widgets.menu_bar = Some(widget);
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+        Some(WidgetIndexPair(2, 0))
+    }
+
+    pub fn set_menu_bar<T: Widget + 'static>(&mut self, widget: T) -> Option<WidgetIndexPair>
+    { self.set_dyn_menu_bar(Box::new(widget)) }
+
+    pub fn unset_menu_bar(&mut self) -> Option<Box<dyn Widget>>
+    {
+        let menu_bar = self.widgets.menu_bar.take();
+        self.change_flag_arc.store(true, Ordering::SeqCst);
+        menu_bar
+    }
     
     pub fn set_dyn(&mut self, mut widget: Box<dyn Widget>) -> Option<WidgetIndexPair>
     {
         widget.set_change_flag_arc(self.change_flag_arc.clone());
+        widget.set_dirty_flag_arc(self.dirty_flag_arc.clone());
         self.widgets.content = Some(widget);
         self.change_flag_arc.store(true, Ordering::SeqCst);
         Some(WidgetIndexPair(1, 0))
@@ -271,6 +337,17 @@ impl ToplevelWindow
     pub fn set_title_path(&mut self, path: Option<RelWidgetPath>)
     { self.title_path = path; }
 
+    pub fn minimize_button_path(&self) -> Option<&RelWidgetPath>
+    {
+        match &self.minimize_button_path {
+            Some(path) => Some(path),
+            None => None,
+        }
+    }
+    
+    pub fn set_minimize_button_path(&mut self, path: Option<RelWidgetPath>)
+    { self.minimize_button_path = path; }
+
     pub fn maximize_button_path(&self) -> Option<&RelWidgetPath>
     {
         match &self.maximize_button_path {
@@ -292,6 +369,76 @@ impl ToplevelWindow
     
     pub fn set_close_button_path(&mut self, path: Option<RelWidgetPath>)
     { self.close_button_path = path; }
+
+    /// Sets the index of the context menu for the content.
+    ///
+    /// The context menu is opened at the pointer or touch position for the popup click, or at the
+    /// focused widget for the menu key or the shift key with the F10 key.
+    pub fn set_context_menu_index(&mut self, idx: Option<WindowIndex>)
+    { self.context_menu_index = idx; }
+
+    fn update_hidden_title_bar(&mut self)
+    { self.widgets.has_hidden_title_bar = self.decoration_mode == ClientDecorationMode::Server || self.is_fullscreen; }
+
+    fn menu_bar(&self) -> Option<&MenuBar>
+    {
+        match &self.widgets.menu_bar {
+            Some(menu_bar) => dyn_widget_as_widget(&**menu_bar),
+            None => None,
+        }
+    }
+
+    fn push_menu_bar_item_menu_opening(queue_context: &mut QueueContext, idx_pair: WidgetIndexPair) -> Option<()>
+    {
+        let current_window_idx = queue_context.current_call_on_path()?.window_index();
+        let mut path = AbsWidgetPath::new(current_window_idx, WidgetIndexPair(2, 0));
+        path.push(idx_pair);
+        queue_context.push_callback(move |_, window_context, _| {
+                open_menu_bar_item_menu(window_context, &path, true)
+        });
+        Some(())
+    }
+
+    fn default_toplevel_window_on(&mut self, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Option<Event>>>
+    {
+        match event {
+            // A key with the alt key doesn't have characters, so the mnemonic is the unmodified
+            // character of the key.
+            Event::Key(key, modifiers) if *modifiers == KeyModifiers::ALT && key.to_char().is_some() => {
+                match self.menu_bar().and_then(|mb| key.to_char().and_then(|c| mb.item_index_pair_for_mnemonic(c))) {
+                    Some(idx_pair) => {
+                        Self::push_menu_bar_item_menu_opening(queue_context, idx_pair)?;
+                        Some(Some(None))
+                    },
+                    None => Some(None),
+                }
+            },
+            Event::Key(VKey::F10, modifiers) if *modifiers == KeyModifiers::EMPTY => {
+                match self.menu_bar().and_then(|mb| mb.first_item_index_pair()) {
+                    Some(idx_pair) => {
+                        Self::push_menu_bar_item_menu_opening(queue_context, idx_pair)?;
+                        Some(Some(None))
+                    },
+                    None => Some(None),
+                }
+            },
+            Event::Key(key, modifiers) => {
+                if self.menu_bar().is_some() {
+                    let current_window_idx = queue_context.current_call_on_path()?.window_index();
+                    let menu_bar_path = AbsWidgetPath::new(current_window_idx, WidgetIndexPair(2, 0));
+                    let key = *key;
+                    let modifiers = *modifiers;
+                    queue_context.push_callback(move |_, window_context, queue_context| {
+                            let path = menu_bar_item_path_for_shortcut(window_context, &menu_bar_path, key, modifiers)?;
+                            queue_context.push_event_for_call_on_path(CallOnPath::Widget(path), Event::Click);
+                            Some(())
+                    });
+                }
+                Some(None)
+            },
+            _ => Some(None),
+        }
+    }
 }
 
 impl Window for ToplevelWindow
@@ -347,12 +494,85 @@ impl Window for ToplevelWindow
         }
         true
     }
+
+    fn is_minimizable(&self) -> bool
+    { true }
+
+    fn is_minimized(&self) -> bool
+    { self.is_minimized }
+
+    fn minimize(&mut self) -> bool
+    {
+        self.is_minimized = true;
+        true
+    }
+
+    fn clear_minimize_flag(&mut self) -> bool
+    {
+        self.is_minimized = false;
+        true
+    }
+
+    fn is_fullscreenable(&self) -> bool
+    { true }
+
+    fn is_fullscreen(&self) -> bool
+    { self.is_fullscreen }
+
+    fn fullscreen_output_name(&self) -> Option<u32>
+    { self.fullscreen_output_name }
+
+    fn set_fullscreen(&mut self, is_fullscreen: bool, output_name: Option<u32>) -> bool
+    {
+        let old_fullscreen_flag = self.is_fullscreen;
+        let old_fullscreen_output_name = self.fullscreen_output_name;
+        self.is_fullscreen = is_fullscreen;
+        self.fullscreen_output_name = output_name;
+        self.update_hidden_title_bar();
+        if old_fullscreen_flag != self.is_fullscreen || old_fullscreen_output_name != self.fullscreen_output_name {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+        true
+    }
+
+    fn toplevel_states(&self) -> ClientToplevelStates
+    { self.toplevel_states }
+
+    fn set_toplevel_states(&mut self, states: ClientToplevelStates) -> bool
+    {
+        let old_toplevel_states = self.toplevel_states;
+        self.toplevel_states = states;
+        if old_toplevel_states != self.toplevel_states {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+        true
+    }
     
+    fn decoration_preference(&self) -> ClientDecorationPreference
+    { self.decoration_preference }
+
+    fn decoration_mode(&self) -> ClientDecorationMode
+    { self.decoration_mode }
+    
+    fn set_decoration_mode(&mut self, mode: ClientDecorationMode) -> bool
+    {
+        let old_decoration_mode = self.decoration_mode;
+        self.decoration_mode = mode;
+        self.update_hidden_title_bar();
+        if old_decoration_mode != self.decoration_mode {
+            self.change_flag_arc.store(true, Ordering::SeqCst);
+        }
+        true
+    }
+
     fn is_moveable(&self) -> bool
     { true }
     
     fn is_resizable(&self) -> bool
-    { !self.is_maximized && self.is_resizable }
+    { !self.is_maximized && !self.is_fullscreen && self.is_resizable }
+
+    fn context_menu_index(&self) -> Option<WindowIndex>
+    { self.context_menu_index }
 
     fn is_changed(&self) -> bool
     { self.change_flag_arc.load(Ordering::SeqCst) }
@@ -360,6 +580,12 @@ impl Window for ToplevelWindow
     fn clear_change_flag(&mut self)
     { self.change_flag_arc.store(false, Ordering::SeqCst); }
 
+    fn is_dirty(&self) -> bool
+    { self.dirty_flag_arc.load(Ordering::SeqCst) }
+
+    fn clear_dirty_flag(&mut self)
+    { self.dirty_flag_arc.store(false, Ordering::SeqCst); }
+
     fn is_moved(&self) -> bool
     { self.is_moved }
 
@@ -475,8 +701,16 @@ impl Draw for ToplevelWindow
 {
     fn update_size(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_size: Size<Option<i32>>) -> Result<(), CairoError>
     {
-        self.edges = theme.toplevel_window_edges();
-        self.corners = theme.toplevel_window_corners();
+        match (self.decoration_mode, self.is_fullscreen) {
+            (ClientDecorationMode::Client, false) => {
+                self.edges = theme.toplevel_window_edges();
+                self.corners = theme.toplevel_window_corners();
+            },
+            _ => {
+                self.edges = Edges::new(0, 0, 0, 0);
+                self.corners = Corners::new(0, 0, 0, 0, 0, 0, 0, 0);
+            },
+        }
         let padding_area_size = inner_opt_size(area_size, self.edges);
         self.widgets.update_size(cairo_context, theme, padding_area_size)?;
         self.padding_bounds.set_size(self.widgets.padding_size(padding_area_size));
@@ -496,13 +730,13 @@ impl Draw for ToplevelWindow
         let mut is_title_bar = false;
         let mut bounds = Rect::new(0, 0, self.size.width, self.size.height);
         match &self.widgets.title_bar {
-            Some(title_bar) => {
+            Some(title_bar) if !self.widgets.has_hidden_title_bar => {
                 theme.draw_toplevel_window_title_bar_bg(cairo_context, Rect::new(0, 0, self.size.width, title_bar.margin_y() + title_bar.margin_height()), is_focused_window)?;
                 bounds.y += title_bar.margin_y() + title_bar.margin_height();
                 bounds.height -= title_bar.margin_y() + title_bar.margin_height(); 
                 is_title_bar = true;
             },
-            None => (),
+            _ => (),
         }
         theme.draw_toplevel_window_content_bg(cairo_context, bounds, is_focused_window, is_title_bar)?;
         self.widgets.draw(cairo_context, theme, is_focused_window)?;
@@ -514,7 +748,9 @@ impl CallOn for ToplevelWindow
 {
     fn call_on(&mut self, client_context: &mut ClientContext, queue_context: &mut QueueContext, event: &Event) -> Option<Option<Event>>
     {
-        let default_event = if let Some(tmp_default_event) = default_window_on(self, client_context, queue_context, event)? {
+        let default_event = if let Some(tmp_default_event) = self.default_toplevel_window_on(queue_context, event)? {
+            tmp_default_event
+        } else if let Some(tmp_default_event) = default_window_on(self, client_context, queue_context, event)? {
             tmp_default_event
         } else {
             None
@@ -537,6 +773,7 @@ mod tests
 {
     use super::*;
     use crate::mocks::*;
+    use crate::window_context::*;
 
     #[test]
     fn test_two_window_widgets_update_size_and_position()
@@ -562,7 +799,7 @@ mod tests
         window.set(button);
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match window.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -593,4 +830,190 @@ mod tests
         assert_eq!(Pos::new(4, 4 + (font_height.ceil() as i32) + 8), window.widgets.content.as_ref().unwrap().margin_pos());
         assert_eq!(Pos::new(4 + 2, 4 + (font_height.ceil() as i32) + 8 + 2), window.widgets.content.as_ref().unwrap().pos());
     }
+
+    #[test]
+    fn test_toplevel_window_update_size_and_position_for_server_decoration_mode()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_toplevel_window_edges(Edges::new(4, 4, 4, 4));
+        theme.set_title_margin_edges(Edges::new(0, 0, 0, 0));
+        theme.set_title_padding_edges(Edges::new(4, 4, 2, 2));
+        theme.set_title_font_size(16.0);
+        theme.set_title_button_margin_edges(Edges::new(2, 2, 2, 2));
+        theme.set_title_button_padding_edges(Edges::new(4, 4, 4, 4));
+        theme.set_title_button_icon_size(Size::new(12, 12));
+        theme.set_button_margin_edges(Edges::new(2, 2, 2, 2));
+        theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
+        theme.set_button_font_size(16.0);
+        let mut window = ToplevelWindow::new().unwrap();
+        window.set_title("T");
+        let mut button = Button::new("B");
+        button.set_preferred_size(Size::new(Some(120), Some(60)));
+        window.set(button);
+        window.set_decoration_mode(ClientDecorationMode::Server);
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match window.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Edges::new(0, 0, 0, 0), window.edges);
+        assert_eq!(Size::new(124, 64), window.padding_bounds.size());
+        assert_eq!(Size::new(124, 64), window.size);
+        assert_eq!(Size::new(124, 64), window.widgets.content.as_ref().unwrap().margin_size());
+        assert_eq!(Size::new(120, 60), window.widgets.content.as_ref().unwrap().size());
+        let area_bounds = Rect::new(0, 0, window.size.width, window.size.height);
+        match window.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Pos::new(0, 0), window.padding_bounds.pos());
+        assert_eq!(Pos::new(0, 0), window.widgets.content.as_ref().unwrap().margin_pos());
+        assert_eq!(Pos::new(2, 2), window.widgets.content.as_ref().unwrap().pos());
+    }
+
+    #[test]
+    fn test_toplevel_window_update_size_and_position_for_fullscreen()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_toplevel_window_edges(Edges::new(4, 4, 4, 4));
+        theme.set_title_margin_edges(Edges::new(0, 0, 0, 0));
+        theme.set_title_padding_edges(Edges::new(4, 4, 2, 2));
+        theme.set_title_font_size(16.0);
+        theme.set_title_button_margin_edges(Edges::new(2, 2, 2, 2));
+        theme.set_title_button_padding_edges(Edges::new(4, 4, 4, 4));
+        theme.set_title_button_icon_size(Size::new(12, 12));
+        theme.set_button_margin_edges(Edges::new(2, 2, 2, 2));
+        theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
+        theme.set_button_font_size(16.0);
+        let mut window = ToplevelWindow::new().unwrap();
+        window.set_title("T");
+        let mut button = Button::new("B");
+        button.set_preferred_size(Size::new(Some(120), Some(60)));
+        window.set(button);
+        window.set_fullscreen(true, None);
+        assert_eq!(true, window.is_fullscreen());
+        assert_eq!(false, window.is_resizable());
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match window.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Edges::new(0, 0, 0, 0), window.edges);
+        assert_eq!(Size::new(124, 64), window.padding_bounds.size());
+        assert_eq!(Size::new(124, 64), window.size);
+        let area_bounds = Rect::new(0, 0, window.size.width, window.size.height);
+        match window.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Pos::new(0, 0), window.padding_bounds.pos());
+        assert_eq!(Pos::new(2, 2), window.widgets.content.as_ref().unwrap().pos());
+        window.set_fullscreen(false, None);
+        match window.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Edges::new(4, 4, 4, 4), window.edges);
+        assert_eq!(false, window.widgets.has_hidden_title_bar);
+    }
+
+    #[test]
+    fn test_toplevel_window_adds_dirty_rects_for_changed_widget_states()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_toplevel_window_edges(Edges::new(4, 4, 4, 4));
+        theme.set_button_margin_edges(Edges::new(2, 2, 2, 2));
+        theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
+        theme.set_button_font_size(16.0);
+        let mut window = ToplevelWindow::new().unwrap();
+        window.set_decoration_mode(ClientDecorationMode::Server);
+        let mut layout = LinearLayout::new();
+        let mut button1 = Button::new("B1");
+        button1.set_preferred_size(Size::new(Some(120), Some(60)));
+        layout.add(button1);
+        let mut button2 = Button::new("B2");
+        button2.set_preferred_size(Size::new(Some(120), Some(60)));
+        layout.add(button2);
+        window.set(layout);
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match window.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        let area_bounds = Rect::new(0, 0, window.size.width, window.size.height);
+        match window.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        window.clear_change_flag();
+        assert_eq!(false, window.is_dirty());
+        let button2 = window.dyn_widget_mut_for_index_pair(WidgetIndexPair(1, 0)).unwrap().dyn_widget_mut_for_index_pair(WidgetIndexPair(1, 0)).unwrap();
+        button2.set_state(WidgetState::Hover);
+        let button2_margin_bounds = button2.margin_bounds();
+        assert_eq!(false, window.is_changed());
+        assert_eq!(true, window.is_dirty());
+        let mut dirty_rects: Vec<Rect<i32>> = Vec::new();
+        window.add_descendant_dirty_rects(&mut dirty_rects);
+        assert_eq!(vec![button2_margin_bounds], dirty_rects);
+        window.clear_dirty_flag();
+        window.clear_descendant_dirty_rects();
+        assert_eq!(false, window.is_dirty());
+        let mut dirty_rects2: Vec<Rect<i32>> = Vec::new();
+        window.add_descendant_dirty_rects(&mut dirty_rects2);
+        assert_eq!(true, dirty_rects2.is_empty());
+    }
+
+    #[test]
+    fn test_toplevel_window_opens_menu_bar_item_menu_for_alt_key_with_mnemonic()
+    {
+        let mut window_context = WindowContext::new(Box::new(MockTheme::new()));
+        let mut window = ToplevelWindow::new().unwrap();
+        let mut menu_bar = MenuBar::new();
+        let mut item1 = MenuBarItem::new("_File");
+        item1.set_menu_index(Some(WindowIndex(1)));
+        menu_bar.add(item1);
+        let mut item2 = MenuBarItem::new("_Edit");
+        item2.set_menu_index(Some(WindowIndex(2)));
+        menu_bar.add(item2);
+        window.set_menu_bar(menu_bar);
+        let window_idx = window_context.window_container_mut().add(window).unwrap();
+        let mut menu1 = Menu::new();
+        menu1.add(MenuItem::new("_Open"));
+        window_context.window_container_mut().add(menu1);
+        let mut menu2 = Menu::new();
+        menu2.add(MenuItem::new("_Copy"));
+        window_context.window_container_mut().add(menu2);
+        let mut queue_context = QueueContext::new();
+        queue_context.current_call_on_path = Some(CallOnPath::Window(window_idx));
+        let window: &mut ToplevelWindow = window_context.window_mut(window_idx).unwrap();
+        match window.default_toplevel_window_on(&mut queue_context, &Event::Key(VKey::X, KeyModifiers::ALT)) {
+            Some(None) => (),
+            _ => assert!(false),
+        }
+        assert_eq!(true, queue_context.callback_queue().is_empty());
+        match window.default_toplevel_window_on(&mut queue_context, &Event::Key(VKey::E, KeyModifiers::ALT)) {
+            Some(Some(None)) => (),
+            _ => assert!(false),
+        }
+        assert_eq!(false, queue_context.callback_queue().is_empty());
+        // The pushed callback opens the menu of the menu bar item for the mnemonic.
+        let idx_pair = window.menu_bar().and_then(|mb| mb.item_index_pair_for_mnemonic(VKey::E.to_char().unwrap())).unwrap();
+        let mut path = AbsWidgetPath::new(window_idx, WidgetIndexPair(2, 0));
+        path.push(idx_pair);
+        assert_eq!(Some(()), open_menu_bar_item_menu(&mut window_context, &path, true));
+        assert_eq!(None, window_context.dyn_window(WindowIndex(1)).unwrap().parent_index());
+        assert_eq!(Some(window_idx), window_context.dyn_window(WindowIndex(2)).unwrap().parent_index());
+    }
 }
@@ -15,10 +15,14 @@ pub struct TwoWindowWidgets
 {
     pub has_trimmed_width: bool,
     pub has_trimmed_height: bool,
+    pub has_hidden_title_bar: bool,
     pub title_bar: Option<Box<dyn Widget>>,
     pub content: Option<Box<dyn Widget>>,
+    pub menu_bar: Option<Box<dyn Widget>>,
 }
 
+const WIDGET_INDEX_PAIRS: [WidgetIndexPair; 3] = [WidgetIndexPair(0, 0), WidgetIndexPair(2, 0), WidgetIndexPair(1, 0)];
+
 impl TwoWindowWidgets
 {
     pub fn new() -> Self
@@ -26,55 +30,51 @@ impl TwoWindowWidgets
         TwoWindowWidgets {
             has_trimmed_width: false,
             has_trimmed_height: false,
+            has_hidden_title_bar: false,
             title_bar: None,
             content: None,
+            menu_bar: None,
         }
     }
 
-    pub fn prev(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
+    fn has_title_bar(&self) -> bool
+    { self.title_bar.is_some() && !self.has_hidden_title_bar }
+
+    fn has_widget(&self, idx_pair: WidgetIndexPair) -> bool
     {
         match idx_pair {
-            None => {
-                if self.content.is_some() {
-                    Some(WidgetIndexPair(1, 0))
-                } else if self.title_bar.is_some() {
-                    Some(WidgetIndexPair(0, 0))
-                } else {
-                    None
-                }
-            },
-            Some(WidgetIndexPair(1, 0)) => {
-                if self.content.is_some() && self.title_bar.is_some() {
-                    Some(WidgetIndexPair(0, 0))
-                } else {
-                    None
-                }
-            },
-            _ => None, 
+            WidgetIndexPair(0, 0) => self.has_title_bar(),
+            WidgetIndexPair(1, 0) => self.content.is_some(),
+            WidgetIndexPair(2, 0) => self.menu_bar.is_some(),
+            _ => false,
+        }
+    }
+
+    fn widget_index_pair_index(&self, idx_pair: WidgetIndexPair) -> Option<usize>
+    {
+        if self.has_widget(idx_pair) {
+            WIDGET_INDEX_PAIRS.iter().position(|p| *p == idx_pair)
+        } else {
+            None
         }
     }
+
+    pub fn prev(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
+    {
+        let i = match idx_pair {
+            Some(idx_pair) => self.widget_index_pair_index(idx_pair)?,
+            None => WIDGET_INDEX_PAIRS.len(),
+        };
+        WIDGET_INDEX_PAIRS[..i].iter().rev().find(|p| self.has_widget(**p)).copied()
+    }
     
     pub fn next(&self, idx_pair: Option<WidgetIndexPair>) -> Option<WidgetIndexPair>
     {
-        match idx_pair {
-            None => {
-                if self.title_bar.is_some() {
-                    Some(WidgetIndexPair(0, 0))
-                } else if self.content.is_some() {
-                    Some(WidgetIndexPair(1, 0))
-                } else {
-                    None
-                }
-            },
-            Some(WidgetIndexPair(0, 0)) => {
-                if self.title_bar.is_some() && self.content.is_some() {
-                    Some(WidgetIndexPair(1, 0))
-                } else {
-                    None
-                }
-            },
-            _ => None, 
-        }
+        let i = match idx_pair {
+            Some(idx_pair) => self.widget_index_pair_index(idx_pair)? + 1,
+            None => 0,
+        };
+        WIDGET_INDEX_PAIRS[i..].iter().find(|p| self.has_widget(**p)).copied()
     }
     
     pub fn dyn_widget(&self, idx_pair: WidgetIndexPair) -> Option<&dyn Widget>
@@ -92,6 +92,12 @@ impl TwoWindowWidgets
                     None => None,
                 }
             },
+            WidgetIndexPair(2, 0) => {
+                match &self.menu_bar {
+                    Some(menu_bar) => Some(&**menu_bar),
+                    None => None,
+                }
+            },
             _ => None,
         }
     }
@@ -111,43 +117,35 @@ impl TwoWindowWidgets
                     None => None,
                 }
             },
+            WidgetIndexPair(2, 0) => {
+                match &mut self.menu_bar {
+                    Some(menu_bar) => Some(&mut **menu_bar),
+                    None => None,
+                }
+            },
             _ => None,
         }
     }
 
     pub fn point(&self, pos: Pos<f64>) -> Option<WidgetIndexPair>
     {
-        let idx_pair = match &self.title_bar {
-            Some(title_bar) => {
-                if title_bar.bounds().to_f64_rect().contains(pos) {
-                    Some(WidgetIndexPair(0, 0))
-                } else {
-                    None
+        for idx_pair in &WIDGET_INDEX_PAIRS {
+            if self.has_widget(*idx_pair) {
+                match self.dyn_widget(*idx_pair) {
+                    Some(widget) if widget.bounds().to_f64_rect().contains(pos) => return Some(*idx_pair),
+                    _ => (),
                 }
-            },
-            None => None,
-        };
-        if idx_pair.is_none() {
-            match &self.content {
-                Some(content) => {
-                    if content.bounds().to_f64_rect().contains(pos) {
-                        Some(WidgetIndexPair(1, 0))
-                    } else {
-                        None
-                    }
-                },
-                None => None,
             }
-        } else {
-            idx_pair
         }
+        None
     }
     
     pub fn update_size(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_size: Size<Option<i32>>) -> Result<(), CairoError>
     {
         let mut area_size2 = area_size;
+        let has_hidden_title_bar = self.has_hidden_title_bar;
         match &mut self.title_bar {
-            Some(title_bar) => {
+            Some(title_bar) if !has_hidden_title_bar => {
                 title_bar.update_size(cairo_context, theme, area_size)?;
                 match area_size2.height {
                     Some(area_height2) => {
@@ -160,16 +158,54 @@ impl TwoWindowWidgets
                     None => (),
                 }
             },
+            _ => (),
+        }
+        match &mut self.menu_bar {
+            Some(menu_bar) => {
+                menu_bar.update_size(cairo_context, theme, area_size2)?;
+                match area_size2.height {
+                    Some(area_height2) => {
+                        if area_height2 >= menu_bar.margin_height() {
+                            area_size2.height = Some(area_height2 - menu_bar.margin_height());
+                        } else {
+                            area_size2.height = Some(0);
+                        }
+                    },
+                    None => (),
+                }
+            },
             None => (),
         }
         match &mut self.content {
             Some(content) => content.update_size(cairo_context, theme, area_size2)?,
             None => (),
         }
-        match (&mut self.title_bar, &self.content) {
-            (Some(title_bar), Some(content)) => {
+        match (&mut self.menu_bar, &self.content) {
+            (Some(menu_bar), Some(content)) => {
                 if area_size.width.is_none() || self.has_trimmed_width {
-                    let area_size3 = Size::new(Some(content.margin_width()), Some(title_bar.margin_height()));
+                    let area_size3 = Size::new(Some(max(menu_bar.margin_width(), content.margin_width())), Some(menu_bar.margin_height()));
+                    menu_bar.update_size(cairo_context, theme, area_size3)?;
+                }
+            },
+            (Some(menu_bar), None) => {
+                if self.has_trimmed_width {
+                    let area_size3 = Size::new(Some(0), Some(menu_bar.margin_height()));
+                    menu_bar.update_size(cairo_context, theme, area_size3)?;
+                }
+            },
+            _ => (),
+        }
+        let width = match (&self.menu_bar, &self.content) {
+            (Some(menu_bar), Some(content)) => Some(max(menu_bar.margin_width(), content.margin_width())),
+            (Some(menu_bar), None) => Some(menu_bar.margin_width()),
+            (None, Some(content)) => Some(content.margin_width()),
+            (None, None) => None,
+        };
+        match (&mut self.title_bar, width) {
+            (Some(_), _) if has_hidden_title_bar => (),
+            (Some(title_bar), Some(width)) => {
+                if area_size.width.is_none() || self.has_trimmed_width {
+                    let area_size3 = Size::new(Some(width), Some(title_bar.margin_height()));
                     title_bar.update_size(cairo_context, theme, area_size3)?;
                 }
             },
@@ -187,13 +223,23 @@ impl TwoWindowWidgets
     pub fn update_pos(&mut self, cairo_context: &CairoContext, theme: &dyn Theme, area_bounds: Rect<i32>) -> Result<(), CairoError>
     {
         let mut area_bounds2 = area_bounds;
+        let has_hidden_title_bar = self.has_hidden_title_bar;
         match &mut self.title_bar {
-            Some(title_bar) => {
+            Some(title_bar) if !has_hidden_title_bar => {
                 let area_bounds3 = Rect::new(area_bounds.x, area_bounds.y, area_bounds.width, title_bar.margin_height());
                 title_bar.update_pos(cairo_context, theme, area_bounds3)?;
                 area_bounds2.y += title_bar.margin_height();
                 area_bounds2.height -= title_bar.margin_height();
             },
+            _ => (),
+        }
+        match &mut self.menu_bar {
+            Some(menu_bar) => {
+                let area_bounds3 = Rect::new(area_bounds2.x, area_bounds2.y, area_bounds2.width, menu_bar.margin_height());
+                menu_bar.update_pos(cairo_context, theme, area_bounds3)?;
+                area_bounds2.y += menu_bar.margin_height();
+                area_bounds2.height -= menu_bar.margin_height();
+            },
             None => (),
         }
         match &mut self.content {
@@ -206,24 +252,34 @@ impl TwoWindowWidgets
     pub fn draw(&self, cairo_context: &CairoContext, theme: &dyn Theme, is_focused_window: bool) -> Result<(), CairoError>
     {
         match &self.title_bar {
-            Some(title_bar) => title_bar.draw(cairo_context, theme, is_focused_window)?,
-            None => (),
+            Some(title_bar) if !self.has_hidden_title_bar && is_in_cairo_clip(cairo_context, title_bar.margin_bounds())? => title_bar.draw(cairo_context, theme, is_focused_window)?,
+            _ => (),
+        }
+        match &self.menu_bar {
+            Some(menu_bar) if is_in_cairo_clip(cairo_context, menu_bar.margin_bounds())? => menu_bar.draw(cairo_context, theme, is_focused_window)?,
+            _ => (),
         }
         match &self.content {
-            Some(content) => content.draw(cairo_context, theme, is_focused_window)?,
-            None => (),
+            Some(content) if is_in_cairo_clip(cairo_context, content.margin_bounds())? => content.draw(cairo_context, theme, is_focused_window)?,
+            _ => (),
         }
         Ok(())
     }
     
     pub fn padding_size(&self, area_size: Size<Option<i32>>) -> Size<i32>
     {
-        let size = match (&self.title_bar, &self.content) {
-            (Some(title_bar), Some(content)) => Size::new(max(title_bar.margin_width(), content.margin_width()), title_bar.margin_height() + content.margin_height()),
-            (Some(title_bar), None) => Size::new(title_bar.margin_width(), title_bar.margin_height()),
-            (None, Some(content)) => Size::new(content.margin_width(), content.margin_height()),
-            (None, None) => Size::new(0, 0),
-        };
+        let mut size = Size::new(0, 0);
+        for idx_pair in &WIDGET_INDEX_PAIRS {
+            if self.has_widget(*idx_pair) {
+                match self.dyn_widget(*idx_pair) {
+                    Some(widget) => {
+                        size.width = max(size.width, widget.margin_width());
+                        size.height += widget.margin_height();
+                    },
+                    None => (),
+                }
+            }
+        }
         let width = if self.has_trimmed_width {
             size.width
         } else {
@@ -463,7 +519,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match widgets.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -515,7 +571,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match widgets.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -565,7 +621,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match widgets.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -617,7 +673,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match widgets.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -667,7 +723,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 120 + 4 + 10;
         let area_size = Size::new(Some(area_width), None);
         match widgets.update_size(&cairo_context, &theme, area_size) {
@@ -720,7 +776,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 120 + 4 + 10;
         let area_size = Size::new(Some(area_width), None);
         match widgets.update_size(&cairo_context, &theme, area_size) {
@@ -772,7 +828,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 120 + 4 + 10;
         let area_size = Size::new(Some(area_width), None);
         match widgets.update_size(&cairo_context, &theme, area_size) {
@@ -826,7 +882,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 120 + 4 + 10;
         let area_size = Size::new(Some(area_width), None);
         match widgets.update_size(&cairo_context, &theme, area_size) {
@@ -877,7 +933,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = (font_height.ceil() as i32) + 8 + 60 + 4 + 10;
         let area_size = Size::new(None, Some(area_height));
         match widgets.update_size(&cairo_context, &theme, area_size) {
@@ -930,7 +986,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = (font_height.ceil() as i32) + 8 + 60 + 4 + 10;
         let area_size = Size::new(None, Some(area_height));
         match widgets.update_size(&cairo_context, &theme, area_size) {
@@ -982,7 +1038,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = (font_height.ceil() as i32) + 8 + 60 + 4 + 10;
         let area_size = Size::new(None, Some(area_height));
         match widgets.update_size(&cairo_context, &theme, area_size) {
@@ -1036,7 +1092,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = (font_height.ceil() as i32) + 8 + 60 + 4 + 10;
         let area_size = Size::new(None, Some(area_height));
         match widgets.update_size(&cairo_context, &theme, area_size) {
@@ -1086,7 +1142,7 @@ mod tests
         let t = cairo_context.text_extents("T").unwrap().x_advance;
         let text_width = t;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match widgets.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -1124,7 +1180,7 @@ mod tests
         let mut button = Button::new("B");
         button.set_preferred_size(Size::new(Some(120), Some(60)));
         widgets.content = Some(Box::new(button));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match widgets.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -1159,7 +1215,7 @@ mod tests
         theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
         theme.set_button_font_size(16.0);
         let mut widgets = TwoWindowWidgets::new();
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         match widgets.update_size(&cairo_context, &theme, area_size) {
             Ok(()) => (),
@@ -1173,6 +1229,58 @@ mod tests
         }
     }
 
+    #[test]
+    fn test_two_window_widgets_update_size_and_position_for_hidden_title_bar()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_title_margin_edges(Edges::new(0, 0, 0, 0));
+        theme.set_title_padding_edges(Edges::new(4, 4, 2, 2));
+        theme.set_title_font_size(16.0);
+        theme.set_title_button_margin_edges(Edges::new(2, 2, 2, 2));
+        theme.set_title_button_padding_edges(Edges::new(4, 4, 4, 4));
+        theme.set_title_button_icon_size(Size::new(12, 12));
+        theme.set_button_margin_edges(Edges::new(2, 2, 2, 2));
+        theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
+        theme.set_button_font_size(16.0);
+        let mut widgets = TwoWindowWidgets::new();
+        let mut title_bar = TitleBar::new();
+        title_bar.add(TitleButton::new(TitleButtonIcon::Menu));
+        title_bar.add(Title::new("T"));
+        title_bar.add(TitleButton::new(TitleButtonIcon::Maximize));
+        title_bar.add(TitleButton::new(TitleButtonIcon::Close));
+        widgets.title_bar = Some(Box::new(title_bar));
+        widgets.has_hidden_title_bar = true;
+        let mut button = Button::new("B");
+        button.set_preferred_size(Size::new(Some(120), Some(60)));
+        widgets.content = Some(Box::new(button));
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match widgets.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Size::new(124, 64), widgets.content.as_ref().unwrap().margin_size());
+        assert_eq!(Size::new(120, 60), widgets.content.as_ref().unwrap().size());
+        let padding_size = widgets.padding_size(area_size);
+        assert_eq!(Size::new(124, 64), padding_size);
+        let area_bounds = Rect::new(20, 10, padding_size.width, padding_size.height);
+        match widgets.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Pos::new(20, 10), widgets.content.as_ref().unwrap().margin_pos());
+        assert_eq!(Pos::new(20 + 2, 10 + 2), widgets.content.as_ref().unwrap().pos());
+        assert_eq!(Some(WidgetIndexPair(1, 0)), widgets.next(None));
+        assert_eq!(None, widgets.prev(Some(WidgetIndexPair(1, 0))));
+        match widgets.point(Pos::new(25.0, 15.0)) {
+            Some(idx_pair) => assert_eq!(WidgetIndexPair(1, 0), idx_pair),
+            None => assert!(false),
+        }
+    }
+
     #[test]
     fn test_two_window_widgets_give_padding_size()
     {
@@ -1201,7 +1309,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         widgets.update_size(&cairo_context, &theme, area_size).unwrap();
         let padding_size = widgets.padding_size(area_size);
@@ -1237,7 +1345,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         widgets.update_size(&cairo_context, &theme, area_size).unwrap();
         let padding_size = widgets.padding_size(area_size);
@@ -1273,7 +1381,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 120 + 4 + 10;
         let area_size = Size::new(Some(area_width), None);
         widgets.update_size(&cairo_context, &theme, area_size).unwrap();
@@ -1311,7 +1419,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_width = 120 + 4 + 10;
         let area_size = Size::new(Some(area_width), None);
         widgets.update_size(&cairo_context, &theme, area_size).unwrap();
@@ -1348,7 +1456,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = (font_height.ceil() as i32) + 8 + 60 + 4 + 10;
         let area_size = Size::new(None, Some(area_height));
         widgets.update_size(&cairo_context, &theme, area_size).unwrap();
@@ -1387,7 +1495,7 @@ mod tests
         widgets.content = Some(Box::new(button));
         theme.set_title_font(&cairo_context).unwrap();
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_height = (font_height.ceil() as i32) + 8 + 60 + 4 + 10;
         let area_size = Size::new(None, Some(area_height));
         widgets.update_size(&cairo_context, &theme, area_size).unwrap();
@@ -1423,7 +1531,7 @@ mod tests
         let t = cairo_context.text_extents("T").unwrap().x_advance;
         let text_width = t;
         let font_height = cairo_context.font_extents().unwrap().height;
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         widgets.update_size(&cairo_context, &theme, area_size).unwrap();
         let padding_size = widgets.padding_size(area_size);
@@ -1451,7 +1559,7 @@ mod tests
         let mut button = Button::new("B");
         button.set_preferred_size(Size::new(Some(120), Some(60)));
         widgets.content = Some(Box::new(button));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         widgets.update_size(&cairo_context, &theme, area_size).unwrap();
         let padding_size = widgets.padding_size(area_size);
@@ -1477,7 +1585,7 @@ mod tests
         theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
         theme.set_button_font_size(16.0);
         let mut widgets = TwoWindowWidgets::new();
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         widgets.update_size(&cairo_context, &theme, area_size).unwrap();
         let padding_size = widgets.padding_size(area_size);
@@ -1511,7 +1619,7 @@ mod tests
         let mut button = Button::new("B");
         button.set_preferred_size(Size::new(Some(120), Some(60)));
         widgets.content = Some(Box::new(button));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         widgets.update_size(&cairo_context, &theme, area_size).unwrap();
         let padding_size = widgets.padding_size(area_size);
@@ -1553,7 +1661,7 @@ mod tests
         let mut button = Button::new("B");
         button.set_preferred_size(Size::new(Some(120), Some(60)));
         widgets.content = Some(Box::new(button));
-        theme.set_cairo_context(&cairo_context, 1).unwrap();
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
         let area_size = Size::new(None, None);
         widgets.update_size(&cairo_context, &theme, area_size).unwrap();
         let padding_size = widgets.padding_size(area_size);
@@ -1564,4 +1672,84 @@ mod tests
             None => assert!(true),
         }
     }
+
+    #[test]
+    fn test_two_window_widgets_give_previous_and_next_widget_index_pairs_for_menu_bar()
+    {
+        let mut widgets = TwoWindowWidgets::new();
+        widgets.title_bar = Some(Box::new(TitleBar::new()));
+        widgets.menu_bar = Some(Box::new(MenuBar::new()));
+        widgets.content = Some(Box::new(Button::new("B")));
+        let mut idx_pair: Option<WidgetIndexPair> = None;
+        idx_pair = widgets.prev(idx_pair);
+        assert_eq!(Some(WidgetIndexPair(1, 0)), idx_pair);
+        idx_pair = widgets.prev(idx_pair);
+        assert_eq!(Some(WidgetIndexPair(2, 0)), idx_pair);
+        idx_pair = widgets.prev(idx_pair);
+        assert_eq!(Some(WidgetIndexPair(0, 0)), idx_pair);
+        idx_pair = widgets.prev(idx_pair);
+        assert_eq!(None, idx_pair);
+        idx_pair = widgets.next(idx_pair);
+        assert_eq!(Some(WidgetIndexPair(0, 0)), idx_pair);
+        idx_pair = widgets.next(idx_pair);
+        assert_eq!(Some(WidgetIndexPair(2, 0)), idx_pair);
+        idx_pair = widgets.next(idx_pair);
+        assert_eq!(Some(WidgetIndexPair(1, 0)), idx_pair);
+        idx_pair = widgets.next(idx_pair);
+        assert_eq!(None, idx_pair);
+    }
+
+    #[test]
+    fn test_two_window_widgets_update_size_and_position_for_menu_bar()
+    {
+        let cairo_surface = create_dummy_cairo_surface().unwrap();
+        let cairo_context = CairoContext::new(&cairo_surface).unwrap();
+        let mut theme = MockTheme::new();
+        theme.set_font_size(32.0);
+        theme.set_title_margin_edges(Edges::new(0, 0, 0, 0));
+        theme.set_title_padding_edges(Edges::new(4, 4, 2, 2));
+        theme.set_title_font_size(16.0);
+        theme.set_menu_bar_item_margin_edges(Edges::new(0, 0, 0, 0));
+        theme.set_menu_bar_item_padding_edges(Edges::new(2, 2, 4, 4));
+        theme.set_menu_bar_item_font_size(16.0);
+        theme.set_button_margin_edges(Edges::new(2, 2, 2, 2));
+        theme.set_button_padding_edges(Edges::new(4, 4, 4, 4));
+        theme.set_button_font_size(16.0);
+        let mut widgets = TwoWindowWidgets::new();
+        let mut title_bar = TitleBar::new();
+        title_bar.add(Title::new("T"));
+        widgets.title_bar = Some(Box::new(title_bar));
+        let mut menu_bar = MenuBar::new();
+        menu_bar.add(MenuBarItem::new("_F"));
+        widgets.menu_bar = Some(Box::new(menu_bar));
+        let mut button = Button::new("B");
+        button.set_preferred_size(Size::new(Some(120), Some(60)));
+        widgets.content = Some(Box::new(button));
+        theme.set_title_font(&cairo_context).unwrap();
+        let title_font_height = cairo_context.font_extents().unwrap().height;
+        theme.set_menu_bar_item_font(&cairo_context).unwrap();
+        let menu_bar_item_font_height = cairo_context.font_extents().unwrap().height;
+        theme.set_cairo_context(&cairo_context, 1.0).unwrap();
+        let area_size = Size::new(None, None);
+        match widgets.update_size(&cairo_context, &theme, area_size) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        let title_bar_height = (title_font_height.ceil() as i32) + 8;
+        let menu_bar_height = (menu_bar_item_font_height.ceil() as i32) + 4;
+        assert_eq!(Size::new(124, title_bar_height), widgets.title_bar.as_ref().unwrap().margin_size());
+        assert_eq!(Size::new(124, menu_bar_height), widgets.menu_bar.as_ref().unwrap().margin_size());
+        assert_eq!(Size::new(124, 64), widgets.content.as_ref().unwrap().margin_size());
+        let padding_size = widgets.padding_size(area_size);
+        assert_eq!(Size::new(124, title_bar_height + menu_bar_height + 64), padding_size);
+        let area_bounds = Rect::new(20, 10, padding_size.width, padding_size.height);
+        match widgets.update_pos(&cairo_context, &theme, area_bounds) {
+            Ok(()) => (),
+            Err(_) => assert!(false),
+        }
+        assert_eq!(Pos::new(20, 10), widgets.title_bar.as_ref().unwrap().margin_pos());
+        assert_eq!(Pos::new(20, 10 + title_bar_height), widgets.menu_bar.as_ref().unwrap().margin_pos());
+        assert_eq!(Pos::new(20, 10 + title_bar_height + menu_bar_height), widgets.content.as_ref().unwrap().margin_pos());
+        assert_eq!(Some(WidgetIndexPair(2, 0)), widgets.point(Pos::new(30.0, (10 + title_bar_height + 1) as f64)));
+    }
 }